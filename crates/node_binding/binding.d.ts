@@ -280,6 +280,12 @@ export declare class JsCompilation {
   getAssetPathWithInfo(filename: string, data: JsPathData): PathWithInfo
   getPath(filename: string, data: JsPathData): string
   getPathWithInfo(filename: string, data: JsPathData): PathWithInfo
+  /**
+   * Maps the frames of a stack trace back to the original sources with the
+   * source maps emitted by this compilation. Frames that can't be mapped are
+   * kept as they are.
+   */
+  symbolicateStackTrace(stack: string): string
   addFileDependencies(deps: Array<string>): void
   addContextDependencies(deps: Array<string>): void
   addMissingDependencies(deps: Array<string>): void
@@ -466,6 +472,7 @@ export declare enum BuiltinPluginName {
   WebWorkerTemplatePlugin = 'WebWorkerTemplatePlugin',
   MergeDuplicateChunksPlugin = 'MergeDuplicateChunksPlugin',
   SplitChunksPlugin = 'SplitChunksPlugin',
+  GranularChunksPlugin = 'GranularChunksPlugin',
   RemoveDuplicateModulesPlugin = 'RemoveDuplicateModulesPlugin',
   ShareRuntimePlugin = 'ShareRuntimePlugin',
   ContainerPlugin = 'ContainerPlugin',
@@ -473,13 +480,18 @@ export declare enum BuiltinPluginName {
   ProvideSharedPlugin = 'ProvideSharedPlugin',
   ConsumeSharedPlugin = 'ConsumeSharedPlugin',
   ModuleFederationRuntimePlugin = 'ModuleFederationRuntimePlugin',
+  ModuleFederationManifestPlugin = 'ModuleFederationManifestPlugin',
+  SharedVersionCheckPlugin = 'SharedVersionCheckPlugin',
+  ModuleFederationTypesPlugin = 'ModuleFederationTypesPlugin',
   NamedModuleIdsPlugin = 'NamedModuleIdsPlugin',
   NaturalModuleIdsPlugin = 'NaturalModuleIdsPlugin',
   DeterministicModuleIdsPlugin = 'DeterministicModuleIdsPlugin',
+  HashedModuleIdsPlugin = 'HashedModuleIdsPlugin',
   NaturalChunkIdsPlugin = 'NaturalChunkIdsPlugin',
   NamedChunkIdsPlugin = 'NamedChunkIdsPlugin',
   DeterministicChunkIdsPlugin = 'DeterministicChunkIdsPlugin',
   OccurrenceChunkIdsPlugin = 'OccurrenceChunkIdsPlugin',
+  RecordIdsPlugin = 'RecordIdsPlugin',
   RealContentHashPlugin = 'RealContentHashPlugin',
   RemoveEmptyChunksPlugin = 'RemoveEmptyChunksPlugin',
   EnsureChunkConditionsPlugin = 'EnsureChunkConditionsPlugin',
@@ -495,6 +507,7 @@ export declare enum BuiltinPluginName {
   SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   EvalSourceMapDevToolPlugin = 'EvalSourceMapDevToolPlugin',
   EvalDevToolModulePlugin = 'EvalDevToolModulePlugin',
+  SourceMapValidationPlugin = 'SourceMapValidationPlugin',
   SideEffectsFlagPlugin = 'SideEffectsFlagPlugin',
   FlagDependencyExportsPlugin = 'FlagDependencyExportsPlugin',
   FlagDependencyUsagePlugin = 'FlagDependencyUsagePlugin',
@@ -521,6 +534,9 @@ export declare enum BuiltinPluginName {
   RsdoctorPlugin = 'RsdoctorPlugin',
   RstestPlugin = 'RstestPlugin',
   CircularDependencyRspackPlugin = 'CircularDependencyRspackPlugin',
+  CriticalCssPlugin = 'CriticalCssPlugin',
+  CssModulesTypingsPlugin = 'CssModulesTypingsPlugin',
+  VueLoaderRspackPlugin = 'VueLoaderRspackPlugin',
   JsLoaderRspackPlugin = 'JsLoaderRspackPlugin',
   LazyCompilationPlugin = 'LazyCompilationPlugin',
   ModuleInfoHeaderPlugin = 'ModuleInfoHeaderPlugin',
@@ -1241,6 +1257,7 @@ export interface JsStatsChunk {
   childrenByOrder: Record<string, Array<string>>
   runtime: Array<string>
   reason?: string
+  splitChunks?: JsStatsSplitChunks
   rendered: boolean
   sizes: Array<JsStatsSize>
   origins: Array<JsOriginRecord>
@@ -1328,6 +1345,7 @@ export interface JsStatsModuleCommonAttributes {
   layer?: string
   size: number
   sizes: Array<JsStatsSize>
+  minimizedSize?: number
   built: boolean
   codeGenerated: boolean
   buildTimeExecuted: boolean
@@ -1420,6 +1438,23 @@ export interface JsStatsSize {
   size: number
 }
 
+export interface JsStatsSplitChunks {
+  decisions: Array<JsStatsSplitChunksDecision>
+  maxSizePart?: string
+  blocked: Array<string>
+}
+
+export interface JsStatsSplitChunksDecision {
+  cacheGroup: string
+  name?: string
+  priority: number
+  modules: number
+  minSize: Array<JsStatsSize>
+  maxAsyncSize: Array<JsStatsSize>
+  maxInitialSize: Array<JsStatsSize>
+  reusedExistingChunk: boolean
+}
+
 export interface JsStatsWarning {
   moduleDescriptor?: JsModuleDescriptor
   name?: string
@@ -1568,6 +1603,8 @@ export interface RawCacheGroupOptions {
   key: string
   priority?: number
   test?: RegExp | string | Function
+  /** The object form of `test`, matching the `package.json` of the modules. */
+  packageTest?: RawCacheGroupPackageTest
   filename?: JsFilename
   idHint?: string
   /** What kind of chunks should be selected. */
@@ -1589,6 +1626,13 @@ export interface RawCacheGroupOptions {
   usedExports?: boolean
 }
 
+/** `test: { package: { .. } }` of a cache group. */
+export interface RawCacheGroupPackageTest {
+  name?: string
+  license?: string
+  source?: "node_modules" | "workspace"
+}
+
 export interface RawCacheOptions {
   type: string
   maxGenerations?: number
@@ -1756,6 +1800,12 @@ export interface RawCopyRspackPluginOptions {
   patterns: Array<RawCopyPattern>
 }
 
+export interface RawCriticalCssPluginOptions {
+  preload?: "media" | "swap" | "none"
+  noscriptFallback?: boolean
+  minify?: boolean
+}
+
 export interface RawCssAutoGeneratorOptions {
   exportsConvention?: "as-is" | "camel-case" | "camel-case-only" | "dashes" | "dashes-only"
   exportsOnly?: boolean
@@ -1766,6 +1816,7 @@ export interface RawCssAutoGeneratorOptions {
 export interface RawCssAutoParserOptions {
   namedExports?: boolean
   url?: boolean
+  warnUnusedClasses?: boolean
 }
 
 export interface RawCssExtractPluginOption {
@@ -1795,6 +1846,12 @@ export interface RawCssModuleGeneratorOptions {
 export interface RawCssModuleParserOptions {
   namedExports?: boolean
   url?: boolean
+  warnUnusedClasses?: boolean
+}
+
+export interface RawCssModulesTypingsPluginOptions {
+  /** Emit a json manifest with this filename instead of a `.d.ts` next to each css module. */
+  manifest?: string
 }
 
 export interface RawCssParserOptions {
@@ -1961,6 +2018,21 @@ export interface RawGeneratorOptions {
   json?: RawJsonGeneratorOptions
 }
 
+export interface RawGranularChunksPluginOptions {
+  maxChunks?: number
+  frameworkPackages?: Array<string>
+  minSize?: number
+  maxSize?: number
+}
+
+export interface RawHashedModuleIdsPluginOptions {
+  context?: string
+  hashFunction?: string
+  hashDigest?: string
+  hashDigestLength?: number
+  salt?: string
+}
+
 export interface RawHtmlRspackPluginBaseOptions {
   href?: string
   target?: "_self" | "_blank" | "_parent" | "_top"
@@ -2013,6 +2085,11 @@ export interface RawIgnorePluginOptions {
   checkResource?: (resource: string, context: string) => boolean
 }
 
+export interface RawImportMap {
+  filename: string
+  prefix: string
+}
+
 export interface RawIncremental {
   silent: boolean
   make: boolean
@@ -2166,6 +2243,12 @@ export interface RawLimitChunkCountPluginOptions {
   maxChunks: number
 }
 
+export interface RawManglePropertiesOptions {
+  regex: RegExp
+  reserved?: Array<string>
+  nameCache?: string
+}
+
 export interface RawModuleArg {
   module: string
   path: string
@@ -2185,6 +2268,19 @@ export interface RawModuleFilenameTemplateFnCtx {
   namespace: string
 }
 
+export interface RawModuleFederationManifestPluginOptions {
+  name?: string
+  filename?: string
+  statsFilename?: string
+}
+
+export interface RawModuleFederationTypesPluginOptions {
+  archiveFilename?: string
+  /** `false` to not fetch the types of the remotes */
+  typesFolder?: string | false
+  http?: RawHttpUriPluginOptions
+}
+
 export interface RawModuleInfo {
   active: boolean
   client: string
@@ -2338,6 +2434,7 @@ export interface RawOutputOptions {
   scriptType: "module" | "text/javascript" | false
   environment: RawEnvironment
   compareBeforeEmit: boolean
+  importMap?: RawImportMap
 }
 
 export interface RawParserOptions {
@@ -2376,6 +2473,11 @@ export interface RawProvideOptions {
   strictVersion?: boolean
 }
 
+export interface RawRecordIdsPluginOptions {
+  inputPath?: string
+  outputPath?: string
+}
+
 export interface RawRelated {
   sourceMap?: string
 }
@@ -2453,8 +2555,15 @@ export interface RawRspackFuture {
 
 }
 
+export interface RawRstestCoverageOptions {
+  include?: string | RegExp | (string | RegExp)[]
+  exclude?: string | RegExp | (string | RegExp)[]
+  filename?: string
+}
+
 export interface RawRstestPluginOptions {
   injectModulePathName: boolean
+  coverage?: RawRstestCoverageOptions
 }
 
 export interface RawRuleSetCondition {
@@ -2488,6 +2597,10 @@ export interface RawRuntimeChunkOptions {
   name: string | ((entrypoint: { name: string }) => string)
 }
 
+export interface RawSharedVersionCheckPluginOptions {
+  manifests?: Array<string>
+}
+
 export interface RawSizeLimitsPluginOptions {
   assetFilter?: (assetFilename: string) => boolean
   hints?: "error" | "warning"
@@ -2495,6 +2608,11 @@ export interface RawSizeLimitsPluginOptions {
   maxEntrypointSize?: number
 }
 
+export interface RawSourceMapValidationPluginOptions {
+  minCoverage?: number
+  filename?: string
+}
+
 export interface RawSplitChunkSizes {
   sizes: Record<string, number>
 }
@@ -2513,6 +2631,7 @@ export interface RawSplitChunksOptions {
   defaultSizeTypes: Array<string>
   minChunks?: number
   hidePathInfo?: boolean
+  explain?: boolean
   minSize?: number | RawSplitChunkSizes
   minSizeReduction?: number | RawSplitChunkSizes
   enforceSizeThreshold?: number
@@ -2543,6 +2662,7 @@ export interface RawSwcJsMinimizerOptions {
   format: any
   module?: boolean
   minify?: boolean
+  mangleProperties?: RawManglePropertiesOptions
 }
 
 export interface RawSwcJsMinimizerRspackPluginOptions {
@@ -2551,6 +2671,7 @@ export interface RawSwcJsMinimizerRspackPluginOptions {
   exclude?: string | RegExp | (string | RegExp)[]
   extractComments?: RawExtractComments
   minimizerOptions: RawSwcJsMinimizerOptions
+  sizeReport?: string
 }
 
 export interface RawToOptions {
@@ -2705,6 +2826,7 @@ export interface SourceMapDevToolPluginOptions {
   include?: string | RegExp | (string | RegExp)[]
   exclude?: string | RegExp | (string | RegExp)[]
   debugIds?: boolean
+  ignoreList?: boolean | string | RegExp | (string | RegExp)[]
 }
 
 export interface SourcePosition {
@@ -2720,6 +2842,13 @@ export interface SourcePosition {
  */
 export declare function startAsyncRuntime(): void
 
+/**
+ * Maps the frames of a stack trace back to the original sources with the
+ * source maps of an output directory, and of `sourceMapDirs` for maps kept
+ * apart from the output and matched by debug id.
+ */
+export declare function symbolicateStackTrace(stack: string, outputDir: string, sourceMapDirs?: Array<string> | undefined | null): string
+
 export interface SyntheticDependencyLocation {
   name: string
 }
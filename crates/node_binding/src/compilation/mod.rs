@@ -520,6 +520,21 @@ impl JsCompilation {
     Ok((path, asset_info).into())
   }
 
+  /// Maps the frames of a stack trace back to the original sources with the
+  /// source maps emitted by this compilation. Frames that can't be mapped are
+  /// kept as they are.
+  #[napi]
+  pub fn symbolicate_stack_trace(&self, stack: String) -> Result<String> {
+    let compilation = self.as_ref()?;
+
+    let symbolicator =
+      rspack_plugin_devtool::get_compilation_symbolicator(compilation.id()).to_napi_result()?;
+    Ok(match symbolicator {
+      Some(symbolicator) => symbolicator.symbolicate_stack_trace(&stack),
+      None => stack,
+    })
+  }
+
   #[napi]
   pub fn add_file_dependencies(&mut self, deps: Vec<String>) -> Result<()> {
     let compilation = self.as_mut()?;
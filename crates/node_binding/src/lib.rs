@@ -64,6 +64,7 @@ mod runtime;
 mod source;
 mod stats;
 mod swc;
+mod symbolicate;
 mod utils;
 
 pub use asset::*;
@@ -109,6 +110,7 @@ pub use source::*;
 pub use stats::*;
 pub use swc::*;
 use swc_core::common::util::take::Take;
+pub use symbolicate::*;
 use tracing::level_filters::LevelFilter;
 use tracing_subscriber::{
   layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer, Registry,
@@ -14,7 +14,9 @@ use rspack_hook::plugin_hook;
 use rspack_loader_lightningcss::{config::Config, LIGHTNINGCSS_LOADER_IDENTIFIER};
 use rspack_loader_preact_refresh::PREACT_REFRESH_LOADER_IDENTIFIER;
 use rspack_loader_react_refresh::REACT_REFRESH_LOADER_IDENTIFIER;
+use rspack_loader_sass::SASS_LOADER_IDENTIFIER;
 use rspack_loader_swc::{SwcLoader, SWC_LOADER_IDENTIFIER};
+use rspack_loader_vue::{VUE_LOADER_IDENTIFIER, VUE_STYLE_POST_LOADER_IDENTIFIER};
 use rspack_paths::Utf8Path;
 use rustc_hash::FxHashMap;
 use tokio::sync::RwLock;
@@ -76,6 +78,34 @@ pub async fn get_builtin_loader(builtin: &str, options: Option<&str>) -> Result<
     ));
   }
 
+  if builtin.starts_with(SASS_LOADER_IDENTIFIER) {
+    let config: rspack_loader_sass::config::RawConfig = serde_json::from_str(options.as_ref())
+      .to_rspack_result_with_detail(
+        options.as_ref(),
+        "Could not parse builtin:sass-loader options",
+      )?;
+    return Ok(Arc::new(rspack_loader_sass::SassLoader::new(
+      config.into(),
+      builtin,
+    )));
+  }
+
+  if builtin.starts_with(VUE_STYLE_POST_LOADER_IDENTIFIER) {
+    return Ok(Arc::new(rspack_loader_vue::VueStylePostLoader::default()));
+  }
+
+  if builtin.starts_with(VUE_LOADER_IDENTIFIER) {
+    let config: rspack_loader_vue::config::RawConfig = serde_json::from_str(options.as_ref())
+      .to_rspack_result_with_detail(
+        options.as_ref(),
+        "Could not parse builtin:vue-loader options",
+      )?;
+    return Ok(Arc::new(rspack_loader_vue::VueLoader::new(
+      config.into(),
+      builtin,
+    )));
+  }
+
   if builtin.starts_with(REACT_REFRESH_LOADER_IDENTIFIER) {
     return Ok(Arc::new(
       rspack_loader_react_refresh::ReactRefreshLoader::default().with_identifier(builtin.into()),
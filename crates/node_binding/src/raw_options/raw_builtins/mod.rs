@@ -3,8 +3,11 @@ mod raw_banner;
 mod raw_bundle_info;
 mod raw_circular_dependency;
 mod raw_copy;
+mod raw_critical_css;
 mod raw_css_extract;
+mod raw_css_modules_typings;
 mod raw_dll;
+mod raw_granular_chunks;
 mod raw_html;
 mod raw_http_uri;
 mod raw_ids;
@@ -24,26 +27,33 @@ use napi::{
   Env, Unknown,
 };
 use napi_derive::napi;
+use raw_critical_css::RawCriticalCssPluginOptions;
+use raw_css_modules_typings::RawCssModulesTypingsPluginOptions;
 use raw_dll::{RawDllReferenceAgencyPluginOptions, RawFlagAllModulesAsUsedPluginOptions};
-use raw_ids::RawOccurrenceChunkIdsPluginOptions;
+use raw_ids::{
+  RawHashedModuleIdsPluginOptions, RawOccurrenceChunkIdsPluginOptions, RawRecordIdsPluginOptions,
+};
 use raw_lightning_css_minimizer::RawLightningCssMinimizerRspackPluginOptions;
 use raw_sri::RawSubresourceIntegrityPluginOptions;
 use rspack_core::{BoxPlugin, Plugin, PluginExt};
 use rspack_error::{Result, ToStringResultToRspackResultExt};
 use rspack_ids::{
-  DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, NamedChunkIdsPlugin,
-  NamedModuleIdsPlugin, NaturalChunkIdsPlugin, NaturalModuleIdsPlugin, OccurrenceChunkIdsPlugin,
+  DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, HashedModuleIdsPlugin,
+  NamedChunkIdsPlugin, NamedModuleIdsPlugin, NaturalChunkIdsPlugin, NaturalModuleIdsPlugin,
+  OccurrenceChunkIdsPlugin, RecordIdsPlugin,
 };
+use rspack_loader_vue::VueLoaderPlugin;
 use rspack_plugin_asset::AssetPlugin;
 use rspack_plugin_banner::BannerPlugin;
 use rspack_plugin_circular_dependencies::CircularDependencyRspackPlugin;
 use rspack_plugin_context_replacement::ContextReplacementPlugin;
 use rspack_plugin_copy::{CopyRspackPlugin, CopyRspackPluginOptions};
-use rspack_plugin_css::CssPlugin;
+use rspack_plugin_critical_css::CriticalCssPlugin;
+use rspack_plugin_css::{CssModulesTypingsPlugin, CssPlugin};
 use rspack_plugin_css_chunking::CssChunkingPlugin;
 use rspack_plugin_devtool::{
   EvalDevToolModulePlugin, EvalSourceMapDevToolPlugin, SourceMapDevToolModuleOptionsPlugin,
-  SourceMapDevToolModuleOptionsPluginOptions, SourceMapDevToolPlugin,
+  SourceMapDevToolModuleOptionsPluginOptions, SourceMapDevToolPlugin, SourceMapValidationPlugin,
 };
 use rspack_plugin_dll::{
   DllEntryPlugin, DllReferenceAgencyPlugin, FlagAllModulesAsUsedPlugin, LibManifestPlugin,
@@ -68,8 +78,9 @@ use rspack_plugin_lightning_css_minimizer::LightningCssMinimizerRspackPlugin;
 use rspack_plugin_limit_chunk_count::LimitChunkCountPlugin;
 use rspack_plugin_merge_duplicate_chunks::MergeDuplicateChunksPlugin;
 use rspack_plugin_mf::{
-  ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ModuleFederationRuntimePlugin,
-  ProvideSharedPlugin, ShareRuntimePlugin,
+  ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ModuleFederationManifestPlugin,
+  ModuleFederationRuntimePlugin, ModuleFederationTypesPlugin, ProvideSharedPlugin,
+  ShareRuntimePlugin, SharedVersionCheckPlugin,
 };
 use rspack_plugin_module_info_header::ModuleInfoHeaderPlugin;
 use rspack_plugin_no_emit_on_errors::NoEmitOnErrorsPlugin;
@@ -111,8 +122,13 @@ pub use self::{
 use self::{
   raw_bundle_info::{RawBundlerInfoModeWrapper, RawBundlerInfoPluginOptions},
   raw_css_extract::RawCssExtractPluginOption,
+  raw_granular_chunks::RawGranularChunksPluginOptions,
   raw_lazy_compilation::{JsBackend, RawLazyCompilationOption},
-  raw_mf::{RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawProvideOptions},
+  raw_mf::{
+    RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions,
+    RawModuleFederationManifestPluginOptions, RawModuleFederationTypesPluginOptions,
+    RawProvideOptions, RawSharedVersionCheckPluginOptions,
+  },
   raw_runtime_chunk::RawRuntimeChunkOptions,
   raw_size_limits::RawSizeLimitsPluginOptions,
 };
@@ -121,7 +137,7 @@ use crate::{
   RawContextReplacementPluginOptions, RawDynamicEntryPluginOptions,
   RawEvalDevToolModulePluginOptions, RawExternalItemWrapper, RawExternalsPluginOptions,
   RawHttpExternalsRspackPluginOptions, RawRsdoctorPluginOptions, RawRstestPluginOptions,
-  RawSplitChunksOptions, SourceMapDevToolPluginOptions,
+  RawSourceMapValidationPluginOptions, RawSplitChunksOptions, SourceMapDevToolPluginOptions,
 };
 
 #[napi(string_enum)]
@@ -152,6 +168,7 @@ pub enum BuiltinPluginName {
   WebWorkerTemplatePlugin,
   MergeDuplicateChunksPlugin,
   SplitChunksPlugin,
+  GranularChunksPlugin,
   RemoveDuplicateModulesPlugin,
   ShareRuntimePlugin,
   ContainerPlugin,
@@ -159,13 +176,18 @@ pub enum BuiltinPluginName {
   ProvideSharedPlugin,
   ConsumeSharedPlugin,
   ModuleFederationRuntimePlugin,
+  ModuleFederationManifestPlugin,
+  SharedVersionCheckPlugin,
+  ModuleFederationTypesPlugin,
   NamedModuleIdsPlugin,
   NaturalModuleIdsPlugin,
   DeterministicModuleIdsPlugin,
+  HashedModuleIdsPlugin,
   NaturalChunkIdsPlugin,
   NamedChunkIdsPlugin,
   DeterministicChunkIdsPlugin,
   OccurrenceChunkIdsPlugin,
+  RecordIdsPlugin,
   RealContentHashPlugin,
   RemoveEmptyChunksPlugin,
   EnsureChunkConditionsPlugin,
@@ -181,6 +203,7 @@ pub enum BuiltinPluginName {
   SourceMapDevToolPlugin,
   EvalSourceMapDevToolPlugin,
   EvalDevToolModulePlugin,
+  SourceMapValidationPlugin,
   SideEffectsFlagPlugin,
   FlagDependencyExportsPlugin,
   FlagDependencyUsagePlugin,
@@ -210,6 +233,9 @@ pub enum BuiltinPluginName {
   RsdoctorPlugin,
   RstestPlugin,
   CircularDependencyRspackPlugin,
+  CriticalCssPlugin,
+  CssModulesTypingsPlugin,
+  VueLoaderRspackPlugin,
 
   // rspack js adapter plugins
   // naming format follow XxxRspackPlugin
@@ -375,9 +401,16 @@ impl<'a> BuiltinPlugin<'a> {
         use rspack_plugin_split_chunks::SplitChunksPlugin;
         let options = downcast_into::<RawSplitChunksOptions>(self.options)
           .map_err(|report| napi::Error::from_reason(report.to_string()))?
-          .into();
+          .try_into()?;
         plugins.push(SplitChunksPlugin::new(options).boxed());
       }
+      BuiltinPluginName::GranularChunksPlugin => {
+        use rspack_plugin_split_chunks::GranularChunksPlugin;
+        let options = downcast_into::<RawGranularChunksPluginOptions>(self.options)
+          .map_err(|report| napi::Error::from_reason(report.to_string()))?
+          .try_into()?;
+        plugins.push(GranularChunksPlugin::new(options).boxed());
+      }
       BuiltinPluginName::RemoveDuplicateModulesPlugin => {
         plugins.push(RemoveDuplicateModulesPlugin::default().boxed());
       }
@@ -428,6 +461,36 @@ impl<'a> BuiltinPlugin<'a> {
       BuiltinPluginName::ModuleFederationRuntimePlugin => {
         plugins.push(ModuleFederationRuntimePlugin::default().boxed())
       }
+      BuiltinPluginName::ModuleFederationManifestPlugin => {
+        plugins.push(
+          ModuleFederationManifestPlugin::new(
+            downcast_into::<RawModuleFederationManifestPluginOptions>(self.options)
+              .map_err(|report| napi::Error::from_reason(report.to_string()))?
+              .into(),
+          )
+          .boxed(),
+        );
+      }
+      BuiltinPluginName::SharedVersionCheckPlugin => {
+        plugins.push(
+          SharedVersionCheckPlugin::new(
+            downcast_into::<RawSharedVersionCheckPluginOptions>(self.options)
+              .map_err(|report| napi::Error::from_reason(report.to_string()))?
+              .into(),
+          )
+          .boxed(),
+        );
+      }
+      BuiltinPluginName::ModuleFederationTypesPlugin => {
+        plugins.push(
+          ModuleFederationTypesPlugin::new(
+            downcast_into::<RawModuleFederationTypesPluginOptions>(self.options)
+              .map_err(|report| napi::Error::from_reason(report.to_string()))?
+              .into(),
+          )
+          .boxed(),
+        );
+      }
       BuiltinPluginName::NamedModuleIdsPlugin => {
         plugins.push(NamedModuleIdsPlugin::default().boxed())
       }
@@ -437,6 +500,14 @@ impl<'a> BuiltinPlugin<'a> {
       BuiltinPluginName::DeterministicModuleIdsPlugin => {
         plugins.push(DeterministicModuleIdsPlugin::default().boxed())
       }
+      BuiltinPluginName::HashedModuleIdsPlugin => plugins.push(
+        HashedModuleIdsPlugin::new(
+          downcast_into::<RawHashedModuleIdsPluginOptions>(self.options)
+            .map_err(|report| napi::Error::from_reason(report.to_string()))?
+            .try_into()?,
+        )
+        .boxed(),
+      ),
       BuiltinPluginName::NaturalChunkIdsPlugin => {
         plugins.push(NaturalChunkIdsPlugin::default().boxed())
       }
@@ -454,6 +525,14 @@ impl<'a> BuiltinPlugin<'a> {
         )
         .boxed(),
       ),
+      BuiltinPluginName::RecordIdsPlugin => plugins.push(
+        RecordIdsPlugin::new(
+          downcast_into::<RawRecordIdsPluginOptions>(self.options)
+            .map_err(|report| napi::Error::from_reason(report.to_string()))?
+            .into(),
+        )
+        .boxed(),
+      ),
       BuiltinPluginName::RealContentHashPlugin => {
         plugins.push(RealContentHashPlugin::default().boxed())
       }
@@ -512,6 +591,16 @@ impl<'a> BuiltinPlugin<'a> {
         );
         plugins.push(EvalSourceMapDevToolPlugin::new(options).boxed());
       }
+      BuiltinPluginName::SourceMapValidationPlugin => {
+        plugins.push(
+          SourceMapValidationPlugin::new(
+            downcast_into::<RawSourceMapValidationPluginOptions>(self.options)
+              .map_err(|report| napi::Error::from_reason(report.to_string()))?
+              .into(),
+          )
+          .boxed(),
+        );
+      }
       BuiltinPluginName::EvalDevToolModulePlugin => {
         plugins.push(
           EvalDevToolModulePlugin::new(
@@ -719,6 +808,17 @@ impl<'a> BuiltinPlugin<'a> {
         let options = raw_options.into();
         plugins.push(SubresourceIntegrityPlugin::new(options).boxed());
       }
+      BuiltinPluginName::CriticalCssPlugin => {
+        let options = downcast_into::<RawCriticalCssPluginOptions>(self.options)
+          .map_err(|report| napi::Error::from_reason(report.to_string()))?;
+        plugins.push(CriticalCssPlugin::new(options.into()).boxed());
+      }
+      BuiltinPluginName::CssModulesTypingsPlugin => {
+        let options = downcast_into::<RawCssModulesTypingsPluginOptions>(self.options)
+          .map_err(|report| napi::Error::from_reason(report.to_string()))?;
+        plugins.push(CssModulesTypingsPlugin::new(options.into()).boxed());
+      }
+      BuiltinPluginName::VueLoaderRspackPlugin => plugins.push(VueLoaderPlugin::new().boxed()),
       BuiltinPluginName::ModuleInfoHeaderPlugin => {
         let verbose = downcast_into::<bool>(self.options)
           .map_err(|report| napi::Error::from_reason(report.to_string()))?;
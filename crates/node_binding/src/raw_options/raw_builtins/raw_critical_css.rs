@@ -0,0 +1,21 @@
+use napi_derive::napi;
+use rspack_plugin_critical_css::CriticalCssPluginOptions;
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawCriticalCssPluginOptions {
+  #[napi(ts_type = "\"media\" | \"swap\" | \"none\"")]
+  pub preload: Option<String>,
+  pub noscript_fallback: Option<bool>,
+  pub minify: Option<bool>,
+}
+
+impl From<RawCriticalCssPluginOptions> for CriticalCssPluginOptions {
+  fn from(value: RawCriticalCssPluginOptions) -> Self {
+    Self {
+      preload: value.preload.map(Into::into).unwrap_or_default(),
+      noscript_fallback: value.noscript_fallback.unwrap_or(true),
+      minify: value.minify.unwrap_or(true),
+    }
+  }
+}
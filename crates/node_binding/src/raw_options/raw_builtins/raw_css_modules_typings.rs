@@ -0,0 +1,20 @@
+use napi_derive::napi;
+use rspack_plugin_css::{CssModulesTypingsOutput, CssModulesTypingsPluginOptions};
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawCssModulesTypingsPluginOptions {
+  /// Emit a json manifest with this filename instead of a `.d.ts` next to each css module.
+  pub manifest: Option<String>,
+}
+
+impl From<RawCssModulesTypingsPluginOptions> for CssModulesTypingsPluginOptions {
+  fn from(value: RawCssModulesTypingsPluginOptions) -> Self {
+    Self {
+      output: match value.manifest {
+        Some(filename) => CssModulesTypingsOutput::Manifest(filename),
+        None => CssModulesTypingsOutput::Source,
+      },
+    }
+  }
+}
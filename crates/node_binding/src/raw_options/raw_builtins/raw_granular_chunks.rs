@@ -0,0 +1,35 @@
+use napi_derive::napi;
+use rspack_plugin_split_chunks::GranularChunksPluginOptions;
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawGranularChunksPluginOptions {
+  pub max_chunks: Option<u32>,
+  pub framework_packages: Option<Vec<String>>,
+  pub min_size: Option<f64>,
+  pub max_size: Option<f64>,
+}
+
+impl TryFrom<RawGranularChunksPluginOptions> for GranularChunksPluginOptions {
+  type Error = napi::Error;
+
+  fn try_from(value: RawGranularChunksPluginOptions) -> napi::Result<Self> {
+    let default = Self::default();
+    Ok(Self {
+      max_chunks: value.max_chunks.unwrap_or(default.max_chunks),
+      framework_packages: match value.framework_packages {
+        Some(names) => names
+          .iter()
+          .map(|name| {
+            glob::Pattern::new(name).map_err(|e| {
+              napi::Error::from_reason(format!("Invalid framework package glob {name:?}: {e}"))
+            })
+          })
+          .collect::<napi::Result<_>>()?,
+        None => default.framework_packages,
+      },
+      min_size: value.min_size.unwrap_or(default.min_size),
+      max_size: value.max_size.unwrap_or(default.max_size),
+    })
+  }
+}
@@ -67,7 +67,7 @@ impl HttpClient for JsHttpClient {
   }
 }
 
-fn create_http_uri_plugin_options(
+pub(super) fn create_http_uri_plugin_options(
   options: RawHttpUriPluginOptions,
   filesystem: Arc<dyn WritableFileSystem>,
 ) -> HttpUriPluginOptions {
@@ -1,5 +1,8 @@
 use napi_derive::napi;
-use rspack_ids::OccurrenceChunkIdsPluginOptions;
+use rspack_hash::{HashDigest, HashFunction, HashSalt};
+use rspack_ids::{
+  HashedModuleIdsPluginOptions, OccurrenceChunkIdsPluginOptions, RecordIdsPluginOptions,
+};
 
 #[derive(Debug)]
 #[napi(object, object_to_js = false)]
@@ -14,3 +17,54 @@ impl From<RawOccurrenceChunkIdsPluginOptions> for OccurrenceChunkIdsPluginOption
     }
   }
 }
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawRecordIdsPluginOptions {
+  pub input_path: Option<String>,
+  pub output_path: Option<String>,
+}
+
+impl From<RawRecordIdsPluginOptions> for RecordIdsPluginOptions {
+  fn from(value: RawRecordIdsPluginOptions) -> Self {
+    Self {
+      input_path: value.input_path.map(Into::into),
+      output_path: value.output_path.map(Into::into),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawHashedModuleIdsPluginOptions {
+  pub context: Option<String>,
+  pub hash_function: Option<String>,
+  pub hash_digest: Option<String>,
+  pub hash_digest_length: Option<u32>,
+  pub salt: Option<String>,
+}
+
+impl TryFrom<RawHashedModuleIdsPluginOptions> for HashedModuleIdsPluginOptions {
+  type Error = napi::Error;
+
+  fn try_from(value: RawHashedModuleIdsPluginOptions) -> napi::Result<Self> {
+    let default = Self::default();
+    Ok(Self {
+      context: value.context,
+      hash_function: value
+        .hash_function
+        .as_deref()
+        .map_or(Ok(default.hash_function), HashFunction::try_from)
+        .map_err(napi::Error::from_reason)?,
+      hash_digest: value
+        .hash_digest
+        .as_deref()
+        .map_or(Ok(default.hash_digest), HashDigest::try_from)
+        .map_err(napi::Error::from_reason)?,
+      hash_digest_length: value
+        .hash_digest_length
+        .map_or(default.hash_digest_length, |length| length as usize),
+      salt: HashSalt::from(value.salt),
+    })
+  }
+}
@@ -4,9 +4,12 @@ use napi::Either;
 use napi_derive::napi;
 use rspack_plugin_mf::{
   ConsumeOptions, ConsumeSharedPluginOptions, ConsumeVersion, ContainerPluginOptions,
-  ContainerReferencePluginOptions, ExposeOptions, ProvideOptions, ProvideVersion, RemoteOptions,
+  ContainerReferencePluginOptions, ExposeOptions, ModuleFederationManifestPluginOptions,
+  ModuleFederationTypesPluginOptions, ProvideOptions, ProvideVersion, RemoteOptions,
+  SharedVersionCheckPluginOptions,
 };
 
+use super::raw_http_uri::{create_http_uri_plugin_options, RawHttpUriPluginOptions};
 use crate::{
   entry::{JsEntryRuntime, JsEntryRuntimeWrapper},
   library::JsLibraryOptions,
@@ -187,6 +190,66 @@ impl From<RawConsumeOptions> for (String, ConsumeOptions) {
   }
 }
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawModuleFederationManifestPluginOptions {
+  pub name: Option<String>,
+  pub filename: Option<String>,
+  pub stats_filename: Option<String>,
+}
+
+impl From<RawModuleFederationManifestPluginOptions> for ModuleFederationManifestPluginOptions {
+  fn from(value: RawModuleFederationManifestPluginOptions) -> Self {
+    let default = Self::default();
+    Self {
+      name: value.name,
+      filename: value.filename.unwrap_or(default.filename),
+      stats_filename: value.stats_filename.unwrap_or(default.stats_filename),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawSharedVersionCheckPluginOptions {
+  pub manifests: Option<Vec<String>>,
+}
+
+impl From<RawSharedVersionCheckPluginOptions> for SharedVersionCheckPluginOptions {
+  fn from(value: RawSharedVersionCheckPluginOptions) -> Self {
+    Self {
+      manifests: value.manifests.unwrap_or_default(),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawModuleFederationTypesPluginOptions {
+  pub archive_filename: Option<String>,
+  /// `false` to not fetch the types of the remotes
+  #[napi(ts_type = "string | false")]
+  pub types_folder: Option<Either<String, bool>>,
+  pub http: Option<RawHttpUriPluginOptions>,
+}
+
+impl From<RawModuleFederationTypesPluginOptions> for ModuleFederationTypesPluginOptions {
+  fn from(value: RawModuleFederationTypesPluginOptions) -> Self {
+    let default = Self::default();
+    Self {
+      archive_filename: value.archive_filename.unwrap_or(default.archive_filename),
+      types_folder: match value.types_folder {
+        Some(Either::A(types_folder)) => Some(types_folder),
+        Some(Either::B(false)) => None,
+        Some(Either::B(true)) | None => default.types_folder,
+      },
+      http: value.http.map(|http| {
+        create_http_uri_plugin_options(http, Arc::new(rspack_fs::NativeFileSystem::new(false)))
+      }),
+    }
+  }
+}
+
 pub type RawVersion = Either<String, bool>;
 
 struct RawVersionWrapper(RawVersion);
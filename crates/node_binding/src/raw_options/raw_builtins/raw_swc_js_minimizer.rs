@@ -2,8 +2,9 @@ use napi::Either;
 use napi_derive::napi;
 use rspack_error::{miette::IntoDiagnostic, Result};
 use rspack_plugin_swc_js_minimizer::{
-  ExtractComments, MinimizerOptions, OptionWrapper, PluginOptions,
+  ExtractComments, ManglePropertiesOptions, MinimizerOptions, OptionWrapper, PluginOptions,
 };
+use rspack_regex::RspackRegex;
 use serde::de::DeserializeOwned;
 use swc_core::base::BoolOrDataConfig;
 
@@ -27,6 +28,7 @@ pub struct RawSwcJsMinimizerRspackPluginOptions {
   pub exclude: Option<RawAssetConditions>,
   pub extract_comments: Option<RawExtractComments>,
   pub minimizer_options: RawSwcJsMinimizerOptions,
+  pub size_report: Option<String>,
 }
 
 #[derive(Debug)]
@@ -37,6 +39,26 @@ pub struct RawSwcJsMinimizerOptions {
   pub format: serde_json::Value,
   pub module: Option<bool>,
   pub minify: Option<bool>,
+  pub mangle_properties: Option<RawManglePropertiesOptions>,
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawManglePropertiesOptions {
+  #[napi(ts_type = "RegExp")]
+  pub regex: RspackRegex,
+  pub reserved: Option<Vec<String>>,
+  pub name_cache: Option<String>,
+}
+
+impl From<RawManglePropertiesOptions> for ManglePropertiesOptions {
+  fn from(value: RawManglePropertiesOptions) -> Self {
+    Self {
+      regex: value.regex,
+      reserved: value.reserved.unwrap_or_default(),
+      name_cache: value.name_cache,
+    }
+  }
 }
 
 fn try_deserialize_into<T>(value: serde_json::Value) -> Result<T>
@@ -89,8 +111,10 @@ impl TryFrom<RawSwcJsMinimizerRspackPluginOptions> for PluginOptions {
         format: try_deserialize_into(value.minimizer_options.format)?,
         module: value.minimizer_options.module,
         minify: value.minimizer_options.minify,
+        mangle_properties: value.minimizer_options.mangle_properties.map(Into::into),
         ..Default::default()
       },
+      size_report: value.size_report,
     })
   }
 }
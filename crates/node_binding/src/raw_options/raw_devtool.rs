@@ -8,7 +8,8 @@ use napi_derive::napi;
 use rspack_core::PathData;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
 use rspack_plugin_devtool::{
-  Append, EvalDevToolModulePluginOptions, ModuleFilenameTemplate, ModuleFilenameTemplateFnCtx,
+  default_ignore_list, Append, EvalDevToolModulePluginOptions, ModuleFilenameTemplate,
+  ModuleFilenameTemplateFnCtx, SourceMapValidationPluginOptions,
 };
 
 use crate::{into_asset_conditions, RawAssetConditions};
@@ -120,6 +121,8 @@ pub struct SourceMapDevToolPluginOptions {
   #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
   pub exclude: Option<RawAssetConditions>,
   pub debug_ids: Option<bool>,
+  #[napi(ts_type = "boolean | string | RegExp | (string | RegExp)[]")]
+  pub ignore_list: Option<Either<bool, RawAssetConditions>>,
 }
 
 impl From<SourceMapDevToolPluginOptions> for rspack_plugin_devtool::SourceMapDevToolPluginOptions {
@@ -156,6 +159,26 @@ impl From<SourceMapDevToolPluginOptions> for rspack_plugin_devtool::SourceMapDev
       include: opts.include.map(into_asset_conditions),
       exclude: opts.exclude.map(into_asset_conditions),
       debug_ids: opts.debug_ids.unwrap_or(false),
+      ignore_list: match opts.ignore_list {
+        None | Some(Either::A(true)) => Some(default_ignore_list()),
+        Some(Either::A(false)) => None,
+        Some(Either::B(conditions)) => Some(into_asset_conditions(conditions)),
+      },
+    }
+  }
+}
+
+#[napi(object, object_to_js = false)]
+pub struct RawSourceMapValidationPluginOptions {
+  pub min_coverage: Option<f64>,
+  pub filename: Option<String>,
+}
+
+impl From<RawSourceMapValidationPluginOptions> for SourceMapValidationPluginOptions {
+  fn from(opts: RawSourceMapValidationPluginOptions) -> Self {
+    Self {
+      min_coverage: opts.min_coverage.unwrap_or(0.5),
+      filename: opts.filename,
     }
   }
 }
@@ -423,6 +423,7 @@ impl From<RawCssParserOptions> for CssParserOptions {
 pub struct RawCssAutoParserOptions {
   pub named_exports: Option<bool>,
   pub url: Option<bool>,
+  pub warn_unused_classes: Option<bool>,
 }
 
 impl From<RawCssAutoParserOptions> for CssAutoParserOptions {
@@ -430,6 +431,7 @@ impl From<RawCssAutoParserOptions> for CssAutoParserOptions {
     Self {
       named_exports: value.named_exports,
       url: value.url,
+      warn_unused_classes: value.warn_unused_classes,
     }
   }
 }
@@ -439,6 +441,7 @@ impl From<RawCssAutoParserOptions> for CssAutoParserOptions {
 pub struct RawCssModuleParserOptions {
   pub named_exports: Option<bool>,
   pub url: Option<bool>,
+  pub warn_unused_classes: Option<bool>,
 }
 
 impl From<RawCssModuleParserOptions> for CssModuleParserOptions {
@@ -446,6 +449,7 @@ impl From<RawCssModuleParserOptions> for CssModuleParserOptions {
     Self {
       named_exports: value.named_exports,
       url: value.url,
+      warn_unused_classes: value.warn_unused_classes,
     }
   }
 }
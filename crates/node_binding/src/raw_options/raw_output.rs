@@ -1,9 +1,10 @@
 use napi::Either;
 use napi_derive::napi;
 use rspack_core::{
-  ChunkLoading, CleanOptions, CrossOriginLoading, Environment, OnPolicyCreationFailure,
-  OutputOptions, PathInfo, TrustedTypes, WasmLoading,
+  ChunkLoading, CleanOptions, CrossOriginLoading, Environment, ImportMapOptions,
+  OnPolicyCreationFailure, OutputOptions, PathInfo, TrustedTypes, WasmLoading,
 };
+use rspack_error::ToStringResultToRspackResultExt;
 
 use crate::{library::JsLibraryOptions, JsCleanOptions, JsFilename, WithFalse};
 
@@ -26,6 +27,22 @@ impl From<RawTrustedTypes> for TrustedTypes {
   }
 }
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawImportMap {
+  pub filename: String,
+  pub prefix: String,
+}
+
+impl From<RawImportMap> for ImportMapOptions {
+  fn from(value: RawImportMap) -> Self {
+    Self {
+      filename: value.filename,
+      prefix: value.prefix,
+    }
+  }
+}
+
 type RawCrossOriginLoading = WithFalse<String>;
 
 impl From<RawCrossOriginLoading> for CrossOriginLoading {
@@ -131,6 +148,7 @@ pub struct RawOutputOptions {
   pub script_type: WithFalse<String>,
   pub environment: RawEnvironment,
   pub compare_before_emit: bool,
+  pub import_map: Option<RawImportMap>,
 }
 
 pub type RawWasmLoading = WithFalse<String>;
@@ -197,8 +215,8 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       module: value.module,
       trusted_types: value.trusted_types.map(Into::into),
       source_map_filename: value.source_map_filename.into(),
-      hash_function: value.hash_function.as_str().into(),
-      hash_digest: value.hash_digest.as_str().into(),
+      hash_function: value.hash_function.as_str().try_into().to_rspack_result()?,
+      hash_digest: value.hash_digest.as_str().try_into().to_rspack_result()?,
       hash_digest_length: value.hash_digest_length as usize,
       hash_salt: value.hash_salt.into(),
       async_chunks: value.async_chunks,
@@ -213,6 +231,7 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       charset: value.charset,
       chunk_load_timeout: value.chunk_load_timeout,
       compare_before_emit: value.compare_before_emit,
+      import_map: value.import_map.map(Into::into),
     })
   }
 }
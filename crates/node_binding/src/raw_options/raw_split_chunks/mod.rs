@@ -16,7 +16,8 @@ use rspack_regex::RspackRegex;
 
 use self::{
   raw_split_chunk_cache_group_test::{
-    default_cache_group_test, normalize_raw_cache_group_test, RawCacheGroupTest,
+    default_cache_group_test, normalize_raw_cache_group_package_test,
+    normalize_raw_cache_group_test, RawCacheGroupPackageTest, RawCacheGroupTest,
   },
   raw_split_chunk_chunks::{create_chunks_filter, Chunks},
   raw_split_chunk_name::default_chunk_option_name,
@@ -44,6 +45,7 @@ pub struct RawSplitChunksOptions<'a> {
   pub default_size_types: Vec<String>,
   pub min_chunks: Option<u32>,
   pub hide_path_info: Option<bool>,
+  pub explain: Option<bool>,
   pub min_size: Option<Either<f64, RawSplitChunkSizes>>,
   pub min_size_reduction: Option<Either<f64, RawSplitChunkSizes>>,
   //   pub min_size_reduction: usize,
@@ -65,6 +67,8 @@ pub struct RawCacheGroupOptions<'a> {
   #[napi(ts_type = "RegExp | string | Function")]
   #[debug(skip)]
   pub test: Option<RawCacheGroupTest>,
+  /// The object form of `test`, matching the `package.json` of the modules.
+  pub package_test: Option<RawCacheGroupPackageTest>,
   pub filename: Option<JsFilename>,
   //   pub enforce: bool,
   pub id_hint: Option<String>,
@@ -102,8 +106,10 @@ pub struct RawCacheGroupOptions<'a> {
   pub used_exports: Option<bool>,
 }
 
-impl<'a> From<RawSplitChunksOptions<'a>> for rspack_plugin_split_chunks::PluginOptions {
-  fn from(raw_opts: RawSplitChunksOptions) -> Self {
+impl<'a> TryFrom<RawSplitChunksOptions<'a>> for rspack_plugin_split_chunks::PluginOptions {
+  type Error = napi::Error;
+
+  fn try_from(raw_opts: RawSplitChunksOptions) -> napi::Result<Self> {
     use rspack_plugin_split_chunks::SplitChunkSizes;
 
     let mut cache_groups = vec![];
@@ -151,7 +157,7 @@ impl<'a> From<RawSplitChunksOptions<'a>> for rspack_plugin_split_chunks::PluginO
         .cache_groups
         .unwrap_or_default()
         .into_iter()
-        .map(|v| {
+        .map(|v| -> napi::Result<_> {
           let enforce = v.enforce.unwrap_or_default();
 
           let min_size = create_sizes(v.min_size).merge(if enforce {
@@ -207,14 +213,16 @@ impl<'a> From<RawSplitChunksOptions<'a>> for rspack_plugin_split_chunks::PluginO
           if matches!(name, ChunkNameGetter::Disabled) {
             name = overall_name_getter.clone();
           }
-          rspack_plugin_split_chunks::CacheGroup {
+          Ok(rspack_plugin_split_chunks::CacheGroup {
             id_hint: v.id_hint.unwrap_or_else(|| v.key.clone()),
             key: v.key,
             name,
             priority: v.priority.unwrap_or(0) as f64,
-            test: v.test.map_or(default_cache_group_test(), |test| {
-              normalize_raw_cache_group_test(test)
-            }),
+            test: match (v.test, v.package_test) {
+              (Some(test), _) => normalize_raw_cache_group_test(test),
+              (None, Some(package_test)) => normalize_raw_cache_group_package_test(package_test)?,
+              (None, None) => default_cache_group_test(),
+            },
             chunk_filter: v.chunks.map(create_chunks_filter).unwrap_or_else(|| {
               overall_chunk_filter
                 .clone()
@@ -240,8 +248,9 @@ impl<'a> From<RawSplitChunksOptions<'a>> for rspack_plugin_split_chunks::PluginO
             used_exports: v
               .used_exports
               .unwrap_or_else(|| raw_opts.used_exports.unwrap_or_default()),
-          }
-        }),
+          })
+        })
+        .collect::<napi::Result<Vec<_>>>()?,
     );
 
     let raw_fallback_cache_group = raw_opts.fallback_cache_group.unwrap_or_default();
@@ -263,7 +272,7 @@ impl<'a> From<RawSplitChunksOptions<'a>> for rspack_plugin_split_chunks::PluginO
       .merge(&overall_max_initial_size)
       .merge(&overall_max_size);
 
-    rspack_plugin_split_chunks::PluginOptions {
+    Ok(rspack_plugin_split_chunks::PluginOptions {
       cache_groups,
       fallback_cache_group: rspack_plugin_split_chunks::FallbackCacheGroup {
         chunks_filter: fallback_chunks_filter.unwrap_or_else(|| {
@@ -279,7 +288,8 @@ impl<'a> From<RawSplitChunksOptions<'a>> for rspack_plugin_split_chunks::PluginO
           .unwrap_or(overall_automatic_name_delimiter.clone()),
       },
       hide_path_info: raw_opts.hide_path_info,
-    }
+      explain: raw_opts.explain.unwrap_or_default(),
+    })
   }
 }
 
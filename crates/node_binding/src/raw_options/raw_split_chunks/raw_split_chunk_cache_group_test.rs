@@ -3,7 +3,9 @@ use std::sync::Arc;
 use napi::bindgen_prelude::Either3;
 use napi_derive::napi;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
-use rspack_plugin_split_chunks::{CacheGroupTest, CacheGroupTestFnCtx};
+use rspack_plugin_split_chunks::{
+  CacheGroupPackageTest, CacheGroupTest, CacheGroupTestFnCtx, PackageSource,
+};
 use rspack_regex::RspackRegex;
 
 use crate::ModuleObject;
@@ -37,6 +39,42 @@ pub(super) fn normalize_raw_cache_group_test(raw: RawCacheGroupTest) -> CacheGro
   }
 }
 
+/// `test: { package: { .. } }` of a cache group.
+#[napi(object, object_to_js = false)]
+#[derive(Debug)]
+pub struct RawCacheGroupPackageTest {
+  pub name: Option<String>,
+  pub license: Option<String>,
+  #[napi(ts_type = r#""node_modules" | "workspace""#)]
+  pub source: Option<String>,
+}
+
+pub(super) fn normalize_raw_cache_group_package_test(
+  raw: RawCacheGroupPackageTest,
+) -> napi::Result<CacheGroupTest> {
+  let pattern = |pattern: String| {
+    glob::Pattern::new(&pattern).map_err(|e| {
+      napi::Error::from_reason(format!(
+        "Invalid glob {pattern:?} in `test.package` of `splitChunks.cacheGroups`: {e}"
+      ))
+    })
+  };
+  Ok(CacheGroupTest::Package(CacheGroupPackageTest {
+    name: raw.name.map(pattern).transpose()?,
+    license: raw.license.map(pattern).transpose()?,
+    source: raw
+      .source
+      .map(|source| match source.as_str() {
+        "node_modules" => Ok(PackageSource::NodeModules),
+        "workspace" => Ok(PackageSource::Workspace),
+        _ => Err(napi::Error::from_reason(format!(
+          "Invalid source {source:?} in `test.package` of `splitChunks.cacheGroups`, expected \"node_modules\" or \"workspace\""
+        ))),
+      })
+      .transpose()?,
+  }))
+}
+
 #[inline]
 pub(super) fn default_cache_group_test() -> CacheGroupTest {
   CacheGroupTest::Enabled
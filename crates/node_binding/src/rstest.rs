@@ -1,17 +1,44 @@
 use derive_more::Debug;
-use rspack_plugin_rstest::RstestPluginOptions;
+use rspack_plugin_rstest::{RstestCoverageOptions, RstestPluginOptions};
+
+use crate::{into_asset_conditions, RawAssetConditions};
 
 #[derive(Debug)]
 #[napi(object, object_to_js = false)]
 pub struct RawRstestPluginOptions {
   // Inject __dirname and __filename to each module.
   pub inject_module_path_name: bool,
+  // Instrument the modules with istanbul coverage counters.
+  pub coverage: Option<RawRstestCoverageOptions>,
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawRstestCoverageOptions {
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub include: Option<RawAssetConditions>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub exclude: Option<RawAssetConditions>,
+  pub filename: Option<String>,
 }
 
 impl From<RawRstestPluginOptions> for RstestPluginOptions {
   fn from(value: RawRstestPluginOptions) -> Self {
     Self {
       module_path_name: value.inject_module_path_name,
+      coverage: value.coverage.map(Into::into),
+    }
+  }
+}
+
+impl From<RawRstestCoverageOptions> for RstestCoverageOptions {
+  fn from(value: RawRstestCoverageOptions) -> Self {
+    Self {
+      include: value.include.map(into_asset_conditions),
+      exclude: value.exclude.map(into_asset_conditions),
+      filename: value
+        .filename
+        .unwrap_or_else(|| "coverage-map.json".to_string()),
     }
   }
 }
@@ -5,7 +5,8 @@ use napi_derive::napi;
 use rspack_collections::IdentifierMap;
 use rspack_core::{
   rspack_sources::{RawBufferSource, RawSource, Source},
-  EntrypointsStatsOption, ExtendedStatsOptions, Stats, StatsChunk, StatsModule, StatsUsedExports,
+  EntrypointsStatsOption, ExtendedStatsOptions, SourceType, SplitChunksExplanation, Stats,
+  StatsChunk, StatsModule, StatsUsedExports,
 };
 use rspack_napi::napi::{
   bindgen_prelude::{Buffer, Result, SharedReference, ToNapiValue},
@@ -497,6 +498,7 @@ pub struct JsStatsModuleCommonAttributes<'a> {
   pub layer: Option<CowStrWrapper<'a>>,
   pub size: f64,
   pub sizes: Vec<JsStatsSize>,
+  pub minimized_size: Option<f64>,
   pub built: bool,
   pub code_generated: bool,
   pub build_time_executed: bool,
@@ -652,6 +654,7 @@ impl<'a> TryFrom<StatsModule<'a>> for JsStatsModule<'a> {
       layer: stats.layer.map(CowStrWrapper::new),
       size: stats.size,
       sizes,
+      minimized_size: stats.minimized_size,
       built: stats.built,
       code_generated: stats.code_generated,
       build_time_executed: stats.build_time_executed,
@@ -809,6 +812,62 @@ pub struct JsStatsSize {
   pub size: f64,
 }
 
+fn to_js_stats_sizes<'a>(
+  sizes: impl IntoIterator<Item = (&'a SourceType, &'a f64)>,
+) -> Vec<JsStatsSize> {
+  let mut sizes = sizes
+    .into_iter()
+    .map(|(source_type, size)| JsStatsSize {
+      source_type: source_type.to_string(),
+      size: *size,
+    })
+    .collect::<Vec<_>>();
+  sizes.sort_by(|a, b| a.source_type.cmp(&b.source_type));
+  sizes
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsSplitChunksDecision<'a> {
+  pub cache_group: &'a str,
+  pub name: Option<&'a str>,
+  pub priority: f64,
+  pub modules: u32,
+  pub min_size: Vec<JsStatsSize>,
+  pub max_async_size: Vec<JsStatsSize>,
+  pub max_initial_size: Vec<JsStatsSize>,
+  pub reused_existing_chunk: bool,
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsSplitChunks<'a> {
+  pub decisions: Vec<JsStatsSplitChunksDecision<'a>>,
+  pub max_size_part: Option<&'a str>,
+  pub blocked: Vec<&'a str>,
+}
+
+impl<'a> From<&'a SplitChunksExplanation> for JsStatsSplitChunks<'a> {
+  fn from(explanation: &'a SplitChunksExplanation) -> Self {
+    Self {
+      decisions: explanation
+        .decisions
+        .iter()
+        .map(|decision| JsStatsSplitChunksDecision {
+          cache_group: &decision.cache_group,
+          name: decision.name.as_deref(),
+          priority: decision.priority,
+          modules: decision.modules as u32,
+          min_size: to_js_stats_sizes(&decision.min_size),
+          max_async_size: to_js_stats_sizes(&decision.max_async_size),
+          max_initial_size: to_js_stats_sizes(&decision.max_initial_size),
+          reused_existing_chunk: decision.reused_existing_chunk,
+        })
+        .collect(),
+      max_size_part: explanation.max_size_part.as_deref(),
+      blocked: explanation.blocked.iter().map(String::as_str).collect(),
+    }
+  }
+}
+
 #[napi(object, object_from_js = false)]
 pub struct JsStatsChunk<'a> {
   pub r#type: &'a str,
@@ -827,6 +886,7 @@ pub struct JsStatsChunk<'a> {
   pub children_by_order: HashMap<String, Vec<String>>,
   pub runtime: Vec<&'a str>,
   pub reason: Option<&'a str>,
+  pub split_chunks: Option<JsStatsSplitChunks<'a>>,
   pub rendered: bool,
   pub sizes: Vec<JsStatsSize>,
   pub origins: Vec<JsOriginRecord<'a>>,
@@ -840,15 +900,7 @@ impl<'a> TryFrom<StatsChunk<'a>> for JsStatsChunk<'a> {
     let mut runtime = stats.runtime.iter().map(|r| r.as_ref()).collect::<Vec<_>>();
     runtime.sort();
 
-    let mut sizes = stats
-      .sizes
-      .iter()
-      .map(|(source_type, size)| JsStatsSize {
-        source_type: source_type.to_string(),
-        size: *size,
-      })
-      .collect::<Vec<_>>();
-    sizes.sort_by(|a, b| a.source_type.cmp(&b.source_type));
+    let sizes = to_js_stats_sizes(&stats.sizes);
 
     Ok(JsStatsChunk {
       r#type: stats.r#type,
@@ -878,6 +930,7 @@ impl<'a> TryFrom<StatsChunk<'a>> for JsStatsChunk<'a> {
       runtime,
       sizes,
       reason: stats.reason,
+      split_chunks: stats.split_chunks.map(Into::into),
       rendered: stats.rendered,
       origins: stats
         .origins
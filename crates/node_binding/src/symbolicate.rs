@@ -0,0 +1,21 @@
+use std::path::{Path, PathBuf};
+
+use crate::RspackResultToNapiResultExt;
+
+/// Maps the frames of a stack trace back to the original sources with the
+/// source maps of an output directory, and of `sourceMapDirs` for maps kept
+/// apart from the output and matched by debug id.
+#[napi]
+pub fn symbolicate_stack_trace(
+  stack: String,
+  output_dir: String,
+  source_map_dirs: Option<Vec<String>>,
+) -> napi::Result<String> {
+  let source_map_dirs = source_map_dirs
+    .unwrap_or_default()
+    .into_iter()
+    .map(PathBuf::from)
+    .collect::<Vec<_>>();
+  rspack_plugin_devtool::symbolicate_stack_trace(&stack, Path::new(&output_dir), &source_map_dirs)
+    .to_napi_result()
+}
@@ -48,13 +48,14 @@ use rspack_core::{
   CssGeneratorOptions, CssModuleGeneratorOptions, CssModuleParserOptions, CssParserOptions,
   DynamicImportMode, EntryDescription, EntryOptions, EntryRuntime, Environment,
   ExperimentCacheOptions, Experiments, ExternalItem, ExternalType, Filename, GeneratorOptions,
-  GeneratorOptionsMap, JavascriptParserOptions, JavascriptParserOrder, JavascriptParserUrl,
-  JsonGeneratorOptions, JsonParserOptions, LibraryName, LibraryNonUmdObject, LibraryOptions,
-  LibraryType, MangleExportsOption, Mode, ModuleNoParseRules, ModuleOptions, ModuleRule,
-  ModuleRuleEffect, ModuleType, NodeDirnameOption, NodeFilenameOption, NodeGlobalOption,
-  NodeOption, Optimization, OutputOptions, ParseOption, ParserOptions, ParserOptionsMap, PathInfo,
-  PublicPath, Resolve, RspackFuture, RuleSetCondition, RuleSetLogicalConditions, SideEffectOption,
-  StatsOptions, TrustedTypes, UsedExportsOption, WasmLoading, WasmLoadingType,
+  GeneratorOptionsMap, ImportMapOptions, JavascriptParserOptions, JavascriptParserOrder,
+  JavascriptParserUrl, JsonGeneratorOptions, JsonParserOptions, LibraryName, LibraryNonUmdObject,
+  LibraryOptions, LibraryType, MangleExportsOption, Mode, ModuleNoParseRules, ModuleOptions,
+  ModuleRule, ModuleRuleEffect, ModuleType, NodeDirnameOption, NodeFilenameOption,
+  NodeGlobalOption, NodeOption, Optimization, OutputOptions, ParseOption, ParserOptions,
+  ParserOptionsMap, PathInfo, PublicPath, Resolve, RspackFuture, RuleSetCondition,
+  RuleSetLogicalConditions, SideEffectOption, StatsOptions, TrustedTypes, UsedExportsOption,
+  WasmLoading, WasmLoadingType,
 };
 use rspack_error::{
   miette::{self, Diagnostic},
@@ -478,6 +479,14 @@ impl CompilerBuilder {
   }
 }
 
+#[cfg(feature = "loader_sass")]
+impl CompilerBuilder {
+  /// Enable support for builtin:sass-loader.
+  pub fn enable_loader_sass(&mut self) -> &mut Self {
+    self.plugin(Box::new(rspack_loader_sass::SassLoaderPlugin::new()))
+  }
+}
+
 #[cfg(feature = "loader_swc")]
 impl CompilerBuilder {
   /// Enable support for builtin:swc-loader.
@@ -486,6 +495,14 @@ impl CompilerBuilder {
   }
 }
 
+#[cfg(feature = "loader_vue")]
+impl CompilerBuilder {
+  /// Enable support for builtin:vue-loader.
+  pub fn enable_loader_vue(&mut self) -> &mut Self {
+    self.plugin(Box::new(rspack_loader_vue::VueLoaderPlugin::new()))
+  }
+}
+
 #[cfg(feature = "loader_react_refresh")]
 impl CompilerBuilder {
   /// Enable support for builtin:react-refresh-loader.
@@ -1024,6 +1041,7 @@ impl CompilerOptionsBuilder {
         include: None,
         exclude: None,
         debug_ids: false,
+        ignore_list: Some(rspack_plugin_devtool::default_ignore_list()),
       };
 
       if eval_wrapped {
@@ -1751,12 +1769,14 @@ impl ModuleOptionsBuilder {
       let css_auto_parser_options = ParserOptions::CssAuto(CssAutoParserOptions {
         named_exports: Some(true),
         url: Some(true),
+        warn_unused_classes: Some(false),
       });
       parser.insert("css/auto".to_string(), css_auto_parser_options);
 
       let css_module_parser_options = ParserOptions::CssModule(CssModuleParserOptions {
         named_exports: Some(true),
         url: Some(true),
+        warn_unused_classes: Some(false),
       });
       parser.insert("css/module".to_string(), css_module_parser_options);
 
@@ -2212,6 +2232,8 @@ pub struct OutputOptionsBuilder {
   environment: Option<Environment>,
   /// Set the compare before emit.
   compare_before_emit: Option<bool>,
+  /// Set the import map.
+  import_map: Option<ImportMapOptions>,
 }
 
 impl From<OutputOptions> for OutputOptionsBuilder {
@@ -2262,6 +2284,7 @@ impl From<OutputOptions> for OutputOptionsBuilder {
       devtool_fallback_module_filename_template: None,
       environment: Some(value.environment),
       compare_before_emit: Some(value.compare_before_emit),
+      import_map: value.import_map,
       enabled_chunk_loading_types: None,
       enabled_wasm_loading_types: None,
     }
@@ -2320,6 +2343,7 @@ impl From<&mut OutputOptionsBuilder> for OutputOptionsBuilder {
         .take(),
       environment: value.environment.take(),
       compare_before_emit: value.compare_before_emit.take(),
+      import_map: value.import_map.take(),
     }
   }
 }
@@ -2636,6 +2660,12 @@ impl OutputOptionsBuilder {
     self
   }
 
+  /// Set the import map the ESM chunks are imported with.
+  pub fn import_map(&mut self, import_map: ImportMapOptions) -> &mut Self {
+    self.import_map = Some(import_map);
+    self
+  }
+
   /// Build [`OutputOptions`] from builder.
   ///
   /// [`OutputOptions`]: rspack_core::options::OutputOptions
@@ -3144,6 +3174,7 @@ impl OutputOptionsBuilder {
       script_type,
       environment,
       compare_before_emit: self.compare_before_emit.take().unwrap_or(true),
+      import_map: self.import_map.take(),
     })
   }
 }
@@ -3596,6 +3627,7 @@ impl OptimizationOptionsBuilder {
                 exclude: None,
                 extract_comments: None,
                 minimizer_options: Default::default(),
+                size_report: None,
               },
             ),
           )),
@@ -40,3 +40,36 @@ async fn basic_sourcemap() {
   );
   assert!(compiler.compilation.assets().get("main.js.map").is_some());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn symbolicate_stack_trace() {
+  let mut compiler = Compiler::builder()
+    .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/symbolicate"))
+    .entry("main", "./src/index.js")
+    .devtool(Devtool::SourceMap)
+    .build()
+    .unwrap();
+
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty());
+
+  // the frame of the `throw` in the emitted bundle
+  let source = compiler.compilation.assets().get("main.js").unwrap();
+  let source = source.source.as_ref().unwrap().source();
+  let (line, column) = source
+    .lines()
+    .enumerate()
+    .find_map(|(line, content)| Some((line + 1, content.find("throw")? + 1)))
+    .unwrap();
+  let stack = format!("Error: production\n    at https://cdn.example.com/main.js:{line}:{column}");
+
+  let symbolicator = rspack_plugin_devtool::get_compilation_symbolicator(compiler.compilation.id())
+    .unwrap()
+    .unwrap();
+  assert_eq!(
+    symbolicator.symbolicate_stack_trace(&stack),
+    "Error: production\n    at webpack:///./src/index.js:2:2"
+  );
+}
@@ -0,0 +1,3 @@
+import App from "./App.vue";
+
+export default App;
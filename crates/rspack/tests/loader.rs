@@ -2,8 +2,8 @@
 
 use rspack::builder::Builder as _;
 use rspack_core::{
-  Compiler, Experiments, ModuleOptions, ModuleRule, ModuleRuleEffect, ModuleRuleUse,
-  ModuleRuleUseLoader, RuleSetCondition,
+  rspack_sources::MapOptions, Compiler, Experiments, ExternalItem, ModuleOptions, ModuleRule,
+  ModuleRuleEffect, ModuleRuleUse, ModuleRuleUseLoader, ModuleType, RuleSetCondition,
 };
 use rspack_paths::Utf8Path;
 use rspack_regex::RspackRegex;
@@ -41,6 +41,120 @@ async fn lightningcss() {
   assert!(errors.is_empty());
 }
 
+#[cfg(feature = "loader_sass")]
+#[tokio::test(flavor = "multi_thread")]
+async fn sass() {
+  let mut compiler = Compiler::builder()
+    .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/sass"))
+    .entry("main", "./src/index.js")
+    .module(ModuleOptions::builder().rule(ModuleRule {
+      test: Some(RuleSetCondition::Regexp(
+        RspackRegex::new("\\.scss$").unwrap(),
+      )),
+      effect: ModuleRuleEffect {
+        r#type: Some(ModuleType::Css),
+        r#use: ModuleRuleUse::Array(vec![ModuleRuleUseLoader {
+          loader: "builtin:sass-loader".to_string(),
+          options: Some(
+            json!({
+              "additionalData": "@use \"theme\" as *;",
+              "sourceMap": true
+            })
+            .to_string(),
+          ),
+        }]),
+        ..Default::default()
+      },
+      ..Default::default()
+    }))
+    .experiments(Experiments::builder().css(true))
+    .enable_loader_sass()
+    .build()
+    .unwrap();
+
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty());
+
+  let module_graph = compiler.compilation.get_module_graph();
+  let source = module_graph
+    .modules()
+    .into_values()
+    .find(|module| module.identifier().as_str().ends_with("index.scss"))
+    .and_then(|module| module.source())
+    .expect("should have the compiled index.scss");
+  assert!(source
+    .source()
+    .contains(".card {\n  color: #bf4040;\n}\n.card .title {\n  margin: 0;\n}"));
+
+  // the lines of `additionalData` are not part of the original locations
+  let map = source
+    .map(&MapOptions::default())
+    .expect("should have a source map");
+  assert!(map.sources()[0].ends_with("index.scss"));
+  assert_eq!(
+    map.sources_content()[0],
+    ".card {\n  color: $primary;\n\n  .title {\n    margin: 0;\n  }\n}\n"
+  );
+  let locations = map
+    .decoded_mappings()
+    .filter_map(|mapping| {
+      let original = mapping.original?;
+      (original.source_index == 0).then_some((
+        mapping.generated_line,
+        original.original_line,
+        original.original_column,
+      ))
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(locations, vec![(1, 1, 0), (2, 2, 2), (4, 4, 2), (5, 5, 4)]);
+}
+
+#[cfg(feature = "loader_sass")]
+#[tokio::test(flavor = "multi_thread")]
+async fn sass_use_namespace() {
+  let mut compiler = Compiler::builder()
+    .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/sass-namespace"))
+    .entry("main", "./src/index.js")
+    .module(ModuleOptions::builder().rule(ModuleRule {
+      test: Some(RuleSetCondition::Regexp(
+        RspackRegex::new("\\.scss$").unwrap(),
+      )),
+      effect: ModuleRuleEffect {
+        r#type: Some(ModuleType::Css),
+        r#use: ModuleRuleUse::Array(vec![ModuleRuleUseLoader {
+          loader: "builtin:sass-loader".to_string(),
+          options: None,
+        }]),
+        ..Default::default()
+      },
+      ..Default::default()
+    }))
+    .experiments(Experiments::builder().css(true))
+    .enable_loader_sass()
+    .build()
+    .unwrap();
+
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty());
+
+  // `theme` resolves to `theme/_index.scss` and `ui-kit` to `scss/main.scss`,
+  // both are still used with the namespace of their url
+  let module_graph = compiler.compilation.get_module_graph();
+  let source = module_graph
+    .modules()
+    .into_values()
+    .find(|module| module.identifier().as_str().ends_with("index.scss"))
+    .and_then(|module| module.source())
+    .expect("should have the compiled index.scss");
+  assert!(source
+    .source()
+    .contains(".card {\n  color: #bf4040;\n  padding: 4px;\n}"));
+}
+
 #[cfg(feature = "loader_swc")]
 #[tokio::test(flavor = "multi_thread")]
 async fn swc() {
@@ -190,3 +304,47 @@ async fn preact_refresh() {
   let errors: Vec<_> = compiler.compilation.get_errors().collect();
   assert!(errors.is_empty());
 }
+
+#[cfg(feature = "loader_vue")]
+#[tokio::test(flavor = "multi_thread")]
+async fn vue() {
+  let mut compiler = Compiler::builder()
+    .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/vue"))
+    .entry("main", "./src/index.js")
+    .module(
+      ModuleOptions::builder()
+        .rule(ModuleRule {
+          test: Some(RuleSetCondition::Regexp(
+            RspackRegex::new("\\.vue$").unwrap(),
+          )),
+          effect: ModuleRuleEffect {
+            r#use: ModuleRuleUse::Array(vec![ModuleRuleUseLoader {
+              loader: "builtin:vue-loader".to_string(),
+              options: None,
+            }]),
+            ..Default::default()
+          },
+          ..Default::default()
+        })
+        .rule(ModuleRule {
+          test: Some(RuleSetCondition::Regexp(
+            RspackRegex::new("\\.css$").unwrap(),
+          )),
+          effect: ModuleRuleEffect {
+            r#type: Some(ModuleType::Css),
+            ..Default::default()
+          },
+          ..Default::default()
+        }),
+    )
+    .externals(ExternalItem::String("vue".to_string()))
+    .experiments(Experiments::builder().css(true))
+    .enable_loader_vue()
+    .build()
+    .unwrap();
+
+  compiler.build().await.unwrap();
+
+  let errors: Vec<_> = compiler.compilation.get_errors().collect();
+  assert!(errors.is_empty());
+}
@@ -7,6 +7,7 @@ use std::{fmt::Debug, sync::Arc};
 use rspack_error::Result;
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem};
 
+use self::persistent::storage::Storage;
 pub use self::{disable::DisableCache, memory::MemoryCache, persistent::PersistentCache};
 use crate::{make::MakeArtifact, Compilation, CompilerOptions, ExperimentCacheOptions};
 
@@ -38,6 +39,12 @@ pub trait Cache: Debug + Send + Sync {
   async fn after_make(&self, _make_artifact: &MakeArtifact) -> Result<()> {
     Ok(())
   }
+
+  /// The storage of the persistent cache, which plugins can use to persist
+  /// their own results in a dedicated scope, see [persistent::ScopeCache].
+  fn storage(&self) -> Option<Arc<dyn Storage>> {
+    None
+  }
 }
 
 pub fn new_cache(
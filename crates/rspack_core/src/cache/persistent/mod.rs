@@ -1,5 +1,6 @@
 mod cacheable_context;
 mod occasion;
+mod scope_cache;
 pub mod snapshot;
 pub mod storage;
 mod version;
@@ -12,6 +13,7 @@ use rspack_fs::{IntermediateFileSystem, ReadableFileSystem};
 use rspack_macros::rspack_version;
 use rspack_paths::ArcPath;
 use rustc_hash::FxHashSet as HashSet;
+pub use scope_cache::ScopeCache;
 
 use self::{
   snapshot::{Snapshot, SnapshotOptions},
@@ -145,4 +147,8 @@ impl Cache for PersistentCache {
     self.make_occasion.save(make_artifact);
     Ok(())
   }
+
+  fn storage(&self) -> Option<Arc<dyn Storage>> {
+    Some(self.storage.clone())
+  }
 }
@@ -0,0 +1,68 @@
+use std::sync::{
+  atomic::{AtomicBool, Ordering},
+  Arc,
+};
+
+use dashmap::{DashMap, DashSet};
+use rspack_error::Result;
+
+use super::storage::Storage;
+
+/// Results of a plugin persisted in a dedicated storage scope.
+///
+/// The scope is loaded from storage by the first compilation, later compilations
+/// read it from memory. Entries that weren't used by a compilation are removed
+/// when it finishes, so only the results of the latest build are kept.
+#[derive(Debug)]
+pub struct ScopeCache {
+  scope: &'static str,
+  loaded: AtomicBool,
+  entries: DashMap<Vec<u8>, Arc<Vec<u8>>>,
+  used: DashSet<Vec<u8>>,
+}
+
+impl ScopeCache {
+  pub fn new(scope: &'static str) -> Self {
+    Self {
+      scope,
+      loaded: AtomicBool::new(false),
+      entries: Default::default(),
+      used: Default::default(),
+    }
+  }
+
+  /// Loads the scope from storage on first call.
+  pub async fn load(&self, storage: &Arc<dyn Storage>) -> Result<()> {
+    if self.loaded.swap(true, Ordering::Relaxed) {
+      return Ok(());
+    }
+    for (key, value) in storage.load(self.scope).await? {
+      self.entries.insert(key.to_vec(), value);
+    }
+    Ok(())
+  }
+
+  pub fn get(&self, key: &[u8]) -> Option<Arc<Vec<u8>>> {
+    let value = self.entries.get(key)?.clone();
+    self.used.insert(key.to_vec());
+    Some(value)
+  }
+
+  pub fn set(&self, storage: &Arc<dyn Storage>, key: Vec<u8>, value: Vec<u8>) {
+    storage.set(self.scope, key.clone(), value.clone());
+    self.used.insert(key.clone());
+    self.entries.insert(key, Arc::new(value));
+  }
+
+  /// Removes the entries that weren't used since the last call.
+  pub fn clean(&self, storage: &Arc<dyn Storage>) {
+    self.entries.retain(|key, _| {
+      let used = self.used.contains(key);
+      if !used {
+        storage.remove(self.scope, key);
+      }
+      used
+    });
+    self.used.clear();
+  }
+}
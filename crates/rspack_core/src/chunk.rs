@@ -52,6 +52,32 @@ pub struct ChunkRenderResult {
   pub diagnostics: Vec<Diagnostic>,
 }
 
+/// A cache group whose modules were moved into a chunk by `SplitChunksPlugin`.
+#[derive(Debug, Clone)]
+pub struct SplitChunksDecision {
+  pub cache_group: String,
+  pub name: Option<String>,
+  pub priority: f64,
+  pub modules: usize,
+  pub min_size: HashMap<SourceType, f64>,
+  pub max_async_size: HashMap<SourceType, f64>,
+  pub max_initial_size: HashMap<SourceType, f64>,
+  /// The modules were moved into a chunk which already existed, because of
+  /// the chunk name or `reuseExistingChunk`.
+  pub reused_existing_chunk: bool,
+}
+
+/// Why `SplitChunksPlugin` shaped a chunk the way it is, recorded with
+/// `optimization.splitChunks.explain`.
+#[derive(Debug, Clone, Default)]
+pub struct SplitChunksExplanation {
+  pub decisions: Vec<SplitChunksDecision>,
+  /// The key of the part when the chunk was split by `maxSize`.
+  pub max_size_part: Option<String>,
+  /// The splits which kept their modules in this chunk.
+  pub blocked: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Chunk {
   ukey: ChunkUkey,
@@ -69,6 +95,7 @@ pub struct Chunk {
   files: HashSet<String>,
   auxiliary_files: HashSet<String>,
   chunk_reason: Option<String>,
+  split_chunks_explanation: Option<Box<SplitChunksExplanation>>,
   rendered: bool,
 }
 
@@ -204,6 +231,18 @@ impl Chunk {
     &mut self.chunk_reason
   }
 
+  pub fn split_chunks_explanation(&self) -> Option<&SplitChunksExplanation> {
+    self.split_chunks_explanation.as_deref()
+  }
+
+  pub fn split_chunks_explanation_mut(&mut self) -> &mut SplitChunksExplanation {
+    self.split_chunks_explanation.get_or_insert_default()
+  }
+
+  pub fn set_split_chunks_explanation(&mut self, explanation: Option<SplitChunksExplanation>) {
+    self.split_chunks_explanation = explanation.map(Box::new);
+  }
+
   pub fn hash<'a>(
     &self,
     chunk_hashes_results: &'a ChunkHashesArtifact,
@@ -287,6 +326,7 @@ impl Chunk {
       runtime: RuntimeSpec::default(),
       kind,
       chunk_reason: Default::default(),
+      split_chunks_explanation: None,
       rendered: false,
     }
   }
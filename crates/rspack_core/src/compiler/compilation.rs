@@ -245,6 +245,10 @@ pub struct Compilation {
   pub module_ids_artifact: ModuleIdsArtifact,
   // artifact for chunk_ids
   pub chunk_ids_artifact: ChunkIdsArtifact,
+  // ids of the modules and chunks of previous builds, which are not given to
+  // other modules and chunks
+  pub used_module_ids: HashSet<String>,
+  pub used_chunk_ids: HashSet<String>,
   // artifact for code_generation
   pub code_generation_results: BindingCell<CodeGenerationResults>,
   // artifact for create_module_hashes
@@ -260,6 +264,9 @@ pub struct Compilation {
 
   pub code_generated_modules: IdentifierSet,
   pub build_time_executed_modules: IdentifierSet,
+  /// Minified bytes of each module summed over all assets, set by minimizers
+  /// that attribute the minified code to modules through source maps.
+  pub minimized_module_sizes: IdentifierMap<f64>,
   pub cache: Arc<dyn Cache>,
   pub old_cache: Arc<OldCache>,
   pub code_splitting_cache: CodeSplittingCache,
@@ -370,6 +377,8 @@ impl Compilation {
       side_effects_optimize_artifact: Default::default(),
       module_ids_artifact: Default::default(),
       chunk_ids_artifact: Default::default(),
+      used_module_ids: Default::default(),
+      used_chunk_ids: Default::default(),
       code_generation_results: Default::default(),
       cgm_hash_artifact: Default::default(),
       cgm_runtime_requirements_artifact: Default::default(),
@@ -378,6 +387,7 @@ impl Compilation {
       chunk_render_artifact: Default::default(),
       code_generated_modules: Default::default(),
       build_time_executed_modules: Default::default(),
+      minimized_module_sizes: Default::default(),
       cache,
       old_cache,
       incremental,
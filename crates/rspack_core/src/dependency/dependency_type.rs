@@ -62,6 +62,8 @@ pub enum DependencyType {
   CssImport,
   // css modules compose
   CssCompose,
+  // css modules @value import
+  CssValueImport,
   // css :export
   CssExport,
   // css modules local ident
@@ -120,6 +122,7 @@ pub enum DependencyType {
   RstestModulePath,
   RstestMockModuleId,
   RstestHoistMock,
+  RstestCoverage,
 }
 
 impl DependencyType {
@@ -155,6 +158,7 @@ impl DependencyType {
       DependencyType::CssUrl => "css url",
       DependencyType::CssImport => "css import",
       DependencyType::CssCompose => "css compose",
+      DependencyType::CssValueImport => "css value import",
       DependencyType::CssExport => "css export",
       DependencyType::CssLocalIdent => "css local ident",
       DependencyType::CssSelfReferenceLocalIdent => "css self reference local ident",
@@ -196,6 +200,7 @@ impl DependencyType {
       DependencyType::RstestModulePath => "rstest module path",
       DependencyType::RstestMockModuleId => "rstest mock module id",
       DependencyType::RstestHoistMock => "rstest hoist mock",
+      DependencyType::RstestCoverage => "rstest coverage",
     }
   }
 }
@@ -1,5 +1,6 @@
 use std::{ptr::NonNull, sync::Arc};
 
+use rspack_fs::ReadableFileSystem;
 pub use rspack_loader_runner::{run_loaders, Content, Loader, LoaderContext};
 use rspack_util::source_map::SourceMapKind;
 
@@ -11,6 +12,7 @@ pub struct RunnerContext {
   pub compilation_id: CompilationId,
   pub options: Arc<CompilerOptions>,
   pub resolver_factory: Arc<ResolverFactory>,
+  pub input_filesystem: Arc<dyn ReadableFileSystem>,
   pub module: NonNull<dyn Module>,
   pub module_source_map_kind: SourceMapKind,
 }
@@ -60,6 +60,14 @@ pub struct BuildInfo {
   pub build_dependencies: HashSet<ArcPath>,
   #[cacheable(with=AsVec<AsPreset>)]
   pub esm_named_exports: HashSet<Atom>,
+  /// Names exported only as TypeScript types or ambient declarations, which
+  /// are erased at runtime.
+  #[cacheable(with=AsVec<AsPreset>)]
+  pub type_only_exports: HashSet<Atom>,
+  /// Names exported as ambient TypeScript const enums, which have no value at
+  /// runtime.
+  #[cacheable(with=AsVec<AsPreset>)]
+  pub ambient_const_enum_exports: HashSet<Atom>,
   pub all_star_exports: Vec<DependencyId>,
   pub need_create_require: bool,
   #[cacheable(with=AsOption<AsPreset>)]
@@ -88,6 +96,8 @@ impl Default for BuildInfo {
       missing_dependencies: HashSet::default(),
       build_dependencies: HashSet::default(),
       esm_named_exports: HashSet::default(),
+      type_only_exports: HashSet::default(),
+      ambient_const_enum_exports: HashSet::default(),
       all_star_exports: Vec::default(),
       need_create_require: false,
       json_data: None,
@@ -255,6 +255,10 @@ impl NormalModule {
     &self.loaders
   }
 
+  pub fn loaders_mut(&mut self) -> &mut Vec<BoxLoader> {
+    &mut self.loaders
+  }
+
   pub fn parser_and_generator(&self) -> &dyn ParserAndGenerator {
     &*self.parser_and_generator
   }
@@ -407,6 +411,7 @@ impl Module for NormalModule {
         compilation_id: build_context.compilation_id,
         options: build_context.compiler_options.clone(),
         resolver_factory: build_context.resolver_factory.clone(),
+        input_filesystem: build_context.fs.clone(),
         #[allow(clippy::unwrap_used)]
         module: NonNull::new(self).unwrap(),
         module_source_map_kind: self.source_map_kind,
@@ -294,6 +294,8 @@ pub struct CssParserOptions {
 pub struct CssAutoParserOptions {
   pub named_exports: Option<bool>,
   pub url: Option<bool>,
+  /// Warn about the local classes whose exports are never used.
+  pub warn_unused_classes: Option<bool>,
 }
 
 impl From<CssParserOptions> for CssAutoParserOptions {
@@ -301,6 +303,7 @@ impl From<CssParserOptions> for CssAutoParserOptions {
     Self {
       named_exports: value.named_exports,
       url: value.url,
+      warn_unused_classes: None,
     }
   }
 }
@@ -310,6 +313,8 @@ impl From<CssParserOptions> for CssAutoParserOptions {
 pub struct CssModuleParserOptions {
   pub named_exports: Option<bool>,
   pub url: Option<bool>,
+  /// Warn about the local classes whose exports are never used.
+  pub warn_unused_classes: Option<bool>,
 }
 
 impl From<CssParserOptions> for CssModuleParserOptions {
@@ -317,6 +322,7 @@ impl From<CssParserOptions> for CssModuleParserOptions {
     Self {
       named_exports: value.named_exports,
       url: value.url,
+      warn_unused_classes: None,
     }
   }
 }
@@ -8,7 +8,7 @@ use rspack_macros::MergeFrom;
 use rspack_paths::Utf8PathBuf;
 
 use super::CleanOptions;
-use crate::{Chunk, ChunkGroupByUkey, ChunkKind, Compilation, Filename};
+use crate::{Chunk, ChunkGroupByUkey, ChunkId, ChunkKind, Compilation, Filename};
 
 #[derive(Debug)]
 pub enum PathInfo {
@@ -62,6 +62,7 @@ pub struct OutputOptions {
   pub script_type: String,
   pub environment: Environment,
   pub compare_before_emit: bool,
+  pub import_map: Option<ImportMapOptions>,
 }
 
 impl From<&OutputOptions> for RspackHash {
@@ -191,6 +192,58 @@ impl From<&str> for WasmLoadingType {
   }
 }
 
+/// The ESM chunks import each other with bare specifiers, which an import map
+/// resolves to their files, so the hash of a chunk doesn't change when the
+/// chunks it imports change.
+#[derive(Debug)]
+pub struct ImportMapOptions {
+  /// The asset the import map is emitted to.
+  pub filename: String,
+  /// The prefix of the specifiers, followed by the chunk id.
+  pub prefix: String,
+}
+
+impl ImportMapOptions {
+  pub fn chunk_specifier(&self, chunk_id: &ChunkId) -> String {
+    format!("{}{}", self.prefix, chunk_id)
+  }
+
+  /// Renders the import map of the JavaScript chunks, their files are
+  /// prefixed with the `public_path`.
+  pub fn render(&self, compilation: &Compilation, public_path: &str) -> String {
+    // The addresses in an import map must be URLs or start with `/`, `./` or `../`.
+    let public_path = if public_path.starts_with('/')
+      || public_path.starts_with("./")
+      || public_path.starts_with("../")
+      || public_path.contains("://")
+    {
+      Cow::Borrowed(public_path)
+    } else {
+      Cow::Owned(format!("./{public_path}"))
+    };
+    let assets = compilation.assets();
+    let mut imports = serde_json::Map::new();
+    for chunk in compilation.chunk_by_ukey.values() {
+      let Some(chunk_id) = chunk.id(&compilation.chunk_ids_artifact) else {
+        continue;
+      };
+      let file = chunk.files().iter().find(|file| {
+        assets
+          .get(*file)
+          .is_some_and(|asset| asset.info.javascript_module == Some(true))
+      });
+      if let Some(file) = file {
+        imports.insert(
+          self.chunk_specifier(chunk_id),
+          serde_json::Value::String(format!("{public_path}{file}")),
+        );
+      }
+    }
+    serde_json::to_string_pretty(&serde_json::json!({ "imports": imports }))
+      .expect("should serialize import map")
+  }
+}
+
 #[derive(Debug, Clone)]
 pub enum CrossOriginLoading {
   Disable,
@@ -37,6 +37,15 @@ pub struct ParseContext<'a> {
   pub build_meta: &'a mut BuildMeta,
 }
 
+/// Key of `parse_meta` for the JSON array of the names that a module exports
+/// only as TypeScript types or ambient declarations, set by the loaders that
+/// strip types.
+pub const TYPE_ONLY_EXPORTS_PARSE_META_KEY: &str = "typeOnlyExports";
+
+/// Key of `parse_meta` for the JSON array of the names that a module exports
+/// as ambient TypeScript const enums, set by the loaders that strip types.
+pub const AMBIENT_CONST_ENUM_EXPORTS_PARSE_META_KEY: &str = "ambientConstEnumExports";
+
 #[derive(Debug)]
 pub struct SideEffectsBailoutItem {
   pub msg: String,
@@ -422,6 +422,7 @@ impl Stats<'_> {
           runtime: c.runtime(),
           sizes: chunk_graph.get_chunk_modules_sizes(&c.ukey(), self.compilation),
           reason: c.chunk_reason(),
+          split_chunks: c.split_chunks_explanation(),
           rendered: c.rendered(),
           origins,
           hash: c.rendered_hash(
@@ -785,6 +786,11 @@ impl Stats<'_> {
       layer: module.get_layer().map(|layer| layer.into()),
       size: module.size(None, Some(self.compilation)),
       sizes,
+      minimized_size: self
+        .compilation
+        .minimized_module_sizes
+        .get(&identifier)
+        .copied(),
       built,
       code_generated,
       build_time_executed: executed,
@@ -1113,6 +1119,7 @@ impl Stats<'_> {
         source_type: SourceType::Custom("runtime".into()),
         size: module.size,
       }],
+      minimized_size: None,
       built,
       code_generated,
       build_time_executed: true,
@@ -1229,6 +1236,11 @@ impl Stats<'_> {
         source_type: SourceType::Custom("runtime".into()),
         size,
       }],
+      minimized_size: self
+        .compilation
+        .minimized_module_sizes
+        .get(identifier)
+        .copied(),
       built,
       code_generated,
       build_time_executed: false,
@@ -5,7 +5,10 @@ use rspack_sources::BoxSource;
 use rspack_util::atom::Atom;
 use rustc_hash::FxHashMap as HashMap;
 
-use crate::{ChunkGroupOrderKey, ModuleId, ModuleIdentifier, ModuleType, RuntimeSpec, SourceType};
+use crate::{
+  ChunkGroupOrderKey, ModuleId, ModuleIdentifier, ModuleType, RuntimeSpec, SourceType,
+  SplitChunksExplanation,
+};
 
 pub enum EntrypointsStatsOption {
   Bool(bool),
@@ -150,6 +153,7 @@ pub struct StatsModule<'a> {
   pub chunks: Option<Vec<&'a str>>, // has id after the call of chunkIds hook
   pub size: f64,
   pub sizes: Vec<StatsSourceTypeSize>,
+  pub minimized_size: Option<f64>,
   pub dependent: Option<bool>,
   pub issuer: Option<ModuleIdentifier>,
   pub issuer_name: Option<Cow<'a, str>>,
@@ -219,6 +223,7 @@ pub struct StatsChunk<'a> {
   pub runtime: &'a RuntimeSpec,
   pub sizes: HashMap<SourceType, f64>,
   pub reason: Option<&'a str>,
+  pub split_chunks: Option<&'a SplitChunksExplanation>,
   pub rendered: bool,
   pub origins: Vec<StatsOriginRecord<'a>>,
   pub id_hints: Vec<&'a str>,
@@ -14,12 +14,16 @@ pub enum HashFunction {
   MD4,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashFunction {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "md4" => Ok(HashFunction::MD4),
+      _ => Err(format!(
+        "Unsupported hash function \"{value}\", expected \"xxhash64\" or \"md4\""
+      )),
     }
   }
 }
@@ -27,13 +31,19 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  Base64,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashDigest {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => unimplemented!(),
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      _ => Err(format!(
+        "Unsupported hash digest \"{value}\", expected \"hex\" or \"base64\""
+      )),
     }
   }
 }
@@ -158,6 +168,20 @@ impl RspackHashDigest {
         let s = hex(inner, &mut buf);
         s.into()
       }
+      HashDigest::Base64 => {
+        // 24 bytes fit the 128bit outputs, longer ones are encoded on the heap
+        let mut buf = [0; 24];
+        let mut heap_buf;
+        let len = inner.len().div_ceil(3) * 4;
+        let buf = if len <= buf.len() {
+          &mut buf[..]
+        } else {
+          heap_buf = vec![0; len];
+          &mut heap_buf[..]
+        };
+        let s = base64(inner, buf);
+        s.into()
+      }
     };
     Self { encoded }
   }
@@ -205,3 +229,68 @@ fn hex<'a>(data: &[u8], output: &'a mut [u8]) -> &'a str {
   // hex is always ascii
   unsafe { std::str::from_utf8_unchecked(&output[..i]) }
 }
+
+/// Standard base64 with padding, the `base64` digest of node's `crypto`.
+#[inline]
+fn base64<'a>(data: &[u8], output: &'a mut [u8]) -> &'a str {
+  const BASE64_TABLE: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+  assert!(data.len().div_ceil(3) * 4 <= output.len());
+
+  let mut i = 0;
+  for chunk in data.chunks(3) {
+    let b0 = chunk[0];
+    let b1 = chunk.get(1).copied().unwrap_or(0);
+    let b2 = chunk.get(2).copied().unwrap_or(0);
+    output[i] = BASE64_TABLE[(b0 >> 2) as usize];
+    output[i + 1] = BASE64_TABLE[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize];
+    output[i + 2] = if chunk.len() > 1 {
+      BASE64_TABLE[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize]
+    } else {
+      b'='
+    };
+    output[i + 3] = if chunk.len() > 2 {
+      BASE64_TABLE[(b2 & 0x3f) as usize]
+    } else {
+      b'='
+    };
+    i += 4;
+  }
+
+  // # Safety
+  //
+  // base64 is always ascii
+  unsafe { std::str::from_utf8_unchecked(&output[..i]) }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_encode_base64() {
+    let digest = |inner: &[u8]| RspackHashDigest::new(inner, &HashDigest::Base64);
+    assert_eq!(digest(b"").encoded(), "");
+    assert_eq!(digest(b"rspack").encoded(), "cnNwYWNr");
+    assert_eq!(digest(b"rspack!").encoded(), "cnNwYWNrIQ==");
+    assert_eq!(
+      digest(&[0xff; 32]).encoded(),
+      "//////////////////////////////////////////8="
+    );
+  }
+
+  #[test]
+  fn should_reject_unknown_names() {
+    assert!(matches!(
+      HashFunction::try_from("xxhash64"),
+      Ok(HashFunction::Xxhash64)
+    ));
+    assert!(HashFunction::try_from("sha256").is_err());
+    assert!(matches!(
+      HashDigest::try_from("base64"),
+      Ok(HashDigest::Base64)
+    ));
+    assert!(HashDigest::try_from("latin1").is_err());
+  }
+}
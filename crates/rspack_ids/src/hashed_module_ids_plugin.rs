@@ -0,0 +1,131 @@
+use std::hash::Hasher;
+
+use rspack_core::{
+  incremental::IncrementalPasses, ApplyContext, ChunkGraph, Compilation, CompilationModuleIds,
+  CompilerOptions, Plugin, PluginContext,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hash::{HashDigest, HashFunction, HashSalt, RspackHash};
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::id_helpers::{
+  compare_modules_by_pre_order_index_or_identifier, get_full_module_name,
+  get_used_module_ids_and_modules,
+};
+
+#[derive(Debug)]
+pub struct HashedModuleIdsPluginOptions {
+  /// The context the module names are made relative to, defaults to the
+  /// compiler context.
+  pub context: Option<String>,
+  pub hash_function: HashFunction,
+  pub hash_digest: HashDigest,
+  /// The minimum length of the ids, an id is lengthened when its prefix is
+  /// already used by another module.
+  pub hash_digest_length: usize,
+  pub salt: HashSalt,
+}
+
+impl Default for HashedModuleIdsPluginOptions {
+  fn default() -> Self {
+    Self {
+      context: None,
+      hash_function: HashFunction::MD4,
+      hash_digest: HashDigest::Base64,
+      hash_digest_length: 4,
+      salt: HashSalt::None,
+    }
+  }
+}
+
+/// Uses the hash of the module name as the module id, as webpack's
+/// `HashedModuleIdsPlugin`, so the ids neither leak the paths nor depend on
+/// the other modules of the compilation.
+#[plugin]
+#[derive(Debug)]
+pub struct HashedModuleIdsPlugin {
+  options: HashedModuleIdsPluginOptions,
+}
+
+impl HashedModuleIdsPlugin {
+  pub fn new(options: HashedModuleIdsPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilationModuleIds for HashedModuleIdsPlugin)]
+async fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  if let Some(diagnostic) = compilation.incremental.disable_passes(
+    IncrementalPasses::MODULE_IDS,
+    "HashedModuleIdsPlugin",
+    "it requires calculating the id of all the modules, which is a global effect",
+  ) {
+    if let Some(diagnostic) = diagnostic {
+      compilation.push_diagnostic(diagnostic);
+    }
+    compilation.module_ids_artifact.clear();
+  }
+
+  let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+
+  let mut module_ids = std::mem::take(&mut compilation.module_ids_artifact);
+  let context = self
+    .options
+    .context
+    .as_deref()
+    .unwrap_or(compilation.options.context.as_str());
+
+  let module_graph = compilation.get_module_graph();
+  let mut modules = modules
+    .into_iter()
+    .filter_map(|i| module_graph.module_by_identifier(&i))
+    .collect::<Vec<_>>();
+  modules
+    .sort_unstable_by(|a, b| compare_modules_by_pre_order_index_or_identifier(&module_graph, a, b));
+
+  let mut diagnostics = vec![];
+  for module in modules {
+    let ident = get_full_module_name(module, context);
+    let mut hasher = RspackHash::with_salt(&self.options.hash_function, &self.options.salt);
+    hasher.write(ident.as_bytes());
+    let digest = hasher.digest(&self.options.hash_digest);
+    let hash_id = digest.encoded();
+    let mut len = self.options.hash_digest_length;
+    while len < hash_id.len() && used_ids.contains(digest.rendered(len)) {
+      len += 1;
+    }
+    let module_id = digest.rendered(len).to_string();
+    if used_ids.contains(&module_id) {
+      diagnostics.push(Diagnostic::error(
+        "HashedModuleIdsPlugin".to_string(),
+        format!(
+          "The id \"{module_id}\" of {ident} is already used by another module, even with the full length of the hash. Change the `hashFunction` or the `salt`."
+        ),
+      ));
+    }
+    ChunkGraph::set_module_id(
+      &mut module_ids,
+      module.identifier(),
+      module_id.clone().into(),
+    );
+    used_ids.insert(module_id);
+  }
+  compilation.module_ids_artifact = module_ids;
+  compilation.extend_diagnostics(diagnostics);
+  Ok(())
+}
+
+impl Plugin for HashedModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.HashedModuleIdsPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .module_ids
+      .tap(module_ids::new(self));
+    Ok(())
+  }
+}
@@ -31,14 +31,7 @@ pub fn get_used_module_ids_and_modules(
 ) -> (FxHashSet<String>, Vec<ModuleIdentifier>) {
   let chunk_graph = &compilation.chunk_graph;
   let mut modules = vec![];
-  let mut used_ids = FxHashSet::default();
-
-  // TODO: currently we don't have logic of compilation.usedModuleIds
-  //   if (compilation.usedModuleIds) {
-  //     for (const id of compilation.usedModuleIds) {
-  //         usedIds.add(id + "");
-  //     }
-  //   }
+  let mut used_ids = compilation.used_module_ids.clone();
 
   compilation
     .get_module_graph()
@@ -318,7 +311,7 @@ pub fn request_to_id(request: &str) -> String {
 }
 
 pub fn get_used_chunk_ids(compilation: &Compilation) -> FxHashSet<String> {
-  let mut used_ids = FxHashSet::default();
+  let mut used_ids = compilation.used_chunk_ids.clone();
   for chunk in compilation.chunk_by_ukey.values() {
     if let Some(id) = chunk.id(&compilation.chunk_ids_artifact) {
       used_ids.insert(id.to_string());
@@ -4,6 +4,8 @@
 
 mod deterministic_module_ids_plugin;
 pub use deterministic_module_ids_plugin::*;
+mod hashed_module_ids_plugin;
+pub use hashed_module_ids_plugin::{HashedModuleIdsPlugin, HashedModuleIdsPluginOptions};
 mod named_module_ids_plugin;
 pub use named_module_ids_plugin::*;
 pub mod id_helpers;
@@ -17,3 +19,5 @@ mod natural_chunk_ids_plugin;
 pub use natural_chunk_ids_plugin::NaturalChunkIdsPlugin;
 mod occurrence_chunk_ids_plugin;
 pub use occurrence_chunk_ids_plugin::*;
+mod record_ids_plugin;
+pub use record_ids_plugin::{RecordIdsPlugin, RecordIdsPluginOptions};
@@ -0,0 +1,286 @@
+use std::{collections::BTreeMap, sync::Mutex};
+
+use rspack_collections::DatabaseItem;
+use rspack_core::{
+  incremental::IncrementalPasses, ApplyContext, ChunkGraph, ChunkUkey, Compilation,
+  CompilationChunkIds, CompilationModuleIds, CompilerAfterEmit, CompilerOptions, ModuleIdentifier,
+  Plugin, PluginContext,
+};
+use rspack_error::{error, Result, ToStringResultToRspackResultExt};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_paths::Utf8PathBuf;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::id_helpers::{get_full_chunk_name, get_full_module_name};
+
+/// Ids are written as numbers when they are numeric, like webpack records.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum RecordId {
+  Number(u64),
+  String(String),
+}
+
+impl From<&str> for RecordId {
+  fn from(id: &str) -> Self {
+    match id.parse::<u64>() {
+      Ok(number) if number.to_string() == id => Self::Number(number),
+      _ => Self::String(id.to_string()),
+    }
+  }
+}
+
+impl RecordId {
+  fn into_string(self) -> String {
+    match self {
+      Self::Number(number) => number.to_string(),
+      Self::String(string) => string,
+    }
+  }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct ModuleRecords {
+  /// module identifier relative to the context -> id
+  by_identifier: BTreeMap<String, RecordId>,
+  used_ids: Vec<RecordId>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct ChunkRecords {
+  by_name: BTreeMap<String, RecordId>,
+  /// root modules of the unnamed chunks -> id
+  by_source: BTreeMap<String, RecordId>,
+  used_ids: Vec<RecordId>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Records {
+  modules: ModuleRecords,
+  chunks: ChunkRecords,
+}
+
+#[derive(Debug, Default)]
+pub struct RecordIdsPluginOptions {
+  /// The records pinning the ids of the previous build, a missing file is
+  /// treated as empty records.
+  pub input_path: Option<Utf8PathBuf>,
+  /// Where the ids of this build are written after emit.
+  pub output_path: Option<Utf8PathBuf>,
+}
+
+/// Gives the modules and chunks the ids they had in the records of the previous
+/// build, and keeps the ids of the removed ones from being reused, as webpack's
+/// `recordsPath`. The ids of the new modules and chunks are assigned by the
+/// `optimization.moduleIds` and `optimization.chunkIds` plugins.
+#[plugin]
+#[derive(Debug)]
+pub struct RecordIdsPlugin {
+  options: RecordIdsPluginOptions,
+  records: Mutex<Records>,
+}
+
+impl RecordIdsPlugin {
+  pub fn new(options: RecordIdsPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+}
+
+#[plugin_hook(CompilationModuleIds for RecordIdsPlugin, stage = -100)]
+async fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let records = match &self.options.input_path {
+    Some(input_path) => match compilation.input_filesystem.read(input_path).await {
+      Ok(content) => serde_json::from_slice::<Records>(&content)
+        .map_err(|e| error!("Cannot parse records {input_path}: {e}"))?,
+      Err(_) => Records::default(),
+    },
+    None => Records::default(),
+  };
+
+  if let Some(diagnostic) = compilation.incremental.disable_passes(
+    IncrementalPasses::MODULE_IDS,
+    "RecordIdsPlugin (recordsPath)",
+    "it requires restoring the id of all the modules, which is a global effect",
+  ) {
+    if let Some(diagnostic) = diagnostic {
+      compilation.push_diagnostic(diagnostic);
+    }
+    compilation.module_ids_artifact.clear();
+  }
+
+  let context = compilation.options.context.as_str();
+  let chunk_graph = &compilation.chunk_graph;
+  let module_graph = compilation.get_module_graph();
+  let mut restored: FxHashMap<String, ModuleIdentifier> = FxHashMap::default();
+  for module in module_graph.modules().values() {
+    if !module.need_id()
+      || chunk_graph.get_number_of_module_chunks(module.identifier()) == 0
+      || ChunkGraph::get_module_id(&compilation.module_ids_artifact, module.identifier()).is_some()
+    {
+      continue;
+    }
+    if let Some(id) = records
+      .modules
+      .by_identifier
+      .get(&get_full_module_name(module, context))
+    {
+      restored
+        .entry(id.clone().into_string())
+        .or_insert(module.identifier());
+    }
+  }
+
+  for (id, module) in restored {
+    ChunkGraph::set_module_id(&mut compilation.module_ids_artifact, module, id.into());
+  }
+  compilation.used_module_ids.extend(
+    records
+      .modules
+      .used_ids
+      .iter()
+      .map(|id| id.clone().into_string()),
+  );
+
+  *self.records.lock().expect("should lock records") = records;
+  Ok(())
+}
+
+#[plugin_hook(CompilationChunkIds for RecordIdsPlugin, stage = -100)]
+async fn chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  if let Some(diagnostic) = compilation.incremental.disable_passes(
+    IncrementalPasses::CHUNK_IDS,
+    "RecordIdsPlugin (recordsPath)",
+    "it requires restoring the id of all the chunks, which is a global effect",
+  ) {
+    if let Some(diagnostic) = diagnostic {
+      compilation.push_diagnostic(diagnostic);
+    }
+    compilation.chunk_ids_artifact.clear();
+  }
+
+  let records = self.records.lock().expect("should lock records");
+  let context = compilation.options.context.as_str();
+  let module_graph = compilation.get_module_graph();
+  let mut restored: FxHashMap<String, ChunkUkey> = FxHashMap::default();
+  let mut chunks = compilation.chunk_by_ukey.values().collect::<Vec<_>>();
+  // named chunks first, their names are more stable than their modules
+  chunks.sort_by_key(|chunk| chunk.name().is_none());
+  for chunk in chunks {
+    if chunk.id(&compilation.chunk_ids_artifact).is_some() {
+      continue;
+    }
+    let id = match chunk.name() {
+      Some(name) => records.chunks.by_name.get(name),
+      None => records.chunks.by_source.get(&get_full_chunk_name(
+        chunk,
+        &compilation.chunk_graph,
+        &module_graph,
+        context,
+      )),
+    };
+    if let Some(id) = id {
+      restored
+        .entry(id.clone().into_string())
+        .or_insert(chunk.ukey());
+    }
+  }
+
+  for (id, chunk) in restored {
+    let chunk = compilation.chunk_by_ukey.expect_get(&chunk);
+    chunk.set_id(&mut compilation.chunk_ids_artifact, id);
+  }
+  compilation.used_chunk_ids.extend(
+    records
+      .chunks
+      .used_ids
+      .iter()
+      .map(|id| id.clone().into_string()),
+  );
+  Ok(())
+}
+
+#[plugin_hook(CompilerAfterEmit for RecordIdsPlugin)]
+async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
+  let context = compilation.options.context.as_str();
+  let module_graph = compilation.get_module_graph();
+  let mut records = Records::default();
+
+  for module in module_graph.modules().values() {
+    if let Some(id) =
+      ChunkGraph::get_module_id(&compilation.module_ids_artifact, module.identifier())
+    {
+      records
+        .modules
+        .by_identifier
+        .insert(get_full_module_name(module, context), id.as_str().into());
+      records.modules.used_ids.push(id.as_str().into());
+    }
+  }
+  for chunk in compilation.chunk_by_ukey.values() {
+    let Some(id) = chunk.id(&compilation.chunk_ids_artifact) else {
+      continue;
+    };
+    match chunk.name() {
+      Some(name) => {
+        records
+          .chunks
+          .by_name
+          .insert(name.to_string(), id.as_str().into());
+      }
+      None => {
+        records.chunks.by_source.insert(
+          get_full_chunk_name(chunk, &compilation.chunk_graph, &module_graph, context),
+          id.as_str().into(),
+        );
+      }
+    }
+    records.chunks.used_ids.push(id.as_str().into());
+  }
+  for used_ids in [&mut records.modules.used_ids, &mut records.chunks.used_ids] {
+    used_ids.sort_by_cached_key(|id| match id {
+      RecordId::Number(number) => (0, *number, String::new()),
+      RecordId::String(string) => (1, 0, string.clone()),
+    });
+  }
+
+  if let Some(output_path) = &self.options.output_path {
+    let json = serde_json::to_string_pretty(&records).to_rspack_result()?;
+    let intermediate_filesystem = compilation.intermediate_filesystem.as_ref();
+    if let Some(dir) = output_path.parent() {
+      intermediate_filesystem.create_dir_all(dir).await?;
+    }
+    intermediate_filesystem
+      .write(output_path, json.as_bytes())
+      .await?;
+  }
+  Ok(())
+}
+
+impl Plugin for RecordIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.RecordIdsPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .module_ids
+      .tap(module_ids::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .chunk_ids
+      .tap(chunk_ids::new(self));
+    ctx
+      .context
+      .compiler_hooks
+      .after_emit
+      .tap(after_emit::new(self));
+    Ok(())
+  }
+}
@@ -17,7 +17,10 @@ use swc_core::{
     BytePos, FileName, Mark,
   },
   ecma::{
-    ast::Ident,
+    ast::{
+      AssignPat, BinExpr, BinaryOp, ComputedPropName, Expr, Ident, IdentName, KeyValuePatProp,
+      KeyValueProp, Lit, MemberProp, ObjectPatProp, Pat, Prop, PropName, Str, SuperProp,
+    },
     parser::{EsSyntax, Syntax},
     transforms::base::{
       fixer::{fixer, paren_remover},
@@ -25,7 +28,7 @@ use swc_core::{
       hygiene::hygiene,
       resolver,
     },
-    visit::{noop_visit_type, Visit, VisitMutWith},
+    visit::{noop_visit_mut_type, noop_visit_type, Visit, VisitMut, VisitMutWith},
   },
 };
 pub use swc_ecma_minifier::option::{
@@ -145,9 +148,14 @@ impl JavaScriptCompiler {
 
           let program = helpers::HELPERS.set(&Helpers::new(false), || {
             HANDLER.set(handler, || {
-              let program = program
+              let mut program = program
                 .apply(&mut resolver(unresolved_mark, top_level_mark, false))
                 .apply(&mut paren_remover(Some(&comments as &dyn Comments)));
+              if let Some(property_names) = &opts.property_names {
+                program.visit_mut_with(&mut PropertyRenamer {
+                  names: property_names,
+                });
+              }
               let mut program = swc_ecma_minifier::optimize(
                 program,
                 self.cm.clone(),
@@ -198,7 +206,11 @@ impl JavaScriptCompiler {
             format: &opts.format,
           };
 
-          self.print(&program, print_options).map_err(|e| e.into())
+          let warnings = handler.take_diagnostics();
+          self
+            .print(&program, print_options)
+            .map(|output| output.with_diagnostics(warnings))
+            .map_err(|e| e.into())
         },
       )
     })
@@ -260,6 +272,12 @@ pub struct JsMinifyOptions {
   #[serde(default = "true_as_default")]
   /// Indicates whether to inline the source content in the source map.
   pub inline_sources_content: bool,
+
+  #[serde(skip)]
+  /// Properties to rename before minifying, from the original name to the new
+  /// one. Unlike `mangle.props`, the names are given by the caller, so they can
+  /// be consistent across files.
+  pub property_names: Option<Arc<FxHashMap<Atom, Atom>>>,
 }
 
 const fn true_as_default() -> bool {
@@ -285,3 +303,104 @@ impl Visit for IdentCollector {
     self.names.insert(ident.span.lo, ident.sym.clone());
   }
 }
+
+/// Renames the keys of object literals, classes and object patterns, the
+/// properties of member expressions, quoted or not, and the quoted property of
+/// `in` checks.
+struct PropertyRenamer<'a> {
+  names: &'a FxHashMap<Atom, Atom>,
+}
+
+impl PropertyRenamer<'_> {
+  fn rename(&self, sym: &mut Atom) {
+    if let Some(renamed) = self.names.get(sym) {
+      *sym = renamed.clone();
+    }
+  }
+
+  fn rename_str(&self, str: &mut Str) {
+    if let Some(renamed) = self.names.get(&str.value) {
+      str.value = renamed.clone();
+      str.raw = None;
+    }
+  }
+
+  fn rename_computed(&mut self, computed: &mut ComputedPropName) {
+    match &mut *computed.expr {
+      Expr::Lit(Lit::Str(str)) => self.rename_str(str),
+      _ => computed.visit_mut_children_with(self),
+    }
+  }
+}
+
+impl VisitMut for PropertyRenamer<'_> {
+  noop_visit_mut_type!();
+
+  fn visit_mut_prop_name(&mut self, name: &mut PropName) {
+    match name {
+      PropName::Ident(ident) => self.rename(&mut ident.sym),
+      PropName::Str(str) => self.rename_str(str),
+      _ => name.visit_mut_children_with(self),
+    }
+  }
+
+  fn visit_mut_member_prop(&mut self, prop: &mut MemberProp) {
+    match prop {
+      MemberProp::Ident(ident) => self.rename(&mut ident.sym),
+      MemberProp::Computed(computed) => self.rename_computed(computed),
+      MemberProp::PrivateName(_) => {}
+    }
+  }
+
+  fn visit_mut_super_prop(&mut self, prop: &mut SuperProp) {
+    match prop {
+      SuperProp::Ident(ident) => self.rename(&mut ident.sym),
+      SuperProp::Computed(computed) => self.rename_computed(computed),
+    }
+  }
+
+  fn visit_mut_bin_expr(&mut self, expr: &mut BinExpr) {
+    // `"foo" in obj` -> `"a" in obj`
+    if expr.op == BinaryOp::In {
+      if let Expr::Lit(Lit::Str(str)) = &mut *expr.left {
+        self.rename_str(str);
+      }
+    }
+    expr.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_prop(&mut self, prop: &mut Prop) {
+    // `{ foo }` -> `{ a: foo }`
+    if let Prop::Shorthand(ident) = prop {
+      if let Some(renamed) = self.names.get(&ident.sym) {
+        *prop = Prop::KeyValue(KeyValueProp {
+          key: PropName::Ident(IdentName::new(renamed.clone(), ident.span)),
+          value: Box::new(Expr::Ident(ident.clone())),
+        });
+        return;
+      }
+    }
+    prop.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_object_pat_prop(&mut self, prop: &mut ObjectPatProp) {
+    prop.visit_mut_children_with(self);
+    // `{ foo = 1 } = obj` -> `{ a: foo = 1 } = obj`
+    if let ObjectPatProp::Assign(assign) = prop {
+      if let Some(renamed) = self.names.get(&assign.key.sym) {
+        let binding = Box::new(Pat::Ident(assign.key.clone()));
+        *prop = ObjectPatProp::KeyValue(KeyValuePatProp {
+          key: PropName::Ident(IdentName::new(renamed.clone(), assign.key.span)),
+          value: match assign.value.take() {
+            Some(right) => Box::new(Pat::Assign(AssignPat {
+              span: assign.span,
+              left: binding,
+              right,
+            })),
+            None => binding,
+          },
+        });
+      }
+    }
+  }
+}
@@ -25,6 +25,12 @@ impl JavaScriptCompiler {
     Self::default()
   }
 
+  /// The source map of the files parsed by this compiler, to resolve the spans
+  /// of their ASTs.
+  pub fn source_map(&self) -> &Arc<SwcSourceMap> {
+    &self.cm
+  }
+
   fn run<R>(&self, op: impl FnOnce() -> R) -> R {
     GLOBALS.set(&self.globals, op)
   }
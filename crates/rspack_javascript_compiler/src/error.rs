@@ -7,7 +7,7 @@ use rspack_cacheable::cacheable;
 use rspack_error::{error, BatchErrors, DiagnosticKind, TraceableError};
 use rustc_hash::FxHashSet as HashSet;
 use swc_core::common::{
-  errors::{Emitter, Handler, HANDLER},
+  errors::{Emitter, Handler, Level, HANDLER},
   SourceFile, SourceMap, Span, Spanned,
 };
 
@@ -91,10 +91,28 @@ struct RspackErrorEmitter {
   source_map: Arc<SourceMap>,
   title: String,
   kind: DiagnosticKind,
+  /// The warnings emitted so far, taken by [Handler::take_diagnostics].
+  warnings: Vec<String>,
 }
 
 impl Emitter for RspackErrorEmitter {
   fn emit(&mut self, db: &mut swc_core::common::errors::DiagnosticBuilder<'_>) {
+    if db.level == Level::Warning {
+      let warning = match db.span.primary_span() {
+        Some(span) => {
+          let loc = self.source_map.lookup_char_pos(span.lo());
+          format!(
+            "{}\n    at {}:{}:{}",
+            db.message(),
+            loc.file.name,
+            loc.line,
+            loc.col_display + 1
+          )
+        }
+        None => db.message(),
+      };
+      self.warnings.push(warning);
+    }
     let source_file_and_byte_pos = db
       .span
       .primary_span()
@@ -121,6 +139,10 @@ impl Emitter for RspackErrorEmitter {
         .expect("Sender should drop after emit called");
     }
   }
+
+  fn take_diagnostics(&mut self) -> Vec<String> {
+    std::mem::take(&mut self.warnings)
+  }
 }
 
 /// Executes a closure with an error handler and returns the result or a BatchErrors if errors occurred.
@@ -128,7 +150,8 @@ impl Emitter for RspackErrorEmitter {
 /// This function sets up an error handler with a custom emitter that sends errors to a channel. It then
 /// executes the provided closure with a reference to the handler. If the handler has errors after the
 /// closure execution, it collects the errors from the channel and returns them as a BatchErrors. If no
-/// errors occurred, it returns the result of the closure.
+/// errors occurred, it returns the result of the closure. The warnings can be taken from the handler
+/// with `take_diagnostics` within the closure.
 ///
 /// # Parameters
 ///
@@ -155,6 +178,7 @@ where
     kind,
     source_map: cm,
     tx,
+    warnings: vec![],
   };
   let handler = Handler::with_emitter(true, false, Box::new(emitter));
 
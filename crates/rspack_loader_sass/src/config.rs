@@ -0,0 +1,59 @@
+use rspack_cacheable::cacheable;
+use serde::Deserialize;
+
+#[cacheable]
+#[derive(Debug, Default, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum OutputStyle {
+  #[default]
+  Expanded,
+  Compressed,
+}
+
+impl From<OutputStyle> for grass::OutputStyle {
+  fn from(value: OutputStyle) -> Self {
+    match value {
+      OutputStyle::Expanded => grass::OutputStyle::Expanded,
+      OutputStyle::Compressed => grass::OutputStyle::Compressed,
+    }
+  }
+}
+
+#[cacheable]
+#[derive(Debug, Default)]
+pub struct Config {
+  pub style: OutputStyle,
+  /// Directories that are searched for imports which can't be resolved
+  /// relative to the importing file.
+  pub load_paths: Vec<String>,
+  /// Code prepended to the entry file, e.g. `@use "variables" as *;`.
+  pub additional_data: Option<String>,
+  /// Silences the warnings from `@warn` and deprecations.
+  pub quiet: bool,
+  /// Defaults to whether the module needs source maps.
+  pub source_map: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RawConfig {
+  pub style: Option<OutputStyle>,
+  pub load_paths: Option<Vec<String>>,
+  // alias of `loadPaths` to ease migrating from sass-loader's legacy api
+  pub include_paths: Option<Vec<String>>,
+  pub additional_data: Option<String>,
+  pub quiet: Option<bool>,
+  pub source_map: Option<bool>,
+}
+
+impl From<RawConfig> for Config {
+  fn from(value: RawConfig) -> Self {
+    Self {
+      style: value.style.unwrap_or_default(),
+      load_paths: value.load_paths.or(value.include_paths).unwrap_or_default(),
+      additional_data: value.additional_data,
+      quiet: value.quiet.unwrap_or(false),
+      source_map: value.source_map,
+    }
+  }
+}
@@ -0,0 +1,432 @@
+//! Sass resolves `@use`, `@forward` and `@import` on its own, which bypasses
+//! `resolve.alias`, package exports and the other options of rspack's resolver.
+//! Before compiling, the import graph is walked here: every url is resolved
+//! with rspack's resolver and rewritten to the absolute path of the resolved
+//! file, so that grass only ever reads files from [`ResolvedFs`].
+
+use std::{
+  io,
+  path::{Path, PathBuf},
+};
+
+use cow_utils::CowUtils;
+use rspack_core::{ResolveResult, Resolver};
+use rspack_error::{Result, ToStringResultToRspackResultExt};
+use rspack_fs::ReadableFileSystem;
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rustc_hash::FxHashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+  Use,
+  Forward,
+  Import,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ImportUrl<'s> {
+  pub kind: ImportKind,
+  pub url: &'s str,
+  /// The byte range of the url, including the quotes if any.
+  pub range: (usize, usize),
+}
+
+#[derive(Debug)]
+pub struct LoadedFile {
+  /// The content as written by the user, used for diagnostics and source maps.
+  pub original: String,
+  /// The content with the resolved urls, which is fed to grass.
+  pub rewritten: String,
+}
+
+/// An in-memory file system holding the entry and every file it imports.
+#[derive(Debug, Default)]
+pub struct ResolvedFs {
+  files: FxHashMap<String, LoadedFile>,
+  order: Vec<Utf8PathBuf>,
+}
+
+impl ResolvedFs {
+  /// Loads `entry` and all of its transitive imports.
+  pub async fn load(
+    entry: &Utf8Path,
+    content: String,
+    resolver: &Resolver,
+    fs: &dyn ReadableFileSystem,
+    load_paths: &[Utf8PathBuf],
+  ) -> Result<Self> {
+    let mut loaded = Self::default();
+    let mut queue = vec![(entry.to_path_buf(), content)];
+    while let Some((path, original)) = queue.pop() {
+      let indented = path.extension() == Some("sass");
+      let dir = path.parent().unwrap_or(&path);
+      let mut rewritten = String::with_capacity(original.len());
+      let mut last = 0;
+      for import in collect_imports(&original, indented) {
+        if is_plain_css_import(&import) {
+          continue;
+        }
+        let Some(resolved) = resolve_import(resolver, dir, load_paths, import.url).await else {
+          // leave it to grass, which reports the missing stylesheet with its span
+          continue;
+        };
+        rewritten.push_str(&original[last..import.range.0]);
+        push_resolved_url(&mut rewritten, &original, &import, resolved.as_str());
+        last = import.range.1;
+
+        let key = normalize(resolved.as_str());
+        if !loaded.files.contains_key(key.as_ref())
+          && resolved != path
+          && !queue.iter().any(|(queued, _)| *queued == resolved)
+        {
+          let content = fs
+            .read(&resolved)
+            .await
+            .to_rspack_result_with_message(|e| format!("Failed to read {resolved}: {e}"))?;
+          queue.push((resolved, String::from_utf8_lossy(&content).into_owned()));
+        }
+      }
+      rewritten.push_str(&original[last..]);
+      loaded.files.insert(
+        normalize(path.as_str()).into_owned(),
+        LoadedFile {
+          original,
+          rewritten,
+        },
+      );
+      loaded.order.push(path);
+    }
+    Ok(loaded)
+  }
+
+  /// The loaded files, starting with the entry.
+  pub fn paths(&self) -> &[Utf8PathBuf] {
+    &self.order
+  }
+
+  pub fn get(&self, path: &str) -> Option<&LoadedFile> {
+    self.files.get(normalize(path).as_ref())
+  }
+}
+
+impl grass::Fs for ResolvedFs {
+  fn is_dir(&self, _path: &Path) -> bool {
+    false
+  }
+
+  fn is_file(&self, path: &Path) -> bool {
+    self
+      .files
+      .contains_key(normalize(&path.to_string_lossy()).as_ref())
+  }
+
+  fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+    self
+      .files
+      .get(normalize(&path.to_string_lossy()).as_ref())
+      .map(|file| file.rewritten.clone().into_bytes())
+      .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    Ok(path.to_path_buf())
+  }
+}
+
+/// Writes the resolved url of `import`. The default namespace of `@use` is
+/// derived from its url, so the namespace of the original url is kept with an
+/// explicit `as` clause: `@use "theme"` resolved to `theme/_index.scss` is
+/// still used as `theme.$primary`.
+fn push_resolved_url(rewritten: &mut String, source: &str, import: &ImportUrl, resolved: &str) {
+  rewritten.push('"');
+  rewritten.push_str(&normalize(resolved).cow_replace('"', "\\\""));
+  rewritten.push('"');
+  if import.kind == ImportKind::Use && !has_namespace(&source[import.range.1..]) {
+    rewritten.push_str(" as ");
+    rewritten.push_str(default_namespace(import.url));
+  }
+}
+
+/// Whether the rest of a `@use` rule starts with an `as` clause.
+fn has_namespace(rest: &str) -> bool {
+  rest
+    .trim_start()
+    .strip_prefix("as")
+    .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_whitespace()))
+}
+
+/// The last component of the url, without the extension and the leading `_`
+/// of partials.
+fn default_namespace(url: &str) -> &str {
+  let url = url.strip_prefix('~').unwrap_or(url);
+  let basename = url.rsplit(['/', ':']).next().unwrap_or(url);
+  let basename = basename.strip_prefix('_').unwrap_or(basename);
+  basename.split('.').next().unwrap_or(basename)
+}
+
+/// Sass strings treat `\` as an escape, so windows paths are written with `/`.
+fn normalize(path: &str) -> std::borrow::Cow<'_, str> {
+  path.cow_replace('\\', "/")
+}
+
+fn is_plain_css_import(import: &ImportUrl) -> bool {
+  let url = import.url;
+  if url.starts_with("sass:") {
+    return true;
+  }
+  if url.starts_with("http://") || url.starts_with("https://") || url.starts_with("//") {
+    return true;
+  }
+  // `@import "theme.css"` is kept as a css `@import`, unlike `@use "theme.css"`
+  import.kind == ImportKind::Import && url.ends_with(".css")
+}
+
+async fn resolve_import(
+  resolver: &Resolver,
+  dir: &Utf8Path,
+  load_paths: &[Utf8PathBuf],
+  url: &str,
+) -> Option<Utf8PathBuf> {
+  // `~` was used by sass-loader to mark module requests
+  let url = url.strip_prefix('~').unwrap_or(url);
+  let requests = possible_requests(url);
+  for context in std::iter::once(dir).chain(load_paths.iter().map(Utf8PathBuf::as_path)) {
+    for request in &requests {
+      if let Ok(ResolveResult::Resource(resource)) =
+        resolver.resolve(context.as_std_path(), request).await
+      {
+        return Some(resource.path);
+      }
+    }
+  }
+  None
+}
+
+/// Sass prefers partials, `foo/_bar.scss` is tried before `foo/bar.scss`.
+fn possible_requests(url: &str) -> Vec<String> {
+  let (dir, basename) = match url.rfind('/') {
+    Some(index) => (&url[..=index], &url[index + 1..]),
+    None => ("", url),
+  };
+  if basename.is_empty() || basename.starts_with('_') || basename.starts_with('.') {
+    return vec![url.to_string()];
+  }
+  vec![format!("{dir}_{basename}"), url.to_string()]
+}
+
+/// Collects the urls of `@use`, `@forward` and `@import` rules.
+pub fn collect_imports(source: &str, indented: bool) -> Vec<ImportUrl<'_>> {
+  let bytes = source.as_bytes();
+  let mut imports = vec![];
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'/') => i = skip_line(bytes, i),
+      b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_comment(bytes, i),
+      b'"' | b'\'' => i = skip_string(bytes, i),
+      b'@' => {
+        let rest = &source[i + 1..];
+        let kind = if rest.starts_with("use") {
+          Some((ImportKind::Use, 3))
+        } else if rest.starts_with("forward") {
+          Some((ImportKind::Forward, 7))
+        } else if rest.starts_with("import") {
+          Some((ImportKind::Import, 6))
+        } else {
+          None
+        };
+        match kind {
+          Some((kind, len)) if bytes.get(i + 1 + len).is_some_and(u8::is_ascii_whitespace) => {
+            i = collect_rule_urls(source, i + 1 + len, kind, indented, &mut imports);
+          }
+          _ => i += 1,
+        }
+      }
+      _ => i += 1,
+    }
+  }
+  imports
+}
+
+fn collect_rule_urls<'s>(
+  source: &'s str,
+  mut i: usize,
+  kind: ImportKind,
+  indented: bool,
+  imports: &mut Vec<ImportUrl<'s>>,
+) -> usize {
+  let bytes = source.as_bytes();
+  loop {
+    while bytes.get(i).is_some_and(|c| *c == b' ' || *c == b'\t') {
+      i += 1;
+    }
+    match bytes.get(i) {
+      Some(b'"' | b'\'') => {
+        let end = skip_string(bytes, i);
+        imports.push(ImportUrl {
+          kind,
+          url: &source[i + 1..end - 1],
+          range: (i, end),
+        });
+        i = end;
+      }
+      // the indented syntax allows unquoted `@import foo, bar`
+      Some(c) if indented && kind == ImportKind::Import && !matches!(c, b'\n' | b'\r') => {
+        let start = i;
+        while bytes
+          .get(i)
+          .is_some_and(|c| !matches!(c, b',' | b'\n' | b'\r' | b' ' | b'\t'))
+        {
+          i += 1;
+        }
+        let url = &source[start..i];
+        if url.is_empty() || url.starts_with("url(") {
+          return i;
+        }
+        imports.push(ImportUrl {
+          kind,
+          url,
+          range: (start, i),
+        });
+      }
+      _ => return i,
+    }
+    // only `@import` takes a list of urls
+    if kind != ImportKind::Import {
+      return i;
+    }
+    while bytes.get(i).is_some_and(|c| *c == b' ' || *c == b'\t') {
+      i += 1;
+    }
+    if bytes.get(i) != Some(&b',') {
+      return i;
+    }
+    i += 1;
+    while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+      i += 1;
+    }
+  }
+}
+
+fn skip_line(bytes: &[u8], start: usize) -> usize {
+  bytes[start..]
+    .iter()
+    .position(|c| *c == b'\n')
+    .map_or(bytes.len(), |index| start + index + 1)
+}
+
+fn skip_comment(bytes: &[u8], start: usize) -> usize {
+  let mut i = start + 2;
+  while i < bytes.len() {
+    if bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/') {
+      return i + 2;
+    }
+    i += 1;
+  }
+  bytes.len()
+}
+
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+  let quote = bytes[start];
+  let mut i = start + 1;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'\\' => i += 2,
+      c if c == quote || c == b'\n' => return i + 1,
+      _ => i += 1,
+    }
+  }
+  bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_collect_imports() {
+    let source = r#"// @use "commented";
+@use "sass:math";
+@use 'variables' as vars;
+@forward "src/list" hide list-reset;
+@import "a", 'b.css';
+.a { content: "@import 'no'"; }
+"#;
+    let imports = collect_imports(source, false);
+    let urls = imports
+      .iter()
+      .map(|import| (import.kind, import.url))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      urls,
+      vec![
+        (ImportKind::Use, "sass:math"),
+        (ImportKind::Use, "variables"),
+        (ImportKind::Forward, "src/list"),
+        (ImportKind::Import, "a"),
+        (ImportKind::Import, "b.css"),
+      ]
+    );
+    assert_eq!(
+      &source[imports[1].range.0..imports[1].range.1],
+      "'variables'"
+    );
+    assert!(is_plain_css_import(&imports[0]));
+    assert!(is_plain_css_import(&imports[4]));
+
+    let imports = collect_imports("@import foo, bar/baz\n.a\n  color: red\n", true);
+    let urls = imports.iter().map(|import| import.url).collect::<Vec<_>>();
+    assert_eq!(urls, vec!["foo", "bar/baz"]);
+  }
+
+  #[test]
+  fn should_keep_the_namespace_of_use() {
+    let source = r#"@use "theme";
+@use "ui-kit" with ($spacing: 4px);
+@use "./src/_corners.scss";
+@use "variables" as vars;
+@forward "colors";
+"#;
+    let resolved = [
+      "/project/src/theme/_index.scss",
+      "/project/node_modules/ui-kit/scss/main.scss",
+      "/project/src/_corners.scss",
+      "/project/src/_variables.scss",
+      "/project/src/_colors.scss",
+    ];
+    let mut rewritten = String::new();
+    let mut last = 0;
+    for (import, resolved) in collect_imports(source, false).iter().zip(resolved) {
+      rewritten.push_str(&source[last..import.range.0]);
+      push_resolved_url(&mut rewritten, source, import, resolved);
+      last = import.range.1;
+    }
+    rewritten.push_str(&source[last..]);
+    assert_eq!(
+      rewritten,
+      r#"@use "/project/src/theme/_index.scss" as theme;
+@use "/project/node_modules/ui-kit/scss/main.scss" as ui-kit with ($spacing: 4px);
+@use "/project/src/_corners.scss" as corners;
+@use "/project/src/_variables.scss" as vars;
+@forward "/project/src/_colors.scss";
+"#
+    );
+  }
+
+  #[test]
+  fn should_prefer_partials() {
+    assert_eq!(
+      possible_requests("./theme/colors"),
+      vec!["./theme/_colors", "./theme/colors"]
+    );
+    assert_eq!(
+      possible_requests("variables"),
+      vec!["_variables", "variables"]
+    );
+    assert_eq!(possible_requests("../_mixins"), vec!["../_mixins"]);
+    assert_eq!(
+      possible_requests("bootstrap/scss/mixins"),
+      vec!["bootstrap/scss/_mixins", "bootstrap/scss/mixins"]
+    );
+  }
+}
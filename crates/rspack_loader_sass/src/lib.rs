@@ -0,0 +1,339 @@
+#![feature(let_chains)]
+
+use std::sync::Mutex;
+
+use codemap::SpanLoc;
+use config::Config;
+use importer::{LoadedFile, ResolvedFs};
+use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_core::{
+  rspack_sources::{encode_mappings, SourceMap},
+  DependencyCategory, Loader, LoaderContext, Resolve, ResolveOptionsWithDependencyType,
+  RunnerContext,
+};
+use rspack_error::{error, miette, Diagnostic, Result, TraceableError};
+use rspack_loader_runner::{Identifiable, Identifier};
+use rspack_paths::Utf8Path;
+
+pub mod config;
+mod importer;
+mod plugin;
+mod source_map;
+
+pub use plugin::SassLoaderPlugin;
+
+pub const SASS_LOADER_IDENTIFIER: &str = "builtin:sass-loader";
+
+#[cacheable]
+#[derive(Debug)]
+pub struct SassLoader {
+  id: Identifier,
+  config: Config,
+}
+
+impl SassLoader {
+  pub fn new(config: Config, ident: &str) -> Self {
+    Self {
+      id: ident.into(),
+      config,
+    }
+  }
+
+  async fn loader_impl(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+    let Some(resource_path) = loader_context.resource_path().map(|p| p.to_path_buf()) else {
+      return Ok(());
+    };
+
+    let Some(content) = loader_context.take_content() else {
+      return Ok(());
+    };
+    let mut content = content.into_string_lossy();
+    // the lines of `additionalData` are taken out of the locations of the
+    // diagnostics and the source map
+    let prepended = self
+      .config
+      .additional_data
+      .as_ref()
+      .map(|additional_data| {
+        content = format!("{additional_data}\n{content}");
+        Prepended {
+          lines: additional_data.matches('\n').count() + 1,
+          len: additional_data.len() + 1,
+        }
+      })
+      .unwrap_or_default();
+
+    let resolver = loader_context
+      .context
+      .resolver_factory
+      .get(ResolveOptionsWithDependencyType {
+        resolve_options: Some(Box::new(sass_resolve_options())),
+        resolve_to_context: false,
+        dependency_category: DependencyCategory::CssImport,
+      });
+    let load_paths = self
+      .config
+      .load_paths
+      .iter()
+      .map(|path| loader_context.context.options.context.as_path().join(path))
+      .collect::<Vec<_>>();
+    let files = ResolvedFs::load(
+      &resource_path,
+      content,
+      &resolver,
+      loader_context.context.input_filesystem.as_ref(),
+      &load_paths,
+    )
+    .await?;
+    for path in files.paths() {
+      if *path != resource_path {
+        loader_context
+          .file_dependencies
+          .insert(path.clone().into_std_path_buf());
+      }
+    }
+
+    let logger = SassLogger::new(resource_path.as_str(), prepended);
+    let options = grass::Options::default()
+      .fs(&files)
+      .logger(&logger)
+      .style(self.config.style.into())
+      .quiet(self.config.quiet);
+    let result = grass::from_path(resource_path.as_std_path(), &options);
+    for warning in logger.take() {
+      loader_context.emit_diagnostic(Diagnostic::warn(
+        SASS_LOADER_IDENTIFIER.to_string(),
+        warning,
+      ));
+    }
+    let css = result.map_err(|e| to_rspack_error(*e, &resource_path, &files, prepended))?;
+
+    let enable_sourcemap = self
+      .config
+      .source_map
+      .unwrap_or_else(|| loader_context.context.module_source_map_kind.enabled());
+    if enable_sourcemap {
+      let sources = files
+        .paths()
+        .iter()
+        .map(|path| {
+          files
+            .get(path.as_str())
+            .map_or("", |file| file.original.as_str())
+        })
+        .collect::<Vec<_>>();
+      let entry = files.paths().iter().position(|path| *path == resource_path);
+      let mappings = source_map::generate_mappings(&css, &sources)
+        .into_iter()
+        .filter_map(|mut mapping| {
+          if let Some(original) = &mut mapping.original
+            && Some(original.source_index as usize) == entry
+          {
+            // the css generated by `additionalData` has no original location
+            original.original_line = original
+              .original_line
+              .checked_sub(prepended.lines as u32)
+              .filter(|line| *line > 0)?;
+          }
+          Some(mapping)
+        })
+        .collect::<Vec<_>>();
+      let source_map = SourceMap::new(
+        encode_mappings(mappings.into_iter()),
+        files
+          .paths()
+          .iter()
+          .map(ToString::to_string)
+          .collect::<Vec<_>>(),
+        sources
+          .into_iter()
+          .enumerate()
+          .map(|(index, source)| {
+            if Some(index) == entry {
+              source[prepended.len..].to_string()
+            } else {
+              source.to_string()
+            }
+          })
+          .collect::<Vec<_>>(),
+        Vec::<String>::new(),
+      );
+      loader_context.finish_with((css, source_map));
+    } else {
+      loader_context.finish_with(css);
+    }
+
+    Ok(())
+  }
+}
+
+/// The same lookup as sass: `_index.scss` for directories, and the `sass` and
+/// `style` fields of packages.
+fn sass_resolve_options() -> Resolve {
+  Resolve {
+    extensions: Some(vec![
+      ".sass".to_string(),
+      ".scss".to_string(),
+      ".css".to_string(),
+    ]),
+    main_files: Some(vec!["_index".to_string(), "index".to_string()]),
+    main_fields: Some(vec![
+      "sass".to_string(),
+      "style".to_string(),
+      "main".to_string(),
+    ]),
+    condition_names: Some(vec!["sass".to_string(), "style".to_string()]),
+    prefer_relative: Some(true),
+    ..Default::default()
+  }
+}
+
+/// The `additionalData` prepended to the entry.
+#[derive(Debug, Default, Clone, Copy)]
+struct Prepended {
+  lines: usize,
+  len: usize,
+}
+
+impl Prepended {
+  /// Formats a 0-based line and column of the entry for diagnostics.
+  fn location(&self, entry: &str, line: usize, column: usize) -> String {
+    if line < self.lines {
+      format!("additionalData:{}:{}", line + 1, column + 1)
+    } else {
+      format!("{entry}:{}:{}", line - self.lines + 1, column + 1)
+    }
+  }
+}
+
+fn to_rspack_error(
+  error: grass::Error,
+  resource_path: &Utf8Path,
+  files: &ResolvedFs,
+  prepended: Prepended,
+) -> rspack_error::Error {
+  let message = error.to_string();
+  let grass::ErrorKind::ParseError {
+    message: reason,
+    loc,
+    ..
+  } = error.kind()
+  else {
+    return error!(message);
+  };
+  let name = loc.file.name();
+  let Some(LoadedFile { original, .. }) = files.get(name) else {
+    return error!(message);
+  };
+  let start = offset(original, loc.begin.line, loc.begin.column);
+  let end = offset(original, loc.end.line, loc.end.column);
+  if name == resource_path.as_str() {
+    if start < prepended.len {
+      return error!(
+        "{reason}\n    at {}",
+        prepended.location(name, loc.begin.line, loc.begin.column)
+      );
+    }
+    return miette::Error::new(TraceableError::from_file(
+      original[prepended.len..].to_string(),
+      start - prepended.len,
+      end.max(start) - prepended.len,
+      "Sass Error".to_string(),
+      reason,
+    ));
+  }
+  let reason = format!(
+    "{reason}\n    at {name}:{}:{}",
+    loc.begin.line + 1,
+    loc.begin.column + 1
+  );
+  miette::Error::new(TraceableError::from_file(
+    original.clone(),
+    start,
+    end.max(start),
+    "Sass Error".to_string(),
+    reason,
+  ))
+}
+
+/// Converts a 0-based line and column (in chars) from grass to a byte offset.
+fn offset(source: &str, line: usize, column: usize) -> usize {
+  let Some(line_start) = (if line == 0 {
+    Some(0)
+  } else {
+    source
+      .match_indices('\n')
+      .nth(line - 1)
+      .map(|(index, _)| index + 1)
+  }) else {
+    return source.len();
+  };
+  let rest = &source[line_start..];
+  let line_end = rest.find('\n').unwrap_or(rest.len());
+  line_start
+    + rest[..line_end]
+      .char_indices()
+      .nth(column)
+      .map_or(line_end, |(index, _)| index)
+}
+
+/// Collects `@warn` and deprecation messages.
+#[derive(Debug)]
+struct SassLogger<'a> {
+  messages: Mutex<Vec<String>>,
+  entry: &'a str,
+  prepended: Prepended,
+}
+
+impl<'a> SassLogger<'a> {
+  fn new(entry: &'a str, prepended: Prepended) -> Self {
+    Self {
+      messages: Default::default(),
+      entry,
+      prepended,
+    }
+  }
+
+  fn take(&self) -> Vec<String> {
+    std::mem::take(&mut *self.messages.lock().expect("should lock"))
+  }
+}
+
+impl grass::Logger for SassLogger<'_> {
+  fn debug(&self, _location: SpanLoc, _message: &str) {}
+
+  fn warn(&self, location: SpanLoc, message: &str) {
+    let name = location.file.name();
+    let location = if name == self.entry {
+      self
+        .prepended
+        .location(name, location.begin.line, location.begin.column)
+    } else {
+      format!(
+        "{name}:{}:{}",
+        location.begin.line + 1,
+        location.begin.column + 1
+      )
+    };
+    self
+      .messages
+      .lock()
+      .expect("should lock")
+      .push(format!("{message}\n    at {location}"));
+  }
+}
+
+impl Identifiable for SassLoader {
+  fn identifier(&self) -> Identifier {
+    self.id
+  }
+}
+
+#[cacheable_dyn]
+#[async_trait::async_trait]
+impl Loader<RunnerContext> for SassLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+    // for better diagnostic, as async_trait macro don't show beautiful error message
+    self.loader_impl(loader_context).await
+  }
+}
@@ -0,0 +1,335 @@
+//! grass doesn't generate source maps and doesn't expose the spans of the css
+//! it emits, so the compiled css is mapped back to the loaded files by matching
+//! its rules with the rules of the sources.
+//!
+//! Only what can be verified is mapped, everything else is left unmapped:
+//!
+//! - a rule is mapped when the last compound selector of each of its selectors
+//!   designates the same and only rule of the sources, whose header has no
+//!   interpolation. The css of `@extend`, which merges selectors of different
+//!   rules, and of interpolated selectors like `.icon-#{$name}` is unmapped.
+//! - the declarations of a mapped rule are mapped when they are exactly the
+//!   declarations written in that rule, in the same order. The declarations of
+//!   a rule that also gets some from `@include`, `@if` or interpolated
+//!   properties are unmapped.
+//! - a rule emitted several times, by a mixin or a loop, is mapped to where it
+//!   is written every time.
+//!
+//! The indented syntax has no braces and is not mapped at all.
+
+use rspack_core::rspack_sources::{Mapping, OriginalLocation};
+use rustc_hash::FxHashMap;
+
+/// Works with both the expanded and the compressed output style, as the
+/// rules and declarations are split at `{`, `;` and `}` instead of lines.
+pub fn generate_mappings(css: &str, sources: &[&str]) -> Vec<Mapping> {
+  let sources = sources
+    .iter()
+    .map(|source| (LineIndex::new(source), parse_blocks(source, true)))
+    .collect::<Vec<_>>();
+
+  // the rules of the sources by the last compound selector of their header
+  let mut by_compound: FxHashMap<&str, Vec<(usize, usize)>> = FxHashMap::default();
+  // `&:hover` and `&-large` only match the end of a compound
+  let mut by_suffix: Vec<(&str, (usize, usize))> = vec![];
+  for (source, (_, blocks)) in sources.iter().enumerate() {
+    for (index, block) in blocks.iter().enumerate() {
+      if block.interpolated {
+        continue;
+      }
+      if block.header.starts_with('@') {
+        by_compound
+          .entry(block.header)
+          .or_default()
+          .push((source, index));
+        continue;
+      }
+      for selector in block.header.split(',') {
+        let compound = last_compound(selector);
+        if let Some(suffix) = compound.strip_prefix('&') {
+          if !suffix.is_empty() && !suffix.contains('&') {
+            by_suffix.push((suffix, (source, index)));
+          }
+        } else if !compound.is_empty() {
+          by_compound
+            .entry(compound)
+            .or_default()
+            .push((source, index));
+        }
+      }
+    }
+  }
+
+  let generated_lines = LineIndex::new(css);
+  let mut mappings = vec![];
+  let mut push = |generated: usize, source: usize, original: usize| {
+    let (generated_line, generated_column) = generated_lines.line_column(generated);
+    let (original_line, original_column) = sources[source].0.line_column(original);
+    mappings.push(Mapping {
+      generated_line,
+      generated_column,
+      original: Some(OriginalLocation {
+        source_index: source as u32,
+        original_line,
+        original_column,
+        name_index: None,
+      }),
+    });
+  };
+
+  for block in parse_blocks(css, false) {
+    let selectors = if block.header.starts_with('@') {
+      vec![block.header]
+    } else {
+      block.header.split(',').map(last_compound).collect()
+    };
+    let mut found = None;
+    for compound in selectors {
+      let mut candidates = by_compound
+        .get(compound)
+        .into_iter()
+        .flatten()
+        .copied()
+        .chain(
+          by_suffix
+            .iter()
+            .filter(|(suffix, _)| compound.len() > suffix.len() && compound.ends_with(suffix))
+            .map(|(_, candidate)| *candidate),
+        )
+        .filter(|(source, index)| {
+          same_properties(
+            &sources[*source].1[*index].declarations,
+            &block.declarations,
+          )
+        })
+        .collect::<Vec<_>>();
+      candidates.dedup();
+      found = match (found, candidates.as_slice()) {
+        (None, [candidate]) => Some(*candidate),
+        (Some(found), [candidate]) if found == *candidate => Some(found),
+        _ => None,
+      };
+      if found.is_none() {
+        break;
+      }
+    }
+    let Some((source, index)) = found else {
+      continue;
+    };
+    let original = &sources[source].1[index];
+    push(block.pos, source, original.pos);
+    for ((_, generated), (_, original)) in block.declarations.iter().zip(&original.declarations) {
+      push(*generated, source, *original);
+    }
+  }
+
+  mappings.sort_by_key(|mapping| (mapping.generated_line, mapping.generated_column));
+  mappings
+}
+
+/// A rule or an at-rule with a block.
+struct Block<'a> {
+  /// The trimmed header, before the `{`.
+  header: &'a str,
+  pos: usize,
+  /// The properties and positions of the declarations directly in the block.
+  declarations: Vec<(&'a str, usize)>,
+  /// Whether the header contains a `#{}` interpolation.
+  interpolated: bool,
+}
+
+/// Collects the blocks of `text`, inner blocks first. Comments, strings and
+/// interpolations are skipped, `//` comments only when `line_comments` is set.
+fn parse_blocks(text: &str, line_comments: bool) -> Vec<Block<'_>> {
+  let bytes = text.as_bytes();
+  let mut blocks = vec![];
+  let mut open: Vec<Block> = vec![];
+  let mut start = 0;
+  let mut interpolated = false;
+  let mut parens = 0usize;
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'\\' => i += 1,
+      b'"' | b'\'' => {
+        let quote = bytes[i];
+        i += 1;
+        while i < bytes.len() && bytes[i] != quote && bytes[i] != b'\n' {
+          if bytes[i] == b'\\' {
+            i += 1;
+          }
+          i += 1;
+        }
+      }
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        let is_blank = text[start..i].trim().is_empty();
+        i = text[i + 2..]
+          .find("*/")
+          .map_or(bytes.len(), |end| i + 2 + end + 2);
+        if is_blank {
+          start = i;
+        }
+        continue;
+      }
+      b'/' if line_comments && parens == 0 && bytes.get(i + 1) == Some(&b'/') => {
+        let is_blank = text[start..i].trim().is_empty();
+        i = text[i..].find('\n').map_or(bytes.len(), |end| i + end);
+        if is_blank {
+          start = i;
+        }
+        continue;
+      }
+      b'#' if bytes.get(i + 1) == Some(&b'{') => {
+        interpolated = true;
+        let mut depth = 0usize;
+        i += 1;
+        while i < bytes.len() {
+          match bytes[i] {
+            b'{' => depth += 1,
+            b'}' => {
+              depth -= 1;
+              if depth == 0 {
+                break;
+              }
+            }
+            _ => {}
+          }
+          i += 1;
+        }
+      }
+      b'(' => parens += 1,
+      b')' => parens = parens.saturating_sub(1),
+      b'{' | b';' | b'}' if parens == 0 => {
+        let raw = &text[start..i];
+        let segment = raw.trim();
+        let pos = start + raw.len() - raw.trim_start().len();
+        if bytes[i] == b'{' {
+          open.push(Block {
+            header: segment,
+            pos,
+            declarations: vec![],
+            interpolated,
+          });
+        } else if let Some(block) = open.last_mut()
+          && !segment.starts_with(['@', '$'])
+          && let Some(colon) = segment.find(':')
+        {
+          block.declarations.push((segment[..colon].trim(), pos));
+        }
+        if bytes[i] == b'}'
+          && let Some(block) = open.pop()
+        {
+          blocks.push(block);
+        }
+        start = i + 1;
+        interpolated = false;
+      }
+      _ => {}
+    }
+    i += 1;
+  }
+  blocks
+}
+
+fn same_properties(a: &[(&str, usize)], b: &[(&str, usize)]) -> bool {
+  a.len() == b.len() && a.iter().zip(b).all(|((a, _), (b, _))| a == b)
+}
+
+/// Nested selectors are flattened by sass, so `.card { .title {} }` is matched
+/// with the last compound selector `.title` of `.card .title`.
+fn last_compound(selector: &str) -> &str {
+  let selector = selector.trim();
+  selector
+    .rsplit(|c: char| c.is_ascii_whitespace() || matches!(c, '>' | '+' | '~'))
+    .next()
+    .unwrap_or(selector)
+}
+
+struct LineIndex {
+  line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+  fn new(text: &str) -> Self {
+    Self {
+      line_starts: std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+        .collect(),
+    }
+  }
+
+  /// Returns the 1-based line and the 0-based column of `pos`.
+  fn line_column(&self, pos: usize) -> (u32, u32) {
+    let line = self.line_starts.partition_point(|start| *start <= pos);
+    (line as u32, (pos - self.line_starts[line - 1]) as u32)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn locations(css: &str, sources: &[&str]) -> Vec<(u32, u32, u32, u32)> {
+    generate_mappings(css, sources)
+      .iter()
+      .map(|mapping| {
+        let original = mapping.original.as_ref().unwrap();
+        (
+          mapping.generated_line,
+          mapping.generated_column,
+          original.original_line,
+          original.original_column,
+        )
+      })
+      .collect()
+  }
+
+  #[test]
+  fn should_map_rules_and_declarations() {
+    let source = ".card {\n  color: red;\n  .title {\n    margin: 0;\n  }\n  &:hover {\n    color: blue;\n  }\n}\n";
+    let css = ".card {\n  color: red;\n}\n.card .title {\n  margin: 0;\n}\n.card:hover {\n  color: blue;\n}\n";
+    assert_eq!(
+      locations(css, &[source]),
+      vec![
+        (1, 0, 1, 0),
+        (2, 2, 2, 2),
+        (4, 0, 3, 2),
+        (5, 2, 4, 4),
+        (7, 0, 6, 2),
+        (8, 2, 7, 4)
+      ]
+    );
+  }
+
+  #[test]
+  fn should_map_compressed_css() {
+    let source = ".card {\n  color: red;\n  .title {\n    margin: 0;\n  }\n}\n";
+    let css = ".card{color:red}.card .title{margin:0}\n";
+    assert_eq!(
+      locations(css, &[source]),
+      vec![(1, 0, 1, 0), (1, 6, 2, 2), (1, 16, 3, 2), (1, 29, 4, 4)]
+    );
+  }
+
+  #[test]
+  fn should_not_map_what_cannot_be_verified() {
+    let source = r##"@mixin theme { color: red; }
+%base { margin: 0; }
+.button { @extend %base; padding: 0; }
+.card { @include theme; padding: 0; }
+@each $name in a, b {
+  .icon-#{$name} { content: "#{$name}"; }
+}
+.title { // .card {
+  padding: 0;
+}
+"##;
+    let css = ".button {\n  margin: 0;\n}\n\n.button {\n  padding: 0;\n}\n\n.card {\n  color: red;\n  padding: 0;\n}\n\n.icon-a {\n  content: \"a\";\n}\n\n.title {\n  padding: 0;\n}\n";
+    // only `.button { padding: 0 }` and `.title`, the rule of `%base`, the
+    // declarations of the mixin and the interpolated rules are left unmapped
+    assert_eq!(
+      locations(css, &[source]),
+      vec![(5, 0, 3, 0), (6, 2, 3, 25), (18, 0, 8, 0), (19, 2, 9, 2)]
+    );
+  }
+}
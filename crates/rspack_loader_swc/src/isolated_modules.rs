@@ -0,0 +1,309 @@
+use rustc_hash::FxHashSet;
+use swc_core::{
+  common::Span,
+  ecma::{
+    ast::{
+      Decl, DefaultDecl, ExportSpecifier, Expr, ImportSpecifier, Module, ModuleDecl, ModuleItem,
+      ObjectPatProp, Pat, Program, Stmt, TsModuleDecl, TsModuleName, TsNamespaceBody,
+    },
+    atoms::Atom,
+    visit::{Visit, VisitWith},
+  },
+};
+
+/// What can't be transpiled when each TypeScript file is transpiled on its
+/// own, like the `isolatedModules` option of TypeScript.
+#[derive(Debug, Default)]
+pub struct IsolatedModulesReport {
+  pub errors: Vec<(Span, String)>,
+  /// Names exported only as types or ambient declarations, which have no
+  /// value at runtime.
+  pub type_only_exports: Vec<String>,
+  /// Names exported as ambient const enums, which are erased instead of being
+  /// inlined in the modules that import them.
+  pub ambient_const_enum_exports: Vec<String>,
+}
+
+/// Checks a TypeScript program before its types are stripped. All the
+/// declarations of a `declaration_file` (`.d.ts`) are ambient, even without
+/// `declare`.
+pub fn check(program: &Program, declaration_file: bool) -> IsolatedModulesReport {
+  let Program::Module(module) = program else {
+    return Default::default();
+  };
+  let bindings = Bindings::new(module, declaration_file);
+  let mut report = IsolatedModulesReport::default();
+
+  for item in &module.body {
+    match item {
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+        if !bindings.is_value_decl(&export.decl) {
+          report.type_only_exports.extend(
+            decl_names(&export.decl)
+              .into_iter()
+              .map(|name| name.to_string()),
+          );
+        }
+        if let Decl::TsEnum(ts_enum) = &export.decl
+          && bindings.ambient_const_enums.contains(&ts_enum.id.sym)
+        {
+          report
+            .ambient_const_enum_exports
+            .push(ts_enum.id.sym.to_string());
+        }
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export))
+        if matches!(export.decl, DefaultDecl::TsInterfaceDecl(_)) =>
+      {
+        report.type_only_exports.push("default".to_string());
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
+        if let Expr::Ident(ident) = &*export.expr
+          && bindings.is_type_only(&ident.sym)
+        {
+          report.type_only_exports.push("default".to_string());
+        }
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) => {
+        for specifier in &export.specifiers {
+          let ExportSpecifier::Named(specifier) = specifier else {
+            continue;
+          };
+          // The names re-exported from other modules are only known to be
+          // types when they are marked as types.
+          let is_type_only = export.type_only
+            || specifier.is_type_only
+            || (export.src.is_none() && bindings.is_type_only(specifier.orig.atom()));
+          let exported = specifier.exported.as_ref().unwrap_or(&specifier.orig);
+          if is_type_only {
+            report.type_only_exports.push(exported.atom().to_string());
+          }
+          if export.src.is_none() && bindings.ambient_const_enums.contains(specifier.orig.atom()) {
+            report
+              .ambient_const_enum_exports
+              .push(exported.atom().to_string());
+          }
+        }
+      }
+      _ => {}
+    }
+
+    if let Some(namespace) = namespace_decl(item)
+      && !namespace.declare
+      && let TsModuleName::Ident(id) = &namespace.id
+      && namespace.body.as_ref().is_some_and(is_instantiated)
+      && bindings.imports.contains(&id.sym)
+    {
+      report.errors.push((
+        id.span,
+        format!(
+          "Namespace '{}' merges with the imported '{}', which can't be transpiled when each file is transpiled on its own",
+          id.sym, id.sym
+        ),
+      ));
+    }
+  }
+
+  // Only the types that don't merge with values are erased.
+  report
+    .type_only_exports
+    .retain(|name| name == "default" || !bindings.values.contains(&Atom::from(name.as_str())));
+  report.type_only_exports.sort_unstable();
+  report.type_only_exports.dedup();
+  report.ambient_const_enum_exports.sort_unstable();
+  report.ambient_const_enum_exports.dedup();
+
+  if !bindings.ambient_const_enums.is_empty() {
+    let mut finder = AmbientConstEnumFinder {
+      ambient_const_enums: &bindings.ambient_const_enums,
+      errors: &mut report.errors,
+    };
+    module.visit_with(&mut finder);
+  }
+
+  report
+}
+
+/// Top-level bindings of a module.
+#[derive(Default)]
+struct Bindings {
+  /// Declarations with a value at runtime.
+  values: FxHashSet<Atom>,
+  /// Bindings imported with a value.
+  imports: FxHashSet<Atom>,
+  /// Declarations and imports without a value at runtime.
+  types: FxHashSet<Atom>,
+  ambient_const_enums: FxHashSet<Atom>,
+  /// Whether all the declarations are ambient, as in a `.d.ts` file.
+  ambient: bool,
+}
+
+impl Bindings {
+  fn new(module: &Module, ambient: bool) -> Self {
+    let mut bindings = Self {
+      ambient,
+      ..Default::default()
+    };
+    for item in &module.body {
+      match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+          for specifier in &import.specifiers {
+            let (local, is_type_only) = match specifier {
+              ImportSpecifier::Named(named) => (&named.local, named.is_type_only),
+              ImportSpecifier::Default(default) => (&default.local, false),
+              ImportSpecifier::Namespace(namespace) => (&namespace.local, false),
+            };
+            if import.type_only || is_type_only {
+              bindings.types.insert(local.sym.clone());
+            } else {
+              bindings.imports.insert(local.sym.clone());
+            }
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => bindings.add_decl(&export.decl),
+        ModuleItem::Stmt(Stmt::Decl(decl)) => bindings.add_decl(decl),
+        _ => {}
+      }
+    }
+    bindings
+  }
+
+  fn add_decl(&mut self, decl: &Decl) {
+    if let Decl::TsEnum(ts_enum) = decl
+      && (ts_enum.declare || self.ambient)
+      && ts_enum.is_const
+    {
+      self.ambient_const_enums.insert(ts_enum.id.sym.clone());
+    }
+    let set = if self.is_value_decl(decl) {
+      &mut self.values
+    } else {
+      &mut self.types
+    };
+    set.extend(decl_names(decl).into_iter().cloned());
+  }
+
+  fn is_value_decl(&self, decl: &Decl) -> bool {
+    !self.ambient && is_value_decl(decl)
+  }
+
+  fn is_type_only(&self, name: &Atom) -> bool {
+    self.types.contains(name) && !self.values.contains(name) && !self.imports.contains(name)
+  }
+}
+
+fn namespace_decl(item: &ModuleItem) -> Option<&TsModuleDecl> {
+  match item {
+    ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(namespace))) => Some(namespace),
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => match &export.decl {
+      Decl::TsModule(namespace) => Some(namespace),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// Whether the declaration has a value at runtime.
+fn is_value_decl(decl: &Decl) -> bool {
+  match decl {
+    Decl::TsInterface(_) | Decl::TsTypeAlias(_) => false,
+    Decl::Var(var) => !var.declare,
+    Decl::Fn(function) => !function.declare,
+    Decl::Class(class) => !class.declare,
+    Decl::TsEnum(ts_enum) => !ts_enum.declare,
+    Decl::TsModule(namespace) => {
+      !namespace.declare && namespace.body.as_ref().is_some_and(is_instantiated)
+    }
+    Decl::Using(_) => true,
+  }
+}
+
+/// Whether a namespace contains values, i.e. isn't erased at runtime.
+fn is_instantiated(body: &TsNamespaceBody) -> bool {
+  match body {
+    TsNamespaceBody::TsModuleBlock(block) => block.body.iter().any(|item| match item {
+      ModuleItem::Stmt(Stmt::Decl(decl)) => is_value_decl(decl),
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => is_value_decl(&export.decl),
+      ModuleItem::Stmt(Stmt::Empty(_)) => false,
+      _ => true,
+    }),
+    TsNamespaceBody::TsNamespaceDecl(namespace) => {
+      !namespace.declare && is_instantiated(&namespace.body)
+    }
+  }
+}
+
+fn decl_names(decl: &Decl) -> Vec<&Atom> {
+  match decl {
+    Decl::Class(class) => vec![&class.ident.sym],
+    Decl::Fn(function) => vec![&function.ident.sym],
+    Decl::Var(var) => {
+      let mut names = vec![];
+      for declarator in &var.decls {
+        pat_names(&declarator.name, &mut names);
+      }
+      names
+    }
+    Decl::Using(using) => {
+      let mut names = vec![];
+      for declarator in &using.decls {
+        pat_names(&declarator.name, &mut names);
+      }
+      names
+    }
+    Decl::TsInterface(interface) => vec![&interface.id.sym],
+    Decl::TsTypeAlias(alias) => vec![&alias.id.sym],
+    Decl::TsEnum(ts_enum) => vec![&ts_enum.id.sym],
+    Decl::TsModule(namespace) => match &namespace.id {
+      TsModuleName::Ident(id) => vec![&id.sym],
+      TsModuleName::Str(_) => vec![],
+    },
+  }
+}
+
+fn pat_names<'a>(pat: &'a Pat, names: &mut Vec<&'a Atom>) {
+  match pat {
+    Pat::Ident(ident) => names.push(&ident.id.sym),
+    Pat::Array(array) => {
+      for elem in array.elems.iter().flatten() {
+        pat_names(elem, names);
+      }
+    }
+    Pat::Object(object) => {
+      for prop in &object.props {
+        match prop {
+          ObjectPatProp::KeyValue(key_value) => pat_names(&key_value.value, names),
+          ObjectPatProp::Assign(assign) => names.push(&assign.key.id.sym),
+          ObjectPatProp::Rest(rest) => pat_names(&rest.arg, names),
+        }
+      }
+    }
+    Pat::Rest(rest) => pat_names(&rest.arg, names),
+    Pat::Assign(assign) => pat_names(&assign.left, names),
+    Pat::Invalid(_) | Pat::Expr(_) => {}
+  }
+}
+
+/// Finds the values that refer to ambient const enums, which are erased
+/// instead of being inlined.
+struct AmbientConstEnumFinder<'a> {
+  ambient_const_enums: &'a FxHashSet<Atom>,
+  errors: &'a mut Vec<(Span, String)>,
+}
+
+impl Visit for AmbientConstEnumFinder<'_> {
+  fn visit_expr(&mut self, expr: &Expr) {
+    if let Expr::Ident(ident) = expr
+      && self.ambient_const_enums.contains(&ident.sym)
+    {
+      self.errors.push((
+        ident.span,
+        format!(
+          "Cannot access the ambient const enum '{}', which has no value when each file is transpiled on its own",
+          ident.sym
+        ),
+      ));
+    }
+    expr.visit_children_with(self);
+  }
+}
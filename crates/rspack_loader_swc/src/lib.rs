@@ -1,5 +1,6 @@
 #![feature(let_chains)]
 
+mod isolated_modules;
 mod options;
 mod plugin;
 mod transformer;
@@ -10,8 +11,10 @@ use options::SwcCompilerOptionsWithAdditional;
 pub use options::SwcLoaderJsOptions;
 pub use plugin::SwcLoaderPlugin;
 use rspack_cacheable::{cacheable, cacheable_dyn};
-use rspack_core::{Mode, RunnerContext};
-use rspack_error::{miette, Diagnostic, Result};
+use rspack_core::{
+  Mode, RunnerContext, AMBIENT_CONST_ENUM_EXPORTS_PARSE_META_KEY, TYPE_ONLY_EXPORTS_PARSE_META_KEY,
+};
+use rspack_error::{miette, Diagnostic, DiagnosticKind, Result, TraceableError};
 use rspack_javascript_compiler::{JavaScriptCompiler, TransformOutput};
 use rspack_loader_runner::{Identifiable, Identifier, Loader, LoaderContext};
 use swc_config::{merge::Merge, types::MergingOption};
@@ -81,10 +84,22 @@ impl SwcLoader {
       swc_options
     };
 
+    let is_typescript = swc_options
+      .config
+      .jsc
+      .syntax
+      .is_some_and(|syntax| syntax.typescript());
+    let is_declaration_file = [".d.ts", ".d.mts", ".d.cts"]
+      .iter()
+      .any(|extension| resource_path.as_str().ends_with(extension));
+    let root_dir = loader_context.context.options.context.as_path();
+    let development = Mode::is_development(&loader_context.context.options.mode);
+    let resource_path = resource_path.into_std_path_buf();
     let javascript_compiler = JavaScriptCompiler::new();
-    let filename = FileName::Real(resource_path.into_std_path_buf());
+    let filename = FileName::Real(resource_path.clone());
 
     let source = content.into_string_lossy();
+    let mut isolated_modules_report = None;
 
     let TransformOutput {
       code,
@@ -95,7 +110,17 @@ impl SwcLoader {
       Some(filename),
       swc_options,
       Some(loader_context.context.module_source_map_kind),
-      |_| transformer::transform(&self.options_with_additional.rspack_experiments),
+      |program| {
+        if is_typescript {
+          isolated_modules_report = Some(isolated_modules::check(program, is_declaration_file));
+        }
+        transformer::transform(
+          &resource_path,
+          root_dir.as_std_path(),
+          development,
+          &self.options_with_additional.rspack_experiments,
+        )
+      },
     )?;
 
     for diagnostic in diagnostics {
@@ -104,6 +129,42 @@ impl SwcLoader {
       );
     }
 
+    if let Some(report) = isolated_modules_report {
+      let source_map = javascript_compiler.source_map();
+      for (span, message) in report.errors {
+        let start = source_map.lookup_byte_offset(span.lo);
+        let start_offset = start.pos.0 as usize;
+        let end_offset = start_offset + (span.hi - span.lo).0 as usize;
+        loader_context.emit_diagnostic(
+          miette::Error::new(
+            TraceableError::from_source_file(
+              &start.sf,
+              start_offset,
+              end_offset,
+              "TypeScript Error".to_string(),
+              message,
+            )
+            .with_kind(DiagnosticKind::Typescript),
+          )
+          .into(),
+        );
+      }
+      if !report.type_only_exports.is_empty() {
+        loader_context.parse_meta.insert(
+          TYPE_ONLY_EXPORTS_PARSE_META_KEY.to_string(),
+          serde_json::to_string(&report.type_only_exports)
+            .expect("should serialize type-only exports"),
+        );
+      }
+      if !report.ambient_const_enum_exports.is_empty() {
+        loader_context.parse_meta.insert(
+          AMBIENT_CONST_ENUM_EXPORTS_PARSE_META_KEY.to_string(),
+          serde_json::to_string(&report.ambient_const_enum_exports)
+            .expect("should serialize ambient const enum exports"),
+        );
+      }
+    }
+
     loader_context.finish_with((code, map));
 
     Ok(())
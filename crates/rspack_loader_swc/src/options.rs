@@ -2,7 +2,11 @@ use rspack_cacheable::{
   cacheable,
   with::{AsRefStr, AsRefStrConverter},
 };
+use rspack_swc_plugin_emotion::EmotionOptions;
 use rspack_swc_plugin_import::{ImportOptions, RawImportOptions};
+use rspack_swc_plugin_relay::RelayOptions;
+use rspack_swc_plugin_remove_console::RemoveConsoleOptions;
+use rspack_swc_plugin_styled_components::StyledComponentsOptions;
 use serde::Deserialize;
 use swc_config::{file_pattern::FilePattern, types::BoolConfig};
 use swc_core::base::config::{
@@ -14,11 +18,19 @@ use swc_core::base::config::{
 #[serde(rename_all = "camelCase", default)]
 pub struct RawRspackExperiments {
   pub import: Option<Vec<RawImportOptions>>,
+  pub emotion: Option<EmotionOptions>,
+  pub styled_components: Option<StyledComponentsOptions>,
+  pub relay: Option<RelayOptions>,
+  pub remove_console: Option<RemoveConsoleOptions>,
 }
 
 #[derive(Default, Debug)]
 pub(crate) struct RspackExperiments {
   pub(crate) import: Option<Vec<ImportOptions>>,
+  pub(crate) emotion: Option<EmotionOptions>,
+  pub(crate) styled_components: Option<StyledComponentsOptions>,
+  pub(crate) relay: Option<RelayOptions>,
+  pub(crate) remove_console: Option<RemoveConsoleOptions>,
 }
 
 impl From<RawRspackExperiments> for RspackExperiments {
@@ -27,6 +39,10 @@ impl From<RawRspackExperiments> for RspackExperiments {
       import: value
         .import
         .map(|i| i.into_iter().map(|v| v.into()).collect()),
+      emotion: value.emotion,
+      styled_components: value.styled_components,
+      relay: value.relay,
+      remove_console: value.remove_console,
     }
   }
 }
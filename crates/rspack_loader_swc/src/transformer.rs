@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use either::Either;
 use swc_core::ecma::ast::{noop_pass, Pass};
 
@@ -22,8 +24,27 @@ macro_rules! either {
 }
 
 #[allow(clippy::too_many_arguments)]
-pub(crate) fn transform(rspack_experiments: &RspackExperiments) -> impl Pass + '_ {
-  either!(rspack_experiments.import, |options| {
-    rspack_swc_plugin_import::plugin_import(options)
-  })
+pub(crate) fn transform<'a>(
+  resource_path: &'a Path,
+  root_dir: &'a Path,
+  development: bool,
+  rspack_experiments: &'a RspackExperiments,
+) -> impl Pass + 'a {
+  (
+    either!(rspack_experiments.import, |options| {
+      rspack_swc_plugin_import::plugin_import(options)
+    }),
+    either!(rspack_experiments.relay, |options| {
+      rspack_swc_plugin_relay::relay(options, resource_path, root_dir)
+    }),
+    either!(rspack_experiments.emotion, |options| {
+      rspack_swc_plugin_emotion::emotion(options, resource_path, root_dir, development)
+    }),
+    either!(rspack_experiments.styled_components, |options| {
+      rspack_swc_plugin_styled_components::styled_components(options, resource_path, root_dir)
+    }),
+    either!(rspack_experiments.remove_console, |options| {
+      rspack_swc_plugin_remove_console::remove_console(options)
+    }),
+  )
 }
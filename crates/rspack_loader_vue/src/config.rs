@@ -0,0 +1,35 @@
+use rspack_cacheable::cacheable;
+use serde::Deserialize;
+
+#[cacheable]
+#[derive(Debug, Default)]
+pub struct Config {
+  /// Emits the code that re-renders or reloads components in place when
+  /// hot module replacement is enabled.
+  pub hot_reload: bool,
+  /// Exposes the path of the file as `__file` of the component, which is
+  /// used by devtools and warnings. Defaults to whether the mode is
+  /// development.
+  pub expose_filename: Option<bool>,
+  /// Components matched by these names are treated as custom elements
+  /// instead of being resolved.
+  pub custom_elements: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RawConfig {
+  pub hot_reload: Option<bool>,
+  pub expose_filename: Option<bool>,
+  pub custom_elements: Option<Vec<String>>,
+}
+
+impl From<RawConfig> for Config {
+  fn from(value: RawConfig) -> Self {
+    Self {
+      hot_reload: value.hot_reload.unwrap_or(true),
+      expose_filename: value.expose_filename,
+      custom_elements: value.custom_elements.unwrap_or_default(),
+    }
+  }
+}
@@ -0,0 +1,280 @@
+//! Splits a single-file component into its top-level blocks.
+
+use rustc_hash::FxHashMap;
+
+/// A top-level block of a single-file component, e.g. `<template>`.
+#[derive(Debug, Clone)]
+pub struct Block {
+  pub attrs: Vec<(String, Option<String>)>,
+  pub content: String,
+  /// Byte offset of the content in the file.
+  pub start: usize,
+}
+
+impl Block {
+  pub fn attr(&self, name: &str) -> Option<&str> {
+    self
+      .attrs
+      .iter()
+      .find(|(key, _)| key == name)
+      .map(|(_, value)| value.as_deref().unwrap_or(""))
+  }
+
+  pub fn has_attr(&self, name: &str) -> bool {
+    self.attrs.iter().any(|(key, _)| key == name)
+  }
+
+  pub fn lang(&self) -> Option<&str> {
+    self.attr("lang").filter(|lang| !lang.is_empty())
+  }
+
+  /// The content prefixed with empty lines, so that the positions of the
+  /// content match the positions in the file.
+  pub fn padded_content(&self, source: &str) -> String {
+    let lines = source[..self.start].matches('\n').count();
+    let mut padded = "\n".repeat(lines);
+    padded.push_str(&self.content);
+    padded
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct Descriptor {
+  pub template: Option<Block>,
+  pub script: Option<Block>,
+  pub script_setup: Option<Block>,
+  pub styles: Vec<Block>,
+}
+
+#[derive(Debug)]
+pub struct DescriptorError {
+  pub message: String,
+  pub offset: usize,
+}
+
+impl Descriptor {
+  pub fn parse(source: &str) -> Result<Self, DescriptorError> {
+    let mut descriptor = Self::default();
+    let bytes = source.as_bytes();
+    let mut pos = 0;
+
+    while let Some(index) = source[pos..].find('<') {
+      let tag_start = pos + index;
+      if source[tag_start..].starts_with("<!--") {
+        pos = match source[tag_start..].find("-->") {
+          Some(end) => tag_start + end + 3,
+          None => source.len(),
+        };
+        continue;
+      }
+      let name_start = tag_start + 1;
+      let name_end = source[name_start..]
+        .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+        .map_or(source.len(), |end| name_start + end);
+      let name = &source[name_start..name_end];
+      if name.is_empty() || !bytes[name_start].is_ascii_alphabetic() {
+        pos = name_start;
+        continue;
+      }
+
+      let (attrs, open_end, self_closing) =
+        parse_attrs(source, name_end).map_err(|offset| DescriptorError {
+          message: format!("Element <{name}> is missing end of the start tag"),
+          offset,
+        })?;
+      if self_closing {
+        pos = open_end;
+        continue;
+      }
+      let (content_end, close_end) =
+        find_close_tag(source, name, open_end).ok_or_else(|| DescriptorError {
+          message: format!("Element <{name}> is missing end tag"),
+          offset: tag_start,
+        })?;
+      let block = Block {
+        attrs,
+        content: source[open_end..content_end].to_string(),
+        start: open_end,
+      };
+      pos = close_end;
+
+      let duplicated = match name {
+        "template" => descriptor.template.replace(block).is_some(),
+        "script" if block.has_attr("setup") => descriptor.script_setup.replace(block).is_some(),
+        "script" => descriptor.script.replace(block).is_some(),
+        "style" => {
+          descriptor.styles.push(block);
+          false
+        }
+        // Custom blocks are ignored.
+        _ => false,
+      };
+      if duplicated {
+        return Err(DescriptorError {
+          message: format!("Single-file component can contain only one <{name}> element"),
+          offset: tag_start,
+        });
+      }
+    }
+
+    if let (Some(script), Some(script_setup)) = (&descriptor.script, &descriptor.script_setup)
+      && script.lang() != script_setup.lang()
+    {
+      return Err(DescriptorError {
+        message: "<script> and <script setup> must have the same lang".to_string(),
+        offset: script_setup.start,
+      });
+    }
+
+    Ok(descriptor)
+  }
+
+  pub fn script_lang(&self) -> &str {
+    self
+      .script_setup
+      .as_ref()
+      .or(self.script.as_ref())
+      .and_then(Block::lang)
+      .unwrap_or("js")
+  }
+}
+
+/// The attributes of a start tag, the end of the start tag and whether it's
+/// self-closing.
+pub type StartTag = (Vec<(String, Option<String>)>, usize, bool);
+
+/// Parses the attributes of a start tag, returns the parsed start tag, or the
+/// offset where the start tag is cut off.
+pub fn parse_attrs(source: &str, mut pos: usize) -> Result<StartTag, usize> {
+  let bytes = source.as_bytes();
+  let mut attrs = vec![];
+  loop {
+    while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+      pos += 1;
+    }
+    if pos >= bytes.len() {
+      return Err(pos);
+    }
+    match bytes[pos] {
+      b'>' => return Ok((attrs, pos + 1, false)),
+      b'/' if bytes.get(pos + 1) == Some(&b'>') => return Ok((attrs, pos + 2, true)),
+      b'/' => {
+        pos += 1;
+        continue;
+      }
+      _ => {}
+    }
+
+    let name_start = pos;
+    // The first character is always part of the name, so that `=foo` doesn't
+    // end up as an empty name.
+    pos += 1;
+    while pos < bytes.len()
+      && !bytes[pos].is_ascii_whitespace()
+      && !matches!(bytes[pos], b'=' | b'>')
+      && (bytes[pos] != b'/' || bytes.get(pos + 1) != Some(&b'>'))
+    {
+      pos += 1;
+    }
+    let name = source[name_start..pos].to_string();
+
+    let mut value_pos = pos;
+    while value_pos < bytes.len() && bytes[value_pos].is_ascii_whitespace() {
+      value_pos += 1;
+    }
+    if bytes.get(value_pos) != Some(&b'=') {
+      attrs.push((name, None));
+      continue;
+    }
+    pos = value_pos + 1;
+    while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+      pos += 1;
+    }
+    let value = match bytes.get(pos) {
+      Some(quote @ (b'"' | b'\'')) => {
+        let end = source[pos + 1..].find(*quote as char).ok_or(name_start)?;
+        let value = &source[pos + 1..pos + 1 + end];
+        pos += end + 2;
+        value
+      }
+      Some(_) => {
+        let start = pos;
+        while pos < bytes.len() && !bytes[pos].is_ascii_whitespace() && bytes[pos] != b'>' {
+          pos += 1;
+        }
+        &source[start..pos]
+      }
+      None => return Err(pos),
+    };
+    attrs.push((name, Some(value.to_string())));
+  }
+}
+
+/// Finds the end tag of a top-level block, returns the end of the content
+/// and the end of the end tag. Only `<template>` can be nested in itself.
+fn find_close_tag(source: &str, name: &str, mut pos: usize) -> Option<(usize, usize)> {
+  let close = format!("</{name}");
+  if name != "template" {
+    let end = pos + source[pos..].find(&close)?;
+    let close_end = end + source[end..].find('>')? + 1;
+    return Some((end, close_end));
+  }
+
+  let open = format!("<{name}");
+  let mut depth = 0usize;
+  loop {
+    let next_close = pos + source[pos..].find(&close)?;
+    match source[pos..next_close].find(&open) {
+      Some(index)
+        if source[pos + index + open.len()..]
+          .starts_with(|c: char| c.is_ascii_whitespace() || c == '>') =>
+      {
+        let open_start = pos + index;
+        let (_, open_end, self_closing) = parse_attrs(source, open_start + open.len()).ok()?;
+        if !self_closing {
+          depth += 1;
+        }
+        pos = open_end;
+      }
+      Some(index) => pos += index + open.len(),
+      None => {
+        let close_end = next_close + source[next_close..].find('>')? + 1;
+        if depth == 0 {
+          return Some((next_close, close_end));
+        }
+        depth -= 1;
+        pos = close_end;
+      }
+    }
+  }
+}
+
+/// The resource query of the requests to the blocks, e.g.
+/// `?vue&type=style&index=0&scoped=true&lang=css`.
+#[derive(Debug, Default)]
+pub struct VueQuery {
+  params: FxHashMap<String, String>,
+}
+
+impl VueQuery {
+  pub fn parse(query: &str) -> Option<Self> {
+    let params: FxHashMap<String, String> = query
+      .trim_start_matches('?')
+      .split('&')
+      .filter(|pair| !pair.is_empty())
+      .map(|pair| match pair.split_once('=') {
+        Some((key, value)) => (key.to_string(), value.to_string()),
+        None => (pair.to_string(), String::new()),
+      })
+      .collect();
+    params.contains_key("vue").then_some(Self { params })
+  }
+
+  pub fn get(&self, key: &str) -> Option<&str> {
+    self.params.get(key).map(String::as_str)
+  }
+
+  pub fn is_enabled(&self, key: &str) -> bool {
+    self.get(key).is_some_and(|value| value == "true")
+  }
+}
@@ -0,0 +1,338 @@
+#![feature(let_chains)]
+
+pub mod config;
+mod descriptor;
+mod plugin;
+mod script;
+mod style;
+mod template;
+
+use std::{hash::Hash, path::Path};
+
+use config::Config;
+use descriptor::{Descriptor, VueQuery};
+pub use plugin::VueLoaderPlugin;
+use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_core::{Loader, LoaderContext, Mode, RunnerContext};
+use rspack_error::{error, miette, Error, Result, TraceableError};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_javascript_compiler::{transform::SwcOptions, JavaScriptCompiler, TransformOutput};
+use rspack_loader_runner::{Identifiable, Identifier};
+pub use style::{VueStylePostLoader, VUE_STYLE_POST_LOADER_IDENTIFIER};
+use sugar_path::SugarPath;
+use swc_core::{
+  common::FileName,
+  ecma::{
+    ast::{noop_pass, EsVersion},
+    visit::visit_mut_pass,
+  },
+};
+
+pub const VUE_LOADER_IDENTIFIER: &str = "builtin:vue-loader";
+
+/// Compiles Vue single-file components.
+///
+/// A component is compiled into a module that imports its blocks, which are
+/// requested with resource queries like `?vue&type=template`, and loaded
+/// by this loader again.
+#[cacheable]
+#[derive(Debug)]
+pub struct VueLoader {
+  id: Identifier,
+  config: Config,
+}
+
+impl VueLoader {
+  pub fn new(config: Config, ident: &str) -> Self {
+    Self {
+      id: ident.into(),
+      config,
+    }
+  }
+
+  fn loader_impl(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+    let Some(resource_path) = loader_context
+      .resource_path()
+      .map(|p| p.as_std_path().to_path_buf())
+    else {
+      return Ok(());
+    };
+    let Some(content) = loader_context.take_content() else {
+      return Ok(());
+    };
+    let source = content.into_string_lossy();
+    let descriptor = Descriptor::parse(&source)
+      .map_err(|error| vue_error(&source, error.offset, error.message))?;
+
+    let Some(query) = loader_context.resource_query().and_then(VueQuery::parse) else {
+      let code = self.component_module(loader_context, &descriptor, &resource_path);
+      loader_context.finish_with(code);
+      return Ok(());
+    };
+
+    match query.get("type") {
+      Some("script") => {
+        let lang = descriptor.script_lang();
+        let component_name = resource_path
+          .file_stem()
+          .map(|stem| stem.to_string_lossy().to_string())
+          .unwrap_or_default();
+        let code = script::compile(&descriptor, &source, &resource_path, &component_name)?;
+        let TransformOutput {
+          code,
+          map,
+          diagnostics,
+        } = JavaScriptCompiler::new().transform(
+          code,
+          Some(FileName::Real(resource_path.clone())),
+          swc_options(&resource_path, lang),
+          Some(loader_context.context.module_source_map_kind),
+          |_| noop_pass(),
+        )?;
+        emit_warnings(loader_context, diagnostics);
+        loader_context.finish_with((code, map));
+      }
+      Some("template") => {
+        let Some(template) = &descriptor.template else {
+          return Err(error!("{} has no <template>", resource_path.display()));
+        };
+        if let Some(lang) = template.lang()
+          && lang != "html"
+        {
+          return Err(vue_error(
+            &source,
+            template.start,
+            format!("<template lang=\"{lang}\"> isn't supported by builtin:vue-loader, templates must be written in HTML"),
+          ));
+        }
+        let bindings = script::setup_bindings(&descriptor, &source, &resource_path)?.names();
+        let code = template::compile(
+          &template.content,
+          template::CodegenOptions {
+            setup_bindings: &bindings,
+            custom_elements: &self.config.custom_elements,
+          },
+        )
+        .map_err(|errors| {
+          let mut errors = errors
+            .into_iter()
+            .map(|error| vue_error(&source, template.start + error.offset, error.message));
+          let first = errors
+            .next()
+            .unwrap_or_else(|| error!("Failed to compile the template"));
+          for error in errors {
+            loader_context.emit_diagnostic(error.into());
+          }
+          first
+        })?;
+        let TransformOutput {
+          code, diagnostics, ..
+        } = JavaScriptCompiler::new().transform(
+          code,
+          Some(FileName::Real(resource_path.clone())),
+          swc_options(&resource_path, descriptor.script_lang()),
+          None,
+          |_| visit_mut_pass(template::PrefixIdentifiers::default()),
+        )?;
+        emit_warnings(loader_context, diagnostics);
+        loader_context.finish_with(code);
+      }
+      Some("style") => {
+        let style = query
+          .get("index")
+          .and_then(|index| index.parse::<usize>().ok())
+          .and_then(|index| descriptor.styles.get(index))
+          .ok_or_else(|| error!("{} has no such <style>", resource_path.display()))?;
+        if let Some(offset) = style::find_v_bind(&style.content) {
+          return Err(vue_error(
+            &source,
+            style.start + offset,
+            "`v-bind()` in <style> isn't supported by builtin:vue-loader, use CSS variables bound with the `style` attribute instead".to_string(),
+          ));
+        }
+        loader_context.finish_with(style.padded_content(&source));
+      }
+      ty => {
+        return Err(error!(
+          "Unknown block type {} of {}",
+          ty.unwrap_or_default(),
+          resource_path.display()
+        ))
+      }
+    }
+    Ok(())
+  }
+
+  /// Generates the module of the component, which assembles the blocks.
+  fn component_module(
+    &self,
+    loader_context: &LoaderContext<RunnerContext>,
+    descriptor: &Descriptor,
+    resource_path: &Path,
+  ) -> String {
+    let options = &loader_context.context.options;
+    let relative_path = resource_path
+      .relative(options.context.as_path().as_std_path())
+      .to_slash_lossy()
+      .to_string();
+    let id = {
+      let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+      relative_path.hash(&mut hasher);
+      hasher.digest(&HashDigest::Hex).rendered(8).to_string()
+    };
+    let request = format!(
+      "./{}",
+      resource_path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default()
+    );
+    let has_scoped = descriptor
+      .styles
+      .iter()
+      .any(|style| style.has_attr("scoped"));
+
+    let mut code = String::new();
+    if descriptor.script.is_some() || descriptor.script_setup.is_some() {
+      let script_request = json(&format!(
+        "{request}?vue&type=script&lang={}",
+        descriptor.script_lang()
+      ));
+      code.push_str(&format!("import script from {script_request};\n"));
+      if descriptor.script.is_some() {
+        code.push_str(&format!("export * from {script_request};\n"));
+      }
+    } else {
+      code.push_str("const script = {};\n");
+    }
+
+    let template_request = descriptor.template.as_ref().map(|_| {
+      json(&format!(
+        "{request}?vue&type=template&id={id}&scoped={has_scoped}"
+      ))
+    });
+    if let Some(template_request) = &template_request {
+      code.push_str(&format!(
+        "import {{ render }} from {template_request};\nscript.render = render;\n"
+      ));
+    }
+
+    let mut css_modules = vec![];
+    for (index, style) in descriptor.styles.iter().enumerate() {
+      let lang = style.lang().unwrap_or("css");
+      let module_name = style
+        .attr("module")
+        .map(|name| if name.is_empty() { "$style" } else { name });
+      let scoped = style.has_attr("scoped");
+      // The match resource makes the style matched by the rules of its
+      // language, e.g. `App.vue.0.scss` by the rule of `.scss`.
+      let match_resource = format!(
+        "{request}.{index}.{}{lang}",
+        if module_name.is_some() { "module." } else { "" }
+      );
+      let style_request = json(&format!(
+        "{match_resource}!=!{VUE_LOADER_IDENTIFIER}!{request}?vue&type=style&index={index}&id={id}&scoped={scoped}&lang={lang}"
+      ));
+      match module_name {
+        Some(name) => {
+          code.push_str(&format!("import * as style{index} from {style_request};\n"));
+          css_modules.push((name, index));
+        }
+        None => code.push_str(&format!("import {style_request};\n")),
+      }
+    }
+    if !css_modules.is_empty() {
+      code.push_str("const cssModules = script.__cssModules = {};\n");
+      for (name, index) in css_modules {
+        code.push_str(&format!("cssModules[{}] = style{index};\n", json(name)));
+      }
+    }
+
+    if has_scoped {
+      code.push_str(&format!("script.__scopeId = \"data-v-{id}\";\n"));
+    }
+    if self
+      .config
+      .expose_filename
+      .unwrap_or_else(|| options.mode == Mode::Development)
+    {
+      code.push_str(&format!("script.__file = {};\n", json(&relative_path)));
+    }
+
+    // The same hot reload code as `vue-loader`: the component accepts its own
+    // updates and hands them to the HMR runtime of Vue, an update of only the
+    // template module rerenders it and keeps its state.
+    if self.config.hot_reload && loader_context.hot {
+      code.push_str(&format!(
+        r#"script.__hmrId = "{id}";
+if (import.meta.webpackHot) {{
+  const api = __VUE_HMR_RUNTIME__;
+  import.meta.webpackHot.accept();
+  if (!api.createRecord("{id}", script)) {{
+    api.reload("{id}", script);
+  }}
+"#
+      ));
+      if let Some(template_request) = &template_request {
+        code.push_str(&format!(
+          r#"  import.meta.webpackHot.accept({template_request}, () => {{
+    api.rerender("{id}", render);
+  }});
+"#
+        ));
+      }
+      code.push_str("}\n");
+    }
+
+    code.push_str("export default script;\n");
+    code
+  }
+}
+
+fn json(value: &str) -> String {
+  serde_json::to_string(value).expect("should serialize string")
+}
+
+fn swc_options(resource_path: &Path, lang: &str) -> SwcOptions {
+  let mut options = SwcOptions::default();
+  options.config.jsc.syntax = Some(script::syntax(lang));
+  options.config.jsc.target = Some(EsVersion::EsNext);
+  options.filename = resource_path.to_string_lossy().to_string();
+  options.source_file_name = Some(options.filename.clone());
+  options
+}
+
+fn vue_error(source: &str, offset: usize, message: String) -> Error {
+  miette::Error::new(TraceableError::from_file(
+    source.to_string(),
+    offset,
+    offset,
+    "Vue Error".to_string(),
+    message,
+  ))
+}
+
+fn emit_warnings(loader_context: &mut LoaderContext<RunnerContext>, diagnostics: Vec<String>) {
+  for diagnostic in diagnostics {
+    loader_context.emit_diagnostic(
+      miette::miette! { severity = miette::Severity::Warning, "{}", diagnostic }.into(),
+    );
+  }
+}
+
+#[cacheable_dyn]
+#[async_trait::async_trait]
+impl Loader<RunnerContext> for VueLoader {
+  #[tracing::instrument("loader:builtin-vue", skip_all, fields(
+    id2 = loader_context.resource(),
+  ))]
+  async fn run(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+    self.loader_impl(loader_context)
+  }
+}
+
+impl Identifiable for VueLoader {
+  fn identifier(&self) -> Identifier {
+    self.id
+  }
+}
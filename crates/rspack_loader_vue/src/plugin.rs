@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use rspack_core::{
+  ApplyContext, BoxLoader, CompilerOptions, Context, ModuleRuleUseLoader, NormalModule,
+  NormalModuleBeforeLoaders, NormalModuleFactoryResolveLoader, Plugin, PluginContext, Resolver,
+};
+use rspack_error::{Result, SerdeResultToRspackResultExt};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_loader_runner::Identifiable;
+
+use crate::{
+  config::{Config, RawConfig},
+  descriptor::VueQuery,
+  VueLoader, VueStylePostLoader, VUE_LOADER_IDENTIFIER, VUE_STYLE_POST_LOADER_IDENTIFIER,
+};
+
+#[plugin]
+#[derive(Debug)]
+pub struct VueLoaderPlugin;
+
+impl VueLoaderPlugin {
+  pub fn new() -> Self {
+    Self::new_inner()
+  }
+}
+
+impl Default for VueLoaderPlugin {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Plugin for VueLoaderPlugin {
+  fn name(&self) -> &'static str {
+    "VueLoaderPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .resolve_loader
+      .tap(resolve_loader::new(self));
+    ctx
+      .context
+      .normal_module_hooks
+      .before_loaders
+      .tap(before_loaders::new(self));
+    Ok(())
+  }
+}
+
+#[plugin_hook(NormalModuleFactoryResolveLoader for VueLoaderPlugin)]
+pub(crate) async fn resolve_loader(
+  &self,
+  _context: &Context,
+  _resolver: &Resolver,
+  l: &ModuleRuleUseLoader,
+) -> Result<Option<BoxLoader>> {
+  let loader_request = &l.loader;
+  let options = l.options.as_deref().unwrap_or("{}");
+
+  if loader_request.starts_with(VUE_LOADER_IDENTIFIER) {
+    let config: RawConfig = serde_json::from_str(options)
+      .to_rspack_result_with_detail(options, "Could not parse builtin:vue-loader options")?;
+    return Ok(Some(Arc::new(VueLoader::new(
+      Config::from(config),
+      loader_request,
+    ))));
+  }
+
+  Ok(None)
+}
+
+/// Appends the style post loader to the scoped styles, the loaders run from
+/// the last to the first, so it runs after the loaders of the style language.
+#[plugin_hook(NormalModuleBeforeLoaders for VueLoaderPlugin)]
+pub(crate) async fn before_loaders(&self, module: &mut NormalModule) -> Result<()> {
+  let is_scoped_style = module
+    .resource_resolved_data()
+    .resource_query
+    .as_deref()
+    .and_then(VueQuery::parse)
+    .is_some_and(|query| query.get("type") == Some("style") && query.is_enabled("scoped"));
+  if !is_scoped_style {
+    return Ok(());
+  }
+  let loaders = module.loaders_mut();
+  if loaders
+    .iter()
+    .any(|loader| loader.identifier().as_str() == VUE_STYLE_POST_LOADER_IDENTIFIER)
+  {
+    return Ok(());
+  }
+  loaders.insert(0, Arc::new(VueStylePostLoader::default()));
+  Ok(())
+}
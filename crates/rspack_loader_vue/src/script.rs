@@ -0,0 +1,1060 @@
+//! Compiles `<script>` and `<script setup>` into the component options.
+//!
+//! Like `@vue/compiler-sfc`, the compiler edits the source text instead of
+//! printing an AST, and the result is transpiled by the JavaScript compiler
+//! afterwards.
+
+use std::{collections::BTreeSet, ops::Range, path::Path, sync::Arc};
+
+use rspack_error::{miette, Error, Result, TraceableError};
+use rspack_javascript_compiler::JavaScriptCompiler;
+use rustc_hash::FxHashSet;
+use swc_core::{
+  base::config::IsModule,
+  common::{FileName, SourceMap, Span, Spanned},
+  ecma::{
+    ast::{
+      ArrowExpr, AwaitExpr, CallExpr, Callee, Class, Decl, DefaultDecl, EsVersion, Expr, ForOfStmt,
+      Function, ImportSpecifier, Lit, Module, ModuleDecl, ModuleItem, ObjectPatProp, Pat, Prop,
+      PropName, PropOrSpread, Stmt, TsEntityName, TsFnOrConstructorType, TsFnParam,
+      TsKeywordTypeKind, TsLit, TsType, TsTypeElement, TsUnionOrIntersectionType, VarDeclKind,
+    },
+    parser::{EsSyntax, Syntax, TsSyntax},
+    visit::{Visit, VisitWith},
+  },
+};
+
+use crate::descriptor::{Block, Descriptor};
+
+const SETUP_MACROS: [&str; 7] = [
+  "defineProps",
+  "defineEmits",
+  "defineExpose",
+  "defineOptions",
+  "defineModel",
+  "defineSlots",
+  "withDefaults",
+];
+
+pub fn syntax(lang: &str) -> Syntax {
+  match lang {
+    "ts" | "tsx" => Syntax::Typescript(TsSyntax {
+      tsx: lang == "tsx",
+      decorators: true,
+      ..Default::default()
+    }),
+    _ => Syntax::Es(EsSyntax {
+      jsx: lang == "jsx",
+      decorators: true,
+      ..Default::default()
+    }),
+  }
+}
+
+/// A parsed block with the text its spans point to.
+struct ParsedBlock {
+  module: Module,
+  text: String,
+  cm: Arc<SourceMap>,
+}
+
+impl ParsedBlock {
+  fn parse(block: &Block, source: &str, resource_path: &Path, lang: &str) -> Result<Self> {
+    let text = block.padded_content(source);
+    let ast = JavaScriptCompiler::new()
+      .parse(
+        FileName::Real(resource_path.to_path_buf()),
+        text.as_str(),
+        EsVersion::EsNext,
+        syntax(lang),
+        IsModule::Bool(true),
+        None,
+      )
+      .map_err(|errors| {
+        errors
+          .into_inner()
+          .into_iter()
+          .next()
+          .unwrap_or_else(|| miette::miette!("Failed to parse the script"))
+      })?;
+    let cm = ast.get_context().source_map.clone();
+    let module = ast
+      .into_program()
+      .get_inner_program()
+      .as_module()
+      .cloned()
+      .ok_or_else(|| miette::miette!("Script of a single-file component should be a module"))?;
+    Ok(Self { module, text, cm })
+  }
+
+  fn range(&self, span: Span) -> Range<usize> {
+    let start = self.cm.lookup_byte_offset(span.lo).pos.0 as usize;
+    start..start + (span.hi - span.lo).0 as usize
+  }
+
+  fn slice(&self, span: Span) -> &str {
+    &self.text[self.range(span)]
+  }
+
+  fn error(&self, span: Span, message: String) -> Error {
+    let range = self.range(span);
+    miette::Error::new(TraceableError::from_file(
+      self.text.clone(),
+      range.start,
+      range.end,
+      "Vue Error".to_string(),
+      message,
+    ))
+  }
+}
+
+/// Applies the replacements, which must not overlap, to the text.
+fn apply_edits(text: &str, mut edits: Vec<(Range<usize>, String)>) -> String {
+  edits.sort_by_key(|(range, _)| range.start);
+  let mut result = String::with_capacity(text.len());
+  let mut pos = 0;
+  for (range, replacement) in edits {
+    result.push_str(&text[pos..range.start]);
+    result.push_str(&replacement);
+    pos = range.end;
+  }
+  result.push_str(&text[pos..]);
+  result
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingKind {
+  /// Bindings that are never reassigned, which are returned as they are.
+  Const,
+  /// Bindings that can be reassigned, which are returned with accessors.
+  Let,
+}
+
+/// The bindings of `<script setup>` returned to the template.
+#[derive(Debug, Default)]
+pub struct SetupBindings {
+  pub bindings: Vec<(String, BindingKind)>,
+}
+
+impl SetupBindings {
+  pub fn names(&self) -> FxHashSet<String> {
+    self.bindings.iter().map(|(name, _)| name.clone()).collect()
+  }
+}
+
+/// Collects the bindings of `<script setup>` that are used by the template.
+pub fn setup_bindings(
+  descriptor: &Descriptor,
+  source: &str,
+  resource_path: &Path,
+) -> Result<SetupBindings> {
+  let Some(script_setup) = &descriptor.script_setup else {
+    return Ok(SetupBindings::default());
+  };
+  let parsed = ParsedBlock::parse(
+    script_setup,
+    source,
+    resource_path,
+    descriptor.script_lang(),
+  )?;
+  Ok(collect_bindings(
+    &parsed.module,
+    &template_identifiers(descriptor),
+  ))
+}
+
+fn collect_bindings(module: &Module, template_identifiers: &FxHashSet<String>) -> SetupBindings {
+  let mut bindings = SetupBindings::default();
+  for item in &module.body {
+    match item {
+      ModuleItem::ModuleDecl(ModuleDecl::Import(import)) if !import.type_only => {
+        for specifier in &import.specifiers {
+          let local = match specifier {
+            ImportSpecifier::Named(named) if named.is_type_only => continue,
+            ImportSpecifier::Named(named) => &named.local,
+            ImportSpecifier::Default(default) => &default.local,
+            ImportSpecifier::Namespace(namespace) => &namespace.local,
+          };
+          // Imports are only returned when the template uses them, so that
+          // the imports of types aren't kept as values.
+          if template_identifiers.contains(local.sym.as_str()) {
+            bindings
+              .bindings
+              .push((local.sym.to_string(), BindingKind::Const));
+          }
+        }
+      }
+      ModuleItem::Stmt(Stmt::Decl(decl)) => match decl {
+        Decl::Var(var) if !var.declare => {
+          let kind = if var.kind == VarDeclKind::Const {
+            BindingKind::Const
+          } else {
+            BindingKind::Let
+          };
+          let mut names = vec![];
+          for declarator in &var.decls {
+            pat_names(&declarator.name, &mut names);
+          }
+          bindings
+            .bindings
+            .extend(names.into_iter().map(|name| (name, kind)));
+        }
+        Decl::Fn(function) if !function.declare => bindings
+          .bindings
+          .push((function.ident.sym.to_string(), BindingKind::Const)),
+        Decl::Class(class) if !class.declare => bindings
+          .bindings
+          .push((class.ident.sym.to_string(), BindingKind::Const)),
+        Decl::TsEnum(ts_enum) if !ts_enum.declare => bindings
+          .bindings
+          .push((ts_enum.id.sym.to_string(), BindingKind::Const)),
+        _ => {}
+      },
+      _ => {}
+    }
+  }
+  bindings
+}
+
+fn pat_names(pat: &Pat, names: &mut Vec<String>) {
+  match pat {
+    Pat::Ident(ident) => names.push(ident.id.sym.to_string()),
+    Pat::Array(array) => {
+      for elem in array.elems.iter().flatten() {
+        pat_names(elem, names);
+      }
+    }
+    Pat::Object(object) => {
+      for prop in &object.props {
+        match prop {
+          ObjectPatProp::KeyValue(key_value) => pat_names(&key_value.value, names),
+          ObjectPatProp::Assign(assign) => names.push(assign.key.id.sym.to_string()),
+          ObjectPatProp::Rest(rest) => pat_names(&rest.arg, names),
+        }
+      }
+    }
+    Pat::Rest(rest) => pat_names(&rest.arg, names),
+    Pat::Assign(assign) => pat_names(&assign.left, names),
+    Pat::Invalid(_) | Pat::Expr(_) => {}
+  }
+}
+
+/// Identifiers that may be referenced by the template, including the
+/// camelized and capitalized names of the kebab-cased tags.
+fn template_identifiers(descriptor: &Descriptor) -> FxHashSet<String> {
+  let mut identifiers = FxHashSet::default();
+  let Some(template) = &descriptor.template else {
+    return identifiers;
+  };
+  let is_identifier_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$';
+  for word in template
+    .content
+    .split(|c: char| !is_identifier_char(c) && c != '-')
+    .filter(|word| !word.is_empty())
+  {
+    for part in word.split('-').filter(|part| !part.is_empty()) {
+      identifiers.insert(part.to_string());
+    }
+    if word.contains('-') {
+      let camelized = crate::template::camelize(word);
+      identifiers.insert(crate::template::capitalize(&camelized));
+      identifiers.insert(camelized);
+    }
+  }
+  identifiers
+}
+
+/// Compiles the scripts into a module, which exports the component options
+/// as default.
+pub fn compile(
+  descriptor: &Descriptor,
+  source: &str,
+  resource_path: &Path,
+  component_name: &str,
+) -> Result<String> {
+  let lang = descriptor.script_lang();
+  let mut code = String::new();
+
+  let has_default_export = match &descriptor.script {
+    Some(script) => {
+      let parsed = ParsedBlock::parse(script, source, resource_path, lang)?;
+      let (script_code, has_default_export) = rewrite_default_export(&parsed);
+      code.push_str(&script_code);
+      code.push('\n');
+      has_default_export
+    }
+    None => false,
+  };
+
+  let Some(script_setup) = &descriptor.script_setup else {
+    if has_default_export {
+      code.push_str("export default __default__;\n");
+    } else {
+      code.push_str("export default {};\n");
+    }
+    return Ok(code);
+  };
+
+  let parsed = ParsedBlock::parse(script_setup, source, resource_path, lang)?;
+  let bindings = collect_bindings(&parsed.module, &template_identifiers(descriptor));
+  let mut setup = SetupScript::analyze(&parsed)?;
+
+  let mut options = vec![];
+  if has_default_export {
+    options.push("...__default__".to_string());
+  }
+  if let Some(define_options) = &setup.options {
+    options.push(format!("...({define_options})"));
+  }
+  options.push(format!(
+    "__name: {}",
+    serde_json::to_string(component_name).expect("should serialize component name")
+  ));
+  let (props, emits) = setup.props_and_emits();
+  if let Some(props) = props {
+    options.push(format!("props: {props}"));
+  }
+  if let Some(emits) = emits {
+    options.push(format!("emits: {emits}"));
+  }
+
+  let returned = bindings
+    .bindings
+    .iter()
+    .map(|(name, kind)| match kind {
+      BindingKind::Const => name.clone(),
+      BindingKind::Let => {
+        format!("get {name}() {{ return {name}; }}, set {name}(v) {{ {name} = v; }}")
+      }
+    })
+    .collect::<Vec<_>>()
+    .join(", ");
+
+  let mut hoisted = std::mem::take(&mut setup.hoisted);
+  if !setup.helpers.is_empty() {
+    let imports = setup
+      .helpers
+      .iter()
+      .map(|helper| format!("{helper} as _{helper}"))
+      .collect::<Vec<_>>();
+    hoisted.push(format!("import {{ {} }} from \"vue\";", imports.join(", ")));
+  }
+  let mut result = hoisted.join("\n");
+  result.push('\n');
+  result.push_str(&code);
+  result.push_str(&format!(
+    "const __sfc__ = {{\n  {},\n  {}setup(__props, {{ expose: __expose, emit: __emit }}) {{\n",
+    options.join(",\n  "),
+    if setup.has_await { "async " } else { "" },
+  ));
+  if !setup.has_expose {
+    result.push_str("__expose();\n");
+  }
+  result.push_str(setup.body.trim_start_matches('\n'));
+  result.push_str(&format!(
+    "\nconst __returned__ = {{ {returned} }};\nObject.defineProperty(__returned__, \"__isScriptSetup\", {{ enumerable: false, value: true }});\nreturn __returned__;\n}}\n}};\nexport default __sfc__;\n"
+  ));
+  Ok(result)
+}
+
+/// Replaces `export default` of `<script>` with `const __default__ =`.
+fn rewrite_default_export(parsed: &ParsedBlock) -> (String, bool) {
+  let mut edits = vec![];
+  for item in &parsed.module.body {
+    let ModuleItem::ModuleDecl(decl) = item else {
+      continue;
+    };
+    let value_start = match decl {
+      ModuleDecl::ExportDefaultExpr(export) => export.expr.span().lo,
+      ModuleDecl::ExportDefaultDecl(export) => match &export.decl {
+        DefaultDecl::Class(class) => class.span().lo,
+        DefaultDecl::Fn(function) => function.span().lo,
+        DefaultDecl::TsInterfaceDecl(_) => continue,
+      },
+      _ => continue,
+    };
+    let item_range = parsed.range(decl.span());
+    let value_range = parsed.range(Span::new(value_start, value_start));
+    edits.push((
+      item_range.start..value_range.start,
+      "const __default__ = ".to_string(),
+    ));
+    if !parsed.slice(decl.span()).ends_with(';') {
+      edits.push((item_range.end..item_range.end, ";".to_string()));
+    }
+  }
+  let has_default_export = !edits.is_empty();
+  (apply_edits(&parsed.text, edits), has_default_export)
+}
+
+/// What `<script setup>` declares with the compiler macros.
+#[derive(Default)]
+struct SetupScript {
+  /// Imports and type declarations moved to the module scope.
+  hoisted: Vec<String>,
+  /// The code of the `setup` function.
+  body: String,
+  props: Option<String>,
+  emits: Option<String>,
+  /// The names and the prop options of `defineModel()`.
+  models: Vec<(String, String)>,
+  options: Option<String>,
+  has_expose: bool,
+  has_await: bool,
+  /// Helpers imported from `vue`.
+  helpers: BTreeSet<&'static str>,
+}
+
+impl SetupScript {
+  fn analyze(parsed: &ParsedBlock) -> Result<Self> {
+    let mut setup = Self::default();
+    let mut edits = vec![];
+
+    for item in &parsed.module.body {
+      match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+          setup.hoisted.push(parsed.slice(import.span).to_string());
+          edits.push((parsed.range(import.span), String::new()));
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export))
+          if matches!(export.decl, Decl::TsInterface(_) | Decl::TsTypeAlias(_)) =>
+        {
+          setup.hoisted.push(parsed.slice(export.span).to_string());
+          edits.push((parsed.range(export.span), String::new()));
+        }
+        ModuleItem::ModuleDecl(decl) => {
+          return Err(
+            parsed.error(
+              decl.span(),
+              "<script setup> cannot contain ES module exports, use a separate <script> to export"
+                .to_string(),
+            ),
+          );
+        }
+        ModuleItem::Stmt(Stmt::Expr(expr_stmt)) => {
+          if let Some((name, call)) = macro_call(&expr_stmt.expr) {
+            match setup.process_macro(parsed, name, call, None)? {
+              Some(replacement) => {
+                edits.push((parsed.range(call.span), replacement));
+              }
+              None => edits.push((parsed.range(expr_stmt.span), String::new())),
+            }
+          }
+        }
+        ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => {
+          for declarator in &var.decls {
+            if let Some(init) = &declarator.init
+              && let Some((name, call)) = macro_call(init)
+            {
+              let replacement = setup
+                .process_macro(parsed, name, call, Some(&declarator.name))?
+                .unwrap_or_else(|| "undefined".to_string());
+              edits.push((parsed.range(init.span()), replacement));
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+
+    let mut await_finder = TopLevelAwaitFinder::default();
+    parsed.module.visit_with(&mut await_finder);
+    setup.has_await = await_finder.found;
+    setup.body = apply_edits(&parsed.text, edits);
+    Ok(setup)
+  }
+
+  /// Records what the macro declares, returns the expression that replaces
+  /// the call, or `None` if the call is removed.
+  fn process_macro(
+    &mut self,
+    parsed: &ParsedBlock,
+    name: &str,
+    call: &CallExpr,
+    binding: Option<&Pat>,
+  ) -> Result<Option<String>> {
+    let first_arg = call.args.first().map(|arg| parsed.slice(arg.expr.span()));
+    match name {
+      "defineProps" | "withDefaults" => {
+        if self.props.is_some() {
+          return Err(parsed.error(call.span, "Duplicated defineProps() call".to_string()));
+        }
+        if let Some(pat) = binding
+          && !matches!(pat, Pat::Ident(_))
+        {
+          return Err(parsed.error(
+            pat.span(),
+            "Destructuring the result of defineProps() isn't supported, assign it to a variable and access the props from it".to_string(),
+          ));
+        }
+        let (define_props, defaults) = if name == "withDefaults" {
+          match call.args.first().and_then(|arg| macro_call(&arg.expr)) {
+            Some(("defineProps", define_props)) => (define_props, call.args.get(1)),
+            _ => {
+              return Err(parsed.error(
+                call.span,
+                "The first argument of withDefaults() should be a defineProps() call".to_string(),
+              ))
+            }
+          }
+        } else {
+          (call, None)
+        };
+        self.props = Some(self.props_options(parsed, define_props, defaults.map(|d| &*d.expr))?);
+        Ok(binding.map(|_| "__props".to_string()))
+      }
+      "defineEmits" => {
+        if self.emits.is_some() {
+          return Err(parsed.error(call.span, "Duplicated defineEmits() call".to_string()));
+        }
+        self.emits = match (first_arg, &call.type_args) {
+          (Some(emits), _) => Some(emits.to_string()),
+          (None, Some(type_args)) => match type_args.params.first() {
+            Some(ty) => Some(emit_names(parsed, ty)?),
+            None => None,
+          },
+          (None, None) => None,
+        };
+        Ok(binding.map(|_| "__emit".to_string()))
+      }
+      "defineModel" => {
+        // `defineModel(name?, options?)`
+        let (name, options) = match call.args.first().map(|arg| &*arg.expr) {
+          Some(Expr::Lit(Lit::Str(name))) => (name.value.to_string(), call.args.get(1)),
+          Some(_) => ("modelValue".to_string(), call.args.first()),
+          None => ("modelValue".to_string(), None),
+        };
+        if self.models.iter().any(|(model, _)| *model == name) {
+          return Err(parsed.error(call.span, format!("Duplicated defineModel() of \"{name}\"")));
+        }
+        let options = options.map(|options| parsed.slice(options.expr.span()));
+        let ty = call
+          .type_args
+          .as_ref()
+          .and_then(|type_args| type_args.params.first())
+          .map(|ty| match runtime_types(parsed, ty).as_slice() {
+            [] => "null".to_string(),
+            [ty] => ty.to_string(),
+            types => format!("[{}]", types.join(", ")),
+          });
+        let prop = match (ty, options) {
+          (Some(ty), Some(options)) => format!("{{ type: {ty}, ...{options} }}"),
+          (Some(ty), None) => format!("{{ type: {ty} }}"),
+          (None, Some(options)) => options.to_string(),
+          (None, None) => "{}".to_string(),
+        };
+        self.models.push((name.clone(), prop));
+        self.helpers.insert("useModel");
+        let name = serde_json::to_string(&name).expect("should serialize model name");
+        // The `get` and `set` of the options are used by `useModel()`.
+        Ok(Some(match options {
+          Some(options) => format!("_useModel(__props, {name}, {options})"),
+          None => format!("_useModel(__props, {name})"),
+        }))
+      }
+      "defineSlots" => {
+        self.helpers.insert("useSlots");
+        Ok(binding.map(|_| "_useSlots()".to_string()))
+      }
+      "defineExpose" => {
+        self.has_expose = true;
+        Ok(Some(format!("__expose({})", first_arg.unwrap_or_default())))
+      }
+      "defineOptions" => {
+        self.options = first_arg.map(ToString::to_string);
+        Ok(None)
+      }
+      _ => Ok(None),
+    }
+  }
+
+  /// The `props` and `emits` options, including the ones of the models.
+  fn props_and_emits(&mut self) -> (Option<String>, Option<String>) {
+    if self.models.is_empty() {
+      return (self.props.clone(), self.emits.clone());
+    }
+    let model_props = self
+      .models
+      .iter()
+      .flat_map(|(name, prop)| {
+        let modifiers = if name == "modelValue" {
+          "modelModifiers".to_string()
+        } else {
+          format!("{name}Modifiers")
+        };
+        [
+          format!(
+            "{}: {prop}",
+            serde_json::to_string(name).expect("should serialize model name")
+          ),
+          format!(
+            "{}: {{}}",
+            serde_json::to_string(&modifiers).expect("should serialize model name")
+          ),
+        ]
+      })
+      .collect::<Vec<_>>();
+    let model_props = format!("{{ {} }}", model_props.join(", "));
+    let model_emits = serde_json::to_string(
+      &self
+        .models
+        .iter()
+        .map(|(name, _)| format!("update:{name}"))
+        .collect::<Vec<_>>(),
+    )
+    .expect("should serialize emits");
+    if self.props.is_some() || self.emits.is_some() {
+      self.helpers.insert("mergeModels");
+    }
+    let merge = |options: &Option<String>, models: String| match options {
+      Some(options) => format!("_mergeModels({options}, {models})"),
+      None => models,
+    };
+    (
+      Some(merge(&self.props, model_props)),
+      Some(merge(&self.emits, model_emits)),
+    )
+  }
+
+  fn props_options(
+    &mut self,
+    parsed: &ParsedBlock,
+    define_props: &CallExpr,
+    defaults: Option<&Expr>,
+  ) -> Result<String> {
+    if let Some(arg) = define_props.args.first() {
+      let runtime = parsed.slice(arg.expr.span()).to_string();
+      return Ok(match defaults {
+        Some(defaults) => {
+          self.helpers.insert("mergeDefaults");
+          format!(
+            "_mergeDefaults({runtime}, {})",
+            parsed.slice(defaults.span())
+          )
+        }
+        None => runtime,
+      });
+    }
+    let Some(ty) = define_props
+      .type_args
+      .as_ref()
+      .and_then(|type_args| type_args.params.first())
+    else {
+      return Ok("{}".to_string());
+    };
+
+    // Defaults declared with an object literal are inlined into the props,
+    // otherwise they are merged at runtime.
+    let static_defaults = match defaults {
+      Some(Expr::Object(object)) => {
+        let mut static_defaults = Some(vec![]);
+        for prop in &object.props {
+          let default = match prop {
+            PropOrSpread::Prop(prop) => match &**prop {
+              Prop::KeyValue(key_value) => prop_name(&key_value.key)
+                .map(|key| (key, parsed.slice(key_value.value.span()).to_string())),
+              Prop::Shorthand(ident) => Some((ident.sym.to_string(), ident.sym.to_string())),
+              _ => None,
+            },
+            PropOrSpread::Spread(_) => None,
+          };
+          match (default, &mut static_defaults) {
+            (Some(default), Some(static_defaults)) => static_defaults.push(default),
+            _ => {
+              static_defaults = None;
+              break;
+            }
+          }
+        }
+        static_defaults
+      }
+      _ => None,
+    };
+
+    let members = type_members(parsed, ty)?;
+    let mut props = vec![];
+    for member in members {
+      let TsTypeElement::TsPropertySignature(signature) = member else {
+        if let TsTypeElement::TsMethodSignature(method) = member
+          && let Some(key) = expr_key(&method.key)
+        {
+          props.push(format!(
+            "{}: {{ type: Function, required: {} }}",
+            serde_json::to_string(&key).expect("should serialize prop name"),
+            !method.optional
+          ));
+        }
+        continue;
+      };
+      let Some(key) = expr_key(&signature.key) else {
+        continue;
+      };
+      let types = signature
+        .type_ann
+        .as_ref()
+        .map(|type_ann| runtime_types(parsed, &type_ann.type_ann))
+        .unwrap_or_default();
+      let ty = match types.as_slice() {
+        [] => "null".to_string(),
+        [ty] => ty.to_string(),
+        types => format!("[{}]", types.join(", ")),
+      };
+      let mut prop = format!(
+        "{}: {{ type: {ty}, required: {}",
+        serde_json::to_string(&key).expect("should serialize prop name"),
+        !signature.optional
+      );
+      if let Some(default) = static_defaults
+        .as_ref()
+        .and_then(|defaults| defaults.iter().find(|(name, _)| *name == key))
+      {
+        prop.push_str(&format!(", default: {}", default.1));
+      }
+      prop.push_str(" }");
+      props.push(prop);
+    }
+    let props = format!("{{ {} }}", props.join(", "));
+
+    Ok(match (defaults, static_defaults) {
+      (Some(defaults), None) => {
+        self.helpers.insert("mergeDefaults");
+        format!("_mergeDefaults({props}, {})", parsed.slice(defaults.span()))
+      }
+      _ => props,
+    })
+  }
+}
+
+fn macro_call(expr: &Expr) -> Option<(&str, &CallExpr)> {
+  let Expr::Call(call) = expr else {
+    return None;
+  };
+  let Callee::Expr(callee) = &call.callee else {
+    return None;
+  };
+  let Expr::Ident(ident) = &**callee else {
+    return None;
+  };
+  SETUP_MACROS
+    .into_iter()
+    .find(|name| ident.sym.as_str() == *name)
+    .map(|name| (name, call))
+}
+
+fn prop_name(name: &PropName) -> Option<String> {
+  match name {
+    PropName::Ident(ident) => Some(ident.sym.to_string()),
+    PropName::Str(str) => Some(str.value.to_string()),
+    PropName::Num(num) => Some(num.value.to_string()),
+    PropName::Computed(_) | PropName::BigInt(_) => None,
+  }
+}
+
+fn expr_key(key: &Expr) -> Option<String> {
+  match key {
+    Expr::Ident(ident) => Some(ident.sym.to_string()),
+    Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+    _ => None,
+  }
+}
+
+/// Finds the interface or the type alias declared in the script.
+fn find_local_type<'a>(parsed: &'a ParsedBlock, name: &str) -> Option<LocalType<'a>> {
+  parsed.module.body.iter().find_map(|item| {
+    let decl = match item {
+      ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => &export.decl,
+      _ => return None,
+    };
+    match decl {
+      Decl::TsInterface(interface) if interface.id.sym.as_str() == name => {
+        Some(LocalType::Interface(&interface.body.body))
+      }
+      Decl::TsTypeAlias(alias) if alias.id.sym.as_str() == name => {
+        Some(LocalType::Alias(&alias.type_ann))
+      }
+      _ => None,
+    }
+  })
+}
+
+enum LocalType<'a> {
+  Interface(&'a [TsTypeElement]),
+  Alias(&'a TsType),
+}
+
+/// The members of the type argument of `defineProps()`, which should be a
+/// type literal, or an interface or a type alias declared in the script.
+fn type_members<'a>(parsed: &'a ParsedBlock, ty: &'a TsType) -> Result<&'a [TsTypeElement]> {
+  match ty {
+    TsType::TsTypeLit(lit) => Ok(&lit.members),
+    TsType::TsParenthesizedType(paren) => type_members(parsed, &paren.type_ann),
+    TsType::TsTypeRef(type_ref) if type_ref.type_params.is_none() => {
+      let TsEntityName::Ident(ident) = &type_ref.type_name else {
+        return Err(unresolvable_type(parsed, ty));
+      };
+      match find_local_type(parsed, &ident.sym) {
+        Some(LocalType::Interface(members)) => Ok(members),
+        Some(LocalType::Alias(alias)) => type_members(parsed, alias),
+        None => Err(unresolvable_type(parsed, ty)),
+      }
+    }
+    _ => Err(unresolvable_type(parsed, ty)),
+  }
+}
+
+fn unresolvable_type(parsed: &ParsedBlock, ty: &TsType) -> Error {
+  parsed.error(
+    ty.span(),
+    "Unresolvable type of props, only type literals, and interfaces and type aliases declared in <script setup> are supported".to_string(),
+  )
+}
+
+/// The constructors that check the type of a prop at runtime.
+fn runtime_types(parsed: &ParsedBlock, ty: &TsType) -> Vec<&'static str> {
+  let mut types = vec![];
+  collect_runtime_types(parsed, ty, &mut types, 0);
+  types.dedup();
+  if types.contains(&"null") {
+    // Any type is accepted.
+    return vec![];
+  }
+  types
+}
+
+fn collect_runtime_types(
+  parsed: &ParsedBlock,
+  ty: &TsType,
+  types: &mut Vec<&'static str>,
+  depth: usize,
+) {
+  let mut push = |ty: &'static str| {
+    if !types.contains(&ty) {
+      types.push(ty);
+    }
+  };
+  match ty {
+    TsType::TsKeywordType(keyword) => match keyword.kind {
+      TsKeywordTypeKind::TsStringKeyword => push("String"),
+      TsKeywordTypeKind::TsNumberKeyword => push("Number"),
+      TsKeywordTypeKind::TsBooleanKeyword => push("Boolean"),
+      TsKeywordTypeKind::TsBigIntKeyword => push("BigInt"),
+      TsKeywordTypeKind::TsSymbolKeyword => push("Symbol"),
+      TsKeywordTypeKind::TsObjectKeyword => push("Object"),
+      TsKeywordTypeKind::TsNullKeyword
+      | TsKeywordTypeKind::TsUndefinedKeyword
+      | TsKeywordTypeKind::TsVoidKeyword
+      | TsKeywordTypeKind::TsNeverKeyword => {}
+      _ => push("null"),
+    },
+    TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(_)) => push("Function"),
+    TsType::TsArrayType(_) | TsType::TsTupleType(_) => push("Array"),
+    TsType::TsTypeLit(lit) => {
+      if lit
+        .members
+        .iter()
+        .all(|member| matches!(member, TsTypeElement::TsCallSignatureDecl(_)))
+        && !lit.members.is_empty()
+      {
+        push("Function")
+      } else {
+        push("Object")
+      }
+    }
+    TsType::TsLitType(lit) => match &lit.lit {
+      TsLit::Str(_) | TsLit::Tpl(_) => push("String"),
+      TsLit::Number(_) => push("Number"),
+      TsLit::Bool(_) => push("Boolean"),
+      TsLit::BigInt(_) => push("BigInt"),
+    },
+    TsType::TsParenthesizedType(paren) => {
+      collect_runtime_types(parsed, &paren.type_ann, types, depth)
+    }
+    TsType::TsOptionalType(optional) => {
+      collect_runtime_types(parsed, &optional.type_ann, types, depth)
+    }
+    TsType::TsTypeOperator(operator) => {
+      collect_runtime_types(parsed, &operator.type_ann, types, depth)
+    }
+    TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(union)) => {
+      for ty in &union.types {
+        collect_runtime_types(parsed, ty, types, depth);
+      }
+    }
+    TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(_))
+    | TsType::TsMappedType(_) => push("Object"),
+    TsType::TsTypeRef(type_ref) => {
+      let TsEntityName::Ident(ident) = &type_ref.type_name else {
+        push("null");
+        return;
+      };
+      match ident.sym.as_str() {
+        "String" => push("String"),
+        "Number" => push("Number"),
+        "Boolean" => push("Boolean"),
+        "Function" => push("Function"),
+        "Array" | "ReadonlyArray" => push("Array"),
+        "Object" | "Record" | "Partial" | "Required" | "Readonly" | "Pick" | "Omit" => {
+          push("Object")
+        }
+        "Date" => push("Date"),
+        "Promise" => push("Promise"),
+        "Map" => push("Map"),
+        "Set" => push("Set"),
+        "WeakMap" => push("WeakMap"),
+        "WeakSet" => push("WeakSet"),
+        "RegExp" => push("RegExp"),
+        "Error" => push("Error"),
+        name => match find_local_type(parsed, name) {
+          Some(LocalType::Interface(_)) => push("Object"),
+          // Guards against the aliases that refer to themselves.
+          Some(LocalType::Alias(alias)) if depth < 8 => {
+            collect_runtime_types(parsed, alias, types, depth + 1)
+          }
+          _ => push("null"),
+        },
+      }
+    }
+    _ => push("null"),
+  }
+}
+
+/// The names of the events declared by the type argument of `defineEmits()`.
+fn emit_names(parsed: &ParsedBlock, ty: &TsType) -> Result<String> {
+  fn push_literals(ty: &TsType, names: &mut Vec<String>) {
+    let types = match ty {
+      TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(union)) => {
+        union.types.iter().map(|ty| &**ty).collect::<Vec<_>>()
+      }
+      ty => vec![ty],
+    };
+    for ty in types {
+      if let TsType::TsLitType(lit) = ty
+        && let TsLit::Str(str) = &lit.lit
+      {
+        names.push(str.value.to_string());
+      }
+    }
+  }
+
+  fn first_param_type(params: &[TsFnParam]) -> Option<&TsType> {
+    params.first().and_then(|param| match param {
+      TsFnParam::Ident(ident) => ident.type_ann.as_ref().map(|type_ann| &*type_ann.type_ann),
+      _ => None,
+    })
+  }
+
+  let mut names: Vec<String> = vec![];
+  match ty {
+    TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(function)) => {
+      if let Some(ty) = first_param_type(&function.params) {
+        push_literals(ty, &mut names);
+      }
+    }
+    ty => {
+      for member in type_members(parsed, ty)? {
+        match member {
+          TsTypeElement::TsCallSignatureDecl(signature) => {
+            if let Some(ty) = first_param_type(&signature.params) {
+              push_literals(ty, &mut names);
+            }
+          }
+          TsTypeElement::TsPropertySignature(signature) => {
+            if let Some(key) = expr_key(&signature.key) {
+              names.push(key);
+            }
+          }
+          _ => {}
+        }
+      }
+    }
+  }
+  names.dedup();
+  Ok(serde_json::to_string(&names).expect("should serialize emits"))
+}
+
+/// Finds `await` at the top level of `<script setup>`, which makes `setup`
+/// async.
+#[derive(Default)]
+struct TopLevelAwaitFinder {
+  found: bool,
+}
+
+impl Visit for TopLevelAwaitFinder {
+  fn visit_await_expr(&mut self, _: &AwaitExpr) {
+    self.found = true;
+  }
+
+  fn visit_for_of_stmt(&mut self, stmt: &ForOfStmt) {
+    self.found |= stmt.is_await;
+    stmt.visit_children_with(self);
+  }
+
+  fn visit_function(&mut self, _: &Function) {}
+
+  fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+  fn visit_class(&mut self, _: &Class) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::Path;
+
+  use super::compile;
+  use crate::descriptor::Descriptor;
+
+  fn compile_sfc(source: &str) -> String {
+    let descriptor = Descriptor::parse(source).expect("should parse the component");
+    compile(&descriptor, source, Path::new("/src/App.vue"), "App")
+      .unwrap_or_else(|error| panic!("failed to compile the script: {error:?}"))
+  }
+
+  #[test]
+  fn script_setup() {
+    insta::assert_snapshot!(compile_sfc(
+      r#"<script setup>
+import { ref } from "vue";
+import Child from "./Child.vue";
+
+const props = defineProps({ title: String });
+const emit = defineEmits(["change"]);
+const count = ref(0);
+defineExpose({ count });
+</script>
+<template><Child :title="props.title" @click="emit('change', count)" /></template>"#
+    ));
+  }
+
+  #[test]
+  fn script_setup_with_types() {
+    insta::assert_snapshot!(compile_sfc(
+      r#"<script lang="ts">
+export const shared = 1;
+export default { inheritAttrs: false };
+</script>
+<script setup lang="ts">
+interface Props {
+  title: string;
+  size?: number | string;
+}
+const props = withDefaults(defineProps<Props>(), { size: 1 });
+const emit = defineEmits<{ (e: "select", id: number): void; (e: "close"): void }>();
+const slots = defineSlots<{ default(): any }>();
+const data = await fetch(props.title);
+</script>"#
+    ));
+  }
+
+  #[test]
+  fn define_model() {
+    insta::assert_snapshot!(compile_sfc(
+      r#"<script setup lang="ts">
+const props = defineProps<{ disabled: boolean }>();
+const modelValue = defineModel<string>();
+const count = defineModel<number>("count", { default: 0 });
+const checked = defineModel("checked", { required: true });
+</script>"#
+    ));
+  }
+}
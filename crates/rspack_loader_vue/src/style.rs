@@ -0,0 +1,316 @@
+//! Scopes the styles of `<style scoped>` to the elements of the component.
+
+use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_core::{Loader, LoaderContext, RunnerContext};
+use rspack_error::Result;
+use rspack_loader_runner::{Identifiable, Identifier};
+
+use crate::descriptor::VueQuery;
+
+pub const VUE_STYLE_POST_LOADER_IDENTIFIER: &str = "builtin:vue-loader/style-post-loader";
+
+/// Runs after the loaders of the style language, so that the selectors
+/// written in preprocessors are scoped as well.
+#[cacheable]
+#[derive(Debug)]
+pub struct VueStylePostLoader {
+  id: Identifier,
+}
+
+impl Default for VueStylePostLoader {
+  fn default() -> Self {
+    Self {
+      id: VUE_STYLE_POST_LOADER_IDENTIFIER.into(),
+    }
+  }
+}
+
+#[cacheable_dyn]
+#[async_trait::async_trait]
+impl Loader<RunnerContext> for VueStylePostLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+    let Some(id) = loader_context
+      .resource_query()
+      .and_then(VueQuery::parse)
+      .filter(|query| query.is_enabled("scoped"))
+      .and_then(|query| query.get("id").map(ToString::to_string))
+    else {
+      return Ok(());
+    };
+    let Some(content) = loader_context.take_content() else {
+      return Ok(());
+    };
+    let css = scope_css(&content.into_string_lossy(), &format!("data-v-{id}"));
+    loader_context.finish_with(css);
+    Ok(())
+  }
+}
+
+impl Identifiable for VueStylePostLoader {
+  fn identifier(&self) -> Identifier {
+    self.id
+  }
+}
+
+/// Adds the attribute selector of the scope to the selectors of the rules.
+pub fn scope_css(css: &str, scope: &str) -> String {
+  let mut result = String::with_capacity(css.len());
+  // Whether the selectors in each block are scoped, e.g. the rules in
+  // `@media` are, but the keyframes in `@keyframes` aren't.
+  let mut blocks = vec![true];
+  let mut pos = 0;
+
+  while let Some((index, token)) = next_token(css, pos) {
+    match token {
+      b'{' => {
+        let prelude = &css[pos..index];
+        let scopes_rules = blocks.last().copied().unwrap_or(false);
+        let trimmed = prelude.trim_start();
+        if !scopes_rules {
+          result.push_str(prelude);
+          blocks.push(false);
+        } else if let Some(at_rule) = trimmed.strip_prefix('@') {
+          result.push_str(prelude);
+          let name = at_rule
+            .split(|c: char| c.is_ascii_whitespace() || c == '(')
+            .next()
+            .unwrap_or_default();
+          blocks.push(matches!(
+            name,
+            "media" | "supports" | "layer" | "container" | "document" | "scope"
+          ));
+        } else {
+          let leading = &prelude[..prelude.len() - trimmed.len()];
+          result.push_str(leading);
+          result.push_str(&scope_selectors(trimmed.trim_end(), scope));
+          result.push(' ');
+          blocks.push(false);
+        }
+        result.push('{');
+      }
+      b'}' => {
+        result.push_str(&css[pos..=index]);
+        blocks.pop();
+      }
+      _ => result.push_str(&css[pos..=index]),
+    }
+    pos = index + 1;
+  }
+  result.push_str(&css[pos..]);
+  result
+}
+
+/// Finds the next `{`, `}` or `;`, skipping the comments and the strings.
+fn next_token(css: &str, mut pos: usize) -> Option<(usize, u8)> {
+  let bytes = css.as_bytes();
+  while pos < bytes.len() {
+    match bytes[pos] {
+      b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+        pos = css[pos + 2..]
+          .find("*/")
+          .map_or(bytes.len(), |end| pos + 2 + end + 2);
+      }
+      quote @ (b'"' | b'\'') => {
+        pos += 1;
+        while pos < bytes.len() && bytes[pos] != quote {
+          if bytes[pos] == b'\\' {
+            pos += 1;
+          }
+          pos += 1;
+        }
+        pos += 1;
+      }
+      token @ (b'{' | b'}' | b';') => return Some((pos, token)),
+      _ => pos += 1,
+    }
+  }
+  None
+}
+
+/// Finds `v-bind()` outside of the comments and the strings, which binds the
+/// state of the component to CSS variables at runtime.
+pub fn find_v_bind(css: &str) -> Option<usize> {
+  let bytes = css.as_bytes();
+  let mut pos = 0;
+  while pos < bytes.len() {
+    match bytes[pos] {
+      b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+        pos = css[pos + 2..]
+          .find("*/")
+          .map_or(bytes.len(), |end| pos + 2 + end + 2);
+      }
+      quote @ (b'"' | b'\'') => {
+        pos += 1;
+        while pos < bytes.len() && bytes[pos] != quote {
+          if bytes[pos] == b'\\' {
+            pos += 1;
+          }
+          pos += 1;
+        }
+        pos += 1;
+      }
+      b'v' if css[pos..].starts_with("v-bind(") => return Some(pos),
+      _ => pos += 1,
+    }
+  }
+  None
+}
+
+/// Splits at the top-level commas, the commas in `:is(a, b)` are kept.
+fn split_selectors(selectors: &str) -> Vec<&str> {
+  let mut parts = vec![];
+  let mut depth = 0usize;
+  let mut start = 0;
+  for (index, c) in selectors.char_indices() {
+    match c {
+      '(' | '[' => depth += 1,
+      ')' | ']' => depth = depth.saturating_sub(1),
+      ',' if depth == 0 => {
+        parts.push(&selectors[start..index]);
+        start = index + 1;
+      }
+      _ => {}
+    }
+  }
+  parts.push(&selectors[start..]);
+  parts
+}
+
+fn scope_selectors(selectors: &str, scope: &str) -> String {
+  split_selectors(selectors)
+    .into_iter()
+    .map(|selector| scope_selector(selector.trim(), scope))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+/// Finds a pseudo-class with an argument, like `:deep(`, returns the
+/// selector before it, the argument, and the selector after it.
+fn find_pseudo<'a>(selector: &'a str, names: &[&str]) -> Option<(&'a str, &'a str, &'a str)> {
+  names.iter().find_map(|name| {
+    let start = selector.find(name)?;
+    let arg_start = start + name.len();
+    let mut depth = 1usize;
+    let arg_end = selector[arg_start..]
+      .char_indices()
+      .find_map(|(index, c)| {
+        match c {
+          '(' => depth += 1,
+          ')' => {
+            depth -= 1;
+            if depth == 0 {
+              return Some(arg_start + index);
+            }
+          }
+          _ => {}
+        }
+        None
+      })?;
+    Some((
+      &selector[..start],
+      &selector[arg_start..arg_end],
+      &selector[arg_end + 1..],
+    ))
+  })
+}
+
+fn scope_selector(selector: &str, scope: &str) -> String {
+  // `:global(.foo)` isn't scoped.
+  if let Some((before, arg, after)) = find_pseudo(selector, &[":global(", "::v-global("]) {
+    return format!("{before}{arg}{after}");
+  }
+  // `.foo :deep(.bar)` is scoped by `.foo`, and `:deep(.bar)` by the root.
+  if let Some((before, arg, after)) = find_pseudo(selector, &[":deep(", "::v-deep("]) {
+    let before = before.trim_end();
+    let before = if before.is_empty() {
+      format!("[{scope}]")
+    } else {
+      scope_compound(before, scope)
+    };
+    return format!("{before} {arg}{after}");
+  }
+  // `:slotted(.foo)` matches the content of the slots, which is scoped by
+  // the slot scope id.
+  if let Some((before, arg, after)) = find_pseudo(selector, &[":slotted(", "::v-slotted("]) {
+    return format!(
+      "{before}{}{after}",
+      scope_compound(arg, &format!("{scope}-s"))
+    );
+  }
+  scope_compound(selector, scope)
+}
+
+/// Inserts the attribute selector into the last compound selector, before
+/// its pseudo-classes and pseudo-elements.
+fn scope_compound(selector: &str, scope: &str) -> String {
+  let mut depth = 0usize;
+  let mut compound_start = 0;
+  let mut pseudo_start = None;
+  for (index, c) in selector.char_indices() {
+    match c {
+      '(' | '[' => depth += 1,
+      ')' | ']' => depth = depth.saturating_sub(1),
+      ' ' | '>' | '+' | '~' | '\n' | '\t' if depth == 0 => {
+        compound_start = index + c.len_utf8();
+        pseudo_start = None;
+      }
+      ':' if depth == 0 && pseudo_start.is_none() => pseudo_start = Some(index),
+      _ => {}
+    }
+  }
+  let insert_at = pseudo_start.unwrap_or(selector.len()).max(compound_start);
+  // `*` is replaced, so that `* .foo` doesn't match everything.
+  if &selector[compound_start..insert_at] == "*" {
+    return format!(
+      "{}[{scope}]{}",
+      &selector[..compound_start],
+      &selector[insert_at..]
+    );
+  }
+  format!(
+    "{}[{scope}]{}",
+    &selector[..insert_at],
+    &selector[insert_at..]
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{find_v_bind, scope_css};
+
+  #[test]
+  fn scopes_selectors() {
+    let css = ".a, .b:hover > .c::before { color: red; }\n@media (max-width: 100px) { .d :deep(.e) { color: blue; } }\n@keyframes fade { from { opacity: 0; } }\n:global(.f) { margin: 0; }";
+    assert_eq!(
+      scope_css(css, "data-v-1"),
+      ".a[data-v-1], .b:hover > .c[data-v-1]::before { color: red; }\n@media (max-width: 100px) { .d[data-v-1] .e { color: blue; } }\n@keyframes fade { from { opacity: 0; } }\n.f { margin: 0; }"
+    );
+  }
+
+  #[test]
+  fn scoped_styles() {
+    insta::assert_snapshot!(scope_css(
+      r#"/* .comment { } */
+* { box-sizing: border-box; }
+.list > li + li, .list :is(.a, .b) { margin: 0; }
+a[href^="http"]:not(.internal)::after { content: "{"; }
+:deep(.child) { color: red; }
+.parent ::v-deep(.child) { color: blue; }
+:slotted(.item) { padding: 0; }
+:global(body) { margin: 0; }
+@supports (display: grid) { .grid { display: grid; } }
+@font-face { font-family: "Custom"; }
+@keyframes spin { 0% { transform: rotate(0); } 100% { transform: rotate(360deg); } }"#,
+      "data-v-7ba5bd90"
+    ));
+  }
+
+  #[test]
+  fn finds_v_bind() {
+    assert_eq!(find_v_bind(".a { color: v-bind(color); }"), Some(12));
+    assert_eq!(
+      find_v_bind("/* v-bind(color) */ .a { content: \"v-bind(color)\"; }"),
+      None
+    );
+  }
+}
@@ -0,0 +1,1084 @@
+//! Generates the render function from the template.
+//!
+//! The expressions in the template are emitted as they are, the identifiers
+//! are prefixed with `_ctx.` by [super::prefix] afterwards.
+
+use std::collections::{BTreeSet, VecDeque};
+
+use rustc_hash::FxHashSet;
+
+use super::{
+  camelize, capitalize,
+  parser::{decode_entities, Element, Node},
+  TemplateError,
+};
+
+const BUILTIN_COMPONENTS: [(&str, &str); 10] = [
+  ("Transition", "Transition"),
+  ("transition", "Transition"),
+  ("TransitionGroup", "TransitionGroup"),
+  ("transition-group", "TransitionGroup"),
+  ("KeepAlive", "KeepAlive"),
+  ("keep-alive", "KeepAlive"),
+  ("Teleport", "Teleport"),
+  ("teleport", "Teleport"),
+  ("Suspense", "Suspense"),
+  ("suspense", "Suspense"),
+];
+
+const SYSTEM_MODIFIERS: [&str; 11] = [
+  "stop", "prevent", "self", "ctrl", "shift", "alt", "meta", "exact", "left", "middle", "right",
+];
+
+const EVENT_OPTION_MODIFIERS: [&str; 3] = ["capture", "once", "passive"];
+
+pub struct CodegenOptions<'a> {
+  /// Bindings of `<script setup>`, which are used as components and
+  /// directives without resolving them.
+  pub setup_bindings: &'a FxHashSet<String>,
+  pub custom_elements: &'a [String],
+}
+
+/// A child of an element, the adjacent texts and interpolations are joined.
+enum Child {
+  Text(String),
+  VNode(String),
+}
+
+/// `v-xxx:arg.modifier`, including the shorthands.
+struct Directive {
+  name: String,
+  arg: Option<DirectiveArg>,
+  modifiers: Vec<String>,
+}
+
+enum DirectiveArg {
+  Static(String),
+  Dynamic(String),
+}
+
+impl Directive {
+  fn parse(attr: &str) -> Option<Self> {
+    let (name, rest) = if let Some(rest) = attr.strip_prefix(':') {
+      ("bind", rest)
+    } else if let Some(rest) = attr.strip_prefix('@') {
+      ("on", rest)
+    } else if let Some(rest) = attr.strip_prefix('#') {
+      ("slot", rest)
+    } else if let Some(rest) = attr.strip_prefix('.') {
+      // `.foo` is the shorthand of `:foo.prop`.
+      let mut directive = Self::parse_arg("bind", rest);
+      directive.modifiers.push("prop".to_string());
+      return Some(directive);
+    } else if let Some(rest) = attr.strip_prefix("v-") {
+      let end = rest.find([':', '.']).unwrap_or(rest.len());
+      let (name, rest) = rest.split_at(end);
+      return Some(Self::parse_arg(
+        name,
+        rest.strip_prefix(':').unwrap_or(rest),
+      ));
+    } else {
+      return None;
+    };
+    Some(Self::parse_arg(name, rest))
+  }
+
+  fn parse_arg(name: &str, rest: &str) -> Self {
+    let (arg, modifiers) = if let Some(dynamic) = rest.strip_prefix('[')
+      && let Some(end) = dynamic.find(']')
+    {
+      (
+        Some(DirectiveArg::Dynamic(dynamic[..end].to_string())),
+        &dynamic[end + 1..],
+      )
+    } else {
+      let end = rest.find('.').unwrap_or(rest.len());
+      let arg = &rest[..end];
+      (
+        (!arg.is_empty()).then(|| DirectiveArg::Static(arg.to_string())),
+        &rest[end..],
+      )
+    };
+    Self {
+      name: name.to_string(),
+      arg,
+      modifiers: modifiers
+        .split('.')
+        .filter(|modifier| !modifier.is_empty())
+        .map(ToString::to_string)
+        .collect(),
+    }
+  }
+
+  fn has_modifier(&self, modifier: &str) -> bool {
+    self.modifiers.iter().any(|m| m == modifier)
+  }
+
+  fn static_arg(&self) -> Option<&str> {
+    match &self.arg {
+      Some(DirectiveArg::Static(arg)) => Some(arg),
+      _ => None,
+    }
+  }
+}
+
+/// The props of an element, `v-bind="obj"` and `v-on="obj"` split the props
+/// into segments which are merged at runtime.
+#[derive(Default)]
+struct Props {
+  segments: Vec<String>,
+  entries: Vec<(String, String)>,
+  classes: Vec<String>,
+  styles: Vec<String>,
+  /// Runtime directives, e.g. `[_vShow, visible]`.
+  directives: Vec<String>,
+  /// Whether the children are replaced by `v-html` or `v-text`.
+  replaces_children: bool,
+}
+
+impl Props {
+  fn insert(&mut self, key: String, value: String) {
+    match self
+      .entries
+      .iter_mut()
+      .find(|(existing, _)| *existing == key)
+    {
+      // Handlers of the same event are all called.
+      Some((_, existing)) if is_handler_key(&key) => {
+        *existing = format!("[{existing}, {value}]");
+      }
+      Some((_, existing)) => *existing = value,
+      None => self.entries.push((key, value)),
+    }
+  }
+
+  fn spread(&mut self, expr: String) {
+    self.flush();
+    self.segments.push(expr);
+  }
+
+  fn flush(&mut self) {
+    if !self.entries.is_empty() {
+      let entries = std::mem::take(&mut self.entries);
+      self.segments.push(object(entries));
+    }
+  }
+
+  fn into_code(mut self, codegen: &mut Codegen) -> String {
+    for (key, parts) in [
+      ("class", std::mem::take(&mut self.classes)),
+      ("style", std::mem::take(&mut self.styles)),
+    ] {
+      match parts.len() {
+        0 => {}
+        1 => self
+          .entries
+          .push((json(key), parts.into_iter().next().unwrap_or_default())),
+        _ => self
+          .entries
+          .push((json(key), format!("[{}]", parts.join(", ")))),
+      }
+    }
+    self.flush();
+    match self.segments.len() {
+      0 => "null".to_string(),
+      1 => self.segments.remove(0),
+      _ => format!(
+        "{}({})",
+        codegen.helper("mergeProps"),
+        self.segments.join(", ")
+      ),
+    }
+  }
+}
+
+fn is_handler_key(key: &str) -> bool {
+  key.starts_with('[')
+    || key
+      .strip_prefix("\"on")
+      .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase() || c == ':'))
+}
+
+fn object(entries: Vec<(String, String)>) -> String {
+  let entries = entries
+    .into_iter()
+    .map(|(key, value)| format!("{key}: {value}"))
+    .collect::<Vec<_>>();
+  format!("{{ {} }}", entries.join(", "))
+}
+
+fn json(value: &str) -> String {
+  serde_json::to_string(value).expect("should serialize string")
+}
+
+pub struct Codegen<'a> {
+  options: CodegenOptions<'a>,
+  helpers: BTreeSet<&'static str>,
+  /// Components resolved at runtime, the tags and the variables.
+  components: Vec<(String, String)>,
+  /// Directives resolved at runtime, the names and the variables.
+  directives: Vec<(String, String)>,
+  errors: Vec<TemplateError>,
+}
+
+impl<'a> Codegen<'a> {
+  pub fn new(options: CodegenOptions<'a>) -> Self {
+    Self {
+      options,
+      helpers: Default::default(),
+      components: vec![],
+      directives: vec![],
+      errors: vec![],
+    }
+  }
+
+  /// Generates a module that exports the render function.
+  pub fn generate(mut self, nodes: Vec<Node>) -> Result<String, Vec<TemplateError>> {
+    let root = self.gen_root(nodes);
+    if !self.errors.is_empty() {
+      return Err(self.errors);
+    }
+
+    let mut code = String::new();
+    if !self.helpers.is_empty() {
+      let imports = self
+        .helpers
+        .iter()
+        .map(|helper| format!("{helper} as _{helper}"))
+        .collect::<Vec<_>>();
+      code.push_str(&format!(
+        "import {{ {} }} from \"vue\";\n",
+        imports.join(", ")
+      ));
+    }
+    code.push_str("export function render(_ctx, _cache, $props, $setup, $data, $options) {\n");
+    for (tag, variable) in &self.components {
+      code.push_str(&format!(
+        "  const {variable} = _resolveComponent({});\n",
+        json(tag)
+      ));
+    }
+    for (name, variable) in &self.directives {
+      code.push_str(&format!(
+        "  const {variable} = _resolveDirective({});\n",
+        json(name)
+      ));
+    }
+    code.push_str(&format!("  return {root};\n}}\n"));
+    Ok(code)
+  }
+
+  fn helper(&mut self, name: &'static str) -> String {
+    self.helpers.insert(name);
+    format!("_{name}")
+  }
+
+  fn error(&mut self, offset: usize, message: impl Into<String>) {
+    self.errors.push(TemplateError {
+      message: message.into(),
+      offset,
+    });
+  }
+
+  fn gen_root(&mut self, nodes: Vec<Node>) -> String {
+    let mut children = self.gen_vnodes(nodes);
+    match children.len() {
+      0 => "null".to_string(),
+      1 => children.remove(0),
+      _ => format!(
+        "{}({}, null, [{}])",
+        self.helper("createVNode"),
+        self.helper("Fragment"),
+        children.join(", ")
+      ),
+    }
+  }
+
+  /// Generates the children as vnodes, the texts are wrapped as text vnodes.
+  fn gen_vnodes(&mut self, nodes: Vec<Node>) -> Vec<String> {
+    self
+      .gen_children(nodes)
+      .into_iter()
+      .map(|child| match child {
+        Child::Text(text) => format!("{}({text})", self.helper("createTextVNode")),
+        Child::VNode(vnode) => vnode,
+      })
+      .collect()
+  }
+
+  fn gen_children(&mut self, nodes: Vec<Node>) -> Vec<Child> {
+    let mut children = vec![];
+    let mut texts: Vec<String> = vec![];
+    let mut nodes: VecDeque<Node> = nodes.into();
+
+    while let Some(node) = nodes.pop_front() {
+      let mut element = match node {
+        Node::Text(text) => {
+          texts.push(json(&text));
+          continue;
+        }
+        Node::Interpolation(expr) => {
+          texts.push(format!("{}({expr})", self.helper("toDisplayString")));
+          continue;
+        }
+        Node::Comment => continue,
+        Node::Element(element) => element,
+      };
+      if !texts.is_empty() {
+        children.push(Child::Text(std::mem::take(&mut texts).join(" + ")));
+      }
+
+      let Some(condition) = element.take_directive("v-if") else {
+        for directive in ["v-else-if", "v-else"] {
+          if element.take_directive(directive).is_some() {
+            self.error(
+              element.offset,
+              format!("{directive} has no adjacent v-if or v-else-if"),
+            );
+          }
+        }
+        children.push(Child::VNode(self.gen_element(element, vec![])));
+        continue;
+      };
+
+      let mut branches = vec![(Some(condition.unwrap_or_default()), element)];
+      loop {
+        // The whitespaces between the branches are ignored.
+        let next_index = match nodes.front() {
+          Some(Node::Text(text)) if text.trim().is_empty() => 1,
+          _ => 0,
+        };
+        let Some(Node::Element(next)) = nodes.get(next_index) else {
+          break;
+        };
+        if next.attr("v-else-if").is_none() && next.attr("v-else").is_none() {
+          break;
+        }
+        nodes.drain(..next_index);
+        let Some(Node::Element(mut next)) = nodes.pop_front() else {
+          break;
+        };
+        if let Some(condition) = next.take_directive("v-else-if") {
+          branches.push((Some(condition.unwrap_or_default()), next));
+        } else {
+          next.take_directive("v-else");
+          branches.push((None, next));
+          break;
+        }
+      }
+      children.push(Child::VNode(self.gen_if(branches)));
+    }
+
+    if !texts.is_empty() {
+      children.push(Child::Text(texts.join(" + ")));
+    }
+    children
+  }
+
+  fn gen_if(&mut self, branches: Vec<(Option<String>, Element)>) -> String {
+    let mut code = String::new();
+    let mut has_else = false;
+    for (key, (condition, element)) in branches.into_iter().enumerate() {
+      // Keys make the branches patched as different nodes.
+      let key_prop = (element.attr("key").is_none() && element.attr(":key").is_none())
+        .then(|| ("key".to_string(), key.to_string()));
+      let vnode = self.gen_element(element, key_prop.into_iter().collect());
+      match condition {
+        Some(condition) => {
+          code.push_str(&format!("({condition}) ? {vnode} : "));
+        }
+        None => {
+          code.push_str(&vnode);
+          has_else = true;
+        }
+      }
+    }
+    if !has_else {
+      code.push_str(&format!(
+        "{}(\"v-if\", true)",
+        self.helper("createCommentVNode")
+      ));
+    }
+    format!("({code})")
+  }
+
+  fn gen_element(&mut self, mut element: Element, extra_props: Vec<(String, String)>) -> String {
+    if let Some(v_for) = element.take_directive("v-for") {
+      let v_for = v_for.unwrap_or_default();
+      let Some((alias, source)) = parse_v_for(&v_for) else {
+        self.error(
+          element.offset,
+          format!("Invalid expression of v-for: {v_for}"),
+        );
+        return "null".to_string();
+      };
+      let vnode = self.gen_element(element, vec![]);
+      return format!(
+        "{}({}, {}, {}({source}, ({alias}) => {{ return {vnode}; }}))",
+        self.helper("createVNode"),
+        self.helper("Fragment"),
+        if extra_props.is_empty() {
+          "null".to_string()
+        } else {
+          object(extra_props)
+        },
+        self.helper("renderList"),
+      );
+    }
+
+    if ["v-pre", "v-memo"]
+      .iter()
+      .any(|directive| element.attr(directive).is_some())
+    {
+      self.error(
+        element.offset,
+        "v-pre and v-memo aren't supported by builtin:vue-loader",
+      );
+    }
+
+    match element.tag.as_str() {
+      "template" => {
+        if element
+          .attrs
+          .iter()
+          .any(|(name, _)| name.starts_with("v-slot") || name.starts_with('#'))
+        {
+          self.error(
+            element.offset,
+            "<template v-slot> can only appear as the child of a component",
+          );
+        }
+        let children = self.gen_vnodes(std::mem::take(&mut element.children));
+        let props = Props {
+          entries: extra_props,
+          ..Default::default()
+        };
+        format!(
+          "{}({}, {}, [{}])",
+          self.helper("createVNode"),
+          self.helper("Fragment"),
+          props.into_code(self),
+          children.join(", ")
+        )
+      }
+      "slot" => self.gen_slot_outlet(element, extra_props),
+      _ if self.is_component(&element.tag) => self.gen_component(element, extra_props),
+      _ => self.gen_native_element(element, extra_props),
+    }
+  }
+
+  fn gen_native_element(
+    &mut self,
+    mut element: Element,
+    extra_props: Vec<(String, String)>,
+  ) -> String {
+    let mut props = self.gen_props(&mut element, false, extra_props);
+    let replaces_children = props.replaces_children;
+    let directives = std::mem::take(&mut props.directives);
+    let props = props.into_code(self);
+
+    let children = if replaces_children {
+      None
+    } else {
+      let children = self.gen_children(std::mem::take(&mut element.children));
+      if children.is_empty() {
+        None
+      } else if children.iter().all(|child| matches!(child, Child::Text(_))) {
+        let texts = children
+          .into_iter()
+          .filter_map(|child| match child {
+            Child::Text(text) => Some(text),
+            Child::VNode(_) => None,
+          })
+          .collect::<Vec<_>>();
+        Some(texts.join(" + "))
+      } else {
+        let vnodes = children
+          .into_iter()
+          .map(|child| match child {
+            Child::Text(text) => format!("{}({text})", self.helper("createTextVNode")),
+            Child::VNode(vnode) => vnode,
+          })
+          .collect::<Vec<_>>();
+        Some(format!("[{}]", vnodes.join(", ")))
+      }
+    };
+
+    let vnode = match children {
+      Some(children) => format!(
+        "{}({}, {props}, {children})",
+        self.helper("createVNode"),
+        json(&element.tag)
+      ),
+      None if props == "null" => format!("{}({})", self.helper("createVNode"), json(&element.tag)),
+      None => format!(
+        "{}({}, {props})",
+        self.helper("createVNode"),
+        json(&element.tag)
+      ),
+    };
+    self.with_directives(vnode, directives)
+  }
+
+  fn with_directives(&mut self, vnode: String, directives: Vec<String>) -> String {
+    if directives.is_empty() {
+      return vnode;
+    }
+    format!(
+      "{}({vnode}, [{}])",
+      self.helper("withDirectives"),
+      directives.join(", ")
+    )
+  }
+
+  fn is_component(&self, tag: &str) -> bool {
+    if tag == "component" || BUILTIN_COMPONENTS.iter().any(|(name, _)| *name == tag) {
+      return true;
+    }
+    if self.options.custom_elements.iter().any(|name| name == tag) {
+      return false;
+    }
+    tag.contains('-')
+      || tag.starts_with(|c: char| c.is_ascii_uppercase())
+      || self.setup_binding(tag).is_some()
+  }
+
+  /// Finds the binding of `<script setup>` that is referred by the tag.
+  fn setup_binding(&self, tag: &str) -> Option<String> {
+    let camelized = camelize(tag);
+    let capitalized = capitalize(&camelized);
+    [tag.to_string(), camelized, capitalized]
+      .into_iter()
+      .find(|name| self.options.setup_bindings.contains(name))
+  }
+
+  fn resolve_component(&mut self, tag: &str) -> String {
+    if let Some((_, builtin)) = BUILTIN_COMPONENTS.iter().find(|(name, _)| *name == tag) {
+      self.helpers.insert(*builtin);
+      return format!("_{builtin}");
+    }
+    if let Some(binding) = self.setup_binding(tag) {
+      return binding;
+    }
+    if let Some((_, variable)) = self.components.iter().find(|(name, _)| name == tag) {
+      return variable.clone();
+    }
+    self.helper("resolveComponent");
+    let variable = format!("_component_{}", sanitize(tag));
+    self.components.push((tag.to_string(), variable.clone()));
+    variable
+  }
+
+  fn resolve_directive(&mut self, name: &str) -> String {
+    let binding = format!("v{}", capitalize(&camelize(name)));
+    if self.options.setup_bindings.contains(&binding) {
+      return binding;
+    }
+    if let Some((_, variable)) = self
+      .directives
+      .iter()
+      .find(|(existing, _)| existing == name)
+    {
+      return variable.clone();
+    }
+    self.helper("resolveDirective");
+    let variable = format!("_directive_{}", sanitize(name));
+    self.directives.push((name.to_string(), variable.clone()));
+    variable
+  }
+
+  fn gen_component(&mut self, mut element: Element, extra_props: Vec<(String, String)>) -> String {
+    let component = if element.tag == "component" {
+      let is = element
+        .take_directive(":is")
+        .or_else(|| element.take_directive("v-bind:is"))
+        .map(|is| is.unwrap_or_default());
+      match is {
+        Some(is) => format!("{}({is})", self.helper("resolveDynamicComponent")),
+        None => match element.take_directive("is") {
+          Some(is) => format!(
+            "{}({})",
+            self.helper("resolveDynamicComponent"),
+            json(is.as_deref().unwrap_or_default())
+          ),
+          None => {
+            self.error(element.offset, "<component> is missing the `is` prop");
+            return "null".to_string();
+          }
+        },
+      }
+    } else {
+      self.resolve_component(&element.tag)
+    };
+
+    // Teleport and KeepAlive take the children as they are instead of slots.
+    let takes_array_children = matches!(component.as_str(), "_Teleport" | "_KeepAlive");
+    let slot_directive = element
+      .attrs
+      .iter()
+      .position(|(name, _)| name.starts_with("v-slot") || name.starts_with('#'))
+      .map(|index| element.attrs.remove(index));
+
+    let mut props = self.gen_props(&mut element, true, extra_props);
+    let directives = std::mem::take(&mut props.directives);
+    let props = props.into_code(self);
+    let children = std::mem::take(&mut element.children);
+
+    let children = if takes_array_children {
+      let vnodes = self.gen_vnodes(children);
+      (!vnodes.is_empty()).then(|| format!("[{}]", vnodes.join(", ")))
+    } else {
+      self.gen_slots(element.offset, slot_directive, children)
+    };
+
+    let vnode = match children {
+      Some(children) => format!(
+        "{}({component}, {props}, {children})",
+        self.helper("createVNode")
+      ),
+      None => format!("{}({component}, {props})", self.helper("createVNode")),
+    };
+    self.with_directives(vnode, directives)
+  }
+
+  fn gen_slots(
+    &mut self,
+    offset: usize,
+    slot_directive: Option<(String, Option<String>)>,
+    children: Vec<Node>,
+  ) -> Option<String> {
+    let mut slots = vec![];
+    let mut default_children = vec![];
+
+    if let Some((name, params)) = slot_directive {
+      // `v-slot` on the component declares the default slot.
+      let directive = Directive::parse(&name)?;
+      let key = self.slot_key(&directive);
+      let vnodes = self.gen_vnodes(children);
+      slots.push((key, self.gen_slot_function(params, vnodes)));
+      return Some(object(slots));
+    }
+
+    for child in children {
+      match child {
+        Node::Element(mut element) if element.tag == "template" => {
+          let slot = element
+            .attrs
+            .iter()
+            .position(|(name, _)| name.starts_with("v-slot") || name.starts_with('#'))
+            .map(|index| element.attrs.remove(index));
+          let Some((name, params)) = slot else {
+            default_children.push(Node::Element(element));
+            continue;
+          };
+          if element.attr("v-if").is_some() || element.attr("v-for").is_some() {
+            self.error(
+              element.offset,
+              "v-if and v-for on <template v-slot> aren't supported by builtin:vue-loader",
+            );
+            continue;
+          }
+          let Some(directive) = Directive::parse(&name) else {
+            continue;
+          };
+          let key = self.slot_key(&directive);
+          let vnodes = self.gen_vnodes(element.children);
+          slots.push((key, self.gen_slot_function(params, vnodes)));
+        }
+        child => default_children.push(child),
+      }
+    }
+
+    let has_default_content = default_children.iter().any(|child| match child {
+      Node::Text(text) => !text.trim().is_empty(),
+      Node::Comment => false,
+      _ => true,
+    });
+    if has_default_content {
+      if slots.iter().any(|(key, _)| key == "default") {
+        self.error(offset, "The default slot is declared twice");
+      }
+      let vnodes = self.gen_vnodes(default_children);
+      slots.push(("default".to_string(), self.gen_slot_function(None, vnodes)));
+    }
+
+    (!slots.is_empty()).then(|| object(slots))
+  }
+
+  fn slot_key(&self, directive: &Directive) -> String {
+    match &directive.arg {
+      Some(DirectiveArg::Static(name)) => json(name),
+      Some(DirectiveArg::Dynamic(expr)) => format!("[{expr}]"),
+      None => "default".to_string(),
+    }
+  }
+
+  fn gen_slot_function(&mut self, params: Option<String>, vnodes: Vec<String>) -> String {
+    format!(
+      "{}(({}) => [{}])",
+      self.helper("withCtx"),
+      params.unwrap_or_default(),
+      vnodes.join(", ")
+    )
+  }
+
+  fn gen_slot_outlet(
+    &mut self,
+    mut element: Element,
+    extra_props: Vec<(String, String)>,
+  ) -> String {
+    let name = match element.take_directive(":name") {
+      Some(name) => name.unwrap_or_default(),
+      None => json(
+        &element
+          .take_directive("name")
+          .flatten()
+          .unwrap_or_else(|| "default".to_string()),
+      ),
+    };
+    let props = self
+      .gen_props(&mut element, true, extra_props)
+      .into_code(self);
+    let fallback = self.gen_vnodes(std::mem::take(&mut element.children));
+    let render_slot = self.helper("renderSlot");
+    if fallback.is_empty() {
+      format!("{render_slot}(_ctx.$slots, {name}, {props})")
+    } else {
+      format!(
+        "{render_slot}(_ctx.$slots, {name}, {props}, () => [{}])",
+        fallback.join(", ")
+      )
+    }
+  }
+
+  fn gen_props(
+    &mut self,
+    element: &mut Element,
+    is_component: bool,
+    extra_props: Vec<(String, String)>,
+  ) -> Props {
+    let mut props = Props {
+      entries: extra_props,
+      ..Default::default()
+    };
+
+    for (name, value) in element.attrs.clone() {
+      let Some(directive) = Directive::parse(&name) else {
+        let value = value
+          .as_deref()
+          .map(|value| decode_entities(value).into_owned())
+          .unwrap_or_default();
+        match name.as_str() {
+          "class" => props.classes.push(json(&value)),
+          "style" => props.styles.push(json(&value)),
+          _ => props.insert(json(&name), json(&value)),
+        }
+        continue;
+      };
+      let expr = value.unwrap_or_default();
+
+      match directive.name.as_str() {
+        "bind" => {
+          let Some(arg) = &directive.arg else {
+            props.spread(expr);
+            continue;
+          };
+          let key = match arg {
+            DirectiveArg::Static(arg) => {
+              let arg = if directive.has_modifier("camel") {
+                camelize(arg)
+              } else {
+                arg.clone()
+              };
+              // `:foo` without value is the shorthand of `:foo="foo"`.
+              let expr = if expr.trim().is_empty() {
+                camelize(&arg)
+              } else {
+                expr.clone()
+              };
+              match arg.as_str() {
+                "class" => {
+                  props.classes.push(format!("({expr})"));
+                  continue;
+                }
+                "style" => {
+                  props.styles.push(format!("({expr})"));
+                  continue;
+                }
+                _ => {}
+              }
+              let arg = if directive.has_modifier("prop") {
+                format!(".{arg}")
+              } else if directive.has_modifier("attr") {
+                format!("^{arg}")
+              } else {
+                arg
+              };
+              props.insert(json(&arg), format!("({expr})"));
+              continue;
+            }
+            DirectiveArg::Dynamic(arg) => format!("[{arg} || \"\"]"),
+          };
+          props.insert(key, format!("({expr})"));
+        }
+        "on" => {
+          let Some(arg) = &directive.arg else {
+            let to_handlers = self.helper("toHandlers");
+            props.spread(format!("{to_handlers}({expr})"));
+            continue;
+          };
+          let (key, event) = match arg {
+            DirectiveArg::Static(event) => {
+              let event = match event.as_str() {
+                "click" if directive.has_modifier("right") => "contextmenu".to_string(),
+                "click" if directive.has_modifier("middle") => "mouseup".to_string(),
+                _ => event.clone(),
+              };
+              let mut key = format!("on{}", capitalize(&camelize(&event)));
+              for modifier in EVENT_OPTION_MODIFIERS {
+                if directive.has_modifier(modifier) {
+                  key.push_str(&capitalize(modifier));
+                }
+              }
+              (json(&key), Some(event))
+            }
+            DirectiveArg::Dynamic(event) => (
+              format!(
+                "[{}({}({event}))]",
+                self.helper("toHandlerKey"),
+                self.helper("camelize")
+              ),
+              None,
+            ),
+          };
+          let handler = self.gen_handler(&expr, &directive, event.as_deref());
+          props.insert(key, handler);
+        }
+        "model" => self.gen_model(element, &directive, &expr, is_component, &mut props),
+        "show" => {
+          let v_show = self.helper("vShow");
+          props.directives.push(format!("[{v_show}, {expr}]"));
+        }
+        "html" => {
+          props.insert(json("innerHTML"), format!("({expr})"));
+          props.replaces_children = true;
+        }
+        "text" => {
+          let to_display_string = self.helper("toDisplayString");
+          props.insert(json("textContent"), format!("{to_display_string}({expr})"));
+          props.replaces_children = true;
+        }
+        "once" | "cloak" | "pre" | "memo" => {}
+        "slot" => {
+          self.error(
+            element.offset,
+            "v-slot can only be used on components or <template>",
+          );
+        }
+        name => {
+          let variable = self.resolve_directive(name);
+          let arg = match &directive.arg {
+            Some(DirectiveArg::Static(arg)) => json(arg),
+            Some(DirectiveArg::Dynamic(arg)) => arg.clone(),
+            None => "void 0".to_string(),
+          };
+          let value = if expr.trim().is_empty() {
+            "void 0".to_string()
+          } else {
+            expr
+          };
+          props.directives.push(format!(
+            "[{variable}, {value}, {arg}, {}]",
+            modifiers_object(&directive.modifiers)
+          ));
+        }
+      }
+    }
+    props
+  }
+
+  fn gen_handler(&mut self, expr: &str, directive: &Directive, event: Option<&str>) -> String {
+    let expr = expr.trim();
+    let mut handler = if expr.is_empty() {
+      "() => {}".to_string()
+    } else if is_member_path(expr) || is_function_expression(expr) {
+      expr.to_string()
+    } else if expr.contains(';') {
+      format!("$event => {{ {expr} }}")
+    } else {
+      format!("$event => ({expr})")
+    };
+
+    let is_keyboard_event = event.is_none_or(|event| event.starts_with("key"));
+    let mut system_modifiers = vec![];
+    let mut key_modifiers = vec![];
+    for modifier in &directive.modifiers {
+      if EVENT_OPTION_MODIFIERS.contains(&modifier.as_str()) {
+        continue;
+      }
+      // `left` and `right` are keys of keyboard events, and buttons of the
+      // others.
+      if SYSTEM_MODIFIERS.contains(&modifier.as_str())
+        && !(is_keyboard_event && matches!(modifier.as_str(), "left" | "right"))
+      {
+        system_modifiers.push(json(modifier));
+      } else {
+        key_modifiers.push(json(modifier));
+      }
+    }
+    if !system_modifiers.is_empty() {
+      handler = format!(
+        "{}({handler}, [{}])",
+        self.helper("withModifiers"),
+        system_modifiers.join(", ")
+      );
+    }
+    if !key_modifiers.is_empty() {
+      handler = format!(
+        "{}({handler}, [{}])",
+        self.helper("withKeys"),
+        key_modifiers.join(", ")
+      );
+    }
+    handler
+  }
+
+  fn gen_model(
+    &mut self,
+    element: &Element,
+    directive: &Directive,
+    expr: &str,
+    is_component: bool,
+    props: &mut Props,
+  ) {
+    if expr.trim().is_empty() {
+      self.error(element.offset, "v-model is missing expression");
+      return;
+    }
+    let assign = format!("$event => (({expr}) = $event)");
+
+    if is_component {
+      let (prop, key) = match &directive.arg {
+        Some(DirectiveArg::Static(prop)) => (json(prop), json(&format!("onUpdate:{prop}"))),
+        Some(DirectiveArg::Dynamic(prop)) => {
+          (format!("[{prop}]"), format!("[\"onUpdate:\" + {prop}]"))
+        }
+        None => (json("modelValue"), json("onUpdate:modelValue")),
+      };
+      props.insert(prop, format!("({expr})"));
+      props.insert(key, assign);
+      if !directive.modifiers.is_empty() {
+        let modifiers_key = match directive.static_arg() {
+          Some(prop) => json(&format!("{prop}Modifiers")),
+          None => json("modelModifiers"),
+        };
+        props.insert(modifiers_key, modifiers_object(&directive.modifiers));
+      }
+      return;
+    }
+
+    let model = match element.tag.as_str() {
+      "select" => "vModelSelect",
+      "textarea" => "vModelText",
+      "input" => match element.attr("type").and_then(|(_, ty)| ty.as_deref()) {
+        Some("checkbox") => "vModelCheckbox",
+        Some("radio") => "vModelRadio",
+        _ if element.attr(":type").is_some() || element.attr("v-bind:type").is_some() => {
+          "vModelDynamic"
+        }
+        _ => "vModelText",
+      },
+      tag => {
+        self.error(
+          element.offset,
+          format!("v-model can only be used on <input>, <textarea>, <select> and components, but found <{tag}>"),
+        );
+        return;
+      }
+    };
+    props.insert(json("onUpdate:modelValue"), assign);
+    let model = self.helper(model);
+    props.directives.push(format!(
+      "[{model}, {expr}, void 0, {}]",
+      modifiers_object(&directive.modifiers)
+    ));
+  }
+}
+
+fn modifiers_object(modifiers: &[String]) -> String {
+  if modifiers.is_empty() {
+    return "void 0".to_string();
+  }
+  object(
+    modifiers
+      .iter()
+      .map(|modifier| (json(modifier), "true".to_string()))
+      .collect(),
+  )
+}
+
+fn sanitize(name: &str) -> String {
+  name
+    .chars()
+    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+    .collect()
+}
+
+/// Splits `(item, index) in list` into the alias and the source.
+fn parse_v_for(expr: &str) -> Option<(String, String)> {
+  let (alias, source) = expr
+    .split_once(" in ")
+    .or_else(|| expr.split_once(" of "))?;
+  let alias = alias.trim();
+  let alias = alias
+    .strip_prefix('(')
+    .and_then(|alias| alias.strip_suffix(')'))
+    .unwrap_or(alias);
+  Some((alias.trim().to_string(), source.trim().to_string()))
+}
+
+/// Whether the handler is a reference to a function, like `onClick` or
+/// `handlers.click`, instead of an inline statement.
+fn is_member_path(expr: &str) -> bool {
+  expr.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$')
+    && expr
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '.'))
+    && !expr.ends_with('.')
+}
+
+fn is_function_expression(expr: &str) -> bool {
+  let expr = expr.strip_prefix("async").unwrap_or(expr).trim_start();
+  if expr.starts_with("function") {
+    return true;
+  }
+  let rest = if expr.starts_with('(') {
+    let mut depth = 0;
+    let end = expr.char_indices().find_map(|(index, c)| {
+      match c {
+        '(' => depth += 1,
+        ')' => {
+          depth -= 1;
+          if depth == 0 {
+            return Some(index + 1);
+          }
+        }
+        _ => {}
+      }
+      None
+    });
+    match end {
+      Some(end) => &expr[end..],
+      None => return false,
+    }
+  } else {
+    let end = expr
+      .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+      .unwrap_or(expr.len());
+    if end == 0 {
+      return false;
+    }
+    &expr[end..]
+  };
+  rest.trim_start().starts_with("=>")
+}
@@ -0,0 +1,127 @@
+//! Compiles `<template>` into a module that exports the render function.
+
+mod codegen;
+mod parser;
+mod prefix;
+
+use codegen::Codegen;
+pub use codegen::CodegenOptions;
+pub use prefix::PrefixIdentifiers;
+
+#[derive(Debug)]
+pub struct TemplateError {
+  pub message: String,
+  /// Byte offset in the template.
+  pub offset: usize,
+}
+
+/// Generates the render function, the identifiers in the expressions are
+/// prefixed by [PrefixIdentifiers] when the module is transformed.
+pub fn compile(template: &str, options: CodegenOptions) -> Result<String, Vec<TemplateError>> {
+  let nodes = parser::parse(template).map_err(|error| vec![error])?;
+  Codegen::new(options).generate(nodes)
+}
+
+/// `foo-bar` to `fooBar`.
+pub fn camelize(name: &str) -> String {
+  let mut result = String::with_capacity(name.len());
+  let mut upper = false;
+  for c in name.chars() {
+    if c == '-' {
+      upper = true;
+    } else if upper {
+      result.push(c.to_ascii_uppercase());
+      upper = false;
+    } else {
+      result.push(c);
+    }
+  }
+  result
+}
+
+/// `fooBar` to `FooBar`.
+pub fn capitalize(name: &str) -> String {
+  let mut chars = name.chars();
+  match chars.next() {
+    Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+    None => String::new(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rustc_hash::FxHashSet;
+
+  use super::{compile, CodegenOptions};
+
+  fn codegen(template: &str, setup_bindings: &[&str]) -> String {
+    let setup_bindings = setup_bindings
+      .iter()
+      .map(ToString::to_string)
+      .collect::<FxHashSet<_>>();
+    compile(
+      template,
+      CodegenOptions {
+        setup_bindings: &setup_bindings,
+        custom_elements: &["my-element".to_string()],
+      },
+    )
+    .unwrap_or_else(|errors| panic!("failed to compile the template: {errors:?}"))
+  }
+
+  fn errors(template: &str) -> Vec<String> {
+    compile(
+      template,
+      CodegenOptions {
+        setup_bindings: &FxHashSet::default(),
+        custom_elements: &[],
+      },
+    )
+    .err()
+    .unwrap_or_default()
+    .into_iter()
+    .map(|error| error.message)
+    .collect()
+  }
+
+  #[test]
+  fn elements_and_directives() {
+    insta::assert_snapshot!(codegen(
+      r#"<div id="app" :class="{ active }" @click="count++">
+  <p v-if="ok">{{ message }}</p>
+  <p v-else-if="maybe">maybe</p>
+  <p v-else>no</p>
+  <li v-for="(item, index) in items" :key="item.id">{{ index }}: {{ item.name }}</li>
+  <input v-model="text" v-show="visible">
+  <my-element v-html="html" />
+</div>"#,
+      &[]
+    ));
+  }
+
+  #[test]
+  fn components_and_slots() {
+    insta::assert_snapshot!(codegen(
+      r#"<Child title="hello" @update="onUpdate">
+  <template #header="{ title }"><h1>{{ title }}</h1></template>
+  <span>default</span>
+</Child>
+<other-child v-model:value="value" />
+<component :is="current" />
+<slot name="footer" :count="count">fallback</slot>"#,
+      &["Child", "value"]
+    ));
+  }
+
+  #[test]
+  fn rejects_unsupported_directives() {
+    assert_eq!(
+      errors("<div v-pre>{{ raw }}</div>"),
+      vec!["v-pre and v-memo aren't supported by builtin:vue-loader"]
+    );
+    assert_eq!(
+      errors("<div v-memo=\"[id]\">{{ id }}</div>"),
+      vec!["v-pre and v-memo aren't supported by builtin:vue-loader"]
+    );
+  }
+}
@@ -0,0 +1,299 @@
+use std::borrow::Cow;
+
+use super::TemplateError;
+use crate::descriptor::parse_attrs;
+
+const VOID_ELEMENTS: [&str; 14] = [
+  "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+  "track", "wbr",
+];
+
+#[derive(Debug)]
+pub enum Node {
+  Element(Element),
+  Text(String),
+  Interpolation(String),
+  Comment,
+}
+
+impl Node {
+  fn is_whitespace(&self) -> bool {
+    matches!(self, Node::Text(text) if text.chars().all(|c| c.is_ascii_whitespace()))
+  }
+}
+
+#[derive(Debug)]
+pub struct Element {
+  pub tag: String,
+  pub attrs: Vec<(String, Option<String>)>,
+  pub children: Vec<Node>,
+  /// Byte offset of the start tag in the template.
+  pub offset: usize,
+}
+
+impl Element {
+  pub fn attr(&self, name: &str) -> Option<&(String, Option<String>)> {
+    self.attrs.iter().find(|(key, _)| key == name)
+  }
+
+  /// Finds a directive, like `v-if`, and removes it from the attributes.
+  pub fn take_directive(&mut self, name: &str) -> Option<Option<String>> {
+    let index = self.attrs.iter().position(|(key, _)| key == name)?;
+    Some(self.attrs.remove(index).1)
+  }
+}
+
+/// Parses the content of `<template>`, the whitespaces are condensed and the
+/// comments are removed like the default options of the Vue compiler.
+pub fn parse(source: &str) -> Result<Vec<Node>, TemplateError> {
+  let bytes = source.as_bytes();
+  let mut stack: Vec<Element> = vec![];
+  let mut roots = vec![];
+  let mut pos = 0;
+
+  fn append(stack: &mut [Element], roots: &mut Vec<Node>, node: Node) {
+    match stack.last_mut() {
+      Some(parent) => parent.children.push(node),
+      None => roots.push(node),
+    }
+  }
+
+  while pos < source.len() {
+    let rest = &source[pos..];
+    if rest.starts_with("<!--") {
+      pos = match rest.find("-->") {
+        Some(end) => pos + end + 3,
+        None => source.len(),
+      };
+      append(&mut stack, &mut roots, Node::Comment);
+      continue;
+    }
+
+    if rest.starts_with("</") && bytes.get(pos + 2).is_some_and(u8::is_ascii_alphabetic) {
+      let end = rest.find('>').ok_or_else(|| TemplateError {
+        message: "End tag is missing '>'".to_string(),
+        offset: pos,
+      })?;
+      let tag = rest[2..end].trim();
+      let Some(element) = stack.pop() else {
+        return Err(TemplateError {
+          message: format!("Invalid end tag </{tag}>"),
+          offset: pos,
+        });
+      };
+      if element.tag != tag {
+        return Err(TemplateError {
+          message: format!("Element <{}> is missing end tag", element.tag),
+          offset: element.offset,
+        });
+      }
+      append(&mut stack, &mut roots, Node::Element(element));
+      pos += end + 1;
+      continue;
+    }
+
+    if rest.starts_with('<') && bytes.get(pos + 1).is_some_and(u8::is_ascii_alphabetic) {
+      let name_end = rest
+        .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+        .map_or(source.len(), |end| pos + end);
+      let tag = source[pos + 1..name_end].to_string();
+      let (attrs, open_end, self_closing) =
+        parse_attrs(source, name_end).map_err(|_| TemplateError {
+          message: format!("Element <{tag}> is missing end of the start tag"),
+          offset: pos,
+        })?;
+      let is_void = VOID_ELEMENTS.contains(&tag.as_str());
+      let element = Element {
+        tag,
+        attrs,
+        children: vec![],
+        offset: pos,
+      };
+      if self_closing || is_void {
+        append(&mut stack, &mut roots, Node::Element(element));
+      } else {
+        stack.push(element);
+      }
+      pos = open_end;
+      continue;
+    }
+
+    let text_end = find_text_end(source, pos);
+    for node in split_interpolations(source, pos, text_end)? {
+      append(&mut stack, &mut roots, node);
+    }
+    pos = text_end;
+  }
+
+  if let Some(element) = stack.pop() {
+    return Err(TemplateError {
+      message: format!("Element <{}> is missing end tag", element.tag),
+      offset: element.offset,
+    });
+  }
+
+  condense(&mut roots);
+  Ok(roots)
+}
+
+/// Finds the end of a text, the interpolations may contain `<`.
+fn find_text_end(source: &str, mut pos: usize) -> usize {
+  let bytes = source.as_bytes();
+  while pos < source.len() {
+    let rest = &source[pos..];
+    let next_tag = rest.find('<');
+    let next_interpolation = rest.find("{{");
+    match (next_tag, next_interpolation) {
+      (_, Some(interpolation)) if next_tag.is_none_or(|tag| interpolation < tag) => {
+        pos = match rest[interpolation..].find("}}") {
+          Some(end) => pos + interpolation + end + 2,
+          None => return source.len(),
+        };
+      }
+      (Some(tag), _) => {
+        let next = bytes.get(pos + tag + 1);
+        if next.is_some_and(|c| c.is_ascii_alphabetic() || *c == b'/' || *c == b'!') {
+          return pos + tag;
+        }
+        pos += tag + 1;
+      }
+      (None, _) => return source.len(),
+    }
+  }
+  source.len()
+}
+
+fn split_interpolations(
+  source: &str,
+  start: usize,
+  end: usize,
+) -> Result<Vec<Node>, TemplateError> {
+  let mut nodes = vec![];
+  let mut pos = start;
+  while let Some(open) = source[pos..end].find("{{") {
+    let open = pos + open;
+    if open > pos {
+      nodes.push(Node::Text(decode_entities(&source[pos..open]).into_owned()));
+    }
+    let close = source[open..end]
+      .find("}}")
+      .map(|close| open + close)
+      .ok_or_else(|| TemplateError {
+        message: "Interpolation is missing end delimiter '}}'".to_string(),
+        offset: open,
+      })?;
+    nodes.push(Node::Interpolation(
+      source[open + 2..close].trim().to_string(),
+    ));
+    pos = close + 2;
+  }
+  if pos < end {
+    nodes.push(Node::Text(decode_entities(&source[pos..end]).into_owned()));
+  }
+  Ok(nodes)
+}
+
+fn condense(nodes: &mut Vec<Node>) {
+  let len = nodes.len();
+  let mut remove = vec![false; len];
+  for index in 0..len {
+    if !nodes[index].is_whitespace() {
+      if let Node::Text(text) = &mut nodes[index] {
+        *text = condense_whitespace(text);
+      }
+      continue;
+    }
+    let prev = index.checked_sub(1).map(|prev| &nodes[prev]);
+    let next = nodes.get(index + 1);
+    let Node::Text(text) = &nodes[index] else {
+      continue;
+    };
+    let removed = match (prev, next) {
+      (None, _) | (_, None) => true,
+      (Some(Node::Comment), _) | (_, Some(Node::Comment)) => true,
+      (Some(Node::Element(_)), Some(Node::Element(_))) => text.contains('\n'),
+      _ => false,
+    };
+    if removed {
+      remove[index] = true;
+    } else {
+      nodes[index] = Node::Text(" ".to_string());
+    }
+  }
+  let mut index = 0;
+  nodes.retain(|node| {
+    let keep = !remove[index] && !matches!(node, Node::Comment);
+    index += 1;
+    keep
+  });
+
+  for node in nodes {
+    if let Node::Element(element) = node
+      && element.tag != "pre"
+      && element.tag != "textarea"
+    {
+      condense(&mut element.children);
+    }
+  }
+}
+
+fn condense_whitespace(text: &str) -> String {
+  let mut result = String::with_capacity(text.len());
+  let mut in_whitespace = false;
+  for c in text.chars() {
+    if c.is_ascii_whitespace() {
+      if !in_whitespace {
+        result.push(' ');
+      }
+      in_whitespace = true;
+    } else {
+      result.push(c);
+      in_whitespace = false;
+    }
+  }
+  result
+}
+
+/// Decodes the character references of HTML.
+pub fn decode_entities(text: &str) -> Cow<'_, str> {
+  if !text.contains('&') {
+    return Cow::Borrowed(text);
+  }
+  let mut result = String::with_capacity(text.len());
+  let mut rest = text;
+  while let Some(index) = rest.find('&') {
+    result.push_str(&rest[..index]);
+    rest = &rest[index..];
+    let decoded = rest.find(';').and_then(|end| {
+      let name = &rest[1..end];
+      let c = match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => name.strip_prefix('#').and_then(|code| {
+          match code.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+            None => code.parse().ok(),
+          }
+          .and_then(char::from_u32)
+        }),
+      }?;
+      Some((c, end + 1))
+    });
+    match decoded {
+      Some((c, len)) => {
+        result.push(c);
+        rest = &rest[len..];
+      }
+      None => {
+        result.push('&');
+        rest = &rest[1..];
+      }
+    }
+  }
+  result.push_str(rest);
+  Cow::Owned(result)
+}
@@ -0,0 +1,208 @@
+use rustc_hash::FxHashSet;
+use swc_core::{
+  common::DUMMY_SP,
+  ecma::{
+    ast::{
+      ArrowExpr, BlockStmt, CatchClause, Decl, Expr, Function, Ident, IdentName, ImportSpecifier,
+      KeyValueProp, MemberExpr, MemberProp, Module, ModuleDecl, ModuleItem, ObjectPatProp, Pat,
+      Prop, PropName, SimpleAssignTarget, Stmt,
+    },
+    atoms::Atom,
+    visit::{VisitMut, VisitMutWith},
+  },
+};
+
+/// Globals that are accessible in the template, the same as the Vue compiler.
+const ALLOWED_GLOBALS: [&str; 27] = [
+  "Infinity",
+  "undefined",
+  "NaN",
+  "isFinite",
+  "isNaN",
+  "parseFloat",
+  "parseInt",
+  "decodeURI",
+  "decodeURIComponent",
+  "encodeURI",
+  "encodeURIComponent",
+  "Math",
+  "Number",
+  "Date",
+  "Array",
+  "Object",
+  "Boolean",
+  "String",
+  "RegExp",
+  "Map",
+  "Set",
+  "JSON",
+  "Intl",
+  "BigInt",
+  "console",
+  "Error",
+  "Symbol",
+];
+
+/// Prefixes the identifiers that aren't declared in the render function with
+/// `_ctx.`, so that they refer to the properties of the component instance.
+#[derive(Default)]
+pub struct PrefixIdentifiers {
+  scopes: Vec<FxHashSet<Atom>>,
+}
+
+impl PrefixIdentifiers {
+  fn should_prefix(&self, sym: &Atom) -> bool {
+    !ALLOWED_GLOBALS.contains(&sym.as_str()) && !self.scopes.iter().any(|scope| scope.contains(sym))
+  }
+
+  fn ctx_member(ident: &Ident) -> MemberExpr {
+    MemberExpr {
+      span: ident.span,
+      obj: Box::new(Expr::Ident(Ident::new_no_ctxt("_ctx".into(), DUMMY_SP))),
+      prop: MemberProp::Ident(IdentName::new(ident.sym.clone(), ident.span)),
+    }
+  }
+
+  fn with_scope(&mut self, names: FxHashSet<Atom>, f: impl FnOnce(&mut Self)) {
+    self.scopes.push(names);
+    f(self);
+    self.scopes.pop();
+  }
+}
+
+impl VisitMut for PrefixIdentifiers {
+  fn visit_mut_module(&mut self, module: &mut Module) {
+    let mut names = FxHashSet::default();
+    for item in &module.body {
+      match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+          for specifier in &import.specifiers {
+            let local = match specifier {
+              ImportSpecifier::Named(named) => &named.local,
+              ImportSpecifier::Default(default) => &default.local,
+              ImportSpecifier::Namespace(namespace) => &namespace.local,
+            };
+            names.insert(local.sym.clone());
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+          decl_names(&export.decl, &mut names)
+        }
+        ModuleItem::Stmt(Stmt::Decl(decl)) => decl_names(decl, &mut names),
+        _ => {}
+      }
+    }
+    self.with_scope(names, |this| module.visit_mut_children_with(this));
+  }
+
+  fn visit_mut_function(&mut self, function: &mut Function) {
+    let mut names = FxHashSet::default();
+    for param in &function.params {
+      pat_names(&param.pat, &mut names);
+    }
+    self.with_scope(names, |this| function.visit_mut_children_with(this));
+  }
+
+  fn visit_mut_arrow_expr(&mut self, arrow: &mut ArrowExpr) {
+    let mut names = FxHashSet::default();
+    for param in &arrow.params {
+      pat_names(param, &mut names);
+    }
+    self.with_scope(names, |this| arrow.visit_mut_children_with(this));
+  }
+
+  fn visit_mut_block_stmt(&mut self, block: &mut BlockStmt) {
+    let mut names = FxHashSet::default();
+    for stmt in &block.stmts {
+      if let Stmt::Decl(decl) = stmt {
+        decl_names(decl, &mut names);
+      }
+    }
+    self.with_scope(names, |this| block.visit_mut_children_with(this));
+  }
+
+  fn visit_mut_catch_clause(&mut self, clause: &mut CatchClause) {
+    let mut names = FxHashSet::default();
+    if let Some(param) = &clause.param {
+      pat_names(param, &mut names);
+    }
+    self.with_scope(names, |this| clause.visit_mut_children_with(this));
+  }
+
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if let Expr::Ident(ident) = expr
+      && self.should_prefix(&ident.sym)
+    {
+      *expr = Expr::Member(Self::ctx_member(ident));
+      return;
+    }
+    expr.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_prop(&mut self, prop: &mut Prop) {
+    if let Prop::Shorthand(ident) = prop
+      && self.should_prefix(&ident.sym)
+    {
+      *prop = Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(IdentName::new(ident.sym.clone(), ident.span)),
+        value: Box::new(Expr::Member(Self::ctx_member(ident))),
+      });
+      return;
+    }
+    prop.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_simple_assign_target(&mut self, target: &mut SimpleAssignTarget) {
+    if let SimpleAssignTarget::Ident(ident) = target
+      && self.should_prefix(&ident.id.sym)
+    {
+      *target = SimpleAssignTarget::Member(Self::ctx_member(&ident.id));
+      return;
+    }
+    target.visit_mut_children_with(self);
+  }
+}
+
+fn decl_names(decl: &Decl, names: &mut FxHashSet<Atom>) {
+  match decl {
+    Decl::Var(var) => {
+      for declarator in &var.decls {
+        pat_names(&declarator.name, names);
+      }
+    }
+    Decl::Fn(function) => {
+      names.insert(function.ident.sym.clone());
+    }
+    Decl::Class(class) => {
+      names.insert(class.ident.sym.clone());
+    }
+    _ => {}
+  }
+}
+
+fn pat_names(pat: &Pat, names: &mut FxHashSet<Atom>) {
+  match pat {
+    Pat::Ident(ident) => {
+      names.insert(ident.id.sym.clone());
+    }
+    Pat::Array(array) => {
+      for elem in array.elems.iter().flatten() {
+        pat_names(elem, names);
+      }
+    }
+    Pat::Object(object) => {
+      for prop in &object.props {
+        match prop {
+          ObjectPatProp::KeyValue(key_value) => pat_names(&key_value.value, names),
+          ObjectPatProp::Assign(assign) => {
+            names.insert(assign.key.id.sym.clone());
+          }
+          ObjectPatProp::Rest(rest) => pat_names(&rest.arg, names),
+        }
+      }
+    }
+    Pat::Rest(rest) => pat_names(&rest.arg, names),
+    Pat::Assign(assign) => pat_names(&assign.left, names),
+    Pat::Invalid(_) | Pat::Expr(_) => {}
+  }
+}
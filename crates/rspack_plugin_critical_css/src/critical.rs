@@ -0,0 +1,129 @@
+use lightningcss::{
+  printer::PrinterOptions,
+  rules::CssRule,
+  stylesheet::{ParserOptions, StyleSheet},
+};
+use rspack_error::{Result, ToStringResultToRspackResultExt};
+
+use crate::dom::Dom;
+
+/// Extracts the rules of `css` whose selectors may match the static markup
+/// in `dom`, returns `None` if nothing matched.
+pub fn extract_critical_css(
+  filename: &str,
+  css: &str,
+  dom: &Dom,
+  minify: bool,
+) -> Result<Option<String>> {
+  let mut stylesheet = StyleSheet::parse(
+    css,
+    ParserOptions {
+      filename: filename.to_string(),
+      error_recovery: true,
+      ..Default::default()
+    },
+  )
+  .to_rspack_result()?;
+
+  if !retain_critical_rules(&mut stylesheet.rules.0, dom) {
+    return Ok(None);
+  }
+  stylesheet.license_comments.clear();
+
+  let result = stylesheet
+    .to_css(PrinterOptions {
+      minify,
+      ..Default::default()
+    })
+    .to_rspack_result()?;
+  Ok(Some(result.code))
+}
+
+/// Drops the rules that can not apply to the page, returns whether any
+/// selector-bearing rule is left.
+fn retain_critical_rules(rules: &mut Vec<CssRule>, dom: &Dom) -> bool {
+  let mut has_matched = false;
+  rules.retain_mut(|rule| match rule {
+    CssRule::Style(style) => {
+      style.selectors.0.retain(|selector| dom.matches(selector));
+      let retained = !style.selectors.0.is_empty();
+      has_matched |= retained;
+      retained
+    }
+    CssRule::Media(media) => retain_nested(&mut media.rules.0, dom, &mut has_matched),
+    CssRule::Supports(supports) => retain_nested(&mut supports.rules.0, dom, &mut has_matched),
+    CssRule::LayerBlock(layer) => retain_nested(&mut layer.rules.0, dom, &mut has_matched),
+    CssRule::Container(container) => retain_nested(&mut container.rules.0, dom, &mut has_matched),
+    CssRule::MozDocument(document) => retain_nested(&mut document.rules.0, dom, &mut has_matched),
+    CssRule::StartingStyle(starting_style) => {
+      retain_nested(&mut starting_style.rules.0, dom, &mut has_matched)
+    }
+    // imported sheets keep loading with the full stylesheet
+    CssRule::Import(_) => false,
+    // `@font-face`, `@keyframes`, `@property`, ... are referenced by the
+    // retained declarations and are cheap to keep
+    _ => true,
+  });
+  has_matched
+}
+
+fn retain_nested(rules: &mut Vec<CssRule>, dom: &Dom, has_matched: &mut bool) -> bool {
+  let retained = retain_critical_rules(rules, dom);
+  *has_matched |= retained;
+  retained
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use swc_core::common::{sync::Lrc, FileName, FilePathMapping, SourceMap};
+  use swc_html::parser::{parse_file_as_document, parser::ParserConfig};
+
+  use super::*;
+
+  fn dom(html: &str) -> Dom {
+    let cm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+    let fm = cm.new_source_file(
+      Arc::new(FileName::Custom("test.html".to_string())),
+      html.to_string(),
+    );
+    let document = parse_file_as_document(fm.as_ref(), ParserConfig::default(), &mut vec![])
+      .expect("should parse html");
+    Dom::from_document(&document)
+  }
+
+  #[test]
+  fn should_keep_matched_rules_only() {
+    let dom = dom(r#"<html><body><main class="hero"><h1 id="title">Hi</h1></main></body></html>"#);
+    let css = r#"
+      .hero h1 { color: red }
+      .footer a { color: blue }
+      #title, .unused { margin: 0 }
+      @media (min-width: 600px) { main > h1 { font-size: 2em } aside { display: none } }
+      @media print { nav { display: none } }
+      a:hover { color: green }
+      @font-face { font-family: Foo; src: url(foo.woff2) }
+    "#;
+    let critical = extract_critical_css("test.css", css, &dom, true)
+      .expect("should extract")
+      .expect("should match");
+    assert!(critical.contains(".hero h1{color:red}"));
+    assert!(critical.contains("#title{margin:0}"));
+    assert!(critical.contains("main>h1{font-size:2em}"));
+    assert!(critical.contains("@font-face"));
+    assert!(!critical.contains(".footer"));
+    assert!(!critical.contains(".unused"));
+    assert!(!critical.contains("aside"));
+    assert!(!critical.contains("print"));
+    assert!(!critical.contains(":hover"));
+  }
+
+  #[test]
+  fn should_return_none_without_matched_rules() {
+    let dom = dom("<html><body><p>text</p></body></html>");
+    let critical =
+      extract_critical_css("test.css", ".card { color: red }", &dom, true).expect("should extract");
+    assert!(critical.is_none());
+  }
+}
@@ -0,0 +1,173 @@
+use cow_utils::CowUtils;
+use lightningcss::selector::{Combinator, Component, Selector};
+use rustc_hash::FxHashMap as HashMap;
+use swc_html::ast::{Child, Document};
+
+/// A flattened, read-only view of the static markup of an html page.
+///
+/// Only the information needed for selector matching is kept. The matcher is
+/// intentionally permissive: whenever a selector depends on runtime state
+/// (`:hover`, `:has()`, `:not()`, ...) it is treated as matching, so that the
+/// critical css is a superset of the rules the page needs on first paint.
+#[derive(Debug, Default)]
+pub struct Dom {
+  elements: Vec<DomElement>,
+}
+
+#[derive(Debug)]
+struct DomElement {
+  tag_name: String,
+  id: Option<String>,
+  classes: Vec<String>,
+  attributes: HashMap<String, String>,
+  parent: Option<usize>,
+  prev_sibling: Option<usize>,
+  is_empty: bool,
+}
+
+impl Dom {
+  pub fn from_document(document: &Document) -> Self {
+    let mut dom = Dom::default();
+    dom.walk(&document.children, None);
+    dom
+  }
+
+  fn walk(&mut self, children: &[Child], parent: Option<usize>) {
+    let mut prev_sibling = None;
+    for child in children {
+      let Child::Element(element) = child else {
+        continue;
+      };
+      let mut attributes = HashMap::default();
+      for attr in &element.attributes {
+        attributes.insert(
+          attr.name.cow_to_ascii_lowercase().into_owned(),
+          attr
+            .value
+            .as_ref()
+            .map(|value| value.to_string())
+            .unwrap_or_default(),
+        );
+      }
+      let id = attributes.get("id").cloned();
+      let classes = attributes
+        .get("class")
+        .map(|class| class.split_ascii_whitespace().map(String::from).collect())
+        .unwrap_or_default();
+      let index = self.elements.len();
+      self.elements.push(DomElement {
+        tag_name: element.tag_name.cow_to_ascii_lowercase().into_owned(),
+        id,
+        classes,
+        attributes,
+        parent,
+        prev_sibling,
+        is_empty: element
+          .children
+          .iter()
+          .all(|child| matches!(child, Child::Comment(_))),
+      });
+      prev_sibling = Some(index);
+      self.walk(&element.children, Some(index));
+    }
+  }
+
+  /// Whether `selector` may match any element of the page.
+  pub fn matches(&self, selector: &Selector) -> bool {
+    let compounds = split_compounds(selector);
+    (0..self.elements.len()).any(|index| self.matches_complex(index, &compounds, 0))
+  }
+
+  fn matches_selector_at(&self, index: usize, selector: &Selector) -> bool {
+    let compounds = split_compounds(selector);
+    self.matches_complex(index, &compounds, 0)
+  }
+
+  fn matches_complex(&self, index: usize, compounds: &[Compound], offset: usize) -> bool {
+    let Some((components, combinator)) = compounds.get(offset) else {
+      return true;
+    };
+    if !components
+      .iter()
+      .all(|component| self.matches_component(index, component))
+    {
+      return false;
+    }
+    let element = &self.elements[index];
+    match combinator {
+      None => true,
+      Some(Combinator::Child) | Some(Combinator::PseudoElement) => element
+        .parent
+        .is_some_and(|parent| self.matches_complex(parent, compounds, offset + 1)),
+      Some(Combinator::Descendant) => {
+        let mut ancestor = element.parent;
+        while let Some(current) = ancestor {
+          if self.matches_complex(current, compounds, offset + 1) {
+            return true;
+          }
+          ancestor = self.elements[current].parent;
+        }
+        false
+      }
+      Some(Combinator::NextSibling) => element
+        .prev_sibling
+        .is_some_and(|sibling| self.matches_complex(sibling, compounds, offset + 1)),
+      Some(Combinator::LaterSibling) => {
+        let mut sibling = element.prev_sibling;
+        while let Some(current) = sibling {
+          if self.matches_complex(current, compounds, offset + 1) {
+            return true;
+          }
+          sibling = self.elements[current].prev_sibling;
+        }
+        false
+      }
+      // shadow dom and vue deep combinators can not be resolved statically
+      Some(_) => true,
+    }
+  }
+
+  fn matches_component(&self, index: usize, component: &Component) -> bool {
+    let element = &self.elements[index];
+    match component {
+      Component::LocalName(name) => element.tag_name == *name.lower_name,
+      Component::ID(id) => element.id.as_deref() == Some(&**id),
+      Component::Class(class) => element.classes.iter().any(|c| c == &**class),
+      Component::AttributeInNoNamespaceExists {
+        local_name_lower, ..
+      } => element.attributes.contains_key(&**local_name_lower),
+      // attribute values are not compared, any element carrying the attribute is a candidate
+      Component::AttributeInNoNamespace {
+        local_name,
+        never_matches,
+        ..
+      } => !never_matches && element.attributes.contains_key(&**local_name),
+      Component::Root => element.tag_name == "html",
+      Component::Empty => element.is_empty,
+      Component::Is(selectors) | Component::Where(selectors) | Component::Any(_, selectors) => {
+        selectors
+          .iter()
+          .any(|selector| self.matches_selector_at(index, selector))
+      }
+      _ => true,
+    }
+  }
+}
+
+type Compound<'a, 'i> = (Vec<&'a Component<'i>>, Option<Combinator>);
+
+/// Splits a selector into compound selectors in match order (right to left),
+/// each paired with the combinator to its left.
+fn split_compounds<'a, 'i>(selector: &'a Selector<'i>) -> Vec<Compound<'a, 'i>> {
+  let mut compounds = vec![];
+  let mut iter = selector.iter();
+  loop {
+    let components = iter.by_ref().collect::<Vec<_>>();
+    let combinator = iter.next_sequence();
+    compounds.push((components, combinator));
+    if combinator.is_none() {
+      break;
+    }
+  }
+  compounds
+}
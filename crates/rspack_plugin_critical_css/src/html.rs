@@ -0,0 +1,280 @@
+use std::sync::Arc;
+
+use cow_utils::CowUtils;
+use rspack_error::{Diagnostic, Result};
+use rustc_hash::FxHashMap as HashMap;
+use swc_core::common::{sync::Lrc, FileName, FilePathMapping, SourceMap};
+use swc_html::{
+  ast::{Child, Element},
+  parser::{parse_file_as_document, parser::ParserConfig},
+};
+
+use crate::{
+  critical::extract_critical_css, dom::Dom, CriticalCssPluginOptions, CriticalCssPreload,
+};
+
+struct LinkElement {
+  start: usize,
+  end: usize,
+  attributes: Vec<(String, Option<String>)>,
+  asset: String,
+}
+
+impl LinkElement {
+  fn media(&self) -> Option<&str> {
+    self
+      .attributes
+      .iter()
+      .find(|(name, _)| name == "media")
+      .and_then(|(_, value)| value.as_deref())
+  }
+}
+
+/// Inlines the critical part of every stylesheet linked by `html` and turns the
+/// links themselves into non render-blocking ones.
+///
+/// `resolve_link` maps the `href` of a stylesheet emitted by this compilation to its
+/// asset name, `stylesheets` maps the asset names to their content.
+pub fn inline_critical_css(
+  html: &str,
+  resolve_link: &dyn Fn(&str) -> Option<String>,
+  stylesheets: &HashMap<String, Arc<str>>,
+  options: &CriticalCssPluginOptions,
+  diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Option<String>> {
+  let cm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+  let fm = cm.new_source_file(
+    Arc::new(FileName::Custom("critical-css.html".to_string())),
+    html.to_string(),
+  );
+  let mut errors = vec![];
+  let Ok(document) = parse_file_as_document(fm.as_ref(), ParserConfig::default(), &mut errors)
+  else {
+    return Ok(None);
+  };
+
+  let mut link_elements = vec![];
+  collect_link_elements(
+    &document.children,
+    resolve_link,
+    fm.start_pos.0,
+    &mut link_elements,
+  );
+  if link_elements.is_empty() {
+    return Ok(None);
+  }
+
+  let dom = Dom::from_document(&document);
+  let mut result = html.to_string();
+  // replace from the end so that the offsets of the preceding links stay valid
+  for link in link_elements.into_iter().rev() {
+    let Some(css) = stylesheets.get(&link.asset) else {
+      continue;
+    };
+    let media = link.media();
+    // print stylesheets never take part in the above-the-fold render
+    if media.is_some_and(|media| media.trim().eq_ignore_ascii_case("print")) {
+      continue;
+    }
+    let critical_css = match extract_critical_css(&link.asset, css, &dom, options.minify) {
+      Ok(critical_css) => critical_css,
+      Err(err) => {
+        diagnostics.push(Diagnostic::warn(
+          "CriticalCssPlugin".to_string(),
+          format!("Failed to extract critical css from {}: {err}", link.asset),
+        ));
+        continue;
+      }
+    };
+
+    let original = &html[link.start..link.end];
+    let mut replacement = String::new();
+    if let Some(critical_css) = critical_css {
+      let critical_css = critical_css.cow_replace("</style", "<\\/style");
+      replacement.push_str("<style>");
+      // keep the rules of e.g. `media="(min-width: 768px)"` stylesheets conditional
+      match media.filter(|media| !is_unconditional_media(media)) {
+        Some(media) => {
+          replacement.push_str("@media ");
+          replacement.push_str(media);
+          replacement.push('{');
+          replacement.push_str(&critical_css);
+          replacement.push('}');
+        }
+        None => replacement.push_str(&critical_css),
+      }
+      replacement.push_str("</style>");
+    }
+    match &options.preload {
+      CriticalCssPreload::None => replacement.push_str(original),
+      preload => {
+        replacement.push_str(&render_async_link(&link.attributes, preload));
+        if options.noscript_fallback {
+          replacement.push_str("<noscript>");
+          replacement.push_str(original);
+          replacement.push_str("</noscript>");
+        }
+      }
+    }
+    result.replace_range(link.start..link.end, &replacement);
+  }
+
+  Ok(Some(result))
+}
+
+fn is_unconditional_media(media: &str) -> bool {
+  let media = media.trim();
+  media.is_empty() || media.eq_ignore_ascii_case("all")
+}
+
+fn collect_link_elements(
+  children: &[Child],
+  resolve_link: &dyn Fn(&str) -> Option<String>,
+  start_pos: u32,
+  result: &mut Vec<LinkElement>,
+) {
+  for child in children {
+    let Child::Element(element) = child else {
+      continue;
+    };
+    // links inside these elements are either inert or already a fallback
+    if matches!(&*element.tag_name, "noscript" | "template") {
+      continue;
+    }
+    if let Some(link) = as_stylesheet_link(element, resolve_link, start_pos) {
+      result.push(link);
+    }
+    collect_link_elements(&element.children, resolve_link, start_pos, result);
+  }
+}
+
+fn as_stylesheet_link(
+  element: &Element,
+  resolve_link: &dyn Fn(&str) -> Option<String>,
+  start_pos: u32,
+) -> Option<LinkElement> {
+  if &*element.tag_name != "link" || element.span.is_dummy() {
+    return None;
+  }
+  let attributes = element
+    .attributes
+    .iter()
+    .map(|attr| {
+      (
+        attr.name.to_string(),
+        attr.value.as_ref().map(|value| value.to_string()),
+      )
+    })
+    .collect::<Vec<_>>();
+  let get_attribute = |name: &str| {
+    attributes
+      .iter()
+      .find(|(attr_name, _)| attr_name == name)
+      .and_then(|(_, value)| value.as_deref())
+  };
+  if get_attribute("rel") != Some("stylesheet") || get_attribute("onload").is_some() {
+    return None;
+  }
+  let asset = resolve_link(get_attribute("href")?)?;
+  Some(LinkElement {
+    start: (element.span.lo.0 - start_pos) as usize,
+    end: (element.span.hi.0 - start_pos) as usize,
+    attributes,
+    asset,
+  })
+}
+
+fn render_async_link(
+  attributes: &[(String, Option<String>)],
+  preload: &CriticalCssPreload,
+) -> String {
+  let media = attributes
+    .iter()
+    .find(|(name, _)| name == "media")
+    .and_then(|(_, value)| value.clone())
+    .unwrap_or_else(|| "all".to_string());
+
+  let mut attributes = attributes
+    .iter()
+    .filter(|(name, _)| match preload {
+      CriticalCssPreload::Media => name != "media",
+      _ => name != "rel",
+    })
+    .cloned()
+    .collect::<Vec<_>>();
+  match preload {
+    CriticalCssPreload::Media => {
+      attributes.push(("media".to_string(), Some("print".to_string())));
+      attributes.push((
+        "onload".to_string(),
+        Some(format!("this.media='{}'", escape_js_string(&media))),
+      ));
+    }
+    _ => {
+      attributes.push(("rel".to_string(), Some("preload".to_string())));
+      attributes.push(("as".to_string(), Some("style".to_string())));
+      attributes.push((
+        "onload".to_string(),
+        Some("this.onload=null;this.rel='stylesheet'".to_string()),
+      ));
+    }
+  }
+
+  let mut link = String::from("<link");
+  for (name, value) in attributes {
+    link.push(' ');
+    link.push_str(&name);
+    if let Some(value) = value {
+      link.push_str("=\"");
+      link.push_str(&value.cow_replace('&', "&amp;").cow_replace('"', "&quot;"));
+      link.push('"');
+    }
+  }
+  link.push('>');
+  link
+}
+
+/// Escapes a value for a single-quoted JavaScript string.
+fn escape_js_string(value: &str) -> String {
+  let mut escaped = String::with_capacity(value.len());
+  for c in value.chars() {
+    match c {
+      '\\' | '\'' => {
+        escaped.push('\\');
+        escaped.push(c);
+      }
+      '\n' => escaped.push_str("\\n"),
+      '\r' => escaped.push_str("\\r"),
+      c => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_escape_media_in_onload() {
+    let attributes = vec![
+      ("rel".to_string(), Some("stylesheet".to_string())),
+      ("href".to_string(), Some("main.css".to_string())),
+      (
+        "media".to_string(),
+        Some("(min-width: 768px) and x'y".to_string()),
+      ),
+    ];
+    assert_eq!(
+      render_async_link(&attributes, &CriticalCssPreload::Media),
+      r#"<link rel="stylesheet" href="main.css" media="print" onload="this.media='(min-width: 768px) and x\'y'">"#
+    );
+  }
+
+  #[test]
+  fn should_keep_screen_media_conditional() {
+    assert!(is_unconditional_media(" all "));
+    assert!(is_unconditional_media(""));
+    assert!(!is_unconditional_media("screen"));
+  }
+}
@@ -0,0 +1,262 @@
+#![feature(let_chains)]
+
+mod critical;
+mod dom;
+mod html;
+
+use std::sync::{Arc, LazyLock};
+
+use rspack_core::{
+  Compilation, CompilationId, CompilationParams, CompilationProcessAssets, CompilerThisCompilation,
+  Plugin, PluginContext,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_plugin_html::{
+  AlterAssetTagGroupsData, BeforeEmitData, HtmlPluginAlterAssetTagGroups, HtmlPluginBeforeEmit,
+  HtmlRspackPlugin,
+};
+use rspack_util::fx_hash::FxDashMap;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::html::inline_critical_css;
+
+/// How the full stylesheet is loaded once its critical part is inlined.
+#[derive(Debug, Clone, Default)]
+pub enum CriticalCssPreload {
+  /// `<link rel="stylesheet" media="print" onload="this.media='all'">`
+  #[default]
+  Media,
+  /// `<link rel="preload" as="style" onload="this.rel='stylesheet'">`
+  Swap,
+  /// Keep the original render-blocking `<link>`, only inline the critical css.
+  None,
+}
+
+impl From<String> for CriticalCssPreload {
+  fn from(value: String) -> Self {
+    match value.as_str() {
+      "swap" => Self::Swap,
+      "none" => Self::None,
+      _ => Self::Media,
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct CriticalCssPluginOptions {
+  pub preload: CriticalCssPreload,
+  /// Keep the original `<link>` in a `<noscript>` for clients without javascript.
+  pub noscript_fallback: bool,
+  pub minify: bool,
+}
+
+#[derive(Debug, Default)]
+struct CriticalCssCompilationContext {
+  /// css asset name -> content
+  stylesheets: HashMap<String, Arc<str>>,
+  /// html output name -> link href -> css asset name
+  links: HashMap<String, HashMap<String, String>>,
+  diagnostics: Vec<Diagnostic>,
+}
+
+static COMPILATION_CONTEXT_MAP: LazyLock<FxDashMap<CompilationId, CriticalCssCompilationContext>> =
+  LazyLock::new(Default::default);
+
+/// Inlines the css rules used by the static markup of each html page emitted by
+/// `HtmlRspackPlugin` and loads the rest of the stylesheets asynchronously.
+#[plugin]
+#[derive(Debug)]
+pub struct CriticalCssPlugin {
+  options: CriticalCssPluginOptions,
+}
+
+impl CriticalCssPlugin {
+  pub fn new(options: CriticalCssPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilerThisCompilation for CriticalCssPlugin)]
+async fn this_compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  COMPILATION_CONTEXT_MAP.insert(compilation.id(), Default::default());
+
+  let mut html_plugin_hooks = HtmlRspackPlugin::get_compilation_hooks_mut(compilation.id());
+  html_plugin_hooks
+    .alter_asset_tag_groups
+    .tap(alter_asset_tag_groups::new(self));
+  html_plugin_hooks.before_emit.tap(before_emit::new(self));
+  Ok(())
+}
+
+// Runs right before `HtmlRspackPlugin` so that the stylesheets are already minimized.
+#[plugin_hook(CompilationProcessAssets for CriticalCssPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_INLINE - 1)]
+async fn collect_stylesheets(&self, compilation: &mut Compilation) -> Result<()> {
+  let stylesheets = compilation
+    .assets()
+    .iter()
+    .filter(|(filename, _)| {
+      filename
+        .split('?')
+        .next()
+        .is_some_and(|filename| filename.ends_with(".css"))
+    })
+    .filter_map(|(filename, asset)| {
+      let source = asset.get_source()?;
+      Some((filename.clone(), Arc::from(source.source().as_ref())))
+    })
+    .collect::<HashMap<_, _>>();
+
+  let mut ctx = COMPILATION_CONTEXT_MAP.entry(compilation.id()).or_default();
+  ctx.stylesheets = stylesheets;
+  Ok(())
+}
+
+#[plugin_hook(CompilationProcessAssets for CriticalCssPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_INLINE + 1)]
+async fn report_diagnostics(&self, compilation: &mut Compilation) -> Result<()> {
+  if let Some((_, ctx)) = COMPILATION_CONTEXT_MAP.remove(&compilation.id()) {
+    compilation.extend_diagnostics(ctx.diagnostics);
+  }
+  Ok(())
+}
+
+#[plugin_hook(HtmlPluginAlterAssetTagGroups for CriticalCssPlugin)]
+async fn alter_asset_tag_groups(
+  &self,
+  data: AlterAssetTagGroupsData,
+) -> Result<AlterAssetTagGroupsData> {
+  let Some(mut ctx) = COMPILATION_CONTEXT_MAP.get_mut(&data.compilation_id) else {
+    return Ok(data);
+  };
+  let mut links = HashMap::default();
+  for tag in data.head_tags.iter().chain(data.body_tags.iter()) {
+    if tag.tag_name != "link" {
+      continue;
+    }
+    let get_attribute = |name: &str| {
+      tag
+        .attributes
+        .iter()
+        .find(|attr| attr.attr_name == name)
+        .and_then(|attr| attr.attr_value.as_deref())
+    };
+    if get_attribute("rel") != Some("stylesheet") {
+      continue;
+    }
+    let Some(href) = get_attribute("href") else {
+      continue;
+    };
+    if let Some(asset) = get_asset_name(href, &data.public_path, &data.output_name, |asset| {
+      ctx.stylesheets.contains_key(asset)
+    }) {
+      links.insert(href.to_string(), asset);
+    }
+  }
+  ctx.links.insert(data.output_name.clone(), links);
+  Ok(data)
+}
+
+#[plugin_hook(HtmlPluginBeforeEmit for CriticalCssPlugin)]
+async fn before_emit(&self, mut data: BeforeEmitData) -> Result<BeforeEmitData> {
+  let Some(mut ctx) = COMPILATION_CONTEXT_MAP.get_mut(&data.compilation_id) else {
+    return Ok(data);
+  };
+  let ctx = &mut *ctx;
+  let Some(links) = ctx.links.get(&data.output_name) else {
+    return Ok(data);
+  };
+  // links written in the template itself are relative to the html asset
+  let resolve_link = |href: &str| {
+    links.get(href).cloned().or_else(|| {
+      get_asset_name(href, "", &data.output_name, |asset| {
+        ctx.stylesheets.contains_key(asset)
+      })
+    })
+  };
+  if let Some(html) = inline_critical_css(
+    &data.html,
+    &resolve_link,
+    &ctx.stylesheets,
+    &self.options,
+    &mut ctx.diagnostics,
+  )? {
+    data.html = html;
+  }
+  Ok(data)
+}
+
+/// Maps the `href` of a link tag generated by `HtmlRspackPlugin` back to the asset name.
+///
+/// The href is either prefixed with the public path, or relative to the html asset
+/// when the public path is `auto`.
+fn get_asset_name(
+  href: &str,
+  public_path: &str,
+  html_name: &str,
+  is_asset: impl Fn(&str) -> bool,
+) -> Option<String> {
+  let href = href.split(['?', '#']).next()?;
+  let href = urlencoding::decode(href).ok()?;
+  if !public_path.is_empty()
+    && let Some(asset) = href.strip_prefix(public_path)
+  {
+    let asset = asset.trim_start_matches("./");
+    if is_asset(asset) {
+      return Some(asset.to_string());
+    }
+  }
+  if href.starts_with('/') || href.contains("://") {
+    return None;
+  }
+  let mut segments = html_name.split('/').collect::<Vec<_>>();
+  // the html file itself
+  segments.pop();
+  for segment in href.split('/') {
+    match segment {
+      "" | "." => {}
+      ".." => {
+        segments.pop()?;
+      }
+      segment => segments.push(segment),
+    }
+  }
+  let asset = segments.join("/");
+  is_asset(&asset).then_some(asset)
+}
+
+impl Plugin for CriticalCssPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.CriticalCssPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .this_compilation
+      .tap(this_compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(collect_stylesheets::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(report_diagnostics::new(self));
+    Ok(())
+  }
+
+  fn clear_cache(&self, id: CompilationId) {
+    COMPILATION_CONTEXT_MAP.remove(&id);
+  }
+}
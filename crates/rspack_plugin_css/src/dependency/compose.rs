@@ -5,10 +5,13 @@ use rspack_cacheable::{
 use rspack_core::{
   AsContextDependency, AsDependencyCodeGeneration, Dependency, DependencyCategory, DependencyId,
   DependencyRange, DependencyType, ExtendedReferencedExport, FactorizeInfo, ModuleDependency,
-  RuntimeSpec,
+  ModuleGraph, RuntimeSpec,
 };
+use rspack_error::Diagnostic;
 use rspack_util::atom::Atom;
 
+use super::get_missing_css_exports_diagnostics;
+
 #[cacheable]
 #[derive(Debug, Clone)]
 pub struct CssComposeDependency {
@@ -30,6 +33,10 @@ impl CssComposeDependency {
       factorize_info: Default::default(),
     }
   }
+
+  pub fn names(&self) -> &[Atom] {
+    &self.names
+  }
 }
 
 #[cacheable_dyn]
@@ -56,7 +63,7 @@ impl Dependency for CssComposeDependency {
 
   fn get_referenced_exports(
     &self,
-    _module_graph: &rspack_core::ModuleGraph,
+    _module_graph: &ModuleGraph,
     _runtime: Option<&RuntimeSpec>,
   ) -> Vec<ExtendedReferencedExport> {
     self
@@ -65,6 +72,10 @@ impl Dependency for CssComposeDependency {
       .map(|n| ExtendedReferencedExport::Array(vec![n.clone()]))
       .collect()
   }
+
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    get_missing_css_exports_diagnostics(self, &self.names, "compose", module_graph)
+  }
 }
 
 #[cacheable_dyn]
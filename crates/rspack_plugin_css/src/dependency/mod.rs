@@ -4,6 +4,7 @@ mod import;
 mod local_ident;
 mod self_reference;
 mod url;
+mod value;
 
 pub use compose::*;
 pub use export::*;
@@ -11,3 +12,4 @@ pub use import::*;
 pub use local_ident::*;
 pub use self_reference::*;
 pub use url::*;
+pub use value::*;
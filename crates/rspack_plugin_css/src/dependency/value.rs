@@ -0,0 +1,249 @@
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsPreset, AsVec},
+};
+use rspack_core::{
+  AsContextDependency, Dependency, DependencyCategory, DependencyCodeGeneration, DependencyId,
+  DependencyRange, DependencyTemplate, DependencyTemplateType, DependencyType,
+  ExtendedReferencedExport, FactorizeInfo, ModuleDependency, ModuleGraph, ModuleIdentifier,
+  RuntimeSpec, TemplateContext, TemplateReplaceSource,
+};
+use rspack_error::{Diagnostic, DiagnosticExt, RspackSeverity, TraceableError};
+use rspack_util::atom::Atom;
+
+use crate::parser_and_generator::CssParserAndGenerator;
+
+/// A use of an imported `@value` that is replaced with the value exported
+/// by the imported module.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CssValueReplacement {
+  pub name: String,
+  pub range: DependencyRange,
+}
+
+/// `@value primary, secondary as accent from "./colors.css";`
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CssValueImportDependency {
+  id: DependencyId,
+  request: String,
+  #[cacheable(with=AsVec<AsPreset>)]
+  names: Vec<Atom>,
+  range: DependencyRange,
+  replaces: Vec<CssValueReplacement>,
+  factorize_info: FactorizeInfo,
+}
+
+impl CssValueImportDependency {
+  pub fn new(
+    request: String,
+    names: Vec<Atom>,
+    range: DependencyRange,
+    replaces: Vec<CssValueReplacement>,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      names,
+      range,
+      replaces,
+      factorize_info: Default::default(),
+    }
+  }
+
+  pub fn names(&self) -> &[Atom] {
+    &self.names
+  }
+}
+
+#[cacheable_dyn]
+impl Dependency for CssValueImportDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CssCompose
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::CssValueImport
+  }
+
+  fn range(&self) -> Option<&DependencyRange> {
+    Some(&self.range)
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::True
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    self
+      .names
+      .iter()
+      .map(|n| ExtendedReferencedExport::Array(vec![n.clone()]))
+      .collect()
+  }
+
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    get_missing_css_exports_diagnostics(self, &self.names, "import @value", module_graph)
+  }
+}
+
+#[cacheable_dyn]
+impl ModuleDependency for CssValueImportDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+
+  fn factorize_info(&self) -> &FactorizeInfo {
+    &self.factorize_info
+  }
+
+  fn factorize_info_mut(&mut self) -> &mut FactorizeInfo {
+    &mut self.factorize_info
+  }
+}
+
+#[cacheable_dyn]
+impl DependencyCodeGeneration for CssValueImportDependency {
+  fn dependency_template(&self) -> Option<DependencyTemplateType> {
+    Some(CssValueImportDependencyTemplate::template_type())
+  }
+}
+
+impl AsContextDependency for CssValueImportDependency {}
+
+#[cacheable]
+#[derive(Debug, Clone, Default)]
+pub struct CssValueImportDependencyTemplate;
+
+impl CssValueImportDependencyTemplate {
+  pub fn template_type() -> DependencyTemplateType {
+    DependencyTemplateType::Dependency(DependencyType::CssValueImport)
+  }
+}
+
+impl DependencyTemplate for CssValueImportDependencyTemplate {
+  fn render(
+    &self,
+    dep: &dyn DependencyCodeGeneration,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let dep = dep
+      .as_any()
+      .downcast_ref::<CssValueImportDependency>()
+      .expect("CssValueImportDependencyTemplate should be used for CssValueImportDependency");
+
+    let module_graph = code_generatable_context.compilation.get_module_graph();
+    let Some(module) = module_graph.module_identifier_by_dependency_id(&dep.id) else {
+      return;
+    };
+    for replace in &dep.replaces {
+      // missing values are reported by `get_diagnostics`, keep the name as is
+      if let Some(value) = resolve_css_value(&module_graph, *module, &replace.name, 0) {
+        source.replace(replace.range.start, replace.range.end, &value, None);
+      }
+    }
+  }
+}
+
+/// Follows `@value` re-exports until the module that defines `name`.
+pub(crate) fn resolve_css_value(
+  module_graph: &ModuleGraph,
+  module: ModuleIdentifier,
+  name: &str,
+  depth: usize,
+) -> Option<String> {
+  // guard against circular `@value` imports
+  if depth > 32 {
+    return None;
+  }
+  let parser = module_graph
+    .module_by_identifier(&module)?
+    .as_normal_module()?
+    .parser_and_generator()
+    .as_any()
+    .downcast_ref::<CssParserAndGenerator>()?;
+  let export = parser.get_export(name)?.first()?;
+  match export.id {
+    None => Some(export.ident.clone()),
+    Some(id) => {
+      let module = module_graph.module_identifier_by_dependency_id(&id)?;
+      resolve_css_value(module_graph, *module, &export.ident, depth + 1)
+    }
+  }
+}
+
+/// Reports the `names` imported by `composes: a from "./a.css"` or
+/// `@value a from "./a.css"` that the imported css module doesn't export.
+pub(crate) fn get_missing_css_exports_diagnostics<T: ModuleDependency>(
+  dep: &T,
+  names: &[Atom],
+  kind: &str,
+  module_graph: &ModuleGraph,
+) -> Option<Vec<Diagnostic>> {
+  let parser = module_graph
+    .get_module_by_dependency_id(dep.id())?
+    .as_normal_module()?
+    .parser_and_generator()
+    .as_any()
+    .downcast_ref::<CssParserAndGenerator>()?;
+  let missing = names
+    .iter()
+    .filter(|name| {
+      parser.get_export(name).is_none()
+        && !parser
+          .local_names
+          .as_ref()
+          .is_some_and(|local_names| local_names.contains_key(&***name))
+    })
+    .collect::<Vec<_>>();
+  if missing.is_empty() {
+    return None;
+  }
+
+  let parent_module_identifier = module_graph.get_parent_module(dep.id())?;
+  let source = module_graph
+    .module_by_identifier(parent_module_identifier)?
+    .source()?
+    .source()
+    .into_owned();
+  let range = dep.range()?;
+  let request = dep.user_request();
+  Some(
+    missing
+      .into_iter()
+      .map(|name| {
+        Diagnostic::from(
+          TraceableError::from_file(
+            source.clone(),
+            range.start as usize,
+            range.end as usize,
+            "CSS Modules error".to_string(),
+            format!("Cannot {kind} \"{name}\" from \"{request}\": the module does not export it."),
+          )
+          .with_severity(RspackSeverity::Error)
+          .boxed(),
+        )
+        .with_hide_stack(Some(true))
+        .with_module_identifier(Some(*parent_module_identifier))
+      })
+      .collect(),
+  )
+}
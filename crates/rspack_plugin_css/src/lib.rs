@@ -6,6 +6,8 @@ pub mod dependency;
 pub mod parser_and_generator;
 pub mod plugin;
 pub mod runtime;
+mod typings;
 mod utils;
 
 pub use plugin::CssPlugin;
+pub use typings::*;
@@ -1,3 +1,5 @@
+mod value;
+
 use std::{
   borrow::Cow,
   sync::{Arc, LazyLock},
@@ -25,15 +27,17 @@ use rspack_error::{
   miette::Diagnostic, IntoTWithDiagnosticArray, Result, RspackSeverity, TWithDiagnosticArray,
 };
 use rspack_hash::{RspackHash, RspackHashDigest};
-use rspack_util::ext::DynHash;
+use rspack_util::{atom::Atom, ext::DynHash};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
   dependency::{
     CssComposeDependency, CssExportDependency, CssImportDependency, CssLayer,
     CssLocalIdentDependency, CssMedia, CssSelfReferenceLocalIdentDependency,
-    CssSelfReferenceLocalIdentReplacement, CssSupports, CssUrlDependency,
+    CssSelfReferenceLocalIdentReplacement, CssSupports, CssUrlDependency, CssValueImportDependency,
+    CssValueReplacement,
   },
+  parser_and_generator::value::{collect_value_references, collect_value_rules, ValueRule},
   utils::{
     css_modules_exports_to_concatenate_module_string, css_modules_exports_to_string,
     css_parsing_traceable_error, export_locals_convention, normalize_url,
@@ -86,6 +90,183 @@ pub struct CssParserAndGenerator {
   pub exports: Option<CssExports>,
   pub local_names: Option<FxHashMap<String, String>>,
   pub hot: bool,
+  pub warn_unused_classes: bool,
+}
+
+impl CssParserAndGenerator {
+  /// The local names (classes, ids and keyframes) of which no export name is
+  /// used, sorted by name.
+  pub fn get_unused_local_names(
+    &self,
+    identifier: ModuleIdentifier,
+    runtime: Option<&RuntimeSpec>,
+    mg: &ModuleGraph,
+  ) -> Vec<&str> {
+    let (Some(local_names), Some(convention)) = (&self.local_names, &self.convention) else {
+      return vec![];
+    };
+    let mut unused = local_names
+      .keys()
+      .filter(|name| {
+        export_locals_convention(name, convention)
+          .iter()
+          .all(|export_name| {
+            mg.get_read_only_export_info(&identifier, export_name.as_str().into())
+              .is_some_and(|export_info| {
+                matches!(
+                  ExportInfoGetter::get_used(export_info.as_data(mg), runtime),
+                  UsageState::Unused
+                )
+              })
+          })
+      })
+      .map(|name| name.as_str())
+      .collect::<Vec<_>>();
+    unused.sort_unstable();
+    unused
+  }
+
+  /// The name `name` is exported as, which is one of the export names of the
+  /// exports convention when `name` isn't exported as is.
+  pub fn get_export_name<'a>(&'a self, name: &'a str) -> Option<Cow<'a, str>> {
+    let exports = self.exports.as_ref()?;
+    if exports.contains_key(name) {
+      return Some(Cow::Borrowed(name));
+    }
+    export_locals_convention(name, self.convention.as_ref()?)
+      .into_iter()
+      .find(|name| exports.contains_key(name))
+      .map(Cow::Owned)
+  }
+
+  /// The export of `@value` or class `name`.
+  pub fn get_export(&self, name: &str) -> Option<&IndexSet<CssExport>> {
+    let name = self.get_export_name(name)?;
+    self.exports.as_ref()?.get(name.as_ref())
+  }
+
+  /// Handles the CSS Modules `@value` definitions and imports, the rules are
+  /// removed from the output and the uses of the values are replaced in place.
+  fn parse_values(
+    &mut self,
+    source_code: &str,
+    dependencies: &mut Vec<Box<dyn Dependency>>,
+    presentational_dependencies: &mut Vec<BoxDependencyTemplate>,
+  ) {
+    let value_rules = collect_value_rules(source_code);
+    if value_rules.is_empty() {
+      return;
+    }
+
+    // local name -> value
+    let mut local_values: IndexMap<&str, &str> = IndexMap::default();
+    // local name -> (imported name, index into `imports`)
+    let mut imported_values: IndexMap<&str, (&str, usize)> = IndexMap::default();
+    let mut imports = vec![];
+    for rule in &value_rules {
+      let (start, end) = rule.range();
+      presentational_dependencies.push(Box::new(ConstDependency::new(
+        (start, end).into(),
+        "".into(),
+        None,
+      )));
+      match rule {
+        ValueRule::Definition { name, value, .. } => {
+          // `@value accent: primary;` aliases a value defined or imported before
+          if let Some(value) = local_values.get(value).copied() {
+            local_values.insert(*name, value);
+          } else if let Some(imported) = imported_values.get(value).copied() {
+            imported_values.insert(*name, imported);
+          } else {
+            local_values.insert(*name, *value);
+          }
+        }
+        ValueRule::Import { names, from, .. } => {
+          // `@value primary from colors;` where `@value colors: "./colors.css";`
+          let from = local_values.get(from).copied().unwrap_or(*from);
+          let request = from.trim_matches(|c| c == '\'' || c == '"');
+          for (imported, local) in names {
+            imported_values.insert(*local, (*imported, imports.len()));
+          }
+          imports.push((
+            request,
+            DependencyRange::new(start, end),
+            names
+              .iter()
+              .map(|(imported, _)| Atom::from(*imported))
+              .collect::<Vec<_>>(),
+            vec![],
+          ));
+        }
+      }
+    }
+
+    let skip = value_rules
+      .iter()
+      .map(|rule| rule.range())
+      .collect::<Vec<_>>();
+    let is_value =
+      |name: &str| local_values.contains_key(name) || imported_values.contains_key(name);
+    for reference in collect_value_references(source_code, &is_value, &skip) {
+      let (start, end) = reference.range;
+      if let Some(value) = local_values.get(reference.name) {
+        presentational_dependencies.push(Box::new(ConstDependency::new(
+          (start, end).into(),
+          (*value).into(),
+          None,
+        )));
+      } else if let Some((imported, index)) = imported_values.get(reference.name) {
+        imports[*index].3.push(CssValueReplacement {
+          name: imported.to_string(),
+          range: DependencyRange::new(start, end),
+        });
+      }
+    }
+
+    let mut dep_ids = vec![];
+    for (request, range, names, replaces) in imports {
+      let dep = CssValueImportDependency::new(request.to_string(), names, range, replaces);
+      dep_ids.push((request, *dep.id()));
+      dependencies.push(Box::new(dep));
+    }
+    // the values are exported with the same names as the classes
+    let convention_names = |name: &str| match &self.convention {
+      Some(convention) => export_locals_convention(name, convention),
+      None => vec![name.to_string()],
+    };
+    let exports = self.exports.get_or_insert_default();
+    let mut export_names = vec![];
+    for (name, value) in &local_values {
+      for name in convention_names(name) {
+        update_css_exports(
+          exports,
+          name.clone(),
+          CssExport {
+            ident: value.to_string(),
+            from: None,
+            id: None,
+          },
+        );
+        export_names.push(name);
+      }
+    }
+    for (name, (imported, index)) in &imported_values {
+      let (request, id) = dep_ids[*index];
+      for name in convention_names(name) {
+        update_css_exports(
+          exports,
+          name.clone(),
+          CssExport {
+            ident: imported.to_string(),
+            from: Some(request.to_string()),
+            id: Some(id),
+          },
+        );
+        export_names.push(name);
+      }
+    }
+    dependencies.push(Box::new(CssExportDependency::new(export_names)));
+  }
 }
 
 #[cacheable_dyn]
@@ -436,6 +617,13 @@ impl ParserAndGenerator for CssParserAndGenerator {
         _ => {}
       }
     }
+    if matches!(mode, css_module_lexer::Mode::Local) {
+      self.parse_values(
+        &source_code,
+        &mut dependencies,
+        &mut presentational_dependencies,
+      );
+    }
     for warning in warnings {
       let range = warning.range();
       let error = css_parsing_traceable_error(
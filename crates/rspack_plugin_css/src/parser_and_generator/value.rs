@@ -0,0 +1,371 @@
+//! The CSS Modules `@value` at-rules, collected from the tokens of
+//! `css_module_lexer` so that comments, strings and `url()`s are skipped the
+//! same way as for the other css dependencies.
+//!
+//! ```css
+//! @value primary: #bf4040;
+//! @value small, large as desktop from "./breakpoints.css";
+//! @media small { .title { color: primary } }
+//! ```
+
+use css_module_lexer::{Lexer, Pos, Visitor};
+
+/// Offsets into the scanned source, in bytes.
+pub type Range = (u32, u32);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ValueRule<'s> {
+  /// `@value name: value;`
+  Definition {
+    name: &'s str,
+    value: &'s str,
+    range: Range,
+  },
+  /// `@value imported as local, other from "./file.css";`
+  Import {
+    /// `(imported, local)` pairs
+    names: Vec<(&'s str, &'s str)>,
+    from: &'s str,
+    range: Range,
+  },
+}
+
+impl ValueRule<'_> {
+  pub fn range(&self) -> Range {
+    match self {
+      ValueRule::Definition { range, .. } | ValueRule::Import { range, .. } => *range,
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ValueReference<'s> {
+  pub name: &'s str,
+  pub range: Range,
+}
+
+/// Collects the `@value` rules of a stylesheet.
+pub fn collect_value_rules(source: &str) -> Vec<ValueRule<'_>> {
+  let mut visitor = ValueVisitor::new(source, None);
+  Lexer::new(source).lex(&mut visitor);
+  let end = source.len() as Pos;
+  visitor.end_prelude(end, end);
+  visitor.rules
+}
+
+/// Collects the identifiers in `source` that refer to one of `names`.
+///
+/// Only declaration values and at-rule preludes are considered: class names and
+/// ids in selectors are local idents handled by `css_module_lexer`, as are the
+/// values of `composes`. The ranges in `skip` (the `@value` rules themselves)
+/// are ignored.
+pub fn collect_value_references<'s>(
+  source: &'s str,
+  names: &dyn Fn(&str) -> bool,
+  skip: &[Range],
+) -> Vec<ValueReference<'s>> {
+  let mut visitor = ValueVisitor::new(source, Some((names, skip)));
+  Lexer::new(source).lex(&mut visitor);
+  visitor.references
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+  Style,
+  AtRule,
+}
+
+struct ValueVisitor<'s, 'a> {
+  source: &'s str,
+  /// The names to look for and the ranges to ignore, when collecting references.
+  references_of: Option<(&'a dyn Fn(&str) -> bool, &'a [Range])>,
+  blocks: Vec<Block>,
+  in_at_rule_prelude: bool,
+  /// The start of the pending `@value` rule and of its prelude.
+  value_rule: Option<(Pos, Pos)>,
+  segment_start: Pos,
+  segment_idents: Vec<Range>,
+  rules: Vec<ValueRule<'s>>,
+  references: Vec<ValueReference<'s>>,
+}
+
+impl<'s, 'a> ValueVisitor<'s, 'a> {
+  fn new(source: &'s str, references_of: Option<(&'a dyn Fn(&str) -> bool, &'a [Range])>) -> Self {
+    Self {
+      source,
+      references_of,
+      blocks: vec![],
+      in_at_rule_prelude: false,
+      value_rule: None,
+      segment_start: 0,
+      segment_idents: vec![],
+      rules: vec![],
+      references: vec![],
+    }
+  }
+
+  fn end_prelude(&mut self, prelude_end: Pos, end: Pos) {
+    self.in_at_rule_prelude = false;
+    let Some((start, prelude_start)) = self.value_rule.take() else {
+      return;
+    };
+    if let Some(rule) = parse_value_rule(
+      &self.source[prelude_start as usize..prelude_end as usize],
+      (start, end),
+    ) {
+      self.rules.push(rule);
+    }
+  }
+
+  /// A segment is the text between two of `{`, `;` and `}`: a selector or an
+  /// at-rule prelude when it is followed by `{`, a declaration otherwise.
+  fn end_segment(&mut self, end: Pos, next_start: Pos, is_declaration: bool) {
+    let idents = std::mem::take(&mut self.segment_idents);
+    let start = std::mem::replace(&mut self.segment_start, next_start) as usize;
+    let Some((names, skip)) = self.references_of else {
+      return;
+    };
+    let segment = &self.source[start..end as usize];
+    let trimmed = segment.trim_start();
+    let offset = start + segment.len() - trimmed.len();
+    let value_start = if trimmed.starts_with('@') {
+      trimmed
+        .find(|c: char| c.is_ascii_whitespace())
+        .map(|index| offset + index)
+    } else if is_declaration {
+      let Some(colon) = trimmed.find(':') else {
+        return;
+      };
+      let property = trimmed[..colon].trim();
+      if property == "composes" || property == "compose-with" {
+        return;
+      }
+      Some(offset + colon + 1)
+    } else {
+      None
+    };
+    let Some(value_start) = value_start else {
+      return;
+    };
+
+    let bytes = self.source.as_bytes();
+    for (ident_start, ident_end) in idents {
+      if (ident_start as usize) < value_start
+        || skip
+          .iter()
+          .any(|(skip_start, skip_end)| (*skip_start..*skip_end).contains(&ident_start))
+      {
+        continue;
+      }
+      let prev = (ident_start as usize)
+        .checked_sub(1)
+        .map(|index| bytes[index])
+        .unwrap_or_default();
+      let name = &self.source[ident_start as usize..ident_end as usize];
+      if !matches!(prev, b'.' | b'#' | b'\\' | b'@') && names(name) {
+        self.references.push(ValueReference {
+          name,
+          range: (ident_start, ident_end),
+        });
+      }
+    }
+  }
+}
+
+impl<'s> Visitor<'s> for ValueVisitor<'s, '_> {
+  fn is_selector(&mut self, _: &mut Lexer<'s>) -> Option<bool> {
+    Some(!self.in_at_rule_prelude && self.blocks.last() != Some(&Block::Style))
+  }
+
+  fn url(&mut self, _: &mut Lexer<'s>, _: Pos, _: Pos, _: Pos, _: Pos) -> Option<()> {
+    Some(())
+  }
+
+  fn string(&mut self, _: &mut Lexer<'s>, _: Pos, _: Pos) -> Option<()> {
+    Some(())
+  }
+
+  fn left_parenthesis(&mut self, _: &mut Lexer<'s>, _: Pos, _: Pos) -> Option<()> {
+    Some(())
+  }
+
+  fn right_parenthesis(&mut self, _: &mut Lexer<'s>, _: Pos, _: Pos) -> Option<()> {
+    Some(())
+  }
+
+  fn comma(&mut self, _: &mut Lexer<'s>, _: Pos, _: Pos) -> Option<()> {
+    Some(())
+  }
+
+  fn function(&mut self, _: &mut Lexer<'s>, _: Pos, _: Pos) -> Option<()> {
+    Some(())
+  }
+
+  fn ident(&mut self, _: &mut Lexer<'s>, start: Pos, end: Pos) -> Option<()> {
+    self.segment_idents.push((start, end));
+    Some(())
+  }
+
+  fn class(&mut self, _: &mut Lexer<'s>, _: Pos, _: Pos) -> Option<()> {
+    Some(())
+  }
+
+  fn id(&mut self, _: &mut Lexer<'s>, _: Pos, _: Pos) -> Option<()> {
+    Some(())
+  }
+
+  fn left_curly_bracket(&mut self, _: &mut Lexer<'s>, start: Pos, end: Pos) -> Option<()> {
+    let block = if self.in_at_rule_prelude {
+      Block::AtRule
+    } else {
+      Block::Style
+    };
+    self.end_prelude(start, start);
+    self.end_segment(start, end, false);
+    self.blocks.push(block);
+    Some(())
+  }
+
+  fn right_curly_bracket(&mut self, _: &mut Lexer<'s>, start: Pos, end: Pos) -> Option<()> {
+    self.end_prelude(start, start);
+    self.end_segment(start, end, true);
+    self.blocks.pop();
+    Some(())
+  }
+
+  fn pseudo_function(&mut self, _: &mut Lexer<'s>, _: Pos, _: Pos) -> Option<()> {
+    Some(())
+  }
+
+  fn pseudo_class(&mut self, _: &mut Lexer<'s>, _: Pos, _: Pos) -> Option<()> {
+    Some(())
+  }
+
+  fn semicolon(&mut self, _: &mut Lexer<'s>, start: Pos, end: Pos) -> Option<()> {
+    self.end_prelude(start, end);
+    self.end_segment(start, end, true);
+    Some(())
+  }
+
+  fn at_keyword(&mut self, lexer: &mut Lexer<'s>, start: Pos, end: Pos) -> Option<()> {
+    self.in_at_rule_prelude = true;
+    if self.blocks.is_empty() && lexer.slice(start, end) == Some("@value") {
+      self.value_rule = Some((start, end));
+    }
+    Some(())
+  }
+}
+
+fn parse_value_rule(prelude: &str, range: Range) -> Option<ValueRule<'_>> {
+  let prelude = prelude.trim();
+  if let Some((names, from)) = split_import(prelude) {
+    let names = names
+      .trim()
+      .trim_start_matches('(')
+      .trim_end_matches(')')
+      .split(',')
+      .filter_map(|name| {
+        let mut parts = name.split_ascii_whitespace();
+        let imported = parts.next()?;
+        let local = match (parts.next(), parts.next()) {
+          (Some("as"), Some(local)) => local,
+          _ => imported,
+        };
+        (is_ident(imported) && is_ident(local)).then_some((imported, local))
+      })
+      .collect::<Vec<_>>();
+    if names.is_empty() {
+      return None;
+    }
+    return Some(ValueRule::Import {
+      names,
+      from: from.trim(),
+      range,
+    });
+  }
+
+  let name_end = prelude
+    .find(|c: char| !is_ident_char(c))
+    .unwrap_or(prelude.len());
+  let (name, value) = prelude.split_at(name_end);
+  if name.is_empty() {
+    return None;
+  }
+  let value = value.trim_start();
+  let value = value.strip_prefix(':').unwrap_or(value).trim();
+  Some(ValueRule::Definition { name, value, range })
+}
+
+/// Splits `a, b as c from "./file.css"` into the names and the request.
+fn split_import(prelude: &str) -> Option<(&str, &str)> {
+  let index = prelude.rfind(" from ")?;
+  let (names, from) = (&prelude[..index], &prelude[index + " from ".len()..]);
+  // `@value selector: a from b;` is a definition that happens to contain `from`
+  if names.contains(':') {
+    return None;
+  }
+  Some((names, from))
+}
+
+fn is_ident_char(c: char) -> bool {
+  c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii()
+}
+
+fn is_ident(s: &str) -> bool {
+  !s.is_empty() && s.chars().all(is_ident_char)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_collect_value_rules() {
+    let source = r#"@value primary: #bf4040;
+@value secondary blue;
+@value small, large as desktop from "./breakpoints.css";
+.a { color: primary }"#;
+    let rules = collect_value_rules(source);
+    assert_eq!(
+      rules,
+      vec![
+        ValueRule::Definition {
+          name: "primary",
+          value: "#bf4040",
+          range: (0, 24),
+        },
+        ValueRule::Definition {
+          name: "secondary",
+          value: "blue",
+          range: (25, 47),
+        },
+        ValueRule::Import {
+          names: vec![("small", "small"), ("large", "desktop")],
+          from: "\"./breakpoints.css\"",
+          range: (48, 104),
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn should_collect_value_references() {
+    let source = r#"@value primary: red;
+@media small { .primary { color: primary; background: url(primary.png) } }
+.b { composes: primary; border: 1px solid primary; --primary: primary }"#;
+    let rules = collect_value_rules(source);
+    let skip = rules.iter().map(|rule| rule.range()).collect::<Vec<_>>();
+    let references =
+      collect_value_references(source, &|name| name == "primary" || name == "small", &skip);
+    let names = references
+      .iter()
+      .map(|reference| reference.name)
+      .collect::<Vec<_>>();
+    // the class selector, the url and the `composes` value are left untouched
+    assert_eq!(names, vec!["small", "primary", "primary", "primary"]);
+    assert_eq!(
+      references[1].range.0 as usize,
+      source.find("color: primary").unwrap() + 7
+    );
+  }
+}
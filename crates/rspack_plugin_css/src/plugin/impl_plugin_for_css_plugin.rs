@@ -15,10 +15,10 @@ use rspack_core::{
     SourceExt,
   },
   AssetInfo, Chunk, ChunkGraph, ChunkKind, ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation,
-  CompilationContentHash, CompilationId, CompilationParams, CompilationRenderManifest,
-  CompilationRuntimeRequirementInTree, CompilerCompilation, CompilerOptions, DependencyType,
-  Module, ModuleGraph, ModuleType, ParserAndGenerator, PathData, Plugin, PublicPath,
-  RenderManifestEntry, RuntimeGlobals, SelfModuleFactory, SourceType,
+  CompilationContentHash, CompilationId, CompilationOptimizeCodeGeneration, CompilationParams,
+  CompilationRenderManifest, CompilationRuntimeRequirementInTree, CompilerCompilation,
+  CompilerOptions, DependencyType, Mode, Module, ModuleGraph, ModuleType, ParserAndGenerator,
+  PathData, Plugin, PublicPath, RenderManifestEntry, RuntimeGlobals, SelfModuleFactory, SourceType,
 };
 use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt};
 use rspack_hash::RspackHash;
@@ -31,11 +31,12 @@ use crate::{
   dependency::{
     CssImportDependencyTemplate, CssLayer, CssLocalIdentDependencyTemplate, CssMedia,
     CssSelfReferenceLocalIdentDependencyTemplate, CssSupports, CssUrlDependencyTemplate,
+    CssValueImportDependencyTemplate,
   },
   parser_and_generator::{CodeGenerationDataUnusedLocalIdent, CssParserAndGenerator},
   plugin::{CssModulesPluginHooks, CssModulesRenderSource, CssPluginInner},
   runtime::CssLoadingRuntimeModule,
-  utils::AUTO_PUBLIC_PATH_PLACEHOLDER,
+  utils::{unused_rule_replacements, AUTO_PUBLIC_PATH_PLACEHOLDER},
   CssPlugin,
 };
 
@@ -93,19 +94,32 @@ impl CssPlugin {
     output_path: &str,
     css_import_modules: Vec<&dyn Module>,
     css_modules: Vec<&dyn Module>,
+    unused_idents: Option<&HashSet<String>>,
   ) -> Result<(BoxSource, Vec<Diagnostic>)> {
     let (ordered_css_modules, conflicts) =
       Self::get_ordered_chunk_css_modules(chunk, compilation, css_import_modules, css_modules);
     let source = Self::render_chunk_to_source(compilation, chunk, &ordered_css_modules).await?;
 
     let content = source.source();
+    let unused_rules = unused_idents
+      .map(|unused_idents| unused_rule_replacements(&content, unused_idents))
+      .unwrap_or_default();
     let len = AUTO_PUBLIC_PATH_PLACEHOLDER.len();
     let auto_public_path_matches: Vec<_> = content
       .match_indices(AUTO_PUBLIC_PATH_PLACEHOLDER)
       .map(|(index, _)| (index, index + len))
+      // the placeholders in the removed rules are gone
+      .filter(|(index, _)| {
+        !unused_rules
+          .iter()
+          .any(|(start, end, _)| (*start..*end).contains(index))
+      })
       .collect();
-    let source = if !auto_public_path_matches.is_empty() {
+    let source = if !auto_public_path_matches.is_empty() || !unused_rules.is_empty() {
       let mut replace = ReplaceSource::new(source);
+      for (start, end, replacement) in unused_rules {
+        replace.replace(start as u32, end as u32, &replacement, None);
+      }
       for (start, end) in auto_public_path_matches {
         let relative = PublicPath::render_auto_public_path(compilation, output_path);
         replace.replace(start as u32, end as u32, &relative, None);
@@ -264,6 +278,10 @@ async fn compilation(
     DependencyType::CssCompose,
     params.normal_module_factory.clone(),
   );
+  compilation.set_dependency_factory(
+    DependencyType::CssValueImport,
+    params.normal_module_factory.clone(),
+  );
   compilation.set_dependency_factory(
     DependencyType::CssSelfReferenceLocalIdent,
     Arc::new(SelfModuleFactory {}),
@@ -284,6 +302,45 @@ async fn compilation(
     CssUrlDependencyTemplate::template_type(),
     Arc::new(CssUrlDependencyTemplate::default()),
   );
+  compilation.set_dependency_template(
+    CssValueImportDependencyTemplate::template_type(),
+    Arc::new(CssValueImportDependencyTemplate::default()),
+  );
+  Ok(())
+}
+
+/// Warns about the local classes that are never imported when the
+/// `warnUnusedClasses` parser option is enabled.
+#[plugin_hook(CompilationOptimizeCodeGeneration for CssPlugin)]
+async fn optimize_code_generation(&self, compilation: &mut Compilation) -> Result<()> {
+  let mut diagnostics = vec![];
+  let module_graph = compilation.get_module_graph();
+  for (module_identifier, module) in module_graph.modules() {
+    let Some(parser) = module.as_normal_module().and_then(|module| {
+      module
+        .parser_and_generator()
+        .as_any()
+        .downcast_ref::<CssParserAndGenerator>()
+    }) else {
+      continue;
+    };
+    if !parser.warn_unused_classes {
+      continue;
+    }
+    for name in parser.get_unused_local_names(module_identifier, None, &module_graph) {
+      diagnostics.push(
+        Diagnostic::warn(
+          "Unused CSS class".into(),
+          format!(
+            "\"{name}\" in {} is never imported",
+            module.readable_identifier(&compilation.options.context)
+          ),
+        )
+        .with_module_identifier(Some(module_identifier)),
+      );
+    }
+  }
+  compilation.extend_diagnostics(diagnostics);
   Ok(())
 }
 
@@ -397,6 +454,10 @@ async fn render_manifest(
   );
   let mut asset_info = AssetInfo::default();
   let unused_idents = Self::get_chunk_unused_local_idents(compilation, chunk, &css_modules);
+  // the rules that only apply to unused local idents are dropped in production
+  let unused_rule_idents = (matches!(compilation.options.mode, Mode::Production)
+    && !unused_idents.is_empty())
+  .then(|| unused_idents.clone());
   asset_info.set_css_unused_idents(unused_idents);
   let output_path = compilation
     .get_path_with_info(
@@ -434,6 +495,7 @@ async fn render_manifest(
           &output_path,
           css_import_modules,
           css_modules,
+          unused_rule_idents.as_ref(),
         )
         .await?;
       Ok((CachedSource::new(source).boxed(), diagnostics))
@@ -467,6 +529,11 @@ impl Plugin for CssPlugin {
       .compiler_hooks
       .compilation
       .tap(compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .optimize_code_generation
+      .tap(optimize_code_generation::new(self));
     ctx
       .context
       .compilation_hooks
@@ -502,6 +569,7 @@ impl Plugin for CssPlugin {
           es_module: g.es_module.expect("should have es_module"),
           hot: false,
           url: p.url.expect("should have url"),
+          warn_unused_classes: false,
         }) as Box<dyn ParserAndGenerator>
       }),
     );
@@ -531,6 +599,7 @@ impl Plugin for CssPlugin {
           es_module: g.es_module.expect("should have es_module"),
           hot: false,
           url: p.url.expect("should have url"),
+          warn_unused_classes: p.warn_unused_classes.unwrap_or_default(),
         }) as Box<dyn ParserAndGenerator>
       }),
     );
@@ -560,6 +629,7 @@ impl Plugin for CssPlugin {
           es_module: g.es_module.expect("should have es_module"),
           hot: false,
           url: p.url.expect("should have url"),
+          warn_unused_classes: p.warn_unused_classes.unwrap_or_default(),
         }) as Box<dyn ParserAndGenerator>
       }),
     );
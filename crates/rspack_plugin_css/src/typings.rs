@@ -0,0 +1,247 @@
+use std::fmt::Write;
+
+use rspack_core::{
+  contextify,
+  rspack_sources::{RawStringSource, SourceExt},
+  ApplyContext, Compilation, CompilationAsset, CompilationProcessAssets, CompilerEmit,
+  CompilerOptions, Plugin, PluginContext,
+};
+use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rspack_util::fx_hash::FxDashMap;
+use serde_json::json;
+
+use crate::parser_and_generator::CssParserAndGenerator;
+
+#[derive(Debug, Clone)]
+pub enum CssModulesTypingsOutput {
+  /// Writes a `<name>.css.d.ts` next to each css module.
+  Source,
+  /// Emits a single json asset that maps each css module to its exports.
+  Manifest(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct CssModulesTypingsPluginOptions {
+  pub output: CssModulesTypingsOutput,
+}
+
+/// Generates TypeScript declarations for the exports of css modules, named
+/// after the configured `exportsConvention`.
+#[plugin]
+#[derive(Debug)]
+pub struct CssModulesTypingsPlugin {
+  options: CssModulesTypingsPluginOptions,
+  /// declaration path -> content written by the previous compilation
+  written: FxDashMap<Utf8PathBuf, String>,
+}
+
+impl CssModulesTypingsPlugin {
+  pub fn new(options: CssModulesTypingsPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+}
+
+struct CssModuleTypings {
+  resource_path: Utf8PathBuf,
+  named_exports: bool,
+  exports: Vec<String>,
+}
+
+fn collect_css_module_typings(compilation: &Compilation) -> Vec<CssModuleTypings> {
+  let module_graph = compilation.get_module_graph();
+  let mut typings = module_graph
+    .modules()
+    .into_values()
+    .filter_map(|module| {
+      let module = module.as_normal_module()?;
+      let parser = module
+        .parser_and_generator()
+        .as_any()
+        .downcast_ref::<CssParserAndGenerator>()?;
+      let exports = parser.exports.as_ref()?;
+      Some(CssModuleTypings {
+        resource_path: module.resource_resolved_data().resource_path.clone()?,
+        named_exports: parser.named_exports,
+        exports: exports.keys().cloned().collect(),
+      })
+    })
+    .collect::<Vec<_>>();
+  // the same file may be requested with different queries
+  typings.sort_unstable_by(|a, b| a.resource_path.cmp(&b.resource_path));
+  typings.dedup_by(|a, b| a.resource_path == b.resource_path);
+  typings
+}
+
+/// Renders the declaration of a css module, with named exports the names that
+/// are not valid identifiers are left out, which is reported by `emit`.
+fn render_declaration(typings: &CssModuleTypings) -> String {
+  let mut code = String::from("// This file is generated by rspack, do not edit.\n");
+  if typings.named_exports {
+    for name in &typings.exports {
+      if is_valid_identifier(name) {
+        let _ = writeln!(code, "export const {name}: string;");
+      }
+    }
+    if typings.exports.is_empty() {
+      code.push_str("export {};\n");
+    }
+  } else {
+    code.push_str("declare const styles: {\n");
+    for name in &typings.exports {
+      let _ = writeln!(
+        code,
+        "  readonly {}: string;",
+        serde_json::to_string(name).expect("should json stringify")
+      );
+    }
+    code.push_str("};\nexport default styles;\n");
+  }
+  code
+}
+
+fn is_valid_identifier(name: &str) -> bool {
+  let mut chars = name.chars();
+  chars
+    .next()
+    .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+    && name != "default"
+}
+
+#[plugin_hook(CompilationProcessAssets for CssModulesTypingsPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let CssModulesTypingsOutput::Manifest(filename) = &self.options.output else {
+    return Ok(());
+  };
+
+  let manifest = collect_css_module_typings(compilation)
+    .into_iter()
+    .map(|typings| {
+      (
+        contextify(&compilation.options.context, typings.resource_path.as_str()),
+        json!({
+          "namedExports": typings.named_exports,
+          "exports": typings.exports,
+        }),
+      )
+    })
+    .collect::<serde_json::Map<_, _>>();
+  let manifest = serde_json::to_string_pretty(&manifest).to_rspack_result()?;
+  compilation.emit_asset(
+    filename.clone(),
+    CompilationAsset::from(RawStringSource::from(manifest).boxed()),
+  );
+  Ok(())
+}
+
+#[plugin_hook(CompilerEmit for CssModulesTypingsPlugin)]
+async fn emit(&self, compilation: &mut Compilation) -> Result<()> {
+  if !matches!(self.options.output, CssModulesTypingsOutput::Source) {
+    return Ok(());
+  }
+
+  for typings in collect_css_module_typings(compilation) {
+    if typings.named_exports {
+      let invalid_names = typings
+        .exports
+        .iter()
+        .filter(|name| !is_valid_identifier(name))
+        .map(|name| format!("\"{name}\""))
+        .collect::<Vec<_>>();
+      if !invalid_names.is_empty() {
+        compilation.push_diagnostic(Diagnostic::warn(
+          "CssModulesTypingsPlugin".to_string(),
+          format!(
+            "The declarations of {} leave out {}, as they are not valid identifiers for named exports. Use `exportsConvention: \"camel-case-only\"` or disable `namedExports` to type them.",
+            contextify(&compilation.options.context, typings.resource_path.as_str()),
+            invalid_names.join(", "),
+          ),
+        ));
+      }
+    }
+
+    let path = Utf8PathBuf::from(format!("{}.d.ts", typings.resource_path));
+    let content = render_declaration(&typings);
+    if self
+      .written
+      .get(&path)
+      .is_some_and(|written| *written == content)
+    {
+      continue;
+    }
+    // avoid touching unchanged declarations so that the watchers of the
+    // type checker are not triggered
+    let existing = compilation.input_filesystem.read(&path).await.ok();
+    if existing.as_deref() != Some(content.as_bytes()) {
+      write_declaration(compilation, &path, &content).await?;
+    }
+    self.written.insert(path, content);
+  }
+  Ok(())
+}
+
+async fn write_declaration(
+  compilation: &Compilation,
+  path: &Utf8Path,
+  content: &str,
+) -> Result<()> {
+  let intermediate_filesystem = compilation.intermediate_filesystem.as_ref();
+  if let Some(dir) = path.parent() {
+    intermediate_filesystem.create_dir_all(dir).await?;
+  }
+  intermediate_filesystem
+    .write(path, content.as_bytes())
+    .await?;
+  Ok(())
+}
+
+impl Plugin for CssModulesTypingsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.CssModulesTypingsPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    ctx.context.compiler_hooks.emit.tap(emit::new(self));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_render_declarations() {
+    let typings = CssModuleTypings {
+      resource_path: "/src/button.module.css".into(),
+      named_exports: false,
+      exports: vec!["button".to_string(), "is-active".to_string()],
+    };
+    assert_eq!(
+      render_declaration(&typings),
+      r#"// This file is generated by rspack, do not edit.
+declare const styles: {
+  readonly "button": string;
+  readonly "is-active": string;
+};
+export default styles;
+"#
+    );
+
+    let typings = CssModuleTypings {
+      named_exports: true,
+      ..typings
+    };
+    assert_eq!(
+      render_declaration(&typings),
+      "// This file is generated by rspack, do not edit.\nexport const button: string;\n"
+    );
+  }
+}
@@ -8,6 +8,11 @@ use std::{
 use cow_utils::CowUtils;
 use heck::{ToKebabCase, ToLowerCamelCase};
 use indexmap::{IndexMap, IndexSet};
+use lightningcss::{
+  rules::CssRule,
+  selector::{Component, Selector},
+  stylesheet::{ParserOptions, StyleSheet},
+};
 use regex::{Captures, Regex};
 use rspack_core::{
   rspack_sources::{ConcatSource, RawStringSource},
@@ -22,7 +27,7 @@ use rspack_hash::RspackHash;
 use rspack_util::{identifier::make_paths_relative, itoa, json_stringify};
 use rustc_hash::FxHashSet as HashSet;
 
-use crate::parser_and_generator::CssExport;
+use crate::parser_and_generator::{CssExport, CssParserAndGenerator};
 
 pub const AUTO_PUBLIC_PATH_PLACEHOLDER: &str = "__RSPACK_PLUGIN_CSS_AUTO_PUBLIC_PATH__";
 pub static LEADING_DIGIT_REGEX: LazyLock<Regex> =
@@ -208,6 +213,19 @@ pub fn stringified_exports<'a>(
             })
             .expect("should have css from module");
 
+          let ident = unescape(ident);
+          // the imported module may export the name with its exports convention
+          let export_name = module_graph
+            .module_by_identifier(&from.module_identifier)
+            .and_then(|module| module.as_normal_module())
+            .and_then(|module| {
+              module
+                .parser_and_generator()
+                .as_any()
+                .downcast_ref::<CssParserAndGenerator>()
+            })
+            .and_then(|parser| parser.get_export_name(&ident).map(Cow::into_owned))
+            .unwrap_or_else(|| ident.to_string());
           let from = serde_json::to_string(
             ChunkGraph::get_module_id(&compilation.module_ids_artifact, from.module_identifier)
               .expect("should have module"),
@@ -217,7 +235,7 @@ pub fn stringified_exports<'a>(
           format!(
             "{}({from})[{}]",
             RuntimeGlobals::REQUIRE,
-            json_stringify(&unescape(ident))
+            json_stringify(&export_name)
           )
         }
       })
@@ -446,3 +464,203 @@ pub fn replace_module_request_prefix<'s>(
     specifier
   }
 }
+
+/// Finds the style rules of a css chunk that can not match because their
+/// selectors require a local ident in `unused_idents`.
+///
+/// Returns `(start, end, replacement)`: a rule is removed when all of its
+/// selectors are unused, otherwise only the unused selectors are removed.
+pub fn unused_rule_replacements(
+  css: &str,
+  unused_idents: &HashSet<String>,
+) -> Vec<(usize, usize, String)> {
+  let bytes = css.as_bytes();
+  let mut replacements = vec![];
+  let mut prelude_start = 0;
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_css_comment(bytes, i),
+      b'"' | b'\'' => i = skip_css_string(bytes, i),
+      b';' | b'}' => {
+        i += 1;
+        prelude_start = i;
+      }
+      b'{' => {
+        let prelude = &css[prelude_start..i];
+        let start = prelude_start + prelude.len() - prelude.trim_start().len();
+        let prelude = prelude.trim();
+        if !prelude.is_empty() && !prelude.starts_with('@') {
+          let selectors = split_selectors(prelude);
+          let used = selectors
+            .iter()
+            .filter(|selector| !references_unused_ident(selector, unused_idents))
+            .collect::<Vec<_>>();
+          if used.is_empty() {
+            let end = find_block_end(bytes, i);
+            replacements.push((start, end, String::new()));
+            i = end;
+            prelude_start = end;
+            continue;
+          }
+          if used.len() != selectors.len() {
+            let used = used.into_iter().copied().collect::<Vec<_>>();
+            replacements.push((start, i, used.join(", ")));
+          }
+        }
+        i += 1;
+        prelude_start = i;
+      }
+      _ => i += 1,
+    }
+  }
+  replacements
+}
+
+fn split_selectors(prelude: &str) -> Vec<&str> {
+  let bytes = prelude.as_bytes();
+  let mut selectors = vec![];
+  let mut depth = 0usize;
+  let mut start = 0;
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'"' | b'\'' => {
+        i = skip_css_string(bytes, i);
+        continue;
+      }
+      b'\\' => i += 1,
+      b'(' | b'[' => depth += 1,
+      b')' | b']' => depth = depth.saturating_sub(1),
+      b',' if depth == 0 => {
+        selectors.push(prelude[start..i].trim());
+        start = i + 1;
+      }
+      _ => {}
+    }
+    i += 1;
+  }
+  selectors.push(prelude[start..].trim());
+  selectors
+}
+
+/// Whether `selector` can only match elements with a class or id in
+/// `unused_idents`, i.e. one of its compound selectors requires it.
+///
+/// The idents in the arguments of `:not()`, `:has()` and the other functional
+/// pseudo-classes don't count, unless every alternative of an `:is()` or a
+/// `:where()` requires one.
+fn references_unused_ident(selector: &str, unused_idents: &HashSet<String>) -> bool {
+  // parsed as the prelude of an empty rule, the selectors that lightningcss
+  // can't parse are kept
+  let source = format!("{selector}{{}}");
+  let Ok(stylesheet) = StyleSheet::parse(&source, ParserOptions::default()) else {
+    return false;
+  };
+  let [CssRule::Style(rule)] = stylesheet.rules.0.as_slice() else {
+    return false;
+  };
+  rule
+    .selectors
+    .0
+    .iter()
+    .all(|selector| requires_unused_ident(selector, unused_idents))
+}
+
+fn requires_unused_ident(selector: &Selector, unused_idents: &HashSet<String>) -> bool {
+  selector
+    .iter_raw_match_order()
+    .any(|component| match component {
+      Component::Class(name) | Component::ID(name) => unused_idents.contains(&**name),
+      Component::Is(selectors) | Component::Where(selectors) | Component::Any(_, selectors) => {
+        selectors
+          .iter()
+          .all(|selector| requires_unused_ident(selector, unused_idents))
+      }
+      _ => false,
+    })
+}
+
+fn find_block_end(bytes: &[u8], start: usize) -> usize {
+  let mut depth = 0usize;
+  let mut i = start;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        i = skip_css_comment(bytes, i);
+        continue;
+      }
+      b'"' | b'\'' => {
+        i = skip_css_string(bytes, i);
+        continue;
+      }
+      b'{' => depth += 1,
+      b'}' => {
+        depth -= 1;
+        if depth == 0 {
+          return i + 1;
+        }
+      }
+      _ => {}
+    }
+    i += 1;
+  }
+  bytes.len()
+}
+
+fn skip_css_comment(bytes: &[u8], start: usize) -> usize {
+  let mut i = start + 2;
+  while i + 1 < bytes.len() {
+    if bytes[i] == b'*' && bytes[i + 1] == b'/' {
+      return i + 2;
+    }
+    i += 1;
+  }
+  bytes.len()
+}
+
+fn skip_css_string(bytes: &[u8], start: usize) -> usize {
+  let quote = bytes[start];
+  let mut i = start + 1;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'\\' => i += 2,
+      c if c == quote || c == b'\n' => return i + 1,
+      _ => i += 1,
+    }
+  }
+  bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_remove_unused_rules() {
+    let css = ".a_used { color: red }\n.b_unused, .a_used:hover { color: blue }\n@media print { .b_unused > span { display: none } }\n.c\\.x_unused { top: 0 }\n";
+    let unused = HashSet::from_iter(["b_unused".to_string(), "c.x_unused".to_string()]);
+    let mut result = css.to_string();
+    for (start, end, replacement) in unused_rule_replacements(css, &unused).into_iter().rev() {
+      result.replace_range(start..end, &replacement);
+    }
+    assert_eq!(
+      result,
+      ".a_used { color: red }\n.a_used:hover{ color: blue }\n@media print {  }\n\n"
+    );
+  }
+
+  #[test]
+  fn should_keep_rules_with_unused_idents_in_pseudo_class_arguments() {
+    let css = ".a:not(.b_unused) { color: red }\n:is(.b_unused, .a) { color: blue }\n:where(.b_unused) span, .a:has(.b_unused) { top: 0 }\n";
+    let unused = HashSet::from_iter(["b_unused".to_string()]);
+    let mut result = css.to_string();
+    for (start, end, replacement) in unused_rule_replacements(css, &unused).into_iter().rev() {
+      result.replace_range(start..end, &replacement);
+    }
+    assert_eq!(
+      result,
+      ".a:not(.b_unused) { color: red }\n:is(.b_unused, .a) { color: blue }\n.a:has(.b_unused){ top: 0 }\n"
+    );
+  }
+}
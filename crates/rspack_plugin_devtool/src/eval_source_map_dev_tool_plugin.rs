@@ -16,10 +16,12 @@ use rspack_plugin_javascript::{
   JavascriptModulesChunkHash, JavascriptModulesInlineInRuntimeBailout,
   JavascriptModulesRenderModuleContent, JsPlugin, RenderSource,
 };
-use rspack_util::identifier::make_paths_absolute;
+use rspack_util::{asset_condition::AssetConditions, identifier::make_paths_absolute};
 
 use crate::{
-  generate_debug_id::generate_debug_id, module_filename_helpers::ModuleFilenameHelpers,
+  generate_debug_id::generate_debug_id,
+  ignore_list::{ignored_sources, to_json_with_ignore_list},
+  module_filename_helpers::ModuleFilenameHelpers,
   ModuleFilenameTemplate, ModuleOrSource, SourceMapDevToolPluginOptions,
 };
 
@@ -35,6 +37,7 @@ pub struct EvalSourceMapDevToolPlugin {
   namespace: String,
   source_root: Option<String>,
   debug_ids: bool,
+  ignore_list: Option<AssetConditions>,
   // TODO: memory leak if not clear across multiple compilations
   cache: DashMap<RspackHashDigest, BoxSource>,
 }
@@ -57,6 +60,7 @@ impl EvalSourceMapDevToolPlugin {
       namespace,
       options.source_root,
       options.debug_ids,
+      options.ignore_list,
       Default::default(),
     )
   }
@@ -189,7 +193,11 @@ async fn eval_source_map_devtool_plugin_render_module_content(
         )));
       }
 
-      let mut map_buffer = Vec::new();
+      let ignored = self
+        .ignore_list
+        .as_ref()
+        .map(|ignore_list| ignored_sources(&map, ignore_list))
+        .unwrap_or_default();
       let module_ids = &compilation.module_ids_artifact;
       // align with https://github.com/webpack/webpack/blob/3919c844eca394d73ca930e4fc5506fb86e2b094/lib/EvalSourceMapDevToolPlugin.js#L171
       let module_id =
@@ -198,10 +206,9 @@ async fn eval_source_map_devtool_plugin_render_module_content(
         } else {
           "unknown"
         };
-      map
-        .to_writer(&mut map_buffer)
-        .unwrap_or_else(|e| panic!("{}", e.to_string()));
-      let base64 = rspack_base64::encode_to_string(&map_buffer);
+      let map_json =
+        to_json_with_ignore_list(map, &ignored).unwrap_or_else(|e| panic!("{}", e.to_string()));
+      let base64 = rspack_base64::encode_to_string(map_json.as_bytes());
       let footer =
         format!("\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,{base64}\n//# sourceURL=webpack-internal:///{module_id}\n");
       let module_content =
@@ -0,0 +1,53 @@
+use rspack_core::rspack_sources::SourceMap;
+use rspack_error::{Result, ToStringResultToRspackResultExt};
+use rspack_regex::RspackRegex;
+use rspack_util::asset_condition::{AssetCondition, AssetConditions};
+use serde::Serialize;
+
+/// The `ignoreList` used by `devtool`: sources of third-party packages and of
+/// the rspack runtime, so debuggers can skip their frames.
+pub fn default_ignore_list() -> AssetConditions {
+  AssetConditions::Multiple(vec![
+    AssetCondition::Regexp(
+      RspackRegex::new(r"[\\/]node_modules[\\/]").expect("should be a valid regex"),
+    ),
+    AssetCondition::Regexp(
+      RspackRegex::new(r"webpack[\\/]runtime[\\/]").expect("should be a valid regex"),
+    ),
+  ])
+}
+
+pub(crate) fn ignored_sources(map: &SourceMap, ignore_list: &AssetConditions) -> Vec<usize> {
+  map
+    .sources()
+    .iter()
+    .enumerate()
+    .filter(|(_, source)| ignore_list.try_match(source))
+    .map(|(index, _)| index)
+    .collect()
+}
+
+/// `rspack_sources::SourceMap` has no `ignoreList` field, so the map is
+/// serialized with a wrapper holding it. Both the standard `ignoreList` and the
+/// `x_google_ignoreList` read by older Chrome DevTools are written.
+#[derive(Serialize)]
+struct SourceMapWithIgnoreList<'a> {
+  #[serde(flatten)]
+  map: &'a SourceMap,
+  #[serde(rename = "ignoreList")]
+  ignore_list: &'a [usize],
+  #[serde(rename = "x_google_ignoreList")]
+  x_google_ignore_list: &'a [usize],
+}
+
+pub(crate) fn to_json_with_ignore_list(map: SourceMap, ignored: &[usize]) -> Result<String> {
+  if ignored.is_empty() {
+    return map.to_json().to_rspack_result();
+  }
+  serde_json::to_string(&SourceMapWithIgnoreList {
+    map: &map,
+    ignore_list: ignored,
+    x_google_ignore_list: ignored,
+  })
+  .to_rspack_result()
+}
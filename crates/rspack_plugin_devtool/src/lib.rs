@@ -3,20 +3,26 @@
 mod eval_dev_tool_module_plugin;
 mod eval_source_map_dev_tool_plugin;
 mod generate_debug_id;
+mod ignore_list;
 mod mapped_assets_cache;
 mod module_filename_helpers;
 mod source_map_dev_tool_module_options_plugin;
 mod source_map_dev_tool_plugin;
+mod source_map_validation_plugin;
+mod symbolicate;
 
 use std::sync::Arc;
 
 pub use eval_dev_tool_module_plugin::*;
 pub use eval_source_map_dev_tool_plugin::*;
 use futures::future::BoxFuture;
+pub use ignore_list::default_ignore_list;
 use rspack_core::ModuleIdentifier;
 use rspack_error::Result;
 pub use source_map_dev_tool_module_options_plugin::*;
 pub use source_map_dev_tool_plugin::*;
+pub use source_map_validation_plugin::*;
+pub use symbolicate::*;
 
 pub type ModuleFilenameTemplateFn =
   Arc<dyn Fn(ModuleFilenameTemplateFnCtx) -> BoxFuture<'static, Result<String>> + Sync + Send>;
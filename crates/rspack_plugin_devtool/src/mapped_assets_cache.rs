@@ -3,7 +3,7 @@ use futures::Future;
 use rspack_core::CompilationAsset;
 use rspack_error::{Error, Result};
 
-use crate::MappedAsset;
+use crate::{MappedAsset, Symbolicator};
 
 #[derive(Debug, Clone)]
 pub struct MappedAssetsCache(DashMap<String, MappedAsset>);
@@ -40,15 +40,33 @@ impl MappedAssetsCache {
 
     self.0.clear();
     for mapped_asset in &mapped_asstes {
-      let MappedAsset {
-        asset: (filename, asset),
-        ..
-      } = mapped_asset;
-      if !asset.info.version.is_empty() {
-        self.0.insert(filename.to_owned(), mapped_asset.clone());
-      }
+      // Assets without a version are never reused, but are kept for the
+      // symbolicator.
+      self
+        .0
+        .insert(mapped_asset.asset.0.to_owned(), mapped_asset.clone());
     }
 
     Ok(mapped_asstes)
   }
+
+  /// Adds the source maps of the last processed assets to the symbolicator,
+  /// without reading them back from the output directory.
+  pub fn add_to_symbolicator(&self, symbolicator: &mut Symbolicator) -> Result<()> {
+    for mapped_asset in self.0.iter() {
+      let MappedAsset {
+        asset: (filename, asset),
+        source_map,
+      } = mapped_asset.value();
+      let Some(source) = asset.get_source() else {
+        continue;
+      };
+      let source_map = source_map
+        .as_ref()
+        .and_then(|(_, source_map)| source_map.get_source())
+        .map(|source_map| source_map.source());
+      symbolicator.add_asset(filename, &source.source(), source_map.as_deref())?;
+    }
+    Ok(())
+  }
 }
@@ -14,19 +14,24 @@ use regex::Regex;
 use rspack_collections::DatabaseItem;
 use rspack_core::{
   rspack_sources::{ConcatSource, MapOptions, RawStringSource, Source, SourceExt},
-  AssetInfo, Chunk, ChunkUkey, Compilation, CompilationAsset, CompilationProcessAssets, Filename,
-  Logger, ModuleIdentifier, PathData, Plugin, PluginContext,
+  AssetInfo, Chunk, ChunkUkey, Compilation, CompilationAsset, CompilationId,
+  CompilationProcessAssets, Filename, Logger, ModuleIdentifier, PathData, Plugin, PluginContext,
 };
-use rspack_error::{error, miette::IntoDiagnostic, Result};
+use rspack_error::{error, Result};
 use rspack_hash::RspackHash;
 use rspack_hook::{plugin, plugin_hook};
-use rspack_util::{asset_condition::AssetConditions, identifier::make_paths_absolute};
+use rspack_util::{
+  asset_condition::AssetConditions, fx_hash::FxDashMap, identifier::make_paths_absolute,
+};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use sugar_path::SugarPath;
 
 use crate::{
-  generate_debug_id::generate_debug_id, mapped_assets_cache::MappedAssetsCache,
-  module_filename_helpers::ModuleFilenameHelpers, ModuleFilenameTemplateFn, ModuleOrSource,
+  generate_debug_id::generate_debug_id,
+  ignore_list::{ignored_sources, to_json_with_ignore_list},
+  mapped_assets_cache::MappedAssetsCache,
+  module_filename_helpers::ModuleFilenameHelpers,
+  ModuleFilenameTemplateFn, ModuleOrSource, Symbolicator,
 };
 
 static SCHEMA_SOURCE_REGEXP: LazyLock<Regex> =
@@ -84,6 +89,8 @@ pub struct SourceMapDevToolPluginOptions {
   pub include: Option<AssetConditions>,
   pub exclude: Option<AssetConditions>,
   pub debug_ids: bool,
+  // Decide whether to mark sources as ignored in the 'ignoreList' of the SourceMap, matched against the names in its 'sources' array.
+  pub ignore_list: Option<AssetConditions>,
 }
 
 enum SourceMappingUrlComment {
@@ -96,6 +103,25 @@ enum SourceMappingUrlCommentRef<'a> {
   Fn(&'a AppendFn),
 }
 
+/// The mapped assets caches of the `SourceMapDevToolPlugin`s of each
+/// compilation, kept until the next compilation to symbolicate its errors.
+static COMPILATION_MAPPED_ASSETS_CACHES: LazyLock<
+  FxDashMap<CompilationId, Vec<Arc<MappedAssetsCache>>>,
+> = LazyLock::new(Default::default);
+
+/// Builds a symbolicator from the source maps emitted by the compilation,
+/// `None` when no `SourceMapDevToolPlugin` processed its assets.
+pub fn get_compilation_symbolicator(id: CompilationId) -> Result<Option<Symbolicator>> {
+  let Some(caches) = COMPILATION_MAPPED_ASSETS_CACHES.get(&id) else {
+    return Ok(None);
+  };
+  let mut symbolicator = Symbolicator::new();
+  for cache in caches.iter() {
+    cache.add_to_symbolicator(&mut symbolicator)?;
+  }
+  Ok(Some(symbolicator))
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct MappedAsset {
   pub(crate) asset: (String, CompilationAsset),
@@ -124,7 +150,8 @@ pub struct SourceMapDevToolPlugin {
   include: Option<AssetConditions>,
   exclude: Option<AssetConditions>,
   debug_ids: bool,
-  mapped_assets_cache: MappedAssetsCache,
+  ignore_list: Option<AssetConditions>,
+  mapped_assets_cache: Arc<MappedAssetsCache>,
 }
 
 fn match_object(obj: &SourceMapDevToolPlugin, str: &str) -> bool {
@@ -189,7 +216,8 @@ impl SourceMapDevToolPlugin {
       options.include,
       options.exclude,
       options.debug_ids,
-      MappedAssetsCache::new(),
+      options.ignore_list,
+      Arc::new(MappedAssetsCache::new()),
     )
   }
 
@@ -425,7 +453,12 @@ impl SourceMapDevToolPlugin {
                 debug_id
               });
 
-              (Some(map.to_json().into_diagnostic()?), debug_id)
+              let ignored = self
+                .ignore_list
+                .as_ref()
+                .map(|ignore_list| ignored_sources(&map, ignore_list))
+                .unwrap_or_default();
+              (Some(to_json_with_ignore_list(map, &ignored)?), debug_id)
             }
             None => (None, None),
           };
@@ -633,6 +666,10 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       self.map_assets(compilation, &file_to_chunk, assets)
     })
     .await?;
+  COMPILATION_MAPPED_ASSETS_CACHES
+    .entry(compilation.id())
+    .or_default()
+    .push(self.mapped_assets_cache.clone());
   logger.time_end(start);
 
   let start = logger.time("emit source map assets");
@@ -681,4 +718,8 @@ impl Plugin for SourceMapDevToolPlugin {
       .tap(process_assets::new(self));
     Ok(())
   }
+
+  fn clear_cache(&self, id: CompilationId) {
+    COMPILATION_MAPPED_ASSETS_CACHES.remove(&id);
+  }
 }
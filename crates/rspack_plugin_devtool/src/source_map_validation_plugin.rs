@@ -0,0 +1,357 @@
+use std::{collections::BTreeMap, sync::LazyLock};
+
+use rayon::prelude::*;
+use regex::Regex;
+use rspack_core::{
+  rspack_sources::{RawStringSource, SourceExt, SourceMap},
+  ApplyContext, Compilation, CompilationAsset, CompilationProcessAssets, CompilerOptions, Plugin,
+  PluginContext,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_fs::ReadableFileSystem;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
+use serde::Serialize;
+use sugar_path::SugarPath;
+
+const PLUGIN_NAME: &str = "rspack.SourceMapValidationPlugin";
+
+/// How many issues of each asset are printed in its warning.
+const MAX_REPORTED_ISSUES: usize = 5;
+
+static INLINE_SOURCE_MAP_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(
+    r"sourceMappingURL=data:application/json;(?:charset=utf-8;)?base64,([A-Za-z0-9+/=]+)\s*(?:\*/)?\s*$",
+  )
+    .expect("failed to compile INLINE_SOURCE_MAP_REGEXP")
+});
+static COMMENT_LINE_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"^\s*(?://|/\*)# (?:sourceMappingURL|debugId)=")
+    .expect("failed to compile COMMENT_LINE_REGEXP")
+});
+static RUNTIME_SOURCE_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"webpack[\\/]runtime[\\/]").expect("failed to compile RUNTIME_SOURCE_REGEXP")
+});
+// Urls of other hosts and modules without a file, like externals, are named
+// by their url or identifier.
+static UNCHECKED_SOURCE_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"^(?:https?:|data:)|(?:^|/)(?:external |ignored\|)")
+    .expect("failed to compile UNCHECKED_SOURCE_REGEXP")
+});
+
+#[derive(Debug)]
+pub struct SourceMapValidationPluginOptions {
+  /// Warn about assets whose mapped generated bytes are below this ratio, from
+  /// 0 to 1.
+  pub min_coverage: f64,
+  /// Emit the per-asset report as a JSON asset with this filename.
+  pub filename: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AssetReport {
+  source_map: String,
+  /// Ratio of generated bytes covered by a mapping to an original source.
+  /// Bytes mapped to runtime code aren't covered.
+  coverage: f64,
+  issues: Vec<String>,
+}
+
+/// Validates the source maps emitted by `SourceMapDevToolPlugin`, both
+/// external and inlined ones, and reports how much of each asset is mapped.
+#[plugin]
+#[derive(Debug)]
+pub struct SourceMapValidationPlugin {
+  options: SourceMapValidationPluginOptions,
+}
+
+impl SourceMapValidationPlugin {
+  pub fn new(options: SourceMapValidationPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+/// Resolves the sources of a map without `sourcesContent` to the files they
+/// name.
+struct SourceResolver<'a> {
+  fs: &'a dyn ReadableFileSystem,
+  context: &'a Utf8Path,
+  /// The directory of the source map, relative sources are resolved from it.
+  map_dir: Utf8PathBuf,
+}
+
+impl SourceResolver<'_> {
+  /// Whether `source` names an existing file, `None` when it can't be checked,
+  /// like urls of other hosts or modules without a file.
+  fn is_resolvable(&self, source: &str, source_root: Option<&str>) -> Option<bool> {
+    if UNCHECKED_SOURCE_REGEXP.is_match(source) {
+      return None;
+    }
+    let path = source.split(['?', '#']).next().unwrap_or(source);
+    let candidates = if let Some(rest) = path.strip_prefix("webpack://") {
+      // `webpack://[namespace]/[resourcePath]` is relative to the context,
+      // and the namespace may contain a `/`.
+      rest
+        .match_indices('/')
+        .map(|(index, _)| self.context.join(&rest[index + 1..]))
+        .collect()
+    } else if let Some(resource_path) = path.strip_prefix("webpack-internal:///") {
+      vec![self.context.join(resource_path)]
+    } else if let Some(path) = path.strip_prefix("file://") {
+      vec![Utf8PathBuf::from(path)]
+    } else {
+      let path = match source_root {
+        Some(source_root) if !source_root.is_empty() => Utf8Path::new(source_root).join(path),
+        _ => Utf8PathBuf::from(path),
+      };
+      vec![self.map_dir.join(path)]
+    };
+    // The output directory may not exist yet, `..` are resolved lexically.
+    Some(candidates.iter().any(|path| {
+      self
+        .fs
+        .metadata_sync(&path.as_std_path().normalize().assert_utf8())
+        .is_ok_and(|metadata| metadata.is_file)
+    }))
+  }
+}
+
+fn utf16_len(s: &str) -> u32 {
+  s.encode_utf16().count() as u32
+}
+
+/// Length of the 1-based `line`.
+fn line_len(lines: &[u32], line: u32) -> Option<u32> {
+  lines.get((line as usize).checked_sub(1)?).copied()
+}
+
+fn validate(code: &str, map: &SourceMap, resolver: &SourceResolver) -> (f64, Vec<String>) {
+  let mut issues = vec![];
+  let generated_lines = code.split('\n').map(utf16_len).collect::<Vec<_>>();
+  // The trailing `sourceMappingURL` and `debugId` comments aren't expected to
+  // be mapped.
+  let total = code
+    .split('\n')
+    .filter(|line| !COMMENT_LINE_REGEXP.is_match(line))
+    .map(|line| utf16_len(line) as u64)
+    .sum::<u64>();
+  let sources_content = map.sources_content();
+  let original_lines = map
+    .sources()
+    .iter()
+    .enumerate()
+    .map(|(index, _)| {
+      sources_content
+        .get(index)
+        .filter(|content| !content.is_empty())
+        .map(|content| content.split('\n').map(utf16_len).collect::<Vec<_>>())
+    })
+    .collect::<Vec<_>>();
+
+  for (index, source) in map.sources().iter().enumerate() {
+    if source.is_empty() {
+      issues.push("a source has an empty name".to_string());
+      continue;
+    }
+    if original_lines[index].is_some() || RUNTIME_SOURCE_REGEXP.is_match(source) {
+      continue;
+    }
+    // Without any `sourcesContent` the map was built with `noSources`.
+    if !sources_content.is_empty() {
+      issues.push(format!("missing sourcesContent of '{source}'"));
+    }
+    if resolver.is_resolvable(source, map.source_root()) == Some(false) {
+      issues.push(format!(
+        "source '{source}' can't be resolved, it has no sourcesContent and names no file"
+      ));
+    }
+  }
+
+  let mut mapped = 0u64;
+  // The mappings into runtime code of each runtime source, which is
+  // generated by rspack and not an original source.
+  let mut runtime_mappings: BTreeMap<&str, usize> = BTreeMap::new();
+  // The start of the mapped segment of the current line, as (line, start
+  // column), when it maps to an original source.
+  let mut segment: Option<(u32, u32)> = None;
+  let mut close_segment = |segment: &mut Option<(u32, u32)>, end: Option<(u32, u32)>| {
+    if let Some((line, start)) = segment.take() {
+      let line_len = line_len(&generated_lines, line).unwrap_or_default();
+      let end = match end {
+        Some((end_line, end_column)) if end_line == line => end_column.min(line_len),
+        _ => line_len,
+      };
+      mapped += end.saturating_sub(start) as u64;
+    }
+  };
+  for mapping in map.decoded_mappings() {
+    let (line, column) = (mapping.generated_line, mapping.generated_column);
+    close_segment(&mut segment, Some((line, column)));
+    match line_len(&generated_lines, line) {
+      Some(line_len) if column <= line_len => {}
+      _ => {
+        issues.push(format!(
+          "generated position {line}:{column} is out of range"
+        ));
+        continue;
+      }
+    }
+    let Some(original) = mapping.original else {
+      continue;
+    };
+    let Some(source) = map.get_source(original.source_index as usize) else {
+      issues.push(format!(
+        "generated position {line}:{column} maps to unknown source #{}",
+        original.source_index
+      ));
+      continue;
+    };
+    if let Some(lines) = &original_lines[original.source_index as usize] {
+      let in_range = line_len(lines, original.original_line)
+        .is_some_and(|line_len| original.original_column <= line_len);
+      if !in_range {
+        issues.push(format!(
+          "generated position {line}:{column} maps out of range to {source}:{}:{}",
+          original.original_line, original.original_column
+        ));
+      }
+    }
+    if RUNTIME_SOURCE_REGEXP.is_match(source) {
+      *runtime_mappings.entry(source).or_default() += 1;
+      continue;
+    }
+    segment = Some((line, column));
+  }
+  close_segment(&mut segment, None);
+  for (source, count) in runtime_mappings {
+    issues.push(format!(
+      "{count} mapping(s) into the runtime code '{source}'"
+    ));
+  }
+
+  let coverage = if total == 0 {
+    1.0
+  } else {
+    (mapped as f64 / total as f64).min(1.0)
+  };
+  (coverage, issues)
+}
+
+#[plugin_hook(CompilationProcessAssets for SourceMapValidationPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_DEV_TOOLING + 1)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let assets = compilation
+    .assets()
+    .iter()
+    .filter_map(|(filename, asset)| {
+      let code = asset.get_source()?.source();
+      if let Some(source_map) = &asset.get_info().related.source_map {
+        let map = compilation.assets().get(source_map)?.get_source()?.source();
+        return Some((filename, source_map.clone(), code, map.into_owned()));
+      }
+      // `inline-source-map` embeds the map as a data URL at the end of the
+      // asset, unlike `eval-source-map` which embeds one per module.
+      let captures = INLINE_SOURCE_MAP_REGEXP.captures(&code)?;
+      let map = rspack_base64::decode_to_vec(captures[1].as_bytes()).ok()?;
+      let map = String::from_utf8(map).ok()?;
+      Some((filename, "(inline)".to_string(), code, map))
+    })
+    .collect::<Vec<_>>();
+
+  let fs = compilation.input_filesystem.as_ref();
+  let context = compilation.options.context.as_path();
+  let output_path = &compilation.options.output.path;
+  let mut reports = assets
+    .into_par_iter()
+    .map(|(filename, source_map, code, map)| {
+      let map_filename = if source_map == "(inline)" {
+        filename
+      } else {
+        &source_map
+      };
+      let resolver = SourceResolver {
+        fs,
+        context,
+        map_dir: output_path
+          .join(map_filename)
+          .parent()
+          .map(|dir| dir.to_path_buf())
+          .unwrap_or_else(|| output_path.clone()),
+      };
+      let (coverage, issues) = match SourceMap::from_json(&map) {
+        Ok(map) => validate(&code, &map, &resolver),
+        Err(e) => (0.0, vec![format!("invalid source map: {e}")]),
+      };
+      (
+        filename.clone(),
+        AssetReport {
+          source_map,
+          coverage,
+          issues,
+        },
+      )
+    })
+    .collect::<Vec<_>>();
+  reports.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+  for (filename, report) in &reports {
+    if !report.issues.is_empty() {
+      let mut message = format!(
+        "Source map '{}' of '{filename}' has {} issue(s):\n",
+        report.source_map,
+        report.issues.len()
+      );
+      for issue in report.issues.iter().take(MAX_REPORTED_ISSUES) {
+        message.push_str(&format!("  - {issue}\n"));
+      }
+      if report.issues.len() > MAX_REPORTED_ISSUES {
+        message.push_str(&format!(
+          "  ... and {} more\n",
+          report.issues.len() - MAX_REPORTED_ISSUES
+        ));
+      }
+      compilation.push_diagnostic(Diagnostic::warn(
+        "SourceMapValidationPlugin".to_string(),
+        message,
+      ));
+    }
+    if report.coverage < self.options.min_coverage {
+      compilation.push_diagnostic(Diagnostic::warn(
+        "SourceMapValidationPlugin".to_string(),
+        format!(
+          "Source map '{}' of '{filename}' covers {:.2}% of the generated code, expected at least {:.2}%.",
+          report.source_map,
+          report.coverage * 100.0,
+          self.options.min_coverage * 100.0
+        ),
+      ));
+    }
+  }
+
+  if let Some(filename) = &self.options.filename {
+    let report = reports
+      .into_iter()
+      .collect::<std::collections::BTreeMap<_, _>>();
+    let json = serde_json::to_string_pretty(&report).expect("should serialize report");
+    compilation.emit_asset(
+      filename.clone(),
+      CompilationAsset::from(RawStringSource::from(json).boxed()),
+    );
+  }
+  Ok(())
+}
+
+impl Plugin for SourceMapValidationPlugin {
+  fn name(&self) -> &'static str {
+    PLUGIN_NAME
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,508 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  sync::LazyLock,
+};
+
+use cow_utils::CowUtils;
+use regex::Regex;
+use rspack_core::rspack_sources::{OriginalLocation, SourceMap};
+use rspack_error::{error, Result};
+use rustc_hash::FxHashMap as HashMap;
+use sugar_path::SugarPath;
+
+// `    at fn (file:line:column)`, `    at file:line:column` (V8)
+static V8_FRAME_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"^(\s*at\s+)(?:(.*?)\s+\()?(.+?):(\d+):(\d+)\)?\s*$")
+    .expect("failed to compile V8_FRAME_REGEXP")
+});
+// `fn@file:line:column` (SpiderMonkey, JavaScriptCore)
+static GECKO_FRAME_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"^(\s*)(.*?)@(.+?):(\d+):(\d+)\s*$").expect("failed to compile GECKO_FRAME_REGEXP")
+});
+static DEBUG_ID_COMMENT_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"//# debugId=([0-9a-fA-F-]+)\s*(?:\n//# sourceMappingURL=.*)?\s*$")
+    .expect("failed to compile DEBUG_ID_COMMENT_REGEXP")
+});
+static SOURCE_MAPPING_URL_COMMENT_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"//# sourceMappingURL=(\S+)\s*$")
+    .expect("failed to compile SOURCE_MAPPING_URL_COMMENT_REGEXP")
+});
+static INLINE_SOURCE_MAP_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"^data:application/json;(?:charset=utf-8;)?base64,([A-Za-z0-9+/=]+)$")
+    .expect("failed to compile INLINE_SOURCE_MAP_REGEXP")
+});
+
+/// A frame of a generated stack trace. Lines are 1-based and columns are
+/// 1-based, as printed by JavaScript engines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackFrame {
+  pub name: Option<String>,
+  pub file: String,
+  pub line: u32,
+  pub column: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OriginalPosition {
+  pub source: String,
+  pub line: u32,
+  pub column: u32,
+  pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolicatedFrame {
+  pub frame: StackFrame,
+  /// `None` when no source map covers the frame.
+  pub original: Option<OriginalPosition>,
+}
+
+#[derive(Debug)]
+struct IndexedSourceMap {
+  map: SourceMap,
+  /// Mappings of each generated line, sorted by generated column.
+  lines: Vec<Vec<(u32, Option<OriginalLocation>)>>,
+}
+
+impl IndexedSourceMap {
+  fn new(map: SourceMap) -> Self {
+    let mut lines: Vec<Vec<_>> = vec![];
+    for mapping in map.decoded_mappings() {
+      let line = mapping.generated_line as usize;
+      if lines.len() < line {
+        lines.resize_with(line, Vec::new);
+      }
+      lines[line - 1].push((mapping.generated_column, mapping.original));
+    }
+    for line in &mut lines {
+      line.sort_by_key(|(column, _)| *column);
+    }
+    Self { map, lines }
+  }
+
+  /// Finds the closest mapping at or before the 0-based `column` of the
+  /// 1-based `line`.
+  fn original_position_for(&self, line: u32, column: u32) -> Option<OriginalPosition> {
+    let mappings = self.lines.get((line as usize).checked_sub(1)?)?;
+    let index = mappings.partition_point(|(generated_column, _)| *generated_column <= column);
+    let (_, original) = mappings.get(index.checked_sub(1)?)?;
+    let original = original.as_ref()?;
+    Some(OriginalPosition {
+      source: self
+        .map
+        .get_source(original.source_index as usize)?
+        .to_string(),
+      line: original.original_line,
+      column: original.original_column + 1,
+      name: original
+        .name_index
+        .and_then(|index| self.map.get_name(index as usize))
+        .map(|name| name.to_string()),
+    })
+  }
+}
+
+/// Maps generated stack traces back to the original sources using the source
+/// maps emitted by `SourceMapDevToolPlugin`.
+///
+/// Source maps are looked up by the path of the emitted asset they map,
+/// relative to the output directory. Maps stored elsewhere are matched through
+/// the `//# debugId=` comment of the asset when `debugIds` is enabled.
+#[derive(Debug, Default)]
+pub struct Symbolicator {
+  maps: Vec<IndexedSourceMap>,
+  by_file: HashMap<String, usize>,
+  by_debug_id: HashMap<String, usize>,
+  /// Debug ids of the emitted assets, for maps not found by file.
+  file_debug_ids: HashMap<String, String>,
+}
+
+impl Symbolicator {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Loads the source maps and the debug ids of the assets in the output
+  /// directory.
+  pub fn from_output_dir(output_dir: &Path) -> Result<Self> {
+    let mut symbolicator = Self::new();
+    symbolicator.add_output_dir(output_dir)?;
+    Ok(symbolicator)
+  }
+
+  /// Loads the `.map` files of a directory that only holds source maps, such
+  /// as maps uploaded separately from the output. `static/main.js.map` maps
+  /// the asset `static/main.js`, other maps are matched by their `debugId`.
+  pub fn add_source_map_dir(&mut self, dir: &Path) -> Result<()> {
+    for path in read_dir_recursive(dir)? {
+      if path.extension().is_some_and(|ext| ext == "map") {
+        let map = read_source_map(&path)?;
+        let file = relative_path(&path, dir)
+          .and_then(|relative| relative.strip_suffix(".map").map(|file| file.to_string()));
+        self.add_source_map(file, map);
+      }
+    }
+    Ok(())
+  }
+
+  /// Adds the source map of the emitted asset `file`, a path relative to the
+  /// output directory.
+  pub fn add_source_map(&mut self, file: Option<String>, map: SourceMap) {
+    let index = self.push_source_map(map);
+    if let Some(file) = file {
+      self.by_file.insert(normalize_path(&file), index);
+    }
+  }
+
+  /// Adds the source map of an emitted asset, either linked by its
+  /// `//# sourceMappingURL=` comment or inlined in it as a data url.
+  /// `source_map` is the content of the linked map, when it was emitted.
+  pub fn add_asset(&mut self, file: &str, content: &str, source_map: Option<&str>) -> Result<()> {
+    let file = normalize_path(file);
+    if let Some(captures) = DEBUG_ID_COMMENT_REGEXP.captures(content) {
+      self
+        .file_debug_ids
+        .insert(file.clone(), captures[1].to_string());
+    }
+    let json = match source_map {
+      Some(json) => Some(json.to_string()),
+      None => inline_source_map(content)?,
+    };
+    if let Some(json) = json {
+      let map = SourceMap::from_json(&json)
+        .map_err(|e| error!("Failed to parse the source map of {file}: {e}"))?;
+      self.add_source_map(Some(file), map);
+    }
+    Ok(())
+  }
+
+  /// Loads the source maps and the debug ids of the assets in an output
+  /// directory. Each asset is paired with the map of its
+  /// `//# sourceMappingURL=` comment, or with `<asset>.map` when the comment
+  /// is omitted, as with `hidden-source-map`. Maps of no asset are only
+  /// matched by their `debugId`.
+  pub fn add_output_dir(&mut self, output_dir: &Path) -> Result<()> {
+    let files = read_dir_recursive(output_dir)?;
+    let mut maps: HashMap<PathBuf, Option<SourceMap>> = HashMap::default();
+    for path in &files {
+      if path.extension().is_some_and(|ext| ext == "map") {
+        maps.insert(path.normalize(), Some(read_source_map(path)?));
+      }
+    }
+    for path in &files {
+      if !path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext, "js" | "mjs" | "cjs"))
+      {
+        continue;
+      }
+      let Some(relative) = relative_path(path, output_dir) else {
+        continue;
+      };
+      let content =
+        fs::read_to_string(path).map_err(|e| error!("Failed to read {}: {e}", path.display()))?;
+      // A `sourceMappingURL` that isn't a path of the output directory, like
+      // the url of a map served by another host, falls back to `<asset>.map`.
+      let linked = SOURCE_MAPPING_URL_COMMENT_REGEXP
+        .captures(&content)
+        .map(|captures| captures[1].to_string())
+        .filter(|url| !url.starts_with("data:"))
+        .map(|url| {
+          let dir = path.parent().unwrap_or(output_dir);
+          dir
+            .join(strip_url(&url).trim_start_matches('/'))
+            .normalize()
+        });
+      let default = PathBuf::from(format!("{}.map", path.display())).normalize();
+      let map_path = linked
+        .into_iter()
+        .chain([default])
+        .find(|map_path| maps.contains_key(map_path));
+      match map_path.and_then(|map_path| maps.get_mut(&map_path)) {
+        Some(map) => {
+          if let Some(captures) = DEBUG_ID_COMMENT_REGEXP.captures(&content) {
+            self
+              .file_debug_ids
+              .insert(relative.clone(), captures[1].to_string());
+          }
+          if let Some(map) = map.take() {
+            self.add_source_map(Some(relative), map);
+          }
+        }
+        None =>self.add_asset(&relative, &content, None)?,
+      }
+    }
+    for map in maps.into_values().flatten() {
+      self.push_source_map(map);
+    }
+    Ok(())
+  }
+
+  fn push_source_map(&mut self, map: SourceMap) -> usize {
+    let index = self.maps.len();
+    if let Some(debug_id) = map.get_debug_id() {
+      self.by_debug_id.insert(debug_id.to_string(), index);
+    }
+    self.maps.push(IndexedSourceMap::new(map));
+    index
+  }
+
+  fn find_map(&self, file: &str) -> Option<&IndexedSourceMap> {
+    let file = normalize_path(strip_url(file));
+    // Frames carry absolute paths or URLs, find the longest known suffix.
+    let matches = |known: &str| {
+      file == known
+        || file
+          .strip_suffix(known)
+          .is_some_and(|prefix| prefix.ends_with('/'))
+    };
+    let by_file = self
+      .by_file
+      .iter()
+      .filter(|(known, _)| matches(known))
+      .max_by_key(|(known, _)| known.len())
+      .map(|(_, index)| *index);
+    let index = by_file.or_else(|| {
+      self
+        .file_debug_ids
+        .iter()
+        .filter(|(known, _)| matches(known))
+        .max_by_key(|(known, _)| known.len())
+        .and_then(|(_, debug_id)| self.by_debug_id.get(debug_id).copied())
+    })?;
+    self.maps.get(index)
+  }
+
+  pub fn symbolicate_frame(&self, frame: &StackFrame) -> SymbolicatedFrame {
+    let original = self
+      .find_map(&frame.file)
+      .and_then(|map| map.original_position_for(frame.line, frame.column.saturating_sub(1)));
+    SymbolicatedFrame {
+      frame: frame.clone(),
+      original,
+    }
+  }
+
+  pub fn symbolicate(&self, stack: &str) -> Vec<SymbolicatedFrame> {
+    stack
+      .lines()
+      .filter_map(|line| parse_frame(line).map(|(_, frame)| frame))
+      .map(|frame| self.symbolicate_frame(&frame))
+      .collect()
+  }
+
+  /// Rewrites the frames of a stack trace to their original positions, in the
+  /// V8 `at name (file:line:column)` format, and keeps lines that aren't frames
+  /// or can't be mapped as they are.
+  pub fn symbolicate_stack_trace(&self, stack: &str) -> String {
+    stack
+      .lines()
+      .map(|line| {
+        let Some((indent, frame)) = parse_frame(line) else {
+          return line.to_string();
+        };
+        let SymbolicatedFrame {
+          original: Some(original),
+          ..
+        } = self.symbolicate_frame(&frame)
+        else {
+          return line.to_string();
+        };
+        let location = format!("{}:{}:{}", original.source, original.line, original.column);
+        match original.name.or(frame.name) {
+          Some(name) => format!("{indent}{name} ({location})"),
+          None => format!("{indent}{location}"),
+        }
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
+
+/// Symbolicates a stack trace with the source maps of an output directory and
+/// of additional source map directories, such as maps kept off the CDN and
+/// matched by debug id.
+pub fn symbolicate_stack_trace(
+  stack: &str,
+  output_dir: &Path,
+  source_map_dirs: &[PathBuf],
+) -> Result<String> {
+  let mut symbolicator = Symbolicator::from_output_dir(output_dir)?;
+  for dir in source_map_dirs {
+    symbolicator.add_source_map_dir(dir)?;
+  }
+  Ok(symbolicator.symbolicate_stack_trace(stack))
+}
+
+/// Parses a stack frame line, returning its indentation (including `at `)
+/// and the frame.
+pub fn parse_frame(line: &str) -> Option<(String, StackFrame)> {
+  if let Some(captures) = V8_FRAME_REGEXP.captures(line) {
+    return Some((
+      captures[1].to_string(),
+      StackFrame {
+        name: captures.get(2).map(|name| name.as_str().to_string()),
+        file: captures[3].to_string(),
+        line: captures[4].parse().ok()?,
+        column: captures[5].parse().ok()?,
+      },
+    ));
+  }
+  let captures = GECKO_FRAME_REGEXP.captures(line)?;
+  Some((
+    format!("{}at ", &captures[1]),
+    StackFrame {
+      name: Some(captures[2].to_string()).filter(|name| !name.is_empty()),
+      file: captures[3].to_string(),
+      line: captures[4].parse().ok()?,
+      column: captures[5].parse().ok()?,
+    },
+  ))
+}
+
+fn read_source_map(path: &Path) -> Result<SourceMap> {
+  let json = fs::read_to_string(path)
+    .map_err(|e| error!("Failed to read source map {}: {e}", path.display()))?;
+  SourceMap::from_json(&json)
+    .map_err(|e| error!("Failed to parse source map {}: {e}", path.display()))
+}
+
+fn inline_source_map(content: &str) -> Result<Option<String>> {
+  let Some(captures) = SOURCE_MAPPING_URL_COMMENT_REGEXP
+    .captures(content)
+    .and_then(|captures| INLINE_SOURCE_MAP_REGEXP.captures(captures.get(1)?.as_str()))
+  else {
+    return Ok(None);
+  };
+  let json = rspack_base64::decode_to_vec(captures[1].as_bytes())
+    .map_err(|e| error!("Failed to decode inline source map: {e}"))?;
+  String::from_utf8(json)
+    .map(Some)
+    .map_err(|e| error!("Failed to decode inline source map: {e}"))
+}
+
+fn relative_path(path: &Path, dir: &Path) -> Option<String> {
+  let relative = path.strip_prefix(dir).ok()?;
+  Some(normalize_path(&relative.to_string_lossy()))
+}
+
+fn read_dir_recursive(dir: &Path) -> Result<Vec<PathBuf>> {
+  let mut files = vec![];
+  let mut queue = vec![dir.to_path_buf()];
+  while let Some(dir) = queue.pop() {
+    let entries =
+      fs::read_dir(&dir).map_err(|e| error!("Failed to read directory {}: {e}", dir.display()))?;
+    for entry in entries.flatten() {
+      let path = entry.path();
+      if path.is_dir() {
+        queue.push(path);
+      } else {
+        files.push(path);
+      }
+    }
+  }
+  files.sort();
+  Ok(files)
+}
+
+fn strip_url(file: &str) -> &str {
+  let file = file.split(['?', '#']).next().unwrap_or(file);
+  file.strip_prefix("file://").unwrap_or(file)
+}
+
+fn normalize_path(path: &str) -> String {
+  path
+    .cow_replace('\\', "/")
+    .trim_start_matches("./")
+    .to_string()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn parse_v8_and_gecko_frames() {
+    assert_eq!(
+      parse_frame("    at foo (https://cdn.example.com/static/main.js:1:120)"),
+      Some((
+        "    at ".to_string(),
+        StackFrame {
+          name: Some("foo".to_string()),
+          file: "https://cdn.example.com/static/main.js".to_string(),
+          line: 1,
+          column: 120,
+        }
+      ))
+    );
+    assert_eq!(
+      parse_frame("    at /dist/main.js:2:3").map(|(_, frame)| frame.name),
+      Some(None)
+    );
+    assert_eq!(
+      parse_frame("foo@https://cdn.example.com/main.js:1:5").map(|(_, frame)| frame.file),
+      Some("https://cdn.example.com/main.js".to_string())
+    );
+    assert_eq!(parse_frame("Error: boom"), None);
+  }
+
+  #[test]
+  fn symbolicate_with_source_map() {
+    // `a.b()` at column 0 of line 1 maps to `src/index.js:3:4`, named `b`.
+    let map = SourceMap::new(
+      "AAEIA;",
+      vec!["src/index.js".to_string()],
+      Vec::<String>::new(),
+      vec!["b".to_string()],
+    );
+    let mut symbolicator = Symbolicator::new();
+    symbolicator.add_source_map(Some("static/main.js".to_string()), map);
+
+    assert_eq!(
+      symbolicator.symbolicate_stack_trace(
+        "Error: boom\n    at t (https://cdn.example.com/static/main.js?v=1:1:10)\n    at other.js:1:1"
+      ),
+      "Error: boom\n    at b (src/index.js:3:5)\n    at other.js:1:1"
+    );
+  }
+
+  #[test]
+  fn symbolicate_output_dir() {
+    let output_dir = std::env::temp_dir().join("rspack-symbolicate-output-dir");
+    let _ = fs::remove_dir_all(&output_dir);
+    fs::create_dir_all(output_dir.join("static/maps")).unwrap();
+    // the `file` field of a map doesn't tell which asset it maps
+    let map = |file: &str, sources: &str| {
+      format!(
+        r#"{{"version":3,"file":"{file}","sources":["{sources}"],"names":[],"mappings":"AAEI"}}"#
+      )
+    };
+    fs::write(
+      output_dir.join("static/main.js"),
+      "a.b();\n//# sourceMappingURL=maps/main.js.map",
+    )
+    .unwrap();
+    fs::write(
+      output_dir.join("static/maps/main.js.map"),
+      map("other.js", "src/main.js"),
+    )
+    .unwrap();
+    fs::write(output_dir.join("other.js"), "a.b();").unwrap();
+    fs::write(
+      output_dir.join("other.js.map"),
+      map("main.js", "src/other.js"),
+    )
+    .unwrap();
+
+    let symbolicator = Symbolicator::from_output_dir(&output_dir).unwrap();
+    fs::remove_dir_all(&output_dir).unwrap();
+    assert_eq!(
+      symbolicator.symbolicate_stack_trace(
+        "Error: boom\n    at https://cdn.example.com/static/main.js:1:1\n    at https://cdn.example.com/other.js:1:1"
+      ),
+      "Error: boom\n    at src/main.js:3:5\n    at src/other.js:3:5"
+    );
+  }
+}
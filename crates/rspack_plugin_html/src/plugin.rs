@@ -17,6 +17,7 @@ use crate::{
   config::{HtmlInject, HtmlRspackPluginOptions},
   injector::AssetInjector,
   parser::HtmlCompiler,
+  tag::HtmlPluginTag,
   template::HtmlTemplate,
   AfterEmitData, AfterTemplateExecutionData, AlterAssetTagGroupsData, AlterAssetTagsData,
   BeforeAssetTagGenerationData, BeforeEmitData, HtmlPluginHooks,
@@ -90,9 +91,17 @@ async fn generate_html(
     })
     .await?;
 
-  let asset_tags: HtmlPluginAssetTags =
+  let mut asset_tags: HtmlPluginAssetTags =
     HtmlPluginAssetTags::from_assets(config, &before_generation_data.assets, &assets_info.1);
 
+  // The import map must come before the module scripts, which are resolved with it.
+  if let Some(import_map) = &compilation.options.output.import_map {
+    let import_map = import_map.render(compilation, &public_path);
+    asset_tags
+      .meta
+      .insert(0, HtmlPluginTag::create_import_map(&import_map));
+  }
+
   let alter_asset_tags_data = hooks
     .alter_asset_tags
     .call(AlterAssetTagsData {
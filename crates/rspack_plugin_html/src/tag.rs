@@ -191,6 +191,18 @@ impl HtmlPluginTag {
     }
   }
 
+  pub fn create_import_map(import_map: &str) -> HtmlPluginTag {
+    HtmlPluginTag {
+      tag_name: "script".to_string(),
+      attributes: vec![HtmlPluginAttribute {
+        attr_name: "type".to_string(),
+        attr_value: Some("importmap".to_string()),
+      }],
+      inner_html: Some(import_map.to_string()),
+      ..Default::default()
+    }
+  }
+
   pub fn create_meta(meta: &HashMap<String, HashMap<String, String>>) -> Vec<HtmlPluginTag> {
     meta
       .values()
@@ -317,8 +317,28 @@ pub fn esm_import_dependency_get_linking_error<T: ModuleDependency>(
           } else {
             " (possible exports unknown)".to_string()
           };
+          // The TypeScript types are stripped by loaders that transpile each
+          // file on its own, such as `builtin:swc-loader`.
+          let type_only_info = if pos == 1
+            && imported_module
+              .build_info()
+              .ambient_const_enum_exports
+              .contains(id)
+          {
+            format!(
+                "\n'{id}' is an ambient const enum in '{}', which has no value at runtime when each file is transpiled on its own, use a non-ambient enum or a plain object instead",
+                module_dependency.user_request()
+              )
+          } else if pos == 1 && imported_module.build_info().type_only_exports.contains(id) {
+            format!(
+                "\n'{id}' has no value at runtime, as it is only a type or an ambient declaration in '{}', use `export type` or `import type` for it",
+                module_dependency.user_request()
+              )
+          } else {
+            String::new()
+          };
           let msg = format!(
-            "export {} {} was not found in '{}'{more_info}",
+            "export {} {} was not found in '{}'{more_info}{type_only_info}",
             ids
               .iter()
               .take(pos)
@@ -9,7 +9,8 @@ use rspack_core::{
   AsyncDependenciesBlockIdentifier, BuildMetaExportsType, ChunkGraph, Compilation,
   DependenciesBlock, DependencyId, DependencyRange, GenerateContext, Module, ModuleGraph,
   ModuleType, ParseContext, ParseResult, ParserAndGenerator, SideEffectsBailoutItem, SourceType,
-  TemplateContext, TemplateReplaceSource,
+  TemplateContext, TemplateReplaceSource, AMBIENT_CONST_ENUM_EXPORTS_PARSE_META_KEY,
+  TYPE_ONLY_EXPORTS_PARSE_META_KEY,
 };
 use rspack_error::{miette::Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_javascript_compiler::JavaScriptCompiler;
@@ -233,6 +234,22 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
 
     let unresolved_mark = ast.get_context().unresolved_mark;
 
+    if let Some(type_only_exports) = parse_meta
+      .get(TYPE_ONLY_EXPORTS_PARSE_META_KEY)
+      .and_then(|names| serde_json::from_str::<Vec<String>>(names).ok())
+    {
+      build_info.type_only_exports = type_only_exports.into_iter().map(Into::into).collect();
+    }
+    if let Some(ambient_const_enum_exports) = parse_meta
+      .get(AMBIENT_CONST_ENUM_EXPORTS_PARSE_META_KEY)
+      .and_then(|names| serde_json::from_str::<Vec<String>>(names).ok())
+    {
+      build_info.ambient_const_enum_exports = ambient_const_enum_exports
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    }
+
     let ScanDependenciesResult {
       dependencies,
       blocks,
@@ -0,0 +1,445 @@
+use std::{collections::BTreeMap, hash::Hash, sync::Mutex};
+
+use rspack_collections::UkeySet;
+use rspack_core::{
+  get_js_chunk_filename_template, property_access,
+  rspack_sources::{BoxSource, ConcatSource, RawStringSource, SourceExt},
+  to_identifier, ApplyContext, AssetInfo, ChunkGraph, ChunkUkey, Compilation, CompilationAsset,
+  CompilationParams, CompilationProcessAssets, CompilationRenderManifest, CompilerCompilation,
+  CompilerOptions, ExportInfoGetter, ExportProvided, ExportsType, LibraryOptions, ModuleGraph,
+  ModuleIdentifier, PathData, Plugin, PluginContext, PublicPath, RenderManifestEntry,
+  RuntimeGlobals, SourceType,
+};
+use rspack_error::{error_bail, Diagnostic, Result};
+use rspack_hash::RspackHash;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_plugin_javascript::{
+  JavascriptModulesChunkHash, JavascriptModulesRender, JavascriptModulesRenderStartup, JsPlugin,
+  RenderSource,
+};
+
+use crate::utils::{get_options_for_chunk, COMMON_LIBRARY_NAME_MESSAGE};
+
+const PLUGIN_NAME: &str = "rspack.DualLibraryPlugin";
+
+/// Emits every entry chunk as an ES module and renders it a second time as a
+/// CommonJS variant next to it (`[name].cjs`). A `package.json` with an
+/// `exports` map pointing `import` and `require` at the two files is emitted,
+/// or merged into an existing `package.json` asset.
+///
+/// The two renders share the module graph and the code generation, only the
+/// startup and the strictness of the chunk differ, so the CommonJS variant
+/// requires an entry chunk without ES module syntax outside of its modules:
+/// the runtime must be inlined and must not read `import.meta`, and the chunk
+/// must not import `module` externals or use top-level await. Async chunks
+/// stay ES modules, which both variants load with `import()`.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct DualLibraryPlugin {
+  js_plugin: JsPlugin,
+  /// The chunks whose CommonJS variant is being rendered.
+  rendering_commonjs: Mutex<UkeySet<ChunkUkey>>,
+}
+
+impl DualLibraryPlugin {
+  fn parse_options(&self, library: &LibraryOptions) -> Result<()> {
+    if library.name.is_some() {
+      error_bail!("Library name must be unset. {COMMON_LIBRARY_NAME_MESSAGE}")
+    }
+    Ok(())
+  }
+
+  fn get_options_for_chunk(
+    &self,
+    compilation: &Compilation,
+    chunk_ukey: &ChunkUkey,
+  ) -> Result<Option<()>> {
+    let Some(library) = get_options_for_chunk(compilation, chunk_ukey)
+      .filter(|library| library.library_type == "dual")
+    else {
+      return Ok(None);
+    };
+    if !compilation.options.output.module {
+      error_bail!("Library type 'dual' requires 'output.module' to be enabled.")
+    }
+    self.parse_options(library).map(Some)
+  }
+
+  fn is_rendering_commonjs(&self, chunk_ukey: &ChunkUkey) -> bool {
+    self
+      .rendering_commonjs
+      .lock()
+      .expect("should lock rendering_commonjs")
+      .contains(chunk_ukey)
+  }
+
+  /// Renders the entry chunk again, with the hooks of this plugin rendering the
+  /// CommonJS startup.
+  async fn render_commonjs(
+    &self,
+    compilation: &Compilation,
+    chunk_ukey: &ChunkUkey,
+    output_path: &str,
+  ) -> Result<BoxSource> {
+    self
+      .rendering_commonjs
+      .lock()
+      .expect("should lock rendering_commonjs")
+      .insert(*chunk_ukey);
+    let source = self
+      .js_plugin
+      .render_main(compilation, chunk_ukey, output_path)
+      .await;
+    self
+      .rendering_commonjs
+      .lock()
+      .expect("should lock rendering_commonjs")
+      .remove(chunk_ukey);
+    source
+  }
+}
+
+/// Renders the startup tail of the ES module variant.
+fn render_esm_exports(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  module: &ModuleIdentifier,
+) -> String {
+  let module_graph = compilation.get_module_graph();
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  let mut esm = String::new();
+  if ModuleGraph::is_async(compilation, module) {
+    esm.push_str("__webpack_exports__ = await __webpack_exports__;\n");
+  }
+  let exports_info = module_graph.get_exports_info(module);
+  let boxed_module = module_graph
+    .module_by_identifier(module)
+    .expect("should have entry module");
+  let exports_type = boxed_module.get_exports_type(&module_graph, boxed_module.build_info().strict);
+  let mut exports = vec![];
+  for export_info in exports_info.ordered_exports(&module_graph) {
+    let export_info_data = export_info.as_data(&module_graph);
+    if matches!(
+      ExportInfoGetter::provided(export_info_data),
+      Some(ExportProvided::NotProvided)
+    ) {
+      continue;
+    };
+
+    let info_name = ExportInfoGetter::name(export_info_data).expect("should have name");
+    let used_name =
+      ExportInfoGetter::get_used_name(export_info_data, Some(info_name), Some(chunk.runtime()))
+        .expect("name can't be empty");
+    let var_name = format!("__webpack_exports__{}", to_identifier(info_name));
+
+    if info_name == "default"
+      && matches!(
+        exports_type,
+        ExportsType::DefaultOnly | ExportsType::DefaultWithNamed | ExportsType::Dynamic
+      )
+    {
+      esm.push_str(&format!("var {var_name} = __webpack_exports__;\n"));
+    } else {
+      esm.push_str(&format!(
+        "var {var_name} = __webpack_exports__{};\n",
+        property_access(vec![used_name], 0)
+      ));
+    }
+    exports.push(format!("{var_name} as {info_name}"));
+  }
+  esm.push_str(&format!("export {{ {} }};\n", exports.join(", ")));
+  esm
+}
+
+/// Returns why the entry chunk can't be rendered as CommonJS, if it can't.
+fn check_commonjs_compatible(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  module: &ModuleIdentifier,
+) -> Option<&'static str> {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  if !chunk.has_runtime(&compilation.chunk_group_by_ukey) {
+    return Some("the runtime is not part of the entry chunk, disable 'optimization.runtimeChunk'");
+  }
+  let runtime_requirements = ChunkGraph::get_tree_runtime_requirements(compilation, chunk_ukey);
+  if runtime_requirements.contains(RuntimeGlobals::BASE_URI)
+    || (runtime_requirements.contains(RuntimeGlobals::PUBLIC_PATH)
+      && matches!(compilation.options.output.public_path, PublicPath::Auto))
+  {
+    return Some("the runtime reads 'import.meta.url', set 'output.publicPath' to a fixed path");
+  }
+  if ModuleGraph::is_async(compilation, module) {
+    return Some("the entry module uses top-level await");
+  }
+  let module_graph = compilation.get_module_graph();
+  let has_esm_externals = compilation
+    .chunk_graph
+    .get_chunk_modules_identifier(chunk_ukey)
+    .iter()
+    .filter_map(|id| module_graph.module_by_identifier(id))
+    .filter_map(|m| m.as_external_module())
+    .any(|m| m.get_external_type() == "module");
+  if has_esm_externals {
+    return Some("the entry chunk imports externals of type 'module'");
+  }
+  None
+}
+
+/// `index.mjs` -> `index.cjs`, `lib/[name].js` -> `lib/[name].cjs`.
+fn commonjs_filename(filename: &str) -> String {
+  let stem = filename
+    .strip_suffix(".mjs")
+    .or_else(|| filename.strip_suffix(".js"))
+    .unwrap_or(filename);
+  format!("{stem}.cjs")
+}
+
+fn export_condition_key(chunk_name: Option<&str>) -> String {
+  match chunk_name {
+    None | Some("main") | Some("index") => ".".to_string(),
+    Some(name) => format!("./{name}"),
+  }
+}
+
+#[plugin_hook(CompilerCompilation for DualLibraryPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  let mut hooks = JsPlugin::get_compilation_hooks_mut(compilation.id());
+  hooks.render.tap(render::new(self));
+  hooks.render_startup.tap(render_startup::new(self));
+  hooks.chunk_hash.tap(js_chunk_hash::new(self));
+  Ok(())
+}
+
+#[plugin_hook(JavascriptModulesRender for DualLibraryPlugin)]
+async fn render(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  render_source: &mut RenderSource,
+) -> Result<()> {
+  if !self.is_rendering_commonjs(chunk_ukey) {
+    return Ok(());
+  }
+  // the modules are generated for `output.module`, which is implicitly strict
+  let mut source = ConcatSource::default();
+  source.add(RawStringSource::from_static("\"use strict\";\n"));
+  source.add(render_source.source.clone());
+  render_source.source = source.boxed();
+  Ok(())
+}
+
+#[plugin_hook(JavascriptModulesRenderStartup for DualLibraryPlugin)]
+async fn render_startup(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  module: &ModuleIdentifier,
+  render_source: &mut RenderSource,
+) -> Result<()> {
+  let Some(_) = self.get_options_for_chunk(compilation, chunk_ukey)? else {
+    return Ok(());
+  };
+  let mut source = ConcatSource::default();
+  source.add(render_source.source.clone());
+  if self.is_rendering_commonjs(chunk_ukey) {
+    source.add(RawStringSource::from_static(
+      "module.exports = __webpack_exports__;\n",
+    ));
+  } else {
+    source.add(RawStringSource::from(render_esm_exports(
+      compilation,
+      chunk_ukey,
+      module,
+    )));
+  }
+  render_source.source = source.boxed();
+  Ok(())
+}
+
+#[plugin_hook(JavascriptModulesChunkHash for DualLibraryPlugin)]
+async fn js_chunk_hash(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  hasher: &mut RspackHash,
+) -> Result<()> {
+  let Some(_) = self.get_options_for_chunk(compilation, chunk_ukey)? else {
+    return Ok(());
+  };
+  PLUGIN_NAME.hash(hasher);
+  Ok(())
+}
+
+#[plugin_hook(CompilationRenderManifest for DualLibraryPlugin)]
+async fn render_manifest(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  manifest: &mut Vec<RenderManifestEntry>,
+  diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  if self
+    .get_options_for_chunk(compilation, chunk_ukey)?
+    .is_none()
+  {
+    return Ok(());
+  }
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  if !chunk.has_runtime(&compilation.chunk_group_by_ukey) {
+    return Ok(());
+  }
+  let Some(module) = compilation
+    .chunk_graph
+    .get_chunk_entry_modules_with_chunk_group_iterable(chunk_ukey)
+    .keys()
+    .last()
+    .copied()
+  else {
+    return Ok(());
+  };
+
+  let filename_template = get_js_chunk_filename_template(
+    chunk,
+    &compilation.options.output,
+    &compilation.chunk_group_by_ukey,
+  );
+  let mut info = AssetInfo::default();
+  let esm_file = compilation
+    .get_path_with_info(
+      &filename_template,
+      PathData::default()
+        .chunk_hash_optional(chunk.rendered_hash(
+          &compilation.chunk_hashes_artifact,
+          compilation.options.output.hash_digest_length,
+        ))
+        .chunk_id_optional(
+          chunk
+            .id(&compilation.chunk_ids_artifact)
+            .map(|id| id.as_str()),
+        )
+        .chunk_name_optional(chunk.name_for_filename_template(&compilation.chunk_ids_artifact))
+        .content_hash_optional(chunk.rendered_content_hash_by_source_type(
+          &compilation.chunk_hashes_artifact,
+          &SourceType::JavaScript,
+          compilation.options.output.hash_digest_length,
+        ))
+        .runtime(chunk.runtime().as_str()),
+      &mut info,
+    )
+    .await?;
+
+  if let Some(reason) = check_commonjs_compatible(compilation, chunk_ukey, &module) {
+    diagnostics.push(Diagnostic::error(
+      "DualLibraryPlugin".to_string(),
+      format!("Can't emit a CommonJS variant of '{esm_file}': {reason}."),
+    ));
+    return Ok(());
+  }
+
+  let commonjs_file = commonjs_filename(&esm_file);
+  let source = self
+    .render_commonjs(compilation, chunk_ukey, &commonjs_file)
+    .await?;
+  info.set_javascript_module(false);
+  manifest.push(RenderManifestEntry {
+    source,
+    filename: commonjs_file,
+    has_filename: false,
+    info,
+    auxiliary: false,
+  });
+  Ok(())
+}
+
+#[plugin_hook(CompilationProcessAssets for DualLibraryPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONS)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let mut exports_map = BTreeMap::new();
+
+  for chunk_ukey in compilation.chunk_by_ukey.keys() {
+    if self
+      .get_options_for_chunk(compilation, chunk_ukey)?
+      .is_none()
+    {
+      continue;
+    }
+    let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+    if !chunk.has_runtime(&compilation.chunk_group_by_ukey) {
+      continue;
+    }
+    let mut files = chunk.files().iter().collect::<Vec<_>>();
+    files.sort();
+    let Some(esm_file) = files.into_iter().find(|file| {
+      compilation
+        .assets()
+        .get(*file)
+        .is_some_and(|asset| asset.get_info().javascript_module == Some(true))
+    }) else {
+      continue;
+    };
+
+    let mut conditions = BTreeMap::new();
+    conditions.insert("import", format!("./{esm_file}"));
+    let commonjs_file = commonjs_filename(esm_file);
+    if chunk.files().contains(&commonjs_file) {
+      conditions.insert("require", format!("./{commonjs_file}"));
+    }
+    exports_map.insert(export_condition_key(chunk.name()), conditions);
+  }
+
+  if exports_map.is_empty() {
+    return Ok(());
+  }
+
+  let exports = serde_json::to_value(exports_map).expect("should serialize exports map");
+  let package_json = match compilation
+    .assets()
+    .get("package.json")
+    .and_then(|asset| asset.get_source())
+    .and_then(|source| serde_json::from_str::<serde_json::Value>(&source.source()).ok())
+  {
+    // Keep the fields of a package.json copied to the output, and only own `exports`.
+    Some(serde_json::Value::Object(mut package_json)) => {
+      package_json.insert("exports".to_string(), exports);
+      serde_json::Value::Object(package_json)
+    }
+    _ => serde_json::json!({
+      "type": "module",
+      "exports": exports,
+    }),
+  };
+  let source = serde_json::to_string_pretty(&package_json).expect("should serialize package.json");
+  compilation.emit_asset(
+    "package.json".to_string(),
+    CompilationAsset::from(RawStringSource::from(source + "\n").boxed()),
+  );
+  Ok(())
+}
+
+impl Plugin for DualLibraryPlugin {
+  fn name(&self) -> &'static str {
+    PLUGIN_NAME
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .render_manifest
+      .tap(render_manifest::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
@@ -2,6 +2,7 @@
 
 mod amd_library_plugin;
 mod assign_library_plugin;
+mod dual_library_plugin;
 mod export_property_library_plugin;
 mod modern_module;
 mod modern_module_library_plugin;
@@ -12,6 +13,7 @@ mod utils;
 
 pub use amd_library_plugin::AmdLibraryPlugin;
 pub use assign_library_plugin::*;
+pub use dual_library_plugin::DualLibraryPlugin;
 pub use export_property_library_plugin::ExportPropertyLibraryPlugin;
 use modern_module_library_plugin::ModernModuleLibraryPlugin;
 pub use module_library_plugin::ModuleLibraryPlugin;
@@ -117,6 +119,11 @@ pub fn enable_library_plugin(library_type: String, plugins: &mut Vec<BoxPlugin>)
         .push(ExportPropertyLibraryPlugin::new(library_type.clone(), ns_object_used, true).boxed());
       plugins.push(ModuleLibraryPlugin::default().boxed());
     }
+    "dual" => {
+      plugins
+        .push(ExportPropertyLibraryPlugin::new(library_type.clone(), ns_object_used, true).boxed());
+      plugins.push(DualLibraryPlugin::default().boxed());
+    }
     "modern-module" => {
       plugins.push(
         ExportPropertyLibraryPlugin::new(library_type.clone(), ns_object_used, false).boxed(),
@@ -14,18 +14,23 @@ use lightningcss::{
 };
 use rayon::prelude::*;
 use regex::Regex;
+use rspack_cacheable::{cacheable, from_bytes, to_bytes};
 use rspack_core::{
+  cache::persistent::ScopeCache,
   diagnostics::MinifyError,
   rspack_sources::{
-    MapOptions, RawStringSource, SourceExt, SourceMap, SourceMapSource, SourceMapSourceOptions,
+    BoxSource, MapOptions, RawStringSource, SourceExt, SourceMap, SourceMapSource,
+    SourceMapSourceOptions,
   },
   ChunkUkey, Compilation, CompilationChunkHash, CompilationProcessAssets, Plugin,
 };
 use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt};
-use rspack_hash::RspackHash;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::asset_condition::AssetConditions;
 
+const CACHE_SCOPE: &str = "plugin_lightning_css_minimizer";
+
 static CSS_ASSET_REGEXP: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"\.css(\?.*)?$").expect("Invalid RegExp"));
 
@@ -98,10 +103,37 @@ impl Hash for MinimizerOptions {
   }
 }
 
+/// The minified asset persisted in the storage of the persistent cache.
+#[cacheable]
+struct CachedAsset {
+  code: String,
+  map: Option<String>,
+}
+
+impl CachedAsset {
+  fn to_source(&self, filename: &str) -> Option<BoxSource> {
+    let source = if let Some(map) = &self.map {
+      SourceMapSource::new(SourceMapSourceOptions {
+        value: self.code.clone(),
+        name: filename,
+        source_map: SourceMap::from_json(map).ok()?,
+        original_source: None,
+        inner_source_map: None,
+        remove_original_source: false,
+      })
+      .boxed()
+    } else {
+      RawStringSource::from(self.code.clone()).boxed()
+    };
+    Some(source)
+  }
+}
+
 #[plugin]
 #[derive(Debug)]
 pub struct LightningCssMinimizerRspackPlugin {
   options: PluginOptions,
+  cache: ScopeCache,
 }
 
 pub fn match_object(obj: &PluginOptions, str: &str) -> bool {
@@ -125,7 +157,31 @@ pub fn match_object(obj: &PluginOptions, str: &str) -> bool {
 
 impl LightningCssMinimizerRspackPlugin {
   pub fn new(options: PluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, ScopeCache::new(CACHE_SCOPE))
+  }
+
+  /// Everything the minified asset depends on: the options, the input and the
+  /// unused local idents to remove.
+  fn cache_key<'a>(
+    &self,
+    filename: &str,
+    input: &str,
+    input_source_map: Option<&SourceMap>,
+    unused_idents: impl Iterator<Item = &'a String>,
+  ) -> Vec<u8> {
+    let mut hasher = RspackHash::new(&HashFunction::MD4);
+    self.options.hash(&mut hasher);
+    filename.hash(&mut hasher);
+    input.hash(&mut hasher);
+    input_source_map.hash(&mut hasher);
+    let mut unused_idents = unused_idents.collect::<Vec<_>>();
+    unused_idents.sort();
+    unused_idents.hash(&mut hasher);
+    hasher
+      .digest(&HashDigest::Hex)
+      .encoded()
+      .as_bytes()
+      .to_vec()
   }
 }
 
@@ -145,6 +201,10 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   let options = &self.options;
   let minimizer_options = &self.options.minimizer_options;
   let all_warnings: RwLock<Vec<Diagnostic>> = Default::default();
+  let storage = compilation.cache.storage();
+  if let Some(storage) = &storage {
+    self.cache.load(storage).await?;
+  }
   compilation
     .assets_mut()
     .par_iter_mut()
@@ -169,6 +229,29 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       if let Some(original_source) = original.get_source() {
         let input = original_source.source().into_owned();
         let input_source_map = original_source.map(&MapOptions::default());
+        let css_unused_idents = if self.options.remove_unused_local_idents {
+          original.info.css_unused_idents.take()
+        } else {
+          None
+        };
+
+        let cache_key = storage.as_ref().map(|_| {
+          self.cache_key(
+            filename,
+            &input,
+            input_source_map.as_ref(),
+            css_unused_idents.iter().flatten(),
+          )
+        });
+        if let Some(cache_key) = &cache_key
+          && let Some(cached) = self.cache.get(cache_key)
+          && let Ok(cached) = from_bytes::<CachedAsset, ()>(&cached, &())
+          && let Some(source) = cached.to_source(filename)
+        {
+          original.set_source(Some(source));
+          original.get_info_mut().minimized.replace(true);
+          return Ok(());
+        }
 
         let mut parser_flags = ParserFlags::empty();
         parser_flags.set(
@@ -219,9 +302,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
               .unwrap_or(Features::empty()),
           };
           let mut unused_symbols = HashSet::from_iter(minimizer_options.unused_symbols.clone());
-          if self.options.remove_unused_local_idents
-            && let Some(css_unused_idents) = original.info.css_unused_idents.take()
-          {
+          if let Some(css_unused_idents) = css_unused_idents {
             unused_symbols.extend(css_unused_idents);
           }
           stylesheet
@@ -293,6 +374,21 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         } else {
           RawStringSource::from(result.code).boxed()
         };
+        if let Some(storage) = &storage
+          && let Some(cache_key) = cache_key
+        {
+          let cached = CachedAsset {
+            code: minimized_source.source().into_owned(),
+            map: minimized_source
+              .map(&MapOptions::default())
+              .and_then(|map| map.to_json().ok()),
+          };
+          self.cache.set(
+            storage,
+            cache_key,
+            to_bytes(&cached, &()).expect("should to bytes success"),
+          );
+        }
 
         original.set_source(Some(minimized_source));
       }
@@ -301,6 +397,9 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     }).map_err(MinifyError)?;
 
   compilation.extend_diagnostics(all_warnings.into_inner().expect("should lock"));
+  if let Some(storage) = &storage {
+    self.cache.clean(storage);
+  }
 
   Ok(())
 }
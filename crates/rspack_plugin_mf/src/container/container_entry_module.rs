@@ -32,6 +32,7 @@ pub struct ContainerEntryModule {
   dependencies: Vec<DependencyId>,
   identifier: ModuleIdentifier,
   lib_ident: String,
+  name: String,
   exposes: Vec<(String, ExposeOptions)>,
   share_scope: String,
   factory_meta: Option<FactoryMeta>,
@@ -57,6 +58,7 @@ impl ContainerEntryModule {
         json_stringify(&exposes),
       )),
       lib_ident,
+      name,
       exposes,
       share_scope,
       factory_meta: None,
@@ -73,6 +75,18 @@ impl ContainerEntryModule {
       enhanced,
     }
   }
+
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  pub fn exposes(&self) -> &[(String, ExposeOptions)] {
+    &self.exposes
+  }
+
+  pub fn share_scope(&self) -> &str {
+    &self.share_scope
+  }
 }
 
 impl Identifiable for ContainerEntryModule {
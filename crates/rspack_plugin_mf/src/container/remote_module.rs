@@ -77,6 +77,10 @@ impl RemoteModule {
       source_map_kind: SourceMapKind::empty(),
     }
   }
+
+  pub fn request(&self) -> &str {
+    &self.request
+  }
 }
 
 impl Identifiable for RemoteModule {
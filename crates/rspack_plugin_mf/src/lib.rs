@@ -1,7 +1,9 @@
 #![feature(let_chains)]
 
 mod container;
+mod manifest;
 mod sharing;
+mod types;
 
 pub use container::{
   container_plugin::{ContainerPlugin, ContainerPluginOptions, ExposeOptions},
@@ -10,6 +12,7 @@ pub use container::{
   },
   module_federation_runtime_plugin::ModuleFederationRuntimePlugin,
 };
+pub use manifest::{ModuleFederationManifestPlugin, ModuleFederationManifestPluginOptions};
 pub use sharing::{
   consume_shared_plugin::{
     ConsumeOptions, ConsumeSharedPlugin, ConsumeSharedPluginOptions, ConsumeVersion,
@@ -19,7 +22,9 @@ pub use sharing::{
     CodeGenerationDataShareInit, DataInitStage, ShareInitData, ShareRuntimeModule,
   },
   share_runtime_plugin::ShareRuntimePlugin,
+  shared_version_check_plugin::{SharedVersionCheckPlugin, SharedVersionCheckPluginOptions},
 };
+pub use types::{ModuleFederationTypesPlugin, ModuleFederationTypesPluginOptions};
 
 mod utils {
   use std::fmt;
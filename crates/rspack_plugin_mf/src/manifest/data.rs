@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+/// The content of `mf-stats.json`. `mf-manifest.json` is the same data without
+/// the build details, see [`ManifestStats::into_manifest`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestStats {
+  pub id: String,
+  pub name: String,
+  pub meta_data: StatsMetaData,
+  pub shared: Vec<StatsShared>,
+  pub remotes: Vec<StatsRemote>,
+  pub exposes: Vec<StatsExpose>,
+}
+
+impl ManifestStats {
+  pub fn into_manifest(mut self) -> Self {
+    for expose in &mut self.exposes {
+      expose.file = None;
+    }
+    for shared in &mut self.shared {
+      shared.used_in = None;
+    }
+    for remote in &mut self.remotes {
+      remote.used_in = None;
+    }
+    self
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsMetaData {
+  pub name: String,
+  pub public_path: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub remote_entry: Option<StatsRemoteEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsRemoteEntry {
+  pub name: String,
+  pub path: String,
+  pub r#type: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsAssets {
+  pub js: StatsAssetGroup,
+  pub css: StatsAssetGroup,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsAssetGroup {
+  pub sync: Vec<String>,
+  pub r#async: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsExpose {
+  pub id: String,
+  pub name: String,
+  pub path: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub file: Option<Vec<String>>,
+  pub assets: StatsAssets,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsShared {
+  pub id: String,
+  pub name: String,
+  pub share_scope: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub version: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub required_version: Option<String>,
+  pub singleton: bool,
+  pub eager: bool,
+  pub strict_version: bool,
+  pub assets: StatsAssets,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub used_in: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsRemote {
+  pub alias: String,
+  pub federation_container_name: String,
+  pub module_name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub entry: Option<String>,
+  pub share_scope: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub used_in: Option<Vec<String>>,
+}
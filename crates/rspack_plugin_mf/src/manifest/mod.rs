@@ -0,0 +1,393 @@
+mod data;
+
+use std::collections::BTreeMap;
+
+use rspack_collections::UkeySet;
+use rspack_core::{
+  rspack_sources::{RawStringSource, SourceExt},
+  ApplyContext, ChunkGroup, ChunkGroupUkey, Compilation, CompilationAsset,
+  CompilationProcessAssets, CompilerOptions, Context, DependenciesBlock, DependencyId,
+  ExternalModule, ExternalRequest, ModuleGraph, ModuleIdentifier, Plugin, PluginContext,
+  PublicPath,
+};
+use rspack_error::{Result, ToStringResultToRspackResultExt};
+use rspack_hook::{plugin, plugin_hook};
+
+pub use self::data::*;
+use crate::{
+  container::{container_entry_module::ContainerEntryModule, remote_module::RemoteModule},
+  sharing::{
+    consume_shared_module::ConsumeSharedModule, provide_shared_module::ProvideSharedModule,
+  },
+  ConsumeVersion, ProvideVersion,
+};
+
+#[derive(Debug)]
+pub struct ModuleFederationManifestPluginOptions {
+  /// Defaults to the name of the container, then `output.uniqueName`.
+  pub name: Option<String>,
+  pub filename: String,
+  pub stats_filename: String,
+}
+
+impl Default for ModuleFederationManifestPluginOptions {
+  fn default() -> Self {
+    Self {
+      name: None,
+      filename: "mf-manifest.json".to_string(),
+      stats_filename: "mf-stats.json".to_string(),
+    }
+  }
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct ModuleFederationManifestPlugin {
+  options: ModuleFederationManifestPluginOptions,
+}
+
+impl ModuleFederationManifestPlugin {
+  pub fn new(options: ModuleFederationManifestPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilationProcessAssets for ModuleFederationManifestPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_TRANSFER)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let public_path = match &compilation.options.output.public_path {
+    PublicPath::Filename(filename) => PublicPath::render_filename(compilation, filename).await,
+    PublicPath::Auto => "auto".to_string(),
+  };
+  let stats = collect_stats(compilation, self.options.name.as_deref(), public_path);
+
+  let stats_json = serde_json::to_string_pretty(&stats).to_rspack_result()?;
+  let manifest_json = serde_json::to_string_pretty(&stats.into_manifest()).to_rspack_result()?;
+  compilation.emit_asset(
+    self.options.stats_filename.clone(),
+    CompilationAsset::from(RawStringSource::from(stats_json).boxed()),
+  );
+  compilation.emit_asset(
+    self.options.filename.clone(),
+    CompilationAsset::from(RawStringSource::from(manifest_json).boxed()),
+  );
+  Ok(())
+}
+
+fn collect_stats(
+  compilation: &Compilation,
+  name: Option<&str>,
+  public_path: String,
+) -> ManifestStats {
+  let module_graph = compilation.get_module_graph();
+  let context = &compilation.options.context;
+
+  let mut container = None;
+  let mut provides = vec![];
+  let mut consumes = vec![];
+  let mut remote_modules = vec![];
+  for (identifier, module) in module_graph.modules() {
+    if let Some(module) = module.downcast_ref::<ContainerEntryModule>() {
+      container = Some(module);
+    } else if let Some(module) = module.downcast_ref::<ProvideSharedModule>() {
+      provides.push(module);
+    } else if let Some(module) = module.downcast_ref::<ConsumeSharedModule>() {
+      consumes.push((identifier, module));
+    } else if let Some(module) = module.downcast_ref::<RemoteModule>() {
+      remote_modules.push((identifier, module));
+    }
+  }
+
+  let name = name
+    .map(ToString::to_string)
+    .or_else(|| container.map(|container| container.name().to_string()))
+    .unwrap_or_else(|| compilation.options.output.unique_name.clone());
+
+  let mut exposes = vec![];
+  let mut remote_entry = None;
+  if let Some(container) = container {
+    remote_entry = get_remote_entry(compilation, container.name());
+    for ((path, options), block) in container.exposes().iter().zip(container.get_blocks()) {
+      let expose_name = path.trim_start_matches("./");
+      let assets = compilation
+        .chunk_graph
+        .get_block_chunk_group(block, &compilation.chunk_group_by_ukey)
+        .map(|chunk_group| get_chunk_group_assets(compilation, chunk_group))
+        .unwrap_or_default();
+      exposes.push(StatsExpose {
+        id: format!("{name}:{expose_name}"),
+        name: expose_name.to_string(),
+        path: path.clone(),
+        file: Some(options.import.clone()),
+        assets,
+      });
+    }
+  }
+
+  // a package may be both provided and consumed, which is one entry in the manifest
+  let mut shared: BTreeMap<(String, String), StatsShared> = BTreeMap::new();
+  for provide in provides {
+    let entry = shared
+      .entry((
+        provide.share_scope().to_string(),
+        provide.name().to_string(),
+      ))
+      .or_insert_with(|| new_shared(&name, provide.share_scope(), provide.name()));
+    if let ProvideVersion::Version(version) = provide.version() {
+      entry.version = Some(version.clone());
+    }
+    if entry.required_version.is_none()
+      && let Some(ConsumeVersion::Version(version)) = provide.required_version()
+    {
+      entry.required_version = Some(version.clone());
+    }
+    entry.singleton |= provide.singleton().unwrap_or(false);
+    entry.eager |= provide.eager();
+    entry.strict_version |= provide.strict_version().unwrap_or(false);
+    let dependency = provide.provided_dependency(&module_graph);
+    extend_assets(
+      &mut entry.assets,
+      get_dependency_files(compilation, &module_graph, dependency),
+    );
+  }
+  for (identifier, consume) in consumes {
+    let options = consume.options();
+    let entry = shared
+      .entry((options.share_scope.clone(), options.share_key.clone()))
+      .or_insert_with(|| new_shared(&name, &options.share_scope, &options.share_key));
+    if let Some(ConsumeVersion::Version(version)) = &options.required_version {
+      entry.required_version = Some(version.clone());
+    }
+    entry.singleton |= options.singleton;
+    entry.eager |= options.eager;
+    entry.strict_version |= options.strict_version;
+    let dependency = consume.fallback_dependency(&module_graph);
+    extend_assets(
+      &mut entry.assets,
+      get_dependency_files(compilation, &module_graph, dependency),
+    );
+    entry
+      .used_in
+      .get_or_insert_with(Vec::new)
+      .extend(get_issuers(&module_graph, &identifier, context));
+  }
+  let shared = shared
+    .into_values()
+    .map(|mut shared| {
+      for group in [&mut shared.assets.js, &mut shared.assets.css] {
+        group.sync.sort();
+        group.sync.dedup();
+      }
+      if let Some(used_in) = &mut shared.used_in {
+        used_in.sort();
+        used_in.dedup();
+      }
+      shared
+    })
+    .collect();
+
+  let mut remotes = remote_modules
+    .into_iter()
+    .map(|(identifier, remote)| {
+      let entry_request = remote
+        .get_dependencies()
+        .first()
+        .and_then(|dependency| module_graph.get_module_by_dependency_id(dependency))
+        .and_then(|module| module.downcast_ref::<ExternalModule>())
+        .and_then(|module| match &module.request {
+          ExternalRequest::Single(request) => Some(request.primary.as_str()),
+          ExternalRequest::Map(_) => None,
+        });
+      // script remotes are written as `name@url`
+      let (federation_container_name, entry) = match entry_request {
+        Some(request) => match request.split_once('@') {
+          Some((global, url)) if !global.is_empty() => (global.to_string(), Some(url.to_string())),
+          _ => (request.to_string(), None),
+        },
+        None => (remote.remote_key.clone(), None),
+      };
+      let used_in = get_issuers(&module_graph, &identifier, context);
+      StatsRemote {
+        alias: remote.remote_key.clone(),
+        federation_container_name,
+        module_name: remote
+          .internal_request
+          .trim_start_matches('.')
+          .trim_start_matches('/')
+          .to_string(),
+        entry,
+        share_scope: remote.share_scope.clone(),
+        used_in: Some(used_in),
+      }
+    })
+    .collect::<Vec<_>>();
+  remotes.sort_by(|a, b| (&a.alias, &a.module_name).cmp(&(&b.alias, &b.module_name)));
+
+  ManifestStats {
+    id: name.clone(),
+    name: name.clone(),
+    meta_data: StatsMetaData {
+      name,
+      public_path,
+      remote_entry,
+    },
+    shared,
+    remotes,
+    exposes,
+  }
+}
+
+fn new_shared(container_name: &str, share_scope: &str, name: &str) -> StatsShared {
+  StatsShared {
+    id: format!("{container_name}:{name}"),
+    name: name.to_string(),
+    share_scope: share_scope.to_string(),
+    version: None,
+    required_version: None,
+    singleton: false,
+    eager: false,
+    strict_version: false,
+    assets: StatsAssets::default(),
+    used_in: Some(vec![]),
+  }
+}
+
+pub(crate) fn get_remote_entry(
+  compilation: &Compilation,
+  container_name: &str,
+) -> Option<StatsRemoteEntry> {
+  let entrypoint = compilation
+    .chunk_group_by_ukey
+    .expect_get(compilation.entrypoints.get(container_name)?);
+  let chunk = compilation
+    .chunk_by_ukey
+    .expect_get(&entrypoint.get_entrypoint_chunk());
+  let mut files = chunk
+    .files()
+    .iter()
+    .filter(|file| is_js(file))
+    .collect::<Vec<_>>();
+  files.sort();
+  let file = files.first()?;
+  let (path, name) = file.rsplit_once('/').unwrap_or(("", file.as_str()));
+  let r#type = entrypoint
+    .get_entry_options()
+    .and_then(|options| options.library.as_ref())
+    .map_or_else(|| "var".to_string(), |library| library.library_type.clone());
+  Some(StatsRemoteEntry {
+    name: name.to_string(),
+    path: path.to_string(),
+    r#type,
+  })
+}
+
+/// The files of the chunk group are loaded when the expose is requested, the
+/// files of its descendants are loaded on demand.
+fn get_chunk_group_assets(compilation: &Compilation, chunk_group: &ChunkGroup) -> StatsAssets {
+  let mut assets = StatsAssets::default();
+  extend_assets(
+    &mut assets,
+    chunk_group.get_files(&compilation.chunk_by_ukey),
+  );
+
+  let mut async_files = vec![];
+  let mut visited: UkeySet<ChunkGroupUkey> = UkeySet::default();
+  let mut queue = chunk_group.children_iterable().copied().collect::<Vec<_>>();
+  while let Some(ukey) = queue.pop() {
+    if !visited.insert(ukey) {
+      continue;
+    }
+    let child = compilation.chunk_group_by_ukey.expect_get(&ukey);
+    async_files.extend(child.get_files(&compilation.chunk_by_ukey));
+    queue.extend(child.children_iterable().copied());
+  }
+  for file in async_files {
+    let group = if is_js(&file) {
+      &mut assets.js
+    } else if file.ends_with(".css") {
+      &mut assets.css
+    } else {
+      continue;
+    };
+    if !group.sync.contains(&file) {
+      group.r#async.push(file);
+    }
+  }
+
+  for group in [&mut assets.js, &mut assets.css] {
+    group.sync.sort();
+    group.sync.dedup();
+    group.r#async.sort();
+    group.r#async.dedup();
+  }
+  assets
+}
+
+fn extend_assets(assets: &mut StatsAssets, files: impl IntoIterator<Item = String>) {
+  for file in files {
+    if is_js(&file) {
+      assets.js.sync.push(file);
+    } else if file.ends_with(".css") {
+      assets.css.sync.push(file);
+    }
+  }
+}
+
+/// The files of the chunks containing the module that `dependency` points to.
+fn get_dependency_files(
+  compilation: &Compilation,
+  module_graph: &ModuleGraph,
+  dependency: Option<&DependencyId>,
+) -> Vec<String> {
+  let Some(module) = dependency.and_then(|d| module_graph.module_identifier_by_dependency_id(d))
+  else {
+    return vec![];
+  };
+  compilation
+    .chunk_graph
+    .get_module_chunks(*module)
+    .iter()
+    .flat_map(|chunk| {
+      compilation
+        .chunk_by_ukey
+        .expect_get(chunk)
+        .files()
+        .iter()
+        .cloned()
+    })
+    .collect()
+}
+
+pub(crate) fn get_issuers(
+  module_graph: &ModuleGraph,
+  module: &ModuleIdentifier,
+  context: &Context,
+) -> Vec<String> {
+  let mut issuers = module_graph
+    .get_incoming_connections(module)
+    .filter_map(|connection| connection.original_module_identifier)
+    .filter_map(|identifier| module_graph.module_by_identifier(&identifier))
+    .map(|module| module.readable_identifier(context).to_string())
+    .collect::<Vec<_>>();
+  issuers.sort();
+  issuers.dedup();
+  issuers
+}
+
+fn is_js(file: &str) -> bool {
+  file.ends_with(".js") || file.ends_with(".mjs") || file.ends_with(".cjs")
+}
+
+impl Plugin for ModuleFederationManifestPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ModuleFederationManifestPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
@@ -93,6 +93,26 @@ impl ConsumeSharedModule {
       source_map_kind: SourceMapKind::empty(),
     }
   }
+
+  pub fn options(&self) -> &ConsumeOptions {
+    &self.options
+  }
+
+  /// The dependency on the fallback module, which is in a block unless eager.
+  pub fn fallback_dependency<'a>(
+    &'a self,
+    module_graph: &'a ModuleGraph,
+  ) -> Option<&'a DependencyId> {
+    if self.options.eager {
+      self.dependencies.first()
+    } else {
+      self
+        .blocks
+        .first()
+        .and_then(|block| module_graph.block_by_id(block))
+        .and_then(|block| block.get_dependencies().first())
+    }
+  }
 }
 
 impl Identifiable for ConsumeSharedModule {
@@ -9,3 +9,5 @@ pub mod provide_shared_module_factory;
 pub mod provide_shared_plugin;
 pub mod share_runtime_module;
 pub mod share_runtime_plugin;
+pub mod shared_version_check_plugin;
+pub mod version_range;
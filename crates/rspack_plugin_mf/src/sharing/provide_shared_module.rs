@@ -85,6 +85,50 @@ impl ProvideSharedModule {
       source_map_kind: SourceMapKind::empty(),
     }
   }
+
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  pub fn share_scope(&self) -> &str {
+    &self.share_scope
+  }
+
+  pub fn version(&self) -> &ProvideVersion {
+    &self.version
+  }
+
+  pub fn eager(&self) -> bool {
+    self.eager
+  }
+
+  pub fn singleton(&self) -> Option<bool> {
+    self.singleton
+  }
+
+  pub fn required_version(&self) -> Option<&ConsumeVersion> {
+    self.required_version.as_ref()
+  }
+
+  pub fn strict_version(&self) -> Option<bool> {
+    self.strict_version
+  }
+
+  /// The dependency on the provided module, which is in a block unless eager.
+  pub fn provided_dependency<'a>(
+    &'a self,
+    module_graph: &'a ModuleGraph,
+  ) -> Option<&'a DependencyId> {
+    if self.eager {
+      self.dependencies.first()
+    } else {
+      self
+        .blocks
+        .first()
+        .and_then(|block| module_graph.block_by_id(block))
+        .and_then(|block| block.get_dependencies().first())
+    }
+  }
 }
 
 impl Identifiable for ProvideSharedModule {
@@ -0,0 +1,271 @@
+use std::collections::BTreeMap;
+
+use rspack_core::{
+  ApplyContext, Compilation, CompilationFinishModules, CompilerOptions, Context, ModuleGraph,
+  NormalModule, Plugin, PluginContext,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+
+use super::{
+  consume_shared_module::ConsumeSharedModule,
+  provide_shared_module::ProvideSharedModule,
+  version_range::{Version, VersionRange},
+};
+use crate::{
+  manifest::{get_issuers, ManifestStats},
+  ConsumeVersion, ProvideVersion,
+};
+
+const PLUGIN_NAME: &str = "rspack.SharedVersionCheckPlugin";
+
+#[derive(Debug, Default)]
+pub struct SharedVersionCheckPluginOptions {
+  /// Paths to the `mf-stats.json` or `mf-manifest.json` of the other federated
+  /// builds sharing modules with this one, relative to `context`.
+  pub manifests: Vec<String>,
+}
+
+/// Reports what would otherwise only be found at runtime by `consumesCommon.js`:
+/// required versions which none of the provided versions satisfy, and
+/// singletons whose loaded version doesn't satisfy all of their consumers.
+#[plugin]
+#[derive(Debug)]
+pub struct SharedVersionCheckPlugin {
+  options: SharedVersionCheckPluginOptions,
+}
+
+impl SharedVersionCheckPlugin {
+  pub fn new(options: SharedVersionCheckPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[derive(Debug)]
+struct ProvidedVersion {
+  version: String,
+  /// The build providing the version.
+  provided_by: String,
+}
+
+#[derive(Debug)]
+struct Requirement {
+  range: String,
+  strict_version: bool,
+  requested_by: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+struct SharedPackage {
+  singleton: bool,
+  provided: Vec<ProvidedVersion>,
+  requirements: Vec<Requirement>,
+}
+
+impl SharedPackage {
+  fn provide(&mut self, version: &str, provided_by: &str) {
+    if !self
+      .provided
+      .iter()
+      .any(|provided| provided.version == version && provided.provided_by == provided_by)
+    {
+      self.provided.push(ProvidedVersion {
+        version: version.to_string(),
+        provided_by: provided_by.to_string(),
+      });
+    }
+  }
+}
+
+type SharedPackages = BTreeMap<(String, String), SharedPackage>;
+
+const THIS_BUILD: &str = "this build";
+
+#[plugin_hook(CompilationFinishModules for SharedVersionCheckPlugin)]
+async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+  let mut packages = SharedPackages::new();
+  collect_modules(
+    &compilation.get_module_graph(),
+    &compilation.options.context,
+    &mut packages,
+  );
+
+  let mut diagnostics = vec![];
+  for manifest in &self.options.manifests {
+    let path = compilation.options.context.as_path().join(manifest);
+    compilation
+      .file_dependencies
+      .insert(path.as_std_path().into());
+    let stats = match compilation.input_filesystem.read(&path).await {
+      Ok(content) => serde_json::from_slice::<ManifestStats>(&content).map_err(|e| e.to_string()),
+      Err(e) => Err(e.to_string()),
+    };
+    match stats {
+      Ok(stats) => collect_manifest(stats, &mut packages),
+      Err(e) => diagnostics.push(Diagnostic::warn(
+        PLUGIN_NAME.to_string(),
+        format!("Failed to read the manifest {path}: {e}"),
+      )),
+    }
+  }
+
+  diagnostics.extend(check_shared_packages(&packages));
+  compilation.extend_diagnostics(diagnostics);
+  Ok(())
+}
+
+fn collect_modules(module_graph: &ModuleGraph, context: &Context, packages: &mut SharedPackages) {
+  for (identifier, module) in module_graph.modules() {
+    if let Some(provide) = module.downcast_ref::<ProvideSharedModule>() {
+      let package = packages
+        .entry((
+          provide.share_scope().to_string(),
+          provide.name().to_string(),
+        ))
+        .or_default();
+      package.singleton |= provide.singleton().unwrap_or(false);
+      if let ProvideVersion::Version(version) = provide.version() {
+        package.provide(version, THIS_BUILD);
+      }
+    } else if let Some(consume) = module.downcast_ref::<ConsumeSharedModule>() {
+      let options = consume.options();
+      let package = packages
+        .entry((options.share_scope.clone(), options.share_key.clone()))
+        .or_default();
+      package.singleton |= options.singleton;
+      // the fallback is used when nothing satisfies the required version, so
+      // its installed version is as good as provided
+      if let Some(version) = consume
+        .fallback_dependency(module_graph)
+        .and_then(|dependency| module_graph.get_module_by_dependency_id(dependency))
+        .and_then(|module| module.downcast_ref::<NormalModule>())
+        .and_then(|module| {
+          module
+            .resource_resolved_data()
+            .resource_description
+            .as_ref()
+        })
+        .and_then(|description| {
+          let description = description.json().as_object()?;
+          let name = description.get("name")?.as_str()?;
+          let package_name = options
+            .package_name
+            .as_deref()
+            .unwrap_or(&options.share_key);
+          if name != package_name {
+            return None;
+          }
+          description.get("version")?.as_str()
+        })
+      {
+        package.provide(version, THIS_BUILD);
+      }
+      if let Some(ConsumeVersion::Version(range)) = &options.required_version {
+        package.requirements.push(Requirement {
+          range: range.clone(),
+          strict_version: options.strict_version,
+          requested_by: get_issuers(module_graph, &identifier, context),
+        });
+      }
+    }
+  }
+}
+
+fn collect_manifest(stats: ManifestStats, packages: &mut SharedPackages) {
+  for shared in stats.shared {
+    let package = packages
+      .entry((shared.share_scope, shared.name))
+      .or_default();
+    package.singleton |= shared.singleton;
+    if let Some(version) = &shared.version {
+      package.provide(version, &stats.name);
+    }
+    if let Some(range) = shared.required_version {
+      let requested_by = match shared.used_in {
+        Some(used_in) if !used_in.is_empty() => used_in
+          .into_iter()
+          .map(|module| format!("{module} ({})", stats.name))
+          .collect(),
+        _ => vec![stats.name.clone()],
+      };
+      package.requirements.push(Requirement {
+        range,
+        strict_version: shared.strict_version,
+        requested_by,
+      });
+    }
+  }
+}
+
+fn check_shared_packages(packages: &SharedPackages) -> Vec<Diagnostic> {
+  let mut diagnostics = vec![];
+  for ((share_scope, name), package) in packages {
+    let versions = package
+      .provided
+      .iter()
+      .filter_map(|provided| Some((Version::parse(&provided.version)?, provided)))
+      .collect::<Vec<_>>();
+    let Some((highest, highest_provided)) = versions.iter().max_by(|a, b| a.0.cmp(&b.0)) else {
+      continue;
+    };
+    for requirement in &package.requirements {
+      let Some(range) = VersionRange::parse(&requirement.range) else {
+        continue;
+      };
+      let summary = if package.singleton {
+        // a singleton is loaded once, in the highest version
+        if range.satisfies(highest) {
+          continue;
+        }
+        format!(
+          "Shared singleton \"{name}\" (share scope \"{share_scope}\") is loaded in version {} provided by {}, which doesn't satisfy \"{}\".",
+          highest_provided.version, highest_provided.provided_by, requirement.range
+        )
+      } else {
+        if versions.iter().any(|(version, _)| range.satisfies(version)) {
+          continue;
+        }
+        format!(
+          "No provided version of shared module \"{name}\" (share scope \"{share_scope}\") satisfies \"{}\".",
+          requirement.range
+        )
+      };
+      let provided = package
+        .provided
+        .iter()
+        .map(|provided| format!("  {} ({})", provided.version, provided.provided_by))
+        .collect::<Vec<_>>()
+        .join("\n");
+      let requested_by = requirement
+        .requested_by
+        .iter()
+        .map(|module| format!("  {module}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+      let message =
+        format!("{summary}\nProvided versions:\n{provided}\nRequested by:\n{requested_by}");
+      // `strictVersion` throws at runtime, otherwise only a warning is logged
+      diagnostics.push(if requirement.strict_version {
+        Diagnostic::error(PLUGIN_NAME.to_string(), message)
+      } else {
+        Diagnostic::warn(PLUGIN_NAME.to_string(), message)
+      });
+    }
+  }
+  diagnostics
+}
+
+impl Plugin for SharedVersionCheckPlugin {
+  fn name(&self) -> &'static str {
+    PLUGIN_NAME
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .finish_modules
+      .tap(finish_modules::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,390 @@
+//! npm semver ranges, as matched against the shared versions at runtime by
+//! `consumesCommon.js`, so conflicts can be found during the build.
+
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Prerelease {
+  Numeric(u64),
+  Alpha(String),
+}
+
+impl Ord for Prerelease {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self, other) {
+      (Self::Numeric(a), Self::Numeric(b)) => a.cmp(b),
+      (Self::Numeric(_), Self::Alpha(_)) => Ordering::Less,
+      (Self::Alpha(_), Self::Numeric(_)) => Ordering::Greater,
+      (Self::Alpha(a), Self::Alpha(b)) => a.cmp(b),
+    }
+  }
+}
+
+impl PartialOrd for Prerelease {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+  major: u64,
+  minor: u64,
+  patch: u64,
+  pre: Vec<Prerelease>,
+}
+
+impl Version {
+  pub fn parse(version: &str) -> Option<Self> {
+    let partial = Partial::parse(version.trim().trim_start_matches(['v', '=']))?;
+    Some(Self {
+      major: partial.major?,
+      minor: partial.minor?,
+      patch: partial.patch?,
+      pre: partial.pre,
+    })
+  }
+
+  fn new(major: u64, minor: u64, patch: u64) -> Self {
+    Self {
+      major,
+      minor,
+      patch,
+      pre: vec![],
+    }
+  }
+
+  /// The lowest prerelease of the version, `1.0.0-0`, which is the exclusive
+  /// upper bound of ranges like `^0.x`.
+  fn lowest(major: u64, minor: u64, patch: u64) -> Self {
+    Self {
+      major,
+      minor,
+      patch,
+      pre: vec![Prerelease::Numeric(0)],
+    }
+  }
+
+  fn same_release(&self, other: &Self) -> bool {
+    (self.major, self.minor, self.patch) == (other.major, other.minor, other.patch)
+  }
+}
+
+impl Ord for Version {
+  fn cmp(&self, other: &Self) -> Ordering {
+    (self.major, self.minor, self.patch)
+      .cmp(&(other.major, other.minor, other.patch))
+      .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => self.pre.cmp(&other.pre),
+      })
+  }
+}
+
+impl PartialOrd for Version {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+/// A version whose missing or `x` parts match anything.
+#[derive(Debug)]
+struct Partial {
+  major: Option<u64>,
+  minor: Option<u64>,
+  patch: Option<u64>,
+  pre: Vec<Prerelease>,
+}
+
+impl Partial {
+  fn parse(version: &str) -> Option<Self> {
+    let version = version
+      .split_once('+')
+      .map_or(version, |(version, _)| version);
+    let (release, pre) = match version.split_once('-') {
+      Some((release, pre)) => (
+        release,
+        pre
+          .split('.')
+          .map(|part| match part.parse::<u64>() {
+            Ok(number) => Prerelease::Numeric(number),
+            Err(_) => Prerelease::Alpha(part.to_string()),
+          })
+          .collect(),
+      ),
+      None => (version, vec![]),
+    };
+    let mut parts = [None; 3];
+    let mut wildcard = false;
+    for (index, part) in release.split('.').enumerate() {
+      if index >= 3 {
+        return None;
+      }
+      if matches!(part, "x" | "X" | "*") || (part.is_empty() && index == 0) {
+        wildcard = true;
+      } else if !wildcard {
+        parts[index] = Some(part.parse::<u64>().ok()?);
+      }
+    }
+    Some(Self {
+      major: parts[0],
+      minor: parts[1],
+      patch: parts[2],
+      pre,
+    })
+  }
+
+  fn floor(&self) -> Version {
+    Version {
+      major: self.major.unwrap_or(0),
+      minor: self.minor.unwrap_or(0),
+      patch: self.patch.unwrap_or(0),
+      pre: self.pre.clone(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+  Lt,
+  Le,
+  Gt,
+  Ge,
+  Eq,
+}
+
+#[derive(Debug)]
+struct Comparator {
+  operator: Operator,
+  version: Version,
+}
+
+impl Comparator {
+  fn new(operator: Operator, version: Version) -> Self {
+    Self { operator, version }
+  }
+
+  fn test(&self, version: &Version) -> bool {
+    let ordering = version.cmp(&self.version);
+    match self.operator {
+      Operator::Lt => ordering.is_lt(),
+      Operator::Le => ordering.is_le(),
+      Operator::Gt => ordering.is_gt(),
+      Operator::Ge => ordering.is_ge(),
+      Operator::Eq => ordering.is_eq(),
+    }
+  }
+}
+
+/// Comparators which all have to match, `None` never matches.
+type ComparatorSet = Option<Vec<Comparator>>;
+
+#[derive(Debug)]
+pub struct VersionRange {
+  sets: Vec<ComparatorSet>,
+}
+
+impl VersionRange {
+  /// Returns `None` for ranges which are not semver, like tags or urls,
+  /// those can't be checked during the build.
+  pub fn parse(range: &str) -> Option<Self> {
+    let sets = range
+      .split("||")
+      .map(parse_comparator_set)
+      .collect::<Option<Vec<_>>>()?;
+    Some(Self { sets })
+  }
+
+  pub fn satisfies(&self, version: &Version) -> bool {
+    self.sets.iter().any(|set| {
+      let Some(comparators) = set else {
+        return false;
+      };
+      if !comparators
+        .iter()
+        .all(|comparator| comparator.test(version))
+      {
+        return false;
+      }
+      // like npm, prereleases only match ranges which mention a prerelease of
+      // the same release
+      version.pre.is_empty()
+        || comparators.iter().any(|comparator| {
+          !comparator.version.pre.is_empty() && comparator.version.same_release(version)
+        })
+    })
+  }
+}
+
+fn parse_comparator_set(set: &str) -> Option<ComparatorSet> {
+  let mut tokens: Vec<String> = vec![];
+  let mut pending_operator = None;
+  for token in set.split_whitespace() {
+    if token
+      .chars()
+      .all(|c| matches!(c, '<' | '>' | '=' | '^' | '~'))
+    {
+      pending_operator = Some(token);
+      continue;
+    }
+    match pending_operator.take() {
+      Some(operator) => tokens.push(format!("{operator}{token}")),
+      None => tokens.push(token.to_string()),
+    }
+  }
+
+  if let [from, hyphen, to] = tokens.as_slice()
+    && hyphen == "-"
+  {
+    let mut comparators = vec![Comparator::new(Operator::Ge, Partial::parse(from)?.floor())];
+    return Some(desugar("<=", Partial::parse(to)?).map(|mut to| {
+      comparators.append(&mut to);
+      comparators
+    }));
+  }
+
+  let mut comparators = vec![];
+  for token in tokens {
+    let operator_len = token
+      .find(|c: char| !matches!(c, '<' | '>' | '=' | '^' | '~' | 'v'))
+      .unwrap_or(token.len());
+    let (operator, version) = token.split_at(operator_len);
+    let operator = operator.trim_end_matches('v');
+    match desugar(operator, Partial::parse(version)?) {
+      Some(mut desugared) => comparators.append(&mut desugared),
+      None => return Some(None),
+    }
+  }
+  Some(Some(comparators))
+}
+
+/// Converts an operator with a partial version to plain comparators, `None`
+/// if nothing can match.
+fn desugar(operator: &str, partial: Partial) -> Option<Vec<Comparator>> {
+  use Operator::*;
+
+  let floor = partial.floor();
+  let comparators = match (operator, partial.major, partial.minor, partial.patch) {
+    ("" | "=", None, ..) | ("^" | "~" | ">=" | "<=", None, ..) => vec![],
+    ("" | "=", Some(_), Some(_), Some(_)) => vec![Comparator::new(Eq, floor)],
+    ("" | "=" | "~" | "^", Some(major), None, _) => vec![
+      Comparator::new(Ge, floor),
+      Comparator::new(Lt, Version::lowest(major + 1, 0, 0)),
+    ],
+    ("" | "=" | "~", Some(major), Some(minor), _) => vec![
+      Comparator::new(Ge, floor),
+      Comparator::new(Lt, Version::lowest(major, minor + 1, 0)),
+    ],
+    ("^", Some(0), Some(0), Some(patch)) => vec![
+      Comparator::new(Ge, floor),
+      Comparator::new(Lt, Version::lowest(0, 0, patch + 1)),
+    ],
+    ("^", Some(0), Some(minor), _) => vec![
+      Comparator::new(Ge, floor),
+      Comparator::new(Lt, Version::lowest(0, minor + 1, 0)),
+    ],
+    ("^", Some(major), ..) => vec![
+      Comparator::new(Ge, floor),
+      Comparator::new(Lt, Version::lowest(major + 1, 0, 0)),
+    ],
+    (">" | "<", None, ..) => return None,
+    (">", Some(major), None, _) => vec![Comparator::new(Ge, Version::new(major + 1, 0, 0))],
+    (">", Some(major), Some(minor), None) => {
+      vec![Comparator::new(Ge, Version::new(major, minor + 1, 0))]
+    }
+    (">", ..) => vec![Comparator::new(Gt, floor)],
+    (">=", ..) => vec![Comparator::new(Ge, floor)],
+    ("<", Some(major), minor, patch) if minor.is_none() || patch.is_none() => {
+      vec![Comparator::new(
+        Lt,
+        Version::lowest(major, minor.unwrap_or(0), patch.unwrap_or(0)),
+      )]
+    }
+    ("<", ..) => vec![Comparator::new(Lt, floor)],
+    ("<=", Some(major), None, _) => vec![Comparator::new(Lt, Version::lowest(major + 1, 0, 0))],
+    ("<=", Some(major), Some(minor), None) => {
+      vec![Comparator::new(Lt, Version::lowest(major, minor + 1, 0))]
+    }
+    ("<=", ..) => vec![Comparator::new(Le, floor)],
+    _ => return None,
+  };
+  Some(comparators)
+}
+
+/// Whether `version` is in `range`, `None` if either of them is not semver.
+pub fn satisfies(range: &str, version: &str) -> Option<bool> {
+  Some(VersionRange::parse(range)?.satisfies(&Version::parse(version)?))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_match_npm_ranges() {
+    let cases = [
+      ("^1.2.3", "1.9.0", true),
+      ("^1.2.3", "2.0.0", false),
+      ("^1.2.3", "1.2.2", false),
+      ("^0.2.3", "0.2.9", true),
+      ("^0.2.3", "0.3.0", false),
+      ("^0.0.3", "0.0.4", false),
+      ("~1.2.3", "1.2.9", true),
+      ("~1.2.3", "1.3.0", false),
+      ("~1", "1.9.9", true),
+      ("1.x", "1.4.0", true),
+      ("1.2", "1.3.0", false),
+      ("*", "3.0.0", true),
+      ("", "3.0.0", true),
+      (">=1.0.0 <2.0.0", "1.5.0", true),
+      (">= 1.0.0 < 2.0.0", "2.0.0", false),
+      (">1.2", "1.2.9", false),
+      (">1.2", "1.3.0", true),
+      ("<=1.2", "1.2.9", true),
+      ("1.2.3 - 2.3", "2.3.9", true),
+      ("1.2.3 - 2.3", "2.4.0", false),
+      ("^16.8.0 || ^17.0.0 || ^18.0.0", "17.0.2", true),
+      ("^16.8.0 || ^17.0.0", "18.2.0", false),
+      ("=1.0.0", "1.0.0", true),
+      ("^1.0.0", "1.1.0-beta.1", false),
+      ("^1.1.0-beta.0", "1.1.0-beta.1", true),
+      ("^1.1.0-beta.0", "1.1.0", true),
+      ("<1.0.0", "1.0.0-rc.1", false),
+    ];
+    for (range, version, expected) in cases {
+      assert_eq!(
+        satisfies(range, version),
+        Some(expected),
+        "{version} in {range}"
+      );
+    }
+    assert_eq!(satisfies("workspace:*", "1.0.0"), None);
+    assert_eq!(satisfies("^1.0.0", "latest"), None);
+  }
+
+  #[test]
+  fn should_order_prereleases() {
+    let mut versions = [
+      "1.0.0",
+      "1.0.0-rc.1",
+      "1.0.0-alpha",
+      "1.0.0-alpha.2",
+      "0.9.9",
+    ]
+    .map(|version| Version::parse(version).unwrap());
+    versions.sort();
+    assert_eq!(
+      versions,
+      [
+        "0.9.9",
+        "1.0.0-alpha",
+        "1.0.0-alpha.2",
+        "1.0.0-rc.1",
+        "1.0.0"
+      ]
+      .map(|version| Version::parse(version).unwrap())
+    );
+  }
+}
@@ -0,0 +1,275 @@
+//! A minimal zip archive, the format of the `@mf-types.zip` of the federation
+//! tooling, with the entries compressed by deflate.
+
+use std::io::{Read, Write};
+
+use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
+
+const LOCAL_FILE_HEADER: u32 = 0x0403_4b50;
+const CENTRAL_DIRECTORY_HEADER: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+const STORED: u16 = 0;
+const DEFLATED: u16 = 8;
+/// The entry names are utf-8.
+const UTF8_FLAG: u16 = 1 << 11;
+/// The archives are downloaded from the remotes, which are not trusted to keep
+/// their size reasonable.
+const MAX_ARCHIVE_SIZE: usize = 64 * 1024 * 1024;
+const MAX_INFLATED_SIZE: usize = 256 * 1024 * 1024;
+
+/// Writes the archive, failing when the sizes, the offsets or the number of
+/// entries overflow the fields of the format, as zip64 is not supported.
+pub fn write_archive(files: &[(String, Vec<u8>)]) -> std::io::Result<Vec<u8>> {
+  let mut archive = vec![];
+  let mut central_directory = vec![];
+  for (name, content) in files {
+    let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+    encoder.write_all(content)?;
+    let compressed = encoder.finish()?;
+    let crc = crc32fast::hash(content);
+    let offset = to_u32(archive.len())?;
+
+    let mut header = vec![];
+    push_u32(&mut header, crc);
+    push_u32(&mut header, to_u32(compressed.len())?);
+    push_u32(&mut header, to_u32(content.len())?);
+    push_u16(&mut header, to_u16(name.len())?);
+    push_u16(&mut header, 0);
+
+    push_u32(&mut archive, LOCAL_FILE_HEADER);
+    push_common(&mut archive);
+    archive.extend_from_slice(&header);
+    archive.extend_from_slice(name.as_bytes());
+    archive.extend_from_slice(&compressed);
+
+    push_u32(&mut central_directory, CENTRAL_DIRECTORY_HEADER);
+    // version made by
+    push_u16(&mut central_directory, 20);
+    push_common(&mut central_directory);
+    central_directory.extend_from_slice(&header);
+    // comment length, disk number, internal and external attributes
+    push_u16(&mut central_directory, 0);
+    push_u16(&mut central_directory, 0);
+    push_u16(&mut central_directory, 0);
+    push_u32(&mut central_directory, 0);
+    push_u32(&mut central_directory, offset);
+    central_directory.extend_from_slice(name.as_bytes());
+  }
+
+  let central_directory_offset = to_u32(archive.len())?;
+  let count = to_u16(files.len())?;
+  let central_directory_len = to_u32(central_directory.len())?;
+  archive.extend_from_slice(&central_directory);
+  push_u32(&mut archive, END_OF_CENTRAL_DIRECTORY);
+  push_u16(&mut archive, 0);
+  push_u16(&mut archive, 0);
+  push_u16(&mut archive, count);
+  push_u16(&mut archive, count);
+  push_u32(&mut archive, central_directory_len);
+  push_u32(&mut archive, central_directory_offset);
+  push_u16(&mut archive, 0);
+  Ok(archive)
+}
+
+fn to_u16(value: usize) -> std::io::Result<u16> {
+  u16::try_from(value).map_err(|_| too_large())
+}
+
+fn to_u32(value: usize) -> std::io::Result<u32> {
+  u32::try_from(value).map_err(|_| too_large())
+}
+
+fn too_large() -> std::io::Error {
+  std::io::Error::other("the types archive is too large, zip64 is not supported")
+}
+
+/// The fields shared by the local and the central headers, up to the crc.
+fn push_common(buf: &mut Vec<u8>) {
+  // version needed to extract
+  push_u16(buf, 20);
+  push_u16(buf, UTF8_FLAG);
+  push_u16(buf, DEFLATED);
+  // modification time and date, 1980-01-01 00:00 so the archive is stable
+  push_u16(buf, 0);
+  push_u16(buf, (1 << 5) | 1);
+}
+
+fn push_u16(buf: &mut Vec<u8>, value: u16) {
+  buf.extend_from_slice(&value.to_le_bytes());
+}
+
+fn push_u32(buf: &mut Vec<u8>, value: u32) {
+  buf.extend_from_slice(&value.to_le_bytes());
+}
+
+/// Reads the files of an archive, directories are skipped.
+///
+/// The archive and the total size of its inflated files are capped, and every
+/// file must match the size and the crc32 of its central directory entry.
+pub fn read_archive(archive: &[u8]) -> Result<Vec<(String, Vec<u8>)>, String> {
+  if archive.len() > MAX_ARCHIVE_SIZE {
+    return Err(format!(
+      "the archive is larger than {MAX_ARCHIVE_SIZE} bytes"
+    ));
+  }
+  let end = (0..archive.len().saturating_sub(21))
+    .rev()
+    .find(|&pos| read_u32(archive, pos) == Some(END_OF_CENTRAL_DIRECTORY))
+    .ok_or("missing the end of central directory")?;
+  let count = read_u16(archive, end + 10).ok_or("truncated archive")?;
+  let mut pos = read_u32(archive, end + 16).ok_or("truncated archive")? as usize;
+
+  let mut files = vec![];
+  let mut total_size = 0usize;
+  for _ in 0..count {
+    if read_u32(archive, pos) != Some(CENTRAL_DIRECTORY_HEADER) {
+      return Err("invalid central directory".to_string());
+    }
+    let field = |offset: usize| read_u16(archive, pos + offset).ok_or("truncated archive");
+    let method = field(10)?;
+    let crc = read_u32(archive, pos + 16).ok_or("truncated archive")?;
+    let compressed_size = read_u32(archive, pos + 20).ok_or("truncated archive")? as usize;
+    let size = read_u32(archive, pos + 24).ok_or("truncated archive")? as usize;
+    let name_len = field(28)? as usize;
+    let extra_len = field(30)? as usize;
+    let comment_len = field(32)? as usize;
+    let offset = read_u32(archive, pos + 42).ok_or("truncated archive")? as usize;
+    let name = archive
+      .get(pos + 46..pos + 46 + name_len)
+      .ok_or("truncated archive")?;
+    let name = String::from_utf8_lossy(name).into_owned();
+    pos += 46 + name_len + extra_len + comment_len;
+    if name.ends_with('/') {
+      continue;
+    }
+
+    let local_name_len = read_u16(archive, offset + 26).ok_or("truncated archive")? as usize;
+    let local_extra_len = read_u16(archive, offset + 28).ok_or("truncated archive")? as usize;
+    let start = offset + 30 + local_name_len + local_extra_len;
+    let data = archive
+      .get(start..start + compressed_size)
+      .ok_or("truncated archive")?;
+    total_size = total_size.saturating_add(size);
+    if total_size > MAX_INFLATED_SIZE {
+      return Err(format!(
+        "the files of the archive are larger than {MAX_INFLATED_SIZE} bytes"
+      ));
+    }
+    let content = match method {
+      STORED => data.to_vec(),
+      DEFLATED => {
+        // the declared size is not trusted for the allocation, and one more
+        // byte is read to detect the files inflating past it
+        let mut content = vec![];
+        DeflateDecoder::new(data)
+          .take(size as u64 + 1)
+          .read_to_end(&mut content)
+          .map_err(|e| format!("failed to inflate {name}: {e}"))?;
+        content
+      }
+      _ => return Err(format!("unsupported compression method {method} of {name}")),
+    };
+    if content.len() != size {
+      return Err(format!(
+        "the size of {name} doesn't match the central directory"
+      ));
+    }
+    if crc32fast::hash(&content) != crc {
+      return Err(format!(
+        "the crc32 of {name} doesn't match the central directory"
+      ));
+    }
+    files.push((name, content));
+  }
+  Ok(files)
+}
+
+fn read_u16(buf: &[u8], pos: usize) -> Option<u16> {
+  Some(u16::from_le_bytes(buf.get(pos..pos + 2)?.try_into().ok()?))
+}
+
+fn read_u32(buf: &[u8], pos: usize) -> Option<u32> {
+  Some(u32::from_le_bytes(buf.get(pos..pos + 4)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_read_written_archive() {
+    let files = vec![
+      (
+        "Button.d.ts".to_string(),
+        b"export * from './compiled-types/src/Button';\n".to_vec(),
+      ),
+      (
+        "compiled-types/src/Button.d.ts".to_string(),
+        b"export declare const Button: () => string;\n".to_vec(),
+      ),
+    ];
+    let archive = write_archive(&files).unwrap();
+    assert_eq!(read_archive(&archive).unwrap(), files);
+    assert_eq!(write_archive(&files).unwrap(), archive);
+  }
+
+  /// The position of the first central directory entry.
+  fn central_directory(archive: &[u8]) -> usize {
+    read_u32(archive, archive.len() - 22 + 16).unwrap() as usize
+  }
+
+  fn patch_u32(archive: &mut [u8], pos: usize, value: u32) {
+    archive[pos..pos + 4].copy_from_slice(&value.to_le_bytes());
+  }
+
+  #[test]
+  fn should_reject_mismatched_files() {
+    let files = vec![("index.d.ts".to_string(), b"export {};\n".to_vec())];
+    let archive = write_archive(&files).unwrap();
+    let entry = central_directory(&archive);
+
+    let mut smaller = archive.clone();
+    patch_u32(&mut smaller, entry + 24, 4);
+    assert_eq!(
+      read_archive(&smaller).unwrap_err(),
+      "the size of index.d.ts doesn't match the central directory"
+    );
+
+    let mut larger = archive.clone();
+    patch_u32(&mut larger, entry + 24, 64);
+    assert!(read_archive(&larger).is_err());
+
+    let mut corrupted = archive.clone();
+    patch_u32(&mut corrupted, entry + 16, 0);
+    assert_eq!(
+      read_archive(&corrupted).unwrap_err(),
+      "the crc32 of index.d.ts doesn't match the central directory"
+    );
+  }
+
+  #[test]
+  fn should_cap_the_sizes() {
+    let files = vec![("index.d.ts".to_string(), b"export {};\n".to_vec())];
+    let mut archive = write_archive(&files).unwrap();
+    let entry = central_directory(&archive);
+    patch_u32(&mut archive, entry + 24, u32::MAX);
+    assert!(read_archive(&archive)
+      .unwrap_err()
+      .starts_with("the files of the archive are larger than"));
+
+    assert!(read_archive(&vec![0; MAX_ARCHIVE_SIZE + 1])
+      .unwrap_err()
+      .starts_with("the archive is larger than"));
+  }
+
+  #[test]
+  fn should_fail_instead_of_truncating() {
+    let files = vec![("a".repeat(usize::from(u16::MAX) + 1), vec![])];
+    assert!(write_archive(&files).is_err());
+
+    let files = (0..=usize::from(u16::MAX))
+      .map(|index| (index.to_string(), vec![]))
+      .collect::<Vec<_>>();
+    assert!(write_archive(&files).is_err());
+  }
+}
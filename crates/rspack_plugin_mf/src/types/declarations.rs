@@ -0,0 +1,284 @@
+//! Collects the `.d.ts` surface of an exposed module: the declaration file next
+//! to each source, or the isolated declarations emitted from typescript
+//! sources, following relative imports so the declarations are self-contained.
+
+use std::sync::Arc;
+
+use rspack_fs::ReadableFileSystem;
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use swc_core::{
+  common::{comments::SingleThreadedComments, FileName, Globals, Mark, SourceMap, GLOBALS},
+  ecma::{
+    ast::{EsVersion, Program},
+    codegen::to_code_default,
+    parser::{parse_file_as_module, Syntax, TsSyntax},
+    transforms::base::resolver,
+    visit::VisitMutWith,
+  },
+  typescript::fast_dts::FastDts,
+};
+
+#[derive(Debug, Default)]
+pub struct Declarations {
+  /// The declarations keyed by their path relative to the context.
+  pub files: Vec<(String, String)>,
+  pub warnings: Vec<String>,
+}
+
+/// Collects the declarations of `entry` and the files it imports relatively,
+/// returns the key of the entry declaration.
+pub async fn collect_declarations(
+  entry: &Utf8Path,
+  context: &Utf8Path,
+  fs: &dyn ReadableFileSystem,
+  declarations: &mut Declarations,
+) -> Option<String> {
+  let mut entry_key = None;
+  let mut queue = vec![entry.to_path_buf()];
+  while let Some(source) = queue.pop() {
+    let Some(key) = declaration_key(&source, context) else {
+      declarations.warnings.push(format!(
+        "Skipped the declarations of {source}, which is outside of the context"
+      ));
+      continue;
+    };
+    if entry_key.is_none() {
+      entry_key = Some(key.clone());
+    }
+    if declarations
+      .files
+      .iter()
+      .any(|(existing, _)| *existing == key)
+    {
+      continue;
+    }
+
+    let Some(content) = load_declaration(&source, fs, &mut declarations.warnings).await else {
+      continue;
+    };
+    let dir = source.parent().unwrap_or(context);
+    for specifier in collect_relative_specifiers(&content) {
+      if let Some(imported) = resolve_source(dir, specifier, fs).await {
+        queue.push(imported);
+      }
+    }
+    declarations.files.push((key, content));
+  }
+  entry_key
+}
+
+/// `src/Button.tsx` is declared by `src/Button.d.ts`, `src/utils.mts` by
+/// `src/utils.d.mts`.
+fn declaration_path(source: &Utf8Path) -> Utf8PathBuf {
+  let name = source.file_name().unwrap_or_default();
+  if is_declaration(name) {
+    return source.to_path_buf();
+  }
+  let (stem, extension) = name.rsplit_once('.').unwrap_or((name, ""));
+  let declaration = match extension {
+    "mjs" | "mts" => format!("{stem}.d.mts"),
+    "cjs" | "cts" => format!("{stem}.d.cts"),
+    _ => format!("{stem}.d.ts"),
+  };
+  source.with_file_name(declaration)
+}
+
+fn declaration_key(source: &Utf8Path, context: &Utf8Path) -> Option<String> {
+  let relative = declaration_path(source)
+    .strip_prefix(context)
+    .ok()?
+    .to_path_buf();
+  Some(
+    relative
+      .components()
+      .map(|component| component.as_str())
+      .collect::<Vec<_>>()
+      .join("/"),
+  )
+}
+
+fn is_declaration(name: &str) -> bool {
+  name.ends_with(".d.ts") || name.ends_with(".d.mts") || name.ends_with(".d.cts")
+}
+
+fn is_typescript(name: &str) -> bool {
+  [".ts", ".tsx", ".mts", ".cts"]
+    .iter()
+    .any(|extension| name.ends_with(extension))
+}
+
+async fn load_declaration(
+  source: &Utf8Path,
+  fs: &dyn ReadableFileSystem,
+  warnings: &mut Vec<String>,
+) -> Option<String> {
+  if let Ok(content) = fs.read(&declaration_path(source)).await {
+    return Some(String::from_utf8_lossy(&content).into_owned());
+  }
+  if !is_typescript(source.as_str()) {
+    warnings.push(format!(
+      "No declaration file found for {source}, add a {} next to it",
+      declaration_path(source).file_name().unwrap_or_default()
+    ));
+    return None;
+  }
+  let content = match fs.read(source).await {
+    Ok(content) => String::from_utf8_lossy(&content).into_owned(),
+    Err(e) => {
+      warnings.push(format!("Failed to read {source}: {e}"));
+      return None;
+    }
+  };
+  match emit_declaration(source, content) {
+    Ok((declaration, issues)) => {
+      warnings.extend(issues);
+      Some(declaration)
+    }
+    Err(e) => {
+      warnings.push(format!("Failed to emit the declarations of {source}: {e}"));
+      None
+    }
+  }
+}
+
+/// Emits the isolated declarations of a typescript source, which needs the
+/// exports to be explicitly typed, as with `isolatedDeclarations`.
+fn emit_declaration(source: &Utf8Path, content: String) -> Result<(String, Vec<String>), String> {
+  let cm = Arc::new(SourceMap::default());
+  let fm = cm.new_source_file(
+    Arc::new(FileName::Real(source.as_std_path().to_path_buf())),
+    content,
+  );
+  GLOBALS.set(&Globals::default(), || {
+    let syntax = Syntax::Typescript(TsSyntax {
+      tsx: source.extension() == Some("tsx"),
+      decorators: true,
+      ..Default::default()
+    });
+    let comments = SingleThreadedComments::default();
+    let mut errors = vec![];
+    let module = parse_file_as_module(
+      &fm,
+      syntax,
+      EsVersion::latest(),
+      Some(&comments),
+      &mut errors,
+    )
+    .map_err(|e| e.into_kind().msg().to_string())?;
+
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+    let mut program = Program::Module(module);
+    program.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, true));
+    let issues = FastDts::new(fm.name.clone(), unresolved_mark, Default::default())
+      .transform(&mut program)
+      .into_iter()
+      .map(|issue| {
+        let loc = cm.lookup_char_pos(issue.range.span.lo);
+        format!(
+          "{}\n    at {source}:{}:{}",
+          issue.message,
+          loc.line,
+          loc.col_display + 1
+        )
+      })
+      .collect();
+    Ok((
+      to_code_default(cm.clone(), Some(&comments), &program),
+      issues,
+    ))
+  })
+}
+
+/// The specifiers of `from "./x"`, `import "./x"` and `import("./x")`.
+fn collect_relative_specifiers(declaration: &str) -> Vec<&str> {
+  let mut specifiers = vec![];
+  for (index, _) in declaration.match_indices(['"', '\'']) {
+    let before = declaration[..index].trim_end();
+    if !(before.ends_with("from") || before.ends_with("import") || before.ends_with("import(")) {
+      continue;
+    }
+    let quote = &declaration[index..=index];
+    let rest = &declaration[index + 1..];
+    let Some(end) = rest.find(quote) else {
+      continue;
+    };
+    let specifier = &rest[..end];
+    if specifier.starts_with("./") || specifier.starts_with("../") {
+      specifiers.push(specifier);
+    }
+  }
+  specifiers
+}
+
+/// Resolves a relative specifier like typescript, `./x.js` may refer to
+/// `./x.ts`.
+async fn resolve_source(
+  dir: &Utf8Path,
+  specifier: &str,
+  fs: &dyn ReadableFileSystem,
+) -> Option<Utf8PathBuf> {
+  let base = dir.join(specifier);
+  let stripped = ["js", "jsx", "mjs", "cjs"]
+    .contains(&base.extension().unwrap_or_default())
+    .then(|| base.with_extension(""));
+  let mut candidates = vec![];
+  for base in std::iter::once(&base).chain(stripped.as_ref()) {
+    for extension in [".d.ts", ".ts", ".tsx", ".d.mts", ".mts", ".d.cts", ".cts"] {
+      candidates.push(Utf8PathBuf::from(format!("{base}{extension}")));
+    }
+    for index in ["index.d.ts", "index.ts", "index.tsx"] {
+      candidates.push(base.join(index));
+    }
+  }
+  for candidate in candidates {
+    if fs
+      .metadata(&candidate)
+      .await
+      .is_ok_and(|metadata| metadata.is_file)
+    {
+      return Some(candidate);
+    }
+  }
+  None
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_collect_relative_specifiers() {
+    let declaration = r#"import type { Theme } from "./theme";
+export * from '../shared/types.js';
+export type Props = import("./props").Props;
+import React from "react";
+export declare const label: "./not-a-specifier";
+"#;
+    assert_eq!(
+      collect_relative_specifiers(declaration),
+      vec!["./theme", "../shared/types.js", "./props"]
+    );
+  }
+
+  #[test]
+  fn should_map_sources_to_declarations() {
+    let context = Utf8Path::new("/app");
+    assert_eq!(
+      declaration_key(Utf8Path::new("/app/src/Button.tsx"), context).as_deref(),
+      Some("src/Button.d.ts")
+    );
+    assert_eq!(
+      declaration_key(Utf8Path::new("/app/src/utils.mjs"), context).as_deref(),
+      Some("src/utils.d.mts")
+    );
+    assert_eq!(
+      declaration_key(Utf8Path::new("/app/src/types.d.ts"), context).as_deref(),
+      Some("src/types.d.ts")
+    );
+    assert_eq!(
+      declaration_key(Utf8Path::new("/lib/index.ts"), context),
+      None
+    );
+  }
+}
@@ -0,0 +1,425 @@
+mod archive;
+mod declarations;
+
+use std::collections::hash_map::Entry;
+
+use rspack_core::{
+  rspack_sources::{RawBufferSource, SourceExt},
+  ApplyContext, Compilation, CompilationAsset, CompilationProcessAssets, CompilerOptions,
+  DependenciesBlock, ExternalModule, ExternalRequest, NormalModule, Plugin, PluginContext,
+};
+use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rspack_plugin_schemes::{fetch_url, HttpUriPluginOptions};
+use rustc_hash::FxHashMap as HashMap;
+
+use self::{
+  archive::{read_archive, write_archive},
+  declarations::{collect_declarations, Declarations},
+};
+use crate::{
+  container::{container_entry_module::ContainerEntryModule, remote_module::RemoteModule},
+  manifest::get_remote_entry,
+};
+
+const PLUGIN_NAME: &str = "rspack.ModuleFederationTypesPlugin";
+
+/// The declarations of the exposes are placed under this folder of the
+/// archive, next to one `<expose>.d.ts` re-exporting each of them.
+const COMPILED_TYPES: &str = "compiled-types";
+
+#[derive(Debug)]
+pub struct ModuleFederationTypesPluginOptions {
+  /// The types archive of the exposes, emitted next to the remote entry and
+  /// fetched next to the remote entries of the remotes.
+  pub archive_filename: String,
+  /// Where the types of the remotes are extracted relative to `context`, one
+  /// folder per remote, `None` to not fetch them.
+  pub types_folder: Option<String>,
+  /// Fetches the archives of `http(s)` remotes with the cache and the lockfile
+  /// of `experiments.buildHttp`.
+  pub http: Option<HttpUriPluginOptions>,
+}
+
+impl Default for ModuleFederationTypesPluginOptions {
+  fn default() -> Self {
+    Self {
+      archive_filename: "@mf-types.zip".to_string(),
+      types_folder: Some("@mf-types".to_string()),
+      http: None,
+    }
+  }
+}
+
+/// Packages the declarations of the exposed modules for the builds consuming
+/// this container, and extracts the declarations of the remotes of this build,
+/// so `import("remote/Button")` is typed with `"paths": { "*": ["./@mf-types/*"] }`.
+///
+/// The declarations of an expose are the `.d.ts` next to its source, or for
+/// typescript sources without one, the isolated declarations emitted by swc,
+/// which needs the exports to be explicitly typed. The other sources without a
+/// declaration file are skipped with a warning.
+#[plugin]
+#[derive(Debug)]
+pub struct ModuleFederationTypesPlugin {
+  options: ModuleFederationTypesPluginOptions,
+}
+
+impl ModuleFederationTypesPlugin {
+  pub fn new(options: ModuleFederationTypesPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilationProcessAssets for ModuleFederationTypesPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let mut warnings = vec![];
+  self.emit_exposes_types(compilation, &mut warnings).await?;
+  if let Some(types_folder) = &self.options.types_folder {
+    self
+      .extract_remotes_types(compilation, types_folder, &mut warnings)
+      .await?;
+  }
+  compilation.extend_diagnostics(
+    warnings
+      .into_iter()
+      .map(|warning| Diagnostic::warn(PLUGIN_NAME.to_string(), warning))
+      .collect(),
+  );
+  Ok(())
+}
+
+impl ModuleFederationTypesPlugin {
+  async fn emit_exposes_types(
+    &self,
+    compilation: &mut Compilation,
+    warnings: &mut Vec<String>,
+  ) -> Result<()> {
+    let (container_name, exposes) = {
+      let module_graph = compilation.get_module_graph();
+      let Some(container) = module_graph
+        .modules()
+        .find_map(|(_, module)| module.downcast_ref::<ContainerEntryModule>())
+      else {
+        return Ok(());
+      };
+      let exposes = container
+        .exposes()
+        .iter()
+        .zip(container.get_blocks())
+        .map(|((name, _), block)| {
+          // the exposed module is the last one of its imports
+          let resource = module_graph
+            .block_by_id(block)
+            .and_then(|block| block.get_dependencies().last())
+            .and_then(|dependency| module_graph.get_module_by_dependency_id(dependency))
+            .and_then(|module| module.downcast_ref::<NormalModule>())
+            .and_then(|module| module.resource_resolved_data().resource_path.clone());
+          (name.clone(), resource)
+        })
+        .collect::<Vec<_>>();
+      (container.name().to_string(), exposes)
+    };
+
+    let context = compilation.options.context.as_path().to_path_buf();
+    let mut declarations = Declarations::default();
+    let mut entries = vec![];
+    for (name, resource) in exposes {
+      let Some(resource) = resource else {
+        warnings.push(format!(
+          "Skipped the declarations of expose \"{name}\", which is not a file"
+        ));
+        continue;
+      };
+      if let Some(key) = collect_declarations(
+        &resource,
+        &context,
+        compilation.input_filesystem.as_ref(),
+        &mut declarations,
+      )
+      .await
+      {
+        entries.push((name, key));
+      }
+    }
+    warnings.append(&mut declarations.warnings);
+    if entries.is_empty() {
+      return Ok(());
+    }
+
+    let mut files = vec![];
+    for (name, key) in entries {
+      let has_default = declarations
+        .files
+        .iter()
+        .find(|(file, _)| *file == key)
+        .is_some_and(|(_, content)| has_default_export(content));
+      let (filename, content) = render_expose_declaration(&name, &key, has_default);
+      files.push((filename, content.into_bytes()));
+    }
+    for (key, content) in declarations.files {
+      compilation
+        .file_dependencies
+        .insert(context.join(&key).as_std_path().into());
+      files.push((format!("{COMPILED_TYPES}/{key}"), content.into_bytes()));
+    }
+    let archive = write_archive(&files).to_rspack_result()?;
+
+    let dir = get_remote_entry(compilation, &container_name)
+      .map(|remote_entry| remote_entry.path)
+      .unwrap_or_default();
+    let filename = if dir.is_empty() {
+      self.options.archive_filename.clone()
+    } else {
+      format!("{dir}/{}", self.options.archive_filename)
+    };
+    compilation.emit_asset(
+      filename,
+      CompilationAsset::from(RawBufferSource::from(archive).boxed()),
+    );
+    Ok(())
+  }
+
+  async fn extract_remotes_types(
+    &self,
+    compilation: &Compilation,
+    types_folder: &str,
+    warnings: &mut Vec<String>,
+  ) -> Result<()> {
+    let context = compilation.options.context.as_path();
+    let mut remotes: Vec<(String, String)> = vec![];
+    {
+      let module_graph = compilation.get_module_graph();
+      for (_, module) in module_graph.modules() {
+        let Some(remote) = module.downcast_ref::<RemoteModule>() else {
+          continue;
+        };
+        if remotes.iter().any(|(alias, _)| *alias == remote.remote_key) {
+          continue;
+        }
+        let Some(request) = remote
+          .get_dependencies()
+          .first()
+          .and_then(|dependency| module_graph.get_module_by_dependency_id(dependency))
+          .and_then(|module| module.downcast_ref::<ExternalModule>())
+          .and_then(|module| match &module.request {
+            ExternalRequest::Single(request) => Some(request.primary.clone()),
+            ExternalRequest::Map(_) => None,
+          })
+        else {
+          continue;
+        };
+        remotes.push((remote.remote_key.clone(), request));
+      }
+    }
+
+    // the archives are fetched again by every compilation, so the types follow
+    // the remotes in watch mode, but only once for the aliases of a remote
+    let mut archives: HashMap<String, std::result::Result<Vec<(String, Vec<u8>)>, String>> =
+      HashMap::default();
+    for (alias, request) in remotes {
+      // script remotes are written as `name@url`, the others are requests of
+      // the container
+      let entry = match request.split_once('@') {
+        Some((global, url)) if !global.is_empty() => url,
+        _ => request.as_str(),
+      };
+      let Some(location) = ArchiveLocation::new(entry, context, &self.options.archive_filename)
+      else {
+        continue;
+      };
+      let key = location.to_string();
+      let archive = match archives.entry(key.clone()) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => {
+          let archive = match &location {
+            ArchiveLocation::Path(path) => compilation
+              .input_filesystem
+              .read(path)
+              .await
+              .map_err(|e| e.to_string()),
+            ArchiveLocation::Url(url) => match &self.options.http {
+              Some(http) => fetch_url(url, http).await.map_err(|e| e.to_string()),
+              None => {
+                Err("fetching remote types over http needs `experiments.buildHttp`".to_string())
+              }
+            },
+          };
+          entry.insert(archive.and_then(|archive| read_archive(&archive)))
+        }
+      };
+      let files = match archive {
+        Ok(files) => &*files,
+        Err(e) => {
+          warnings.push(format!(
+            "Failed to load the types of remote \"{alias}\" from {key}: {e}"
+          ));
+          continue;
+        }
+      };
+
+      let dir = context.join(types_folder).join(&alias);
+      for (name, content) in files {
+        let Some(path) = join_entry(&dir, name) else {
+          warnings.push(format!(
+            "Skipped {name} in the types of remote \"{alias}\", which is outside of the archive"
+          ));
+          continue;
+        };
+        // avoid touching unchanged declarations so that the watchers of the
+        // type checker are not triggered
+        let existing = compilation.input_filesystem.read(&path).await.ok();
+        if existing.as_deref() != Some(content.as_slice()) {
+          let intermediate_filesystem = compilation.intermediate_filesystem.as_ref();
+          if let Some(dir) = path.parent() {
+            intermediate_filesystem.create_dir_all(dir).await?;
+          }
+          intermediate_filesystem.write(&path, content).await?;
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+enum ArchiveLocation {
+  Path(Utf8PathBuf),
+  Url(String),
+}
+
+impl ArchiveLocation {
+  /// The archive is next to the remote entry.
+  fn new(entry: &str, context: &Utf8Path, archive_filename: &str) -> Option<Self> {
+    let entry = entry.split(['?', '#']).next().unwrap_or_default();
+    if entry.starts_with("http://") || entry.starts_with("https://") {
+      let (dir, _) = entry.rsplit_once('/')?;
+      return Some(Self::Url(format!("{dir}/{archive_filename}")));
+    }
+    let path = entry.strip_prefix("file://").unwrap_or(entry);
+    if !(path.starts_with('.') || Utf8Path::new(path).is_absolute()) {
+      return None;
+    }
+    let path = context.join(path);
+    Some(Self::Path(path.parent()?.join(archive_filename)))
+  }
+}
+
+impl std::fmt::Display for ArchiveLocation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Path(path) => write!(f, "{path}"),
+      Self::Url(url) => write!(f, "{url}"),
+    }
+  }
+}
+
+/// Joins an entry of an archive to `dir`, refusing entries escaping it.
+fn join_entry(dir: &Utf8Path, name: &str) -> Option<Utf8PathBuf> {
+  let mut path = dir.to_path_buf();
+  for segment in name.split(['/', '\\']) {
+    match segment {
+      "" | "." => {}
+      ".." => return None,
+      segment if segment.contains(':') => return None,
+      segment => path.push(segment),
+    }
+  }
+  Some(path)
+}
+
+fn has_default_export(declaration: &str) -> bool {
+  declaration.contains("export default") || declaration.contains(" as default")
+}
+
+/// `./components/Button` is declared by `components/Button.d.ts`, which
+/// re-exports `compiled-types/src/components/Button.d.ts`.
+fn render_expose_declaration(name: &str, key: &str, has_default: bool) -> (String, String) {
+  let name = name.trim_start_matches("./");
+  let name = if name.is_empty() || name == "." {
+    "index"
+  } else {
+    name
+  };
+  let specifier = if let Some(stem) = key.strip_suffix(".d.mts") {
+    format!("{stem}.mjs")
+  } else if let Some(stem) = key.strip_suffix(".d.cts") {
+    format!("{stem}.cjs")
+  } else {
+    key.strip_suffix(".d.ts").unwrap_or(key).to_string()
+  };
+  let prefix = match name.matches('/').count() {
+    0 => "./".to_string(),
+    depth => "../".repeat(depth),
+  };
+  let specifier = format!("{prefix}{COMPILED_TYPES}/{specifier}");
+  let mut content = format!("export * from \"{specifier}\";\n");
+  if has_default {
+    content.push_str(&format!("export {{ default }} from \"{specifier}\";\n"));
+  }
+  (format!("{name}.d.ts"), content)
+}
+
+impl Plugin for ModuleFederationTypesPlugin {
+  fn name(&self) -> &'static str {
+    PLUGIN_NAME
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_render_expose_declarations() {
+    assert_eq!(
+      render_expose_declaration("./Button", "src/Button.d.ts", true),
+      (
+        "Button.d.ts".to_string(),
+        "export * from \"./compiled-types/src/Button\";\nexport { default } from \"./compiled-types/src/Button\";\n".to_string()
+      )
+    );
+    assert_eq!(
+      render_expose_declaration("./components/utils", "src/utils.d.mts", false),
+      (
+        "components/utils.d.ts".to_string(),
+        "export * from \"../compiled-types/src/utils.mjs\";\n".to_string()
+      )
+    );
+    assert_eq!(
+      render_expose_declaration(".", "src/index.d.ts", false).0,
+      "index.d.ts"
+    );
+  }
+
+  #[test]
+  fn should_locate_archives_next_to_remote_entries() {
+    let context = Utf8Path::new("/app");
+    assert_eq!(
+      ArchiveLocation::new(
+        "http://localhost:3001/static/remoteEntry.js?v=1",
+        context,
+        "@mf-types.zip"
+      )
+      .map(|location| location.to_string()),
+      Some("http://localhost:3001/static/@mf-types.zip".to_string())
+    );
+    assert_eq!(
+      ArchiveLocation::new("../remote/dist/remoteEntry.js", context, "@mf-types.zip")
+        .map(|location| location.to_string()),
+      Some("/app/../remote/dist/@mf-types.zip".to_string())
+    );
+    assert!(ArchiveLocation::new("remote", context, "@mf-types.zip").is_none());
+    assert!(join_entry(context, "../escape.d.ts").is_none());
+  }
+}
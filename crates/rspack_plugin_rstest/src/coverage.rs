@@ -0,0 +1,603 @@
+use std::hash::Hash;
+
+use rspack_core::DependencyRange;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_plugin_javascript::{visitors::JavascriptParser, JavascriptParserPlugin};
+use swc_core::{
+  common::{Span, Spanned},
+  ecma::{
+    ast::{
+      ArrowExpr, BinExpr, BinaryOp, BlockStmt, BlockStmtOrExpr, ClassMethod, CondExpr, Constructor,
+      Decl, DoWhileStmt, ExportDecl, Expr, FnDecl, FnExpr, ForInStmt, ForOfStmt, ForStmt, Function,
+      GetterProp, IfStmt, LabeledStmt, Lit, MethodProp, Module, ModuleDecl, ModuleItem, Pat,
+      PrivateMethod, Program, PropName, Script, SetterProp, Stmt, SwitchStmt, VarDecl,
+      VarDeclarator, WhileStmt, WithStmt,
+    },
+    visit::{Visit, VisitWith},
+  },
+};
+
+use crate::{
+  coverage_dependency::{CoverageBranch, CoverageDependency, CoverageFunction, CoverageInsertion},
+  RstestCoverageOptions,
+};
+
+#[derive(Debug)]
+pub struct CoverageParserPlugin {
+  options: RstestCoverageOptions,
+}
+
+impl CoverageParserPlugin {
+  pub fn new(options: RstestCoverageOptions) -> Self {
+    Self { options }
+  }
+}
+
+impl JavascriptParserPlugin for CoverageParserPlugin {
+  fn program(&self, parser: &mut JavascriptParser, ast: &Program) -> Option<bool> {
+    let path = parser.resource_data.resource_path.as_ref()?.as_str();
+    if !self.options.should_instrument(path) {
+      return None;
+    }
+
+    let counter = {
+      let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+      path.hash(&mut hasher);
+      format!("cov_{}", hasher.digest(&HashDigest::Hex).rendered(16))
+    };
+    let mut instrumenter = Instrumenter::new(&counter);
+    ast.visit_with(&mut instrumenter);
+    let dependency = instrumenter.into_dependency(path.to_string(), counter.clone());
+    parser
+      .presentational_dependencies
+      .push(Box::new(dependency));
+    None
+  }
+}
+
+/// Collects the statements, functions and branches of a module like
+/// `istanbul-lib-instrument`, and the code of the counters to insert.
+struct Instrumenter<'a> {
+  counter: &'a str,
+  statements: Vec<DependencyRange>,
+  functions: Vec<CoverageFunction>,
+  branches: Vec<CoverageBranch>,
+  insertions: Vec<PendingInsertion>,
+  /// The nesting of the instrumented nodes, which orders the insertions at
+  /// the same position, e.g. the inner `}` is inserted before the outer one.
+  depth: u32,
+  /// The name of the next function, e.g. the variable it's assigned to.
+  function_name: Option<String>,
+}
+
+struct PendingInsertion {
+  pos: u32,
+  after: bool,
+  depth: u32,
+  content: String,
+}
+
+impl<'a> Instrumenter<'a> {
+  fn new(counter: &'a str) -> Self {
+    Self {
+      counter,
+      statements: vec![],
+      functions: vec![],
+      branches: vec![],
+      insertions: vec![],
+      depth: 0,
+      function_name: None,
+    }
+  }
+
+  fn into_dependency(mut self, path: String, counter: String) -> CoverageDependency {
+    // Insertions that close a node come first, the inner ones first, then
+    // the insertions that open a node, the outer ones first.
+    self.insertions.sort_by_key(|insertion| {
+      (
+        insertion.pos,
+        !insertion.after,
+        if insertion.after {
+          u32::MAX - insertion.depth
+        } else {
+          insertion.depth
+        },
+      )
+    });
+    let mut insertions: Vec<CoverageInsertion> = vec![];
+    for insertion in self.insertions {
+      match insertions.last_mut() {
+        Some(last) if last.pos == insertion.pos => last.content.push_str(&insertion.content),
+        _ => insertions.push(CoverageInsertion {
+          pos: insertion.pos,
+          content: insertion.content,
+        }),
+      }
+    }
+    CoverageDependency {
+      path,
+      counter,
+      statements: self.statements,
+      functions: self.functions,
+      branches: self.branches,
+      insertions,
+    }
+  }
+
+  fn insert_before(&mut self, pos: u32, content: String) {
+    self.insertions.push(PendingInsertion {
+      pos,
+      after: false,
+      depth: self.depth,
+      content,
+    });
+  }
+
+  fn insert_after(&mut self, pos: u32, content: String) {
+    self.insertions.push(PendingInsertion {
+      pos,
+      after: true,
+      depth: self.depth,
+      content,
+    });
+  }
+
+  fn statement_counter(&mut self, span: Span) -> String {
+    let index = self.statements.len();
+    self.statements.push(span.into());
+    format!("{}().s[{index}]++;", self.counter)
+  }
+
+  fn branch_counter(&self, branch: usize, location: usize) -> String {
+    format!("{}().b[{branch}][{location}]++", self.counter)
+  }
+
+  fn new_branch(&mut self, r#type: &str, loc: Span, locations: Vec<Span>) -> usize {
+    self.branches.push(CoverageBranch {
+      r#type: r#type.to_string(),
+      loc: loc.into(),
+      locations: locations.into_iter().map(Into::into).collect(),
+    });
+    self.branches.len() - 1
+  }
+
+  fn function_counter(&mut self, name: Option<String>, decl: Option<Span>, loc: Span) -> String {
+    let index = self.functions.len();
+    let name = name.unwrap_or_else(|| format!("(anonymous_{index})"));
+    self.functions.push(CoverageFunction {
+      name,
+      decl: decl.unwrap_or(loc).into(),
+      loc: loc.into(),
+    });
+    format!("{}().f[{index}]++;", self.counter)
+  }
+
+  /// Counts the statements of a statement list, the directives like
+  /// `"use strict"` are skipped as the counters would turn them into plain
+  /// expressions.
+  fn visit_stmts(&mut self, stmts: &[Stmt], skip_directives: bool) {
+    self.depth += 1;
+    let directives = if skip_directives {
+      stmts.iter().take_while(|stmt| is_directive(stmt)).count()
+    } else {
+      0
+    };
+    for (index, stmt) in stmts.iter().enumerate() {
+      if index >= directives && is_countable(stmt) {
+        let counter = self.statement_counter(stmt.span());
+        self.insert_before(DependencyRange::from(stmt.span()).start, counter);
+      }
+      stmt.visit_with(self);
+    }
+    self.depth -= 1;
+  }
+
+  /// Counts the body of a statement, like the consequent of `if`, the body
+  /// is wrapped in a block unless it's already one.
+  fn visit_body(&mut self, body: &Stmt, prefix: String) {
+    self.depth += 1;
+    let range = DependencyRange::from(body.span());
+    if let Stmt::Block(block) = body {
+      self.insert_before(range.start + 1, prefix);
+      self.visit_stmts(&block.stmts, false);
+    } else {
+      let counter = if is_countable(body) {
+        self.statement_counter(body.span())
+      } else {
+        String::new()
+      };
+      self.insert_before(range.start, format!("{{{prefix}{counter}"));
+      body.visit_with(self);
+      self.insert_after(range.end, "}".to_string());
+    }
+    self.depth -= 1;
+  }
+
+  /// Wraps an expression in a sequence expression with the counter.
+  fn visit_wrapped_expr(&mut self, expr: &Expr, counter: String) {
+    self.depth += 1;
+    let range = DependencyRange::from(expr.span());
+    self.insert_before(range.start, format!("({counter}, "));
+    expr.visit_with(self);
+    self.insert_after(range.end, ")".to_string());
+    self.depth -= 1;
+  }
+
+  fn visit_function_body(
+    &mut self,
+    name: Option<String>,
+    decl: Option<Span>,
+    span: Span,
+    body: &BlockStmt,
+  ) {
+    self.depth += 1;
+    let loc = Span::new(span.lo, body.span.lo);
+    let counter = self.function_counter(name, decl, loc);
+    let directives = body
+      .stmts
+      .iter()
+      .take_while(|stmt| is_directive(stmt))
+      .last();
+    match directives {
+      // Without a semicolon, the counter would continue the expression of
+      // the directive.
+      Some(Stmt::Expr(directive)) if directive.span.hi == directive.expr.span().hi => {
+        self.insert_before(
+          DependencyRange::from(directive.span).end,
+          format!(";{counter}"),
+        );
+      }
+      Some(directive) => self.insert_before(DependencyRange::from(directive.span()).end, counter),
+      None => self.insert_before(DependencyRange::from(body.span).start + 1, counter),
+    }
+    self.visit_stmts(&body.stmts, true);
+    self.depth -= 1;
+  }
+
+  /// Counts the initializers of an exported variable declaration like
+  /// istanbul, as the counters can't be inserted before `export`.
+  fn visit_exported_var(&mut self, var: &VarDecl) {
+    self.depth += 1;
+    let end = DependencyRange::from(var.span).end;
+    for declarator in &var.decls {
+      let Some(init) = &declarator.init else {
+        declarator.visit_with(self);
+        continue;
+      };
+      let counter = self.statement_counter(init.span());
+      // Wrapping a function would lose the name inferred from the variable,
+      // so it's counted after the declaration.
+      if matches!(&**init, Expr::Arrow(_) | Expr::Fn(_) | Expr::Class(_)) {
+        self.insert_after(end, format!(";{counter}"));
+        declarator.visit_with(self);
+      } else {
+        declarator.name.visit_with(self);
+        self.visit_wrapped_expr(init, counter.trim_end_matches(';').to_string());
+      }
+    }
+    self.depth -= 1;
+  }
+
+  fn visit_function_with_name(
+    &mut self,
+    name: Option<String>,
+    decl: Option<Span>,
+    function: &Function,
+  ) {
+    function.decorators.visit_with(self);
+    function.params.visit_with(self);
+    if let Some(body) = &function.body {
+      self.visit_function_body(name, decl, function.span, body);
+    }
+  }
+}
+
+impl Visit for Instrumenter<'_> {
+  fn visit_module(&mut self, module: &Module) {
+    self.depth += 1;
+    let directives = module
+      .body
+      .iter()
+      .take_while(|item| matches!(item, ModuleItem::Stmt(stmt) if is_directive(stmt)))
+      .count();
+    for (index, item) in module.body.iter().enumerate() {
+      // The counters can't be inserted before the module declarations like
+      // `export default`, whose keywords are replaced by the code generation,
+      // the exported expressions are counted instead.
+      match item {
+        ModuleItem::Stmt(stmt) if index >= directives && is_countable(stmt) => {
+          let counter = self.statement_counter(stmt.span());
+          self.insert_before(DependencyRange::from(stmt.span()).start, counter);
+          stmt.visit_with(self);
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+          decl: Decl::Var(var),
+          ..
+        }))
+          if !var.declare =>
+        {
+          self.visit_exported_var(var)
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
+          let counter = self.statement_counter(export.span);
+          self.visit_wrapped_expr(&export.expr, counter.trim_end_matches(';').to_string());
+        }
+        _ => item.visit_with(self),
+      }
+    }
+    self.depth -= 1;
+  }
+
+  fn visit_script(&mut self, script: &Script) {
+    self.visit_stmts(&script.body, true);
+  }
+
+  fn visit_block_stmt(&mut self, block: &BlockStmt) {
+    self.visit_stmts(&block.stmts, false);
+  }
+
+  fn visit_if_stmt(&mut self, stmt: &IfStmt) {
+    self.depth += 1;
+    stmt.test.visit_with(self);
+    let locations = vec![
+      stmt.cons.span(),
+      stmt.alt.as_ref().map_or(stmt.span, |alt| alt.span()),
+    ];
+    let branch = self.new_branch("if", stmt.span, locations);
+    self.visit_body(&stmt.cons, format!("{};", self.branch_counter(branch, 0)));
+    match &stmt.alt {
+      Some(alt) => self.visit_body(alt, format!("{};", self.branch_counter(branch, 1))),
+      None => {
+        let counter = self.branch_counter(branch, 1);
+        self.insert_after(
+          DependencyRange::from(stmt.cons.span()).end,
+          format!(" else {{{counter};}}"),
+        );
+      }
+    }
+    self.depth -= 1;
+  }
+
+  fn visit_switch_stmt(&mut self, stmt: &SwitchStmt) {
+    self.depth += 1;
+    stmt.discriminant.visit_with(self);
+    let locations = stmt.cases.iter().map(|case| case.span).collect();
+    let branch = self.new_branch("switch", stmt.span, locations);
+    for (index, case) in stmt.cases.iter().enumerate() {
+      case.test.visit_with(self);
+      let pos = match case.cons.first() {
+        Some(first) => DependencyRange::from(first.span()).start,
+        None => DependencyRange::from(case.span).end,
+      };
+      let counter = self.branch_counter(branch, index);
+      self.insert_before(pos, format!("{counter};"));
+      self.visit_stmts(&case.cons, false);
+    }
+    self.depth -= 1;
+  }
+
+  fn visit_for_stmt(&mut self, stmt: &ForStmt) {
+    stmt.init.visit_with(self);
+    stmt.test.visit_with(self);
+    stmt.update.visit_with(self);
+    self.visit_body(&stmt.body, String::new());
+  }
+
+  fn visit_for_in_stmt(&mut self, stmt: &ForInStmt) {
+    stmt.left.visit_with(self);
+    stmt.right.visit_with(self);
+    self.visit_body(&stmt.body, String::new());
+  }
+
+  fn visit_for_of_stmt(&mut self, stmt: &ForOfStmt) {
+    stmt.left.visit_with(self);
+    stmt.right.visit_with(self);
+    self.visit_body(&stmt.body, String::new());
+  }
+
+  fn visit_while_stmt(&mut self, stmt: &WhileStmt) {
+    stmt.test.visit_with(self);
+    self.visit_body(&stmt.body, String::new());
+  }
+
+  fn visit_do_while_stmt(&mut self, stmt: &DoWhileStmt) {
+    self.visit_body(&stmt.body, String::new());
+    stmt.test.visit_with(self);
+  }
+
+  fn visit_with_stmt(&mut self, stmt: &WithStmt) {
+    stmt.obj.visit_with(self);
+    self.visit_body(&stmt.body, String::new());
+  }
+
+  fn visit_labeled_stmt(&mut self, stmt: &LabeledStmt) {
+    // Wrapping the body in a block would break `continue label`, the body is
+    // counted as a part of the labeled statement.
+    stmt.body.visit_with(self);
+  }
+
+  fn visit_cond_expr(&mut self, expr: &CondExpr) {
+    self.depth += 1;
+    expr.test.visit_with(self);
+    let branch = self.new_branch(
+      "cond-expr",
+      expr.span,
+      vec![expr.cons.span(), expr.alt.span()],
+    );
+    self.visit_wrapped_expr(&expr.cons, self.branch_counter(branch, 0));
+    self.visit_wrapped_expr(&expr.alt, self.branch_counter(branch, 1));
+    self.depth -= 1;
+  }
+
+  fn visit_bin_expr(&mut self, expr: &BinExpr) {
+    if !matches!(
+      expr.op,
+      BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing
+    ) {
+      expr.visit_children_with(self);
+      return;
+    }
+    self.depth += 1;
+    // `a && b && c` is a branch of three locations.
+    let mut leaves = vec![];
+    collect_logical_leaves(expr, expr.op, &mut leaves);
+    let branch = self.new_branch(
+      "binary-expr",
+      expr.span,
+      leaves.iter().map(|leaf| leaf.span()).collect(),
+    );
+    for (index, leaf) in leaves.into_iter().enumerate() {
+      self.visit_wrapped_expr(leaf, self.branch_counter(branch, index));
+    }
+    self.depth -= 1;
+  }
+
+  fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+    declarator.name.visit_with(self);
+    if let Some(init) = &declarator.init {
+      if let Pat::Ident(ident) = &declarator.name
+        && matches!(
+          &**init,
+          Expr::Arrow(_) | Expr::Fn(FnExpr { ident: None, .. })
+        )
+      {
+        self.function_name = Some(ident.id.sym.to_string());
+      }
+      init.visit_with(self);
+    }
+  }
+
+  fn visit_fn_decl(&mut self, decl: &FnDecl) {
+    self.function_name = None;
+    self.visit_function_with_name(
+      Some(decl.ident.sym.to_string()),
+      Some(decl.ident.span),
+      &decl.function,
+    );
+  }
+
+  fn visit_fn_expr(&mut self, expr: &FnExpr) {
+    let name = expr
+      .ident
+      .as_ref()
+      .map(|ident| ident.sym.to_string())
+      .or_else(|| self.function_name.take());
+    self.function_name = None;
+    self.visit_function_with_name(
+      name,
+      expr.ident.as_ref().map(|ident| ident.span),
+      &expr.function,
+    );
+  }
+
+  fn visit_function(&mut self, function: &Function) {
+    let name = self.function_name.take();
+    self.visit_function_with_name(name, None, function);
+  }
+
+  fn visit_arrow_expr(&mut self, expr: &ArrowExpr) {
+    let name = self.function_name.take();
+    expr.params.visit_with(self);
+    match &*expr.body {
+      BlockStmtOrExpr::BlockStmt(body) => self.visit_function_body(name, None, expr.span, body),
+      BlockStmtOrExpr::Expr(body) => {
+        self.depth += 1;
+        let loc = Span::new(expr.span.lo, body.span().lo);
+        let function_counter = self.function_counter(name, None, loc);
+        let statement_counter = self.statement_counter(body.span());
+        let counters = format!(
+          "{}, {}",
+          function_counter.trim_end_matches(';'),
+          statement_counter.trim_end_matches(';')
+        );
+        self.visit_wrapped_expr(body, counters);
+        self.depth -= 1;
+      }
+    }
+  }
+
+  fn visit_method_prop(&mut self, prop: &MethodProp) {
+    prop.key.visit_with(self);
+    self.visit_function_with_name(prop_name(&prop.key), Some(prop.key.span()), &prop.function);
+  }
+
+  fn visit_getter_prop(&mut self, prop: &GetterProp) {
+    prop.key.visit_with(self);
+    if let Some(body) = &prop.body {
+      self.visit_function_body(prop_name(&prop.key), Some(prop.key.span()), prop.span, body);
+    }
+  }
+
+  fn visit_setter_prop(&mut self, prop: &SetterProp) {
+    prop.key.visit_with(self);
+    prop.param.visit_with(self);
+    if let Some(body) = &prop.body {
+      self.visit_function_body(prop_name(&prop.key), Some(prop.key.span()), prop.span, body);
+    }
+  }
+
+  fn visit_class_method(&mut self, method: &ClassMethod) {
+    method.key.visit_with(self);
+    self.visit_function_with_name(
+      prop_name(&method.key),
+      Some(method.key.span()),
+      &method.function,
+    );
+  }
+
+  fn visit_private_method(&mut self, method: &PrivateMethod) {
+    self.visit_function_with_name(
+      Some(format!("#{}", method.key.name)),
+      Some(method.key.span),
+      &method.function,
+    );
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor) {
+    constructor.key.visit_with(self);
+    constructor.params.visit_with(self);
+    if let Some(body) = &constructor.body {
+      self.visit_function_body(
+        Some("constructor".to_string()),
+        Some(constructor.key.span()),
+        constructor.span,
+        body,
+      );
+    }
+  }
+}
+
+fn collect_logical_leaves<'a>(expr: &'a BinExpr, op: BinaryOp, leaves: &mut Vec<&'a Expr>) {
+  for side in [&expr.left, &expr.right] {
+    match &**side {
+      Expr::Bin(bin) if bin.op == op => collect_logical_leaves(bin, op, leaves),
+      side => leaves.push(side),
+    }
+  }
+}
+
+fn prop_name(key: &PropName) -> Option<String> {
+  match key {
+    PropName::Ident(ident) => Some(ident.sym.to_string()),
+    PropName::Str(str) => Some(str.value.to_string()),
+    _ => None,
+  }
+}
+
+fn is_directive(stmt: &Stmt) -> bool {
+  matches!(stmt, Stmt::Expr(expr) if matches!(&*expr.expr, Expr::Lit(Lit::Str(_))))
+}
+
+fn is_countable(stmt: &Stmt) -> bool {
+  match stmt {
+    Stmt::Block(_) | Stmt::Empty(_) => false,
+    Stmt::Decl(decl) => match decl {
+      Decl::Fn(_) => false,
+      Decl::Var(var) => !var.declare,
+      Decl::Class(class) => !class.declare,
+      Decl::Using(_) => true,
+      Decl::TsInterface(_) | Decl::TsTypeAlias(_) | Decl::TsEnum(_) | Decl::TsModule(_) => false,
+    },
+    _ => true,
+  }
+}
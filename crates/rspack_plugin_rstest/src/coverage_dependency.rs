@@ -0,0 +1,299 @@
+use std::{cell::RefCell, hash::Hash};
+
+use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_core::{
+  rspack_sources::{BoxSource, MapOptions},
+  AsContextDependency, AsModuleDependency, DependencyCodeGeneration, DependencyRange,
+  DependencyTemplate, DependencyTemplateType, DependencyType, InitFragmentExt, InitFragmentKey,
+  InitFragmentStage, NormalInitFragment, TemplateContext, TemplateReplaceSource,
+};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use serde_json::{json, Map, Value};
+
+/// The version of the istanbul coverage schema the emitted data follows.
+const COVERAGE_SCHEMA: &str = "1a1c01bbd47fc00a2c39e90264f33305809e6abf";
+
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CoverageFunction {
+  pub name: String,
+  pub decl: DependencyRange,
+  pub loc: DependencyRange,
+}
+
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CoverageBranch {
+  pub r#type: String,
+  pub loc: DependencyRange,
+  pub locations: Vec<DependencyRange>,
+}
+
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CoverageInsertion {
+  pub pos: u32,
+  pub content: String,
+}
+
+/// Inserts the coverage counters into a module, the ranges are offsets in
+/// the source produced by the loaders, which are mapped back to the original
+/// source when the coverage data is rendered.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CoverageDependency {
+  pub path: String,
+  pub counter: String,
+  pub statements: Vec<DependencyRange>,
+  pub functions: Vec<CoverageFunction>,
+  pub branches: Vec<CoverageBranch>,
+  pub insertions: Vec<CoverageInsertion>,
+}
+
+impl CoverageDependency {
+  /// Renders the istanbul file coverage of the module, and the generated
+  /// positions of the locations that have no original position.
+  pub fn file_coverage(&self, source: &BoxSource) -> (Map<String, Value>, Vec<(u32, u32)>) {
+    let locator = Locator::new(source);
+    let location = |range: &DependencyRange| locator.location(range);
+
+    let statement_map = self
+      .statements
+      .iter()
+      .enumerate()
+      .map(|(index, range)| (index.to_string(), location(range)))
+      .collect::<Map<_, _>>();
+    let fn_map = self
+      .functions
+      .iter()
+      .enumerate()
+      .map(|(index, function)| {
+        let loc = location(&function.loc);
+        (
+          index.to_string(),
+          json!({
+            "name": function.name,
+            "decl": location(&function.decl),
+            "line": loc["start"]["line"],
+            "loc": loc,
+          }),
+        )
+      })
+      .collect::<Map<_, _>>();
+    let branch_map = self
+      .branches
+      .iter()
+      .enumerate()
+      .map(|(index, branch)| {
+        let loc = location(&branch.loc);
+        (
+          index.to_string(),
+          json!({
+            "type": branch.r#type,
+            "line": loc["start"]["line"],
+            "loc": loc,
+            "locations": branch.locations.iter().map(location).collect::<Vec<_>>(),
+          }),
+        )
+      })
+      .collect::<Map<_, _>>();
+    let zeros = |len: usize| {
+      (0..len)
+        .map(|index| (index.to_string(), json!(0)))
+        .collect::<Map<_, _>>()
+    };
+
+    let mut coverage = Map::new();
+    coverage.insert("path".to_string(), json!(self.path));
+    coverage.insert("statementMap".to_string(), Value::Object(statement_map));
+    coverage.insert("fnMap".to_string(), Value::Object(fn_map));
+    coverage.insert("branchMap".to_string(), Value::Object(branch_map));
+    coverage.insert("s".to_string(), Value::Object(zeros(self.statements.len())));
+    coverage.insert("f".to_string(), Value::Object(zeros(self.functions.len())));
+    coverage.insert(
+      "b".to_string(),
+      Value::Object(
+        self
+          .branches
+          .iter()
+          .enumerate()
+          .map(|(index, branch)| (index.to_string(), json!(vec![0; branch.locations.len()])))
+          .collect(),
+      ),
+    );
+    coverage.insert("_coverageSchema".to_string(), json!(COVERAGE_SCHEMA));
+
+    let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+    Value::Object(coverage.clone())
+      .to_string()
+      .hash(&mut hasher);
+    coverage.insert(
+      "hash".to_string(),
+      json!(hasher.digest(&HashDigest::Hex).encoded()),
+    );
+    (coverage, locator.unmapped.into_inner())
+  }
+}
+
+#[cacheable_dyn]
+impl DependencyCodeGeneration for CoverageDependency {
+  fn dependency_template(&self) -> Option<DependencyTemplateType> {
+    Some(CoverageDependencyTemplate::template_type())
+  }
+}
+
+impl AsModuleDependency for CoverageDependency {}
+impl AsContextDependency for CoverageDependency {}
+
+#[cacheable]
+#[derive(Debug, Clone, Default)]
+pub struct CoverageDependencyTemplate;
+
+impl CoverageDependencyTemplate {
+  pub fn template_type() -> DependencyTemplateType {
+    DependencyTemplateType::Dependency(DependencyType::RstestCoverage)
+  }
+}
+
+impl DependencyTemplate for CoverageDependencyTemplate {
+  fn render(
+    &self,
+    dep: &dyn DependencyCodeGeneration,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext { init_fragments, .. } = code_generatable_context;
+
+    let dep = dep
+      .as_any()
+      .downcast_ref::<CoverageDependency>()
+      .expect("CoverageDependencyTemplate can only be applied to CoverageDependency");
+
+    // The unmapped locations are reported when the coverage map is emitted.
+    let (file_coverage, _) = dep.file_coverage(source.original());
+    let file_coverage = Value::Object(file_coverage);
+    for insertion in &dep.insertions {
+      source.insert(insertion.pos, &insertion.content, None);
+    }
+
+    // The same as the counter function of istanbul, which is hoisted so that
+    // the counters work before the module is evaluated, e.g. in circular
+    // dependencies.
+    let counter = &dep.counter;
+    let init = NormalInitFragment::new(
+      format!(
+        r#"function {counter}() {{
+  var path = {path};
+  var coverageData = {file_coverage};
+  var coverage = globalThis.__coverage__ || (globalThis.__coverage__ = {{}});
+  if (!coverage[path] || coverage[path].hash !== coverageData.hash) {{
+    coverage[path] = coverageData;
+  }}
+  var actualCoverage = coverage[path];
+  {counter} = function () {{
+    return actualCoverage;
+  }};
+  return actualCoverage;
+}}
+"#,
+        path = json!(dep.path),
+      ),
+      InitFragmentStage::StageConstants,
+      0,
+      InitFragmentKey::Const(format!("rstest coverage {counter}")),
+      None,
+    );
+    init_fragments.push(init.boxed());
+  }
+}
+
+/// Maps the offsets in the source produced by the loaders to the lines and
+/// columns of the original source, with the source map of the loaders.
+struct Locator {
+  code: String,
+  line_starts: Vec<usize>,
+  // (generated line, generated column, original line, original column)
+  mappings: Option<Vec<(u32, u32, u32, u32)>>,
+  /// The generated positions of the locations that have no original
+  /// position, which are reported with the generated positions.
+  unmapped: RefCell<Vec<(u32, u32)>>,
+}
+
+impl Locator {
+  fn new(source: &BoxSource) -> Self {
+    let code = source.source().into_owned();
+    let line_starts = std::iter::once(0)
+      .chain(code.match_indices('\n').map(|(index, _)| index + 1))
+      .collect();
+    let mappings = source.map(&MapOptions::default()).map(|map| {
+      map
+        .decoded_mappings()
+        .filter_map(|mapping| {
+          let original = mapping.original?;
+          Some((
+            mapping.generated_line,
+            mapping.generated_column,
+            original.original_line,
+            original.original_column,
+          ))
+        })
+        .collect()
+    });
+    Self {
+      code,
+      line_starts,
+      mappings,
+      unmapped: RefCell::new(vec![]),
+    }
+  }
+
+  /// Returns the 1-based line and the 0-based column in UTF-16 code units.
+  fn generated_position(&self, offset: u32) -> (u32, u32) {
+    let offset = (offset as usize).min(self.code.len());
+    let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+    let column = self
+      .code
+      .get(self.line_starts[line]..offset)
+      .map_or(0, |text| text.encode_utf16().count());
+    (line as u32 + 1, column as u32)
+  }
+
+  fn original_position(&self, (line, column): (u32, u32)) -> Option<(u32, u32)> {
+    let Some(mappings) = &self.mappings else {
+      return Some((line, column));
+    };
+    let index = mappings.partition_point(|(l, c, ..)| (*l, *c) <= (line, column));
+    let (generated_line, generated_column, original_line, original_column) =
+      mappings.get(index.checked_sub(1)?)?;
+    (*generated_line == line).then(|| {
+      (
+        *original_line,
+        original_column + (column - generated_column),
+      )
+    })
+  }
+
+  fn location(&self, range: &DependencyRange) -> Value {
+    let start = self.generated_position(range.start);
+    let end = self.generated_position(range.end);
+    let original_start = self.original_position(start);
+    // The end is exclusive, look up its last character so that it isn't
+    // mapped by the token after the range.
+    let original_end = self
+      .original_position((end.0, end.1.saturating_sub(1)))
+      .map(|(line, column)| (line, column + u32::from(end.1 > 0)));
+    let (start, end) = match (original_start, original_end) {
+      (Some(start), Some(end)) if start <= end => (start, end),
+      // Code generated by the loaders, e.g. the helpers, has no original
+      // position.
+      _ => {
+        self.unmapped.borrow_mut().push(start);
+        (start, end)
+      }
+    };
+    json!({
+      "start": { "line": start.0, "column": start.1 },
+      "end": { "line": end.0, "column": end.1 },
+    })
+  }
+}
@@ -1,4 +1,6 @@
 #![feature(let_chains)]
+mod coverage;
+mod coverage_dependency;
 mod mock_hoist_dependency;
 mod mock_module_id_dependency;
 mod module_path_name_dependency;
@@ -5,26 +5,56 @@ use std::{
 
 use async_trait::async_trait;
 use rspack_core::{
-  rspack_sources::{BoxSource, ReplaceSource, SourceExt},
-  ApplyContext, Compilation, CompilationParams, CompilationProcessAssets, CompilerCompilation,
-  CompilerOptions, ModuleType, NormalModuleFactoryParser, ParserAndGenerator, ParserOptions,
-  Plugin, PluginContext,
+  rspack_sources::{BoxSource, RawStringSource, ReplaceSource, SourceExt},
+  ApplyContext, Compilation, CompilationAsset, CompilationParams, CompilationProcessAssets,
+  CompilerCompilation, CompilerOptions, ModuleType, NormalModuleFactoryParser, ParserAndGenerator,
+  ParserOptions, Plugin, PluginContext,
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_plugin_javascript::{
   parser_and_generator::JavaScriptParserAndGenerator, BoxJavascriptParserPlugin,
 };
+use rspack_util::asset_condition::AssetConditions;
 
 use crate::{
+  coverage::CoverageParserPlugin,
+  coverage_dependency::{CoverageDependency, CoverageDependencyTemplate},
   mock_hoist_dependency::MockHoistDependencyTemplate,
   mock_module_id_dependency::MockModuleIdDependencyTemplate,
-  module_path_name_dependency::ModulePathNameDependencyTemplate, parser_plugin::RstestParserPlugin,
+  module_path_name_dependency::ModulePathNameDependencyTemplate,
+  parser_plugin::RstestParserPlugin,
 };
 
 #[derive(Debug)]
 pub struct RstestPluginOptions {
   pub module_path_name: bool,
+  pub coverage: Option<RstestCoverageOptions>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RstestCoverageOptions {
+  /// Only the modules whose resource path matches are instrumented.
+  pub include: Option<AssetConditions>,
+  /// The modules whose resource path matches aren't instrumented, defaults
+  /// to the modules in `node_modules`.
+  pub exclude: Option<AssetConditions>,
+  /// The filename of the emitted coverage map asset.
+  pub filename: String,
+}
+
+impl RstestCoverageOptions {
+  pub fn should_instrument(&self, path: &str) -> bool {
+    if let Some(include) = &self.include
+      && !include.try_match(path)
+    {
+      return false;
+    }
+    match &self.exclude {
+      Some(exclude) => !exclude.try_match(path),
+      None => !path.contains("/node_modules/") && !path.contains("\\node_modules\\"),
+    }
+  }
 }
 
 #[derive(Debug)]
@@ -99,7 +129,14 @@ async fn nmf_parser(
   if module_type.is_js_like()
     && let Some(parser) = parser.downcast_mut::<JavaScriptParserAndGenerator>()
   {
-    parser.add_parser_plugin(Box::<RstestParserPlugin>::default() as BoxJavascriptParserPlugin);
+    if self.options.module_path_name {
+      parser.add_parser_plugin(Box::<RstestParserPlugin>::default() as BoxJavascriptParserPlugin);
+    }
+    if let Some(coverage) = &self.options.coverage {
+      parser.add_parser_plugin(
+        Box::new(CoverageParserPlugin::new(coverage.clone())) as BoxJavascriptParserPlugin
+      );
+    }
   }
 
   Ok(())
@@ -125,6 +162,11 @@ async fn compilation(
     MockModuleIdDependencyTemplate::template_type(),
     Arc::new(MockModuleIdDependencyTemplate::default()),
   );
+
+  compilation.set_dependency_template(
+    CoverageDependencyTemplate::template_type(),
+    Arc::new(CoverageDependencyTemplate::default()),
+  );
   Ok(())
 }
 
@@ -199,6 +241,61 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   Ok(())
 }
 
+/// Emits the coverage maps of the instrumented modules, keyed by their paths
+/// like the `__coverage__` object, so that the uncovered files are reported
+/// as well.
+#[plugin_hook(CompilationProcessAssets for RstestPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
+async fn emit_coverage_map(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(coverage) = &self.options.coverage else {
+    return Ok(());
+  };
+
+  let mut coverage_map = vec![];
+  let mut diagnostics = vec![];
+  for module in compilation.get_module_graph().modules().into_values() {
+    let Some(module) = module.as_normal_module() else {
+      continue;
+    };
+    let Some(dependency) = module.get_presentational_dependencies().and_then(|deps| {
+      deps
+        .iter()
+        .find_map(|dep| dep.as_any().downcast_ref::<CoverageDependency>())
+    }) else {
+      continue;
+    };
+    let Some(source) = module.source() else {
+      continue;
+    };
+    let (file_coverage, unmapped) = dependency.file_coverage(source);
+    if let Some((line, column)) = unmapped.first() {
+      diagnostics.push(Diagnostic::warn(
+        "RstestPlugin".to_string(),
+        format!(
+          "{} coverage locations of {} have no original position in the source map, e.g. line {line}, column {column} of the code transformed by the loaders, the positions in the transformed code are reported instead",
+          unmapped.len(),
+          dependency.path,
+        ),
+      ));
+    }
+    coverage_map.push((
+      dependency.path.clone(),
+      serde_json::Value::Object(file_coverage),
+    ));
+  }
+  coverage_map.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+  diagnostics.sort_by_cached_key(Diagnostic::message);
+  compilation.extend_diagnostics(diagnostics);
+  let coverage_map =
+    serde_json::to_string(&coverage_map.into_iter().collect::<serde_json::Map<_, _>>())
+      .to_rspack_result()?;
+
+  compilation.emit_asset(
+    coverage.filename.clone(),
+    CompilationAsset::from(RawStringSource::from(coverage_map).boxed()),
+  );
+  Ok(())
+}
+
 #[async_trait]
 impl Plugin for RstestPlugin {
   fn name(&self) -> &'static str {
@@ -206,7 +303,7 @@ impl Plugin for RstestPlugin {
   }
 
   fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
-    if self.options.module_path_name {
+    if self.options.module_path_name || self.options.coverage.is_some() {
       ctx
         .context
         .compiler_hooks
@@ -218,7 +315,9 @@ impl Plugin for RstestPlugin {
         .normal_module_factory_hooks
         .parser
         .tap(nmf_parser::new(self));
+    }
 
+    if self.options.module_path_name {
       ctx
         .context
         .compilation_hooks
@@ -226,6 +325,14 @@ impl Plugin for RstestPlugin {
         .tap(process_assets::new(self));
     }
 
+    if self.options.coverage.is_some() {
+      ctx
+        .context
+        .compilation_hooks
+        .process_assets
+        .tap(emit_coverage_map::new(self));
+    }
+
     Ok(())
   }
 }
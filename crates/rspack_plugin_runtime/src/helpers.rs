@@ -111,10 +111,10 @@ pub fn get_all_chunks(
   chunks
 }
 
-pub async fn get_runtime_chunk_output_name(
-  compilation: &Compilation,
+pub fn get_runtime_chunk<'a>(
+  compilation: &'a Compilation,
   chunk_ukey: &ChunkUkey,
-) -> Result<String> {
+) -> Result<&'a Chunk> {
   let entry_point = {
     let entry_points = compilation
       .chunk_graph
@@ -128,10 +128,18 @@ pub async fn get_runtime_chunk_output_name(
     compilation.chunk_group_by_ukey.expect_get(entry_point_ukey)
   };
 
-  let runtime_chunk = compilation
-    .chunk_by_ukey
-    .expect_get(&entry_point.get_runtime_chunk(&compilation.chunk_group_by_ukey));
+  Ok(
+    compilation
+      .chunk_by_ukey
+      .expect_get(&entry_point.get_runtime_chunk(&compilation.chunk_group_by_ukey)),
+  )
+}
 
+pub async fn get_runtime_chunk_output_name(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+) -> Result<String> {
+  let runtime_chunk = get_runtime_chunk(compilation, chunk_ukey)?;
   get_chunk_output_name(runtime_chunk, compilation).await
 }
 
@@ -4,8 +4,9 @@ use async_trait::async_trait;
 use rspack_core::{
   rspack_sources::{ConcatSource, RawStringSource, SourceExt},
   ApplyContext, ChunkGraph, ChunkKind, ChunkUkey, Compilation,
-  CompilationAdditionalChunkRuntimeRequirements, CompilationDependentFullHash, CompilationParams,
-  CompilerCompilation, CompilerOptions, Plugin, PluginContext, RuntimeGlobals,
+  CompilationAdditionalChunkRuntimeRequirements, CompilationAsset, CompilationDependentFullHash,
+  CompilationParams, CompilationProcessAssets, CompilerCompilation, CompilerOptions, Plugin,
+  PluginContext, RuntimeGlobals,
 };
 use rspack_error::{miette, Result};
 use rspack_hash::RspackHash;
@@ -19,7 +20,7 @@ use rustc_hash::FxHashSet as HashSet;
 
 use super::update_hash_for_entry_startup;
 use crate::{
-  chunk_has_js, get_all_chunks, get_chunk_output_name, get_relative_path,
+  chunk_has_js, get_all_chunks, get_chunk_output_name, get_relative_path, get_runtime_chunk,
   get_runtime_chunk_output_name, runtime_chunk_has_hash,
 };
 
@@ -109,6 +110,11 @@ async fn compilation_dependent_full_hash(
     return Ok(None);
   }
 
+  // The runtime chunk is imported with its specifier instead of its filename.
+  if compilation.options.output.import_map.is_some() {
+    return Ok(None);
+  }
+
   if runtime_chunk_has_hash(compilation, chunk_ukey).await? {
     return Ok(Some(true));
   }
@@ -160,15 +166,24 @@ async fn render_chunk(
   }
 
   if chunk.has_entry_module(&compilation.chunk_graph) {
-    let runtime_chunk_output_name = get_runtime_chunk_output_name(compilation, chunk_ukey).await?;
+    let runtime_chunk_request = match &compilation.options.output.import_map {
+      Some(import_map) => {
+        let runtime_chunk = get_runtime_chunk(compilation, chunk_ukey)?;
+        import_map.chunk_specifier(runtime_chunk.expect_id(&compilation.chunk_ids_artifact))
+      }
+      None => {
+        let runtime_chunk_output_name =
+          get_runtime_chunk_output_name(compilation, chunk_ukey).await?;
+        get_relative_path(
+          base_chunk_output_name
+            .trim_start_matches("/")
+            .trim_start_matches("\\"),
+          &runtime_chunk_output_name,
+        )
+      }
+    };
     sources.add(RawStringSource::from(format!(
-      "import __webpack_require__ from '{}';\n",
-      get_relative_path(
-        base_chunk_output_name
-          .trim_start_matches("/")
-          .trim_start_matches("\\"),
-        &runtime_chunk_output_name
-      )
+      "import __webpack_require__ from '{runtime_chunk_request}';\n"
     )));
 
     let entries = compilation
@@ -208,11 +223,18 @@ async fn render_chunk(
         loaded_chunks.insert(*chunk_ukey);
         let index = loaded_chunks.len();
         let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
-        let other_chunk_output_name = get_chunk_output_name(chunk, compilation).await?;
+        let chunk_request = match &compilation.options.output.import_map {
+          Some(import_map) => {
+            import_map.chunk_specifier(chunk.expect_id(&compilation.chunk_ids_artifact))
+          }
+          None => {
+            let other_chunk_output_name = get_chunk_output_name(chunk, compilation).await?;
+            get_relative_path(&base_chunk_output_name, &other_chunk_output_name)
+          }
+        };
         startup_source.push(format!(
-          "import * as __webpack_chunk_${}__ from '{}';",
+          "import * as __webpack_chunk_${}__ from '{chunk_request}';",
           itoa!(index),
-          get_relative_path(&base_chunk_output_name, &other_chunk_output_name)
         ));
         startup_source.push(format!(
           "{}(__webpack_chunk_${}__);",
@@ -255,6 +277,26 @@ async fn render_chunk(
   Ok(())
 }
 
+#[plugin_hook(CompilationProcessAssets for ModuleChunkFormatPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONS)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(import_map) = &compilation.options.output.import_map else {
+    return Ok(());
+  };
+  let public_path = compilation
+    .options
+    .output
+    .public_path
+    .render(compilation, &import_map.filename)
+    .await;
+  let source = import_map.render(compilation, &public_path);
+  let filename = import_map.filename.clone();
+  compilation.emit_asset(
+    filename,
+    CompilationAsset::from(RawStringSource::from(source).boxed()),
+  );
+  Ok(())
+}
+
 #[async_trait]
 impl Plugin for ModuleChunkFormatPlugin {
   fn name(&self) -> &'static str {
@@ -277,6 +319,11 @@ impl Plugin for ModuleChunkFormatPlugin {
       .compilation_hooks
       .dependent_full_hash
       .tap(compilation_dependent_full_hash::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
     Ok(())
   }
 }
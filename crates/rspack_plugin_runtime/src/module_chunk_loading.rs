@@ -30,7 +30,10 @@ async fn runtime_requirements_in_tree(
     match runtime_requirement {
       RuntimeGlobals::ENSURE_CHUNK_HANDLERS if is_enabled_for_chunk => {
         has_chunk_loading = true;
-        runtime_requirements_mut.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
+        // The chunks are imported with their specifiers in the import map.
+        if compilation.options.output.import_map.is_none() {
+          runtime_requirements_mut.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
+        }
       }
       RuntimeGlobals::EXTERNAL_INSTALL_CHUNK if is_enabled_for_chunk => {
         has_chunk_loading = true;
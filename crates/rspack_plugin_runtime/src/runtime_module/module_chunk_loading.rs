@@ -135,6 +135,33 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
 
     let root_output_dir = get_output_dir(chunk, compilation, true).await?;
 
+    // With an import map, the chunks are imported with their specifiers, so
+    // the runtime doesn't contain the hashed filenames of the chunks.
+    let (chunk_request, chunk_url) = match &compilation.options.output.import_map {
+      Some(import_map) => {
+        let prefix = serde_json::to_string(&import_map.prefix).expect("should serialize prefix");
+        (
+          format!("{prefix} + chunkId"),
+          format!(
+            "{}.resolve({prefix} + chunkId)",
+            compilation.options.output.import_meta_name
+          ),
+        )
+      }
+      None => (
+        format!(
+          "{} + {}(chunkId)",
+          serde_json::to_string(&root_output_dir).expect("should serialize output dir"),
+          RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME
+        ),
+        format!(
+          "{} + {}(chunkId)",
+          RuntimeGlobals::PUBLIC_PATH,
+          RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME
+        ),
+      ),
+    };
+
     let mut source = String::default();
 
     if with_base_uri {
@@ -181,7 +208,7 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
           Some(serde_json::json!({
             "_js_matcher": &has_js_matcher.render("chunkId"),
             "_import_function_name":&compilation.options.output.import_function_name,
-            "_output_dir": &root_output_dir,
+            "_chunk_request": &chunk_request,
             "_match_fallback":    if matches!(has_js_matcher, BooleanMatcher::Condition(true)) {
               ""
             } else {
@@ -221,8 +248,9 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
     }
     link.rel = 'prefetch';
     link.as = 'script';
-    link.href = __webpack_require__.p + __webpack_require__.u(chunkId);  
+    link.href = $CHUNK_URL$;  
       "#
+        .cow_replace("$CHUNK_URL$", &chunk_url)
         .cow_replace(
           "$LINK_CHART_CHARSET$",
           if charset {
@@ -282,9 +310,10 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
       link.setAttribute("nonce", __webpack_require__.nc);
     }
     link.rel = 'modulepreload';
-    link.href = __webpack_require__.p + __webpack_require__.u(chunkId);
+    link.href = $CHUNK_URL$;
     $CROSS_ORIGIN$
       "#
+        .cow_replace("$CHUNK_URL$", &chunk_url)
         .cow_replace(
           "$LINK_CHART_CHARSET$",
           if charset {
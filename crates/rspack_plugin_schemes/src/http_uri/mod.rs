@@ -61,6 +61,12 @@ async fn resolve_content(
     }
   }
 }
+/// Fetches `url` through the cache and the lockfile, as the `http(s)` modules
+/// are, for the plugins loading other remote files.
+pub async fn fetch_url(url: &str, options: &HttpUriPluginOptions) -> Result<Vec<u8>> {
+  Ok(get_info(url, options).await?.content().to_vec())
+}
+
 impl HttpUriPlugin {
   pub fn new(options: HttpUriPluginOptions) -> Self {
     Self::new_inner(options)
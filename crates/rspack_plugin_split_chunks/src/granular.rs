@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use rspack_core::{
+  ApplyContext, CompilerOptions, Module, Plugin, PluginContext, SourceType, DEFAULT_DELIMITER,
+};
+use rspack_error::Result;
+use rspack_hook::plugin;
+
+use crate::{
+  create_all_chunk_filter, create_default_module_layer_filter, create_default_module_type_filter,
+  CacheGroup, CacheGroupPackageTest, CacheGroupTest, CacheGroupTestFnCtx, ChunkNameGetter,
+  ChunkNameGetterFnCtx, FallbackCacheGroup, PackageSource, PluginOptions, SplitChunkSizes,
+  SplitChunksPlugin,
+};
+
+#[derive(Debug, Clone)]
+pub struct GranularChunksPluginOptions {
+  /// The number of chunks an entry or an async chunk is split into at most,
+  /// they are requested in parallel.
+  pub max_chunks: u32,
+  /// The packages put into the `framework` chunk, like `react`.
+  pub framework_packages: Vec<glob::Pattern>,
+  pub min_size: f64,
+  /// The chunks bigger than this are split into parts of similar sizes.
+  pub max_size: f64,
+}
+
+impl Default for GranularChunksPluginOptions {
+  fn default() -> Self {
+    Self {
+      max_chunks: 25,
+      framework_packages: ["react", "react-dom", "scheduler"]
+        .into_iter()
+        .map(|name| glob::Pattern::new(name).expect("should be a valid glob"))
+        .collect(),
+      min_size: 20000.0,
+      max_size: 250000.0,
+    }
+  }
+}
+
+/// Splits the chunks into many small chunks for HTTP/2, by how often their
+/// modules change: the framework packages change the least, then the other
+/// packages in `node_modules`, then the modules shared by several chunks, and
+/// the modules left in the chunks change the most.
+///
+/// It is a preset of `SplitChunksPlugin`, so the chunks only depend on the
+/// module graph and their names and hashes are stable between builds.
+#[plugin]
+#[derive(Debug)]
+pub struct GranularChunksPlugin {
+  options: GranularChunksPluginOptions,
+}
+
+impl GranularChunksPlugin {
+  pub fn new(options: GranularChunksPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+
+  fn split_chunks_options(&self) -> PluginOptions {
+    let size_types = [SourceType::JavaScript, SourceType::Unknown];
+    let min_size = SplitChunkSizes::with_initial_value(&size_types, self.options.min_size);
+    let max_size = SplitChunkSizes::with_initial_value(&size_types, self.options.max_size);
+    let max_chunks = self.options.max_chunks as f64;
+
+    let cache_group =
+      |key: &str, priority: f64, test: CacheGroupTest, name: ChunkNameGetter| CacheGroup {
+        key: key.to_string(),
+        chunk_filter: create_all_chunk_filter(),
+        test,
+        r#type: create_default_module_type_filter(),
+        layer: create_default_module_layer_filter(),
+        name,
+        priority,
+        min_size: min_size.clone(),
+        min_size_reduction: min_size.clone(),
+        reuse_existing_chunk: true,
+        min_chunks: 1,
+        id_hint: key.to_string(),
+        max_initial_requests: max_chunks,
+        max_async_requests: max_chunks,
+        max_async_size: max_size.clone(),
+        max_initial_size: max_size.clone(),
+        filename: None,
+        automatic_name_delimiter: DEFAULT_DELIMITER.to_string(),
+        used_exports: false,
+      };
+
+    let framework_tests = self
+      .options
+      .framework_packages
+      .iter()
+      .map(|name| CacheGroupPackageTest {
+        name: Some(name.clone()),
+        license: None,
+        source: Some(PackageSource::NodeModules),
+      })
+      .collect::<Vec<_>>();
+    let framework_test = CacheGroupTest::Fn(Arc::new(move |ctx: CacheGroupTestFnCtx| {
+      let res = framework_tests.iter().any(|test| test.test(ctx.module));
+      Box::pin(async move { Ok(Some(res)) })
+    }));
+    let node_modules_test = || {
+      CacheGroupTest::Package(CacheGroupPackageTest {
+        source: Some(PackageSource::NodeModules),
+        ..Default::default()
+      })
+    };
+    let lib_name = ChunkNameGetter::Fn(Arc::new(|ctx: ChunkNameGetterFnCtx| {
+      let name = package_name(ctx.module).map(|name| format!("lib-{name}"));
+      Box::pin(async move { Ok(name) })
+    }));
+
+    let framework = CacheGroup {
+      // The framework is always split out, no matter how small it is.
+      min_size: SplitChunkSizes::empty(),
+      min_size_reduction: SplitChunkSizes::empty(),
+      max_async_size: SplitChunkSizes::empty(),
+      max_initial_size: SplitChunkSizes::empty(),
+      ..cache_group(
+        "framework",
+        40.0,
+        framework_test,
+        ChunkNameGetter::String("framework".to_string()),
+      )
+    };
+    let lib = cache_group("lib", 30.0, node_modules_test(), lib_name);
+    let vendors = cache_group(
+      "vendors",
+      20.0,
+      node_modules_test(),
+      ChunkNameGetter::Disabled,
+    );
+    let shared = CacheGroup {
+      min_chunks: 2,
+      ..cache_group(
+        "shared",
+        10.0,
+        CacheGroupTest::Enabled,
+        ChunkNameGetter::Disabled,
+      )
+    };
+
+    PluginOptions {
+      cache_groups: vec![framework, lib, vendors, shared],
+      fallback_cache_group: FallbackCacheGroup {
+        chunks_filter: create_all_chunk_filter(),
+        min_size,
+        max_async_size: max_size.clone(),
+        max_initial_size: max_size,
+        automatic_name_delimiter: DEFAULT_DELIMITER.to_string(),
+      },
+      hide_path_info: Some(true),
+      explain: false,
+    }
+  }
+}
+
+/// The name of the package the module belongs to, made safe for a chunk name,
+/// `@babel/runtime` becomes `babel-runtime`.
+fn package_name(module: &dyn Module) -> Option<String> {
+  let description = module
+    .as_normal_module()?
+    .resource_resolved_data()
+    .resource_description
+    .as_ref()?;
+  let name = description.json().get("name")?.as_str()?;
+  let name = name
+    .split(|c: char| !c.is_ascii_alphanumeric() && c != '_' && c != '.')
+    .filter(|part| !part.is_empty())
+    .collect::<Vec<_>>()
+    .join("-");
+  (!name.is_empty()).then_some(name)
+}
+
+impl Plugin for GranularChunksPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.GranularChunksPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, options: &CompilerOptions) -> Result<()> {
+    SplitChunksPlugin::new(self.split_chunks_options())
+      .apply(PluginContext::with_context(ctx.context), options)
+  }
+}
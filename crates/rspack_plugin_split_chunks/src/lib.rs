@@ -1,6 +1,7 @@
 #![feature(let_chains)]
 
 mod common;
+mod granular;
 mod module_group;
 mod options;
 mod plugin;
@@ -11,9 +12,10 @@ pub use common::{
   create_initial_chunk_filter, create_regex_chunk_filter_from_str, ChunkFilter, FallbackCacheGroup,
   ModuleLayerFilter, ModuleTypeFilter, SplitChunkSizes,
 };
+pub use granular::{GranularChunksPlugin, GranularChunksPluginOptions};
 pub use options::{
   cache_group::CacheGroup,
-  cache_group_test::{CacheGroupTest, CacheGroupTestFnCtx},
+  cache_group_test::{CacheGroupPackageTest, CacheGroupTest, CacheGroupTestFnCtx, PackageSource},
   chunk_name::{ChunkNameGetter, ChunkNameGetterFnCtx},
 };
 pub use plugin::{PluginOptions, SplitChunksPlugin};
@@ -17,5 +17,104 @@ pub enum CacheGroupTest {
   String(String),
   Fn(CacheGroupTestFn),
   RegExp(rspack_regex::RspackRegex),
+  Package(CacheGroupPackageTest),
   Enabled,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageSource {
+  /// The package is installed in `node_modules`.
+  NodeModules,
+  /// The package is part of the workspace, like the project itself or a
+  /// linked monorepo package.
+  Workspace,
+}
+
+/// Matches the modules by the `package.json` they are resolved with, the
+/// modules without a `package.json` never match.
+#[derive(Debug, Clone, Default)]
+pub struct CacheGroupPackageTest {
+  /// Glob of the package `name`, like `@babel/*`.
+  pub name: Option<glob::Pattern>,
+  /// Glob of the package `license`, like `MIT` or `*GPL*`.
+  pub license: Option<glob::Pattern>,
+  pub source: Option<PackageSource>,
+}
+
+impl CacheGroupPackageTest {
+  pub fn test(&self, module: &dyn Module) -> bool {
+    let Some(description) = module.as_normal_module().and_then(|module| {
+      module
+        .resource_resolved_data()
+        .resource_description
+        .as_ref()
+    }) else {
+      return false;
+    };
+    let json = description.json();
+
+    if let Some(name) = &self.name
+      && !json
+        .get("name")
+        .and_then(|name| name.as_str())
+        .is_some_and(|package_name| name.matches(package_name))
+    {
+      return false;
+    }
+
+    if let Some(license) = &self.license
+      && !package_licenses(json).any(|package_license| license.matches(package_license))
+    {
+      return false;
+    }
+
+    if let Some(source) = self.source {
+      let in_node_modules = description
+        .path()
+        .components()
+        .any(|component| component.as_os_str() == "node_modules");
+      if in_node_modules != (source == PackageSource::NodeModules) {
+        return false;
+      }
+    }
+
+    true
+  }
+}
+
+/// `license` is a string, or `{ "type": "MIT" }` in legacy packages, which may
+/// also list `licenses`.
+fn package_licenses(json: &serde_json::Value) -> impl Iterator<Item = &str> {
+  let license = json.get("license").into_iter();
+  let licenses = json
+    .get("licenses")
+    .and_then(|licenses| licenses.as_array())
+    .into_iter()
+    .flatten();
+  license.chain(licenses).filter_map(|license| {
+    license
+      .as_str()
+      .or_else(|| license.get("type").and_then(|r#type| r#type.as_str()))
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_collect_package_licenses() {
+    let json = serde_json::json!({
+      "license": "MIT",
+      "licenses": [{ "type": "Apache-2.0" }, "BSD-3-Clause"]
+    });
+    assert_eq!(
+      package_licenses(&json).collect::<Vec<_>>(),
+      vec!["MIT", "Apache-2.0", "BSD-3-Clause"]
+    );
+    assert_eq!(
+      package_licenses(&serde_json::json!({ "license": { "type": "ISC" } })).collect::<Vec<_>>(),
+      vec!["ISC"]
+    );
+  }
+}
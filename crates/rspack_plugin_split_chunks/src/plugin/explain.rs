@@ -0,0 +1,106 @@
+use rspack_collections::UkeySet;
+use rspack_core::{ChunkUkey, Compilation, SplitChunksDecision};
+
+use super::max_request::MaxRequestViolation;
+use crate::{module_group::ModuleGroup, CacheGroup, SplitChunkSizes, SplitChunksPlugin};
+
+/// Records the decisions of `SplitChunksPlugin` on the chunks when
+/// `splitChunks.explain` is enabled, they are exposed as `splitChunks` of the
+/// chunks in stats.
+impl SplitChunksPlugin {
+  pub(crate) fn explain_split(
+    &self,
+    compilation: &mut Compilation,
+    cache_group: &CacheGroup,
+    module_group: &ModuleGroup,
+    chunk: ChunkUkey,
+    is_reuse_existing_chunk: bool,
+  ) {
+    let chunk = compilation.chunk_by_ukey.expect_get_mut(&chunk);
+    chunk
+      .split_chunks_explanation_mut()
+      .decisions
+      .push(SplitChunksDecision {
+        cache_group: cache_group.key.clone(),
+        name: module_group.chunk_name.clone(),
+        priority: cache_group.priority,
+        modules: module_group.modules.len(),
+        min_size: cache_group.min_size.0.clone(),
+        max_async_size: cache_group.max_async_size.0.clone(),
+        max_initial_size: cache_group.max_initial_size.0.clone(),
+        reused_existing_chunk: is_reuse_existing_chunk,
+      });
+  }
+
+  pub(crate) fn explain_min_size_violation(
+    &self,
+    compilation: &mut Compilation,
+    module_group: &ModuleGroup,
+    option: &str,
+  ) {
+    let cache_group = module_group.get_cache_group(&self.cache_groups);
+    let sizes = match option {
+      "minSizeReduction" => &cache_group.min_size_reduction,
+      _ => &cache_group.min_size,
+    };
+    let reason = format!(
+      "cache group \"{}\" kept its modules, they are smaller than {option} ({})",
+      cache_group.key,
+      format_sizes(sizes)
+    );
+    block(compilation, &module_group.chunks, &reason);
+  }
+
+  pub(crate) fn explain_max_request_violations(
+    &self,
+    compilation: &mut Compilation,
+    cache_group: &CacheGroup,
+    violations: &[MaxRequestViolation],
+  ) {
+    for violation in violations {
+      let reason = format!(
+        "cache group \"{}\" kept its modules, the chunk is already loaded with {} requests, which reaches {} ({})",
+        cache_group.key, violation.requests, violation.option, violation.max_requests
+      );
+      block(compilation, [&violation.chunk], &reason);
+    }
+  }
+
+  pub(crate) fn explain_min_chunks_violation(
+    &self,
+    compilation: &mut Compilation,
+    cache_group: &CacheGroup,
+    used_chunks: &UkeySet<ChunkUkey>,
+    used_chunks_len: usize,
+  ) {
+    let reason = format!(
+      "cache group \"{}\" kept its modules, only {used_chunks_len} chunks remain within maxAsyncRequests and maxInitialRequests, which is less than minChunks ({})",
+      cache_group.key, cache_group.min_chunks
+    );
+    block(compilation, used_chunks, &reason);
+  }
+}
+
+fn block<'a>(
+  compilation: &mut Compilation,
+  chunks: impl IntoIterator<Item = &'a ChunkUkey>,
+  reason: &str,
+) {
+  for chunk in chunks {
+    compilation
+      .chunk_by_ukey
+      .expect_get_mut(chunk)
+      .split_chunks_explanation_mut()
+      .blocked
+      .push(reason.to_string());
+  }
+}
+
+fn format_sizes(sizes: &SplitChunkSizes) -> String {
+  let mut sizes = sizes
+    .iter()
+    .map(|(ty, size)| format!("{ty}: {size}"))
+    .collect::<Vec<_>>();
+  sizes.sort_unstable();
+  sizes.join(", ")
+}
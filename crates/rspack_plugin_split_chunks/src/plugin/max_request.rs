@@ -5,6 +5,15 @@ use rspack_core::{ChunkUkey, Compilation};
 
 use crate::{CacheGroup, SplitChunksPlugin};
 
+/// A chunk removed from a split as it reaches its max requests.
+#[derive(Debug)]
+pub(crate) struct MaxRequestViolation {
+  pub chunk: ChunkUkey,
+  pub requests: u32,
+  pub option: &'static str,
+  pub max_requests: f64,
+}
+
 impl SplitChunksPlugin {
   /// Affected by `splitChunks.maxInitialRequests`/`splitChunks.cacheGroups.{cacheGroup}.maxInitialRequests`
   /// Affected by `splitChunks.maxAsyncRequests`/`splitChunks.cacheGroups.{cacheGroup}.maxAsyncRequests`
@@ -14,22 +23,25 @@ impl SplitChunksPlugin {
     compilation: &Compilation,
     cache_group: &CacheGroup,
     used_chunks: &mut Cow<UkeySet<ChunkUkey>>,
-  ) {
+  ) -> Vec<MaxRequestViolation> {
     let chunk_db = &compilation.chunk_by_ukey;
     let chunk_group_db = &compilation.chunk_group_by_ukey;
-    let invalided_chunks = used_chunks
+    let violations = used_chunks
       .iter()
       .map(|c| chunk_db.expect_get(c))
       .filter_map(|chunk| {
-        let allowed_max_request = if chunk.is_only_initial(chunk_group_db) {
-          cache_group.max_initial_requests
+        let (allowed_max_request, option) = if chunk.is_only_initial(chunk_group_db) {
+          (cache_group.max_initial_requests, "maxInitialRequests")
         } else if chunk.can_be_initial(chunk_group_db) {
-          f64::max(
-            cache_group.max_initial_requests,
-            cache_group.max_async_requests,
+          (
+            f64::max(
+              cache_group.max_initial_requests,
+              cache_group.max_async_requests,
+            ),
+            "maxInitialRequests and maxAsyncRequests",
           )
         } else {
-          cache_group.max_async_requests
+          (cache_group.max_async_requests, "maxAsyncRequests")
         };
 
         // `Chunk`s in `used_chunks` are all code-splitting chunk.
@@ -55,14 +67,20 @@ impl SplitChunksPlugin {
           .unwrap_or_default();
 
         if actually_requests as f64 >= allowed_max_request {
-          Some(chunk.ukey())
+          Some(MaxRequestViolation {
+            chunk: chunk.ukey(),
+            requests: actually_requests,
+            option,
+            max_requests: allowed_max_request,
+          })
         } else {
           None
         }
       })
       .collect::<Vec<_>>();
-    invalided_chunks.into_iter().for_each(|c| {
-      used_chunks.to_mut().remove(&c);
-    })
+    violations.iter().for_each(|violation| {
+      used_chunks.to_mut().remove(&violation.chunk);
+    });
+    violations
   }
 }
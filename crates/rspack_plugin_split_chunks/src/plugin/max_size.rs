@@ -685,6 +685,14 @@ impl SplitChunksPlugin {
           let new_part_ukey = new_part.ukey();
           chunk.split(new_part, &mut compilation.chunk_group_by_ukey);
           *new_part.chunk_reason_mut() = chunk.chunk_reason().map(ToString::to_string);
+          if self.explain {
+            let mut explanation = chunk
+              .split_chunks_explanation()
+              .cloned()
+              .unwrap_or_default();
+            explanation.max_size_part = Some(group_key.clone());
+            new_part.set_split_chunks_explanation(Some(explanation));
+          }
           if chunk.filename_template().is_some() {
             new_part.set_filename_template(chunk.filename_template().cloned());
           }
@@ -717,6 +725,9 @@ impl SplitChunksPlugin {
               .disconnect_chunk_and_module(&old_chunk, module.module)
           })
         } else {
+          if self.explain {
+            chunk.split_chunks_explanation_mut().max_size_part = Some(group_key);
+          }
           chunk.set_name(name);
         }
       })
@@ -95,11 +95,12 @@ impl SplitChunksPlugin {
 
   /// Affected by `splitChunks.minSize`/`splitChunks.cacheGroups.{cacheGroup}.minSize`
   // #[tracing::instrument(skip_all)]
+  /// Returns the removed `ModuleGroup`s along with the violated option.
   pub(crate) fn ensure_min_size_fit(
     &self,
     compilation: &Compilation,
     module_group_map: &mut ModuleGroupMap,
-  ) {
+  ) -> Vec<(ModuleGroup, &'static str)> {
     let invalidated_module_groups = module_group_map
       .iter_mut()
       .filter_map(|(module_group_key, module_group)| {
@@ -119,24 +120,32 @@ impl SplitChunksPlugin {
           compilation,
           module_group,
           cache_group,
-        ) || !Self::check_min_size_reduction(
+        ) {
+          Some((module_group_key.clone(), "minSize"))
+        } else if !Self::check_min_size_reduction(
           &module_group.sizes,
           &cache_group.min_size_reduction,
           module_group.chunks.len(),
         ) {
-          Some(module_group_key.clone())
+          Some((module_group_key.clone(), "minSizeReduction"))
         } else {
           None
         }
       })
       .collect::<Vec<_>>();
 
-    invalidated_module_groups.into_iter().for_each(|key| {
-      tracing::debug!(
-        "ModuleGroup({}) is removed. Reason: empty modules cause by `minSize` checking",
-        key,
-      );
-      module_group_map.remove(&key);
-    });
+    invalidated_module_groups
+      .into_iter()
+      .filter_map(|(key, option)| {
+        tracing::debug!(
+          "ModuleGroup({}) is removed. Reason: empty modules cause by `{}` checking",
+          key,
+          option,
+        );
+        module_group_map
+          .remove(&key)
+          .map(|module_group| (module_group, option))
+      })
+      .collect()
   }
 }
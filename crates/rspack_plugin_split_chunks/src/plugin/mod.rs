@@ -1,4 +1,5 @@
 mod chunk;
+mod explain;
 mod max_request;
 mod max_size;
 mod min_size;
@@ -21,6 +22,9 @@ pub struct PluginOptions {
   pub cache_groups: Vec<CacheGroup>,
   pub fallback_cache_group: FallbackCacheGroup,
   pub hide_path_info: Option<bool>,
+  /// Record why each chunk was split, exposed as `splitChunks` of the chunks
+  /// in stats.
+  pub explain: bool,
 }
 
 #[plugin]
@@ -28,6 +32,7 @@ pub struct SplitChunksPlugin {
   cache_groups: Box<[CacheGroup]>,
   fallback_cache_group: FallbackCacheGroup,
   hide_path_info: bool,
+  explain: bool,
 }
 
 impl SplitChunksPlugin {
@@ -37,6 +42,7 @@ impl SplitChunksPlugin {
       options.cache_groups.into(),
       options.fallback_cache_group,
       options.hide_path_info.unwrap_or(false),
+      options.explain,
     )
   }
 
@@ -48,7 +54,12 @@ impl SplitChunksPlugin {
     logger.time_end(start);
 
     let start: rspack_core::StartTime = logger.time("ensure min size fit");
-    self.ensure_min_size_fit(compilation, &mut module_group_map);
+    let min_size_violations = self.ensure_min_size_fit(compilation, &mut module_group_map);
+    if self.explain {
+      for (module_group, option) in &min_size_violations {
+        self.explain_min_size_violation(compilation, module_group, option);
+      }
+    }
     logger.time_end(start);
 
     let start = logger.time("process module group map");
@@ -99,7 +110,11 @@ impl SplitChunksPlugin {
 
       let mut used_chunks = Cow::Borrowed(&module_group.chunks);
 
-      self.ensure_max_request_fit(compilation, cache_group, &mut used_chunks);
+      let max_request_violations =
+        self.ensure_max_request_fit(compilation, cache_group, &mut used_chunks);
+      if self.explain {
+        self.explain_max_request_violations(compilation, cache_group, &max_request_violations);
+      }
 
       if used_chunks.len() != module_group.chunks.len() {
         // There are some chunks removed by `ensure_max_request_fit`
@@ -112,6 +127,14 @@ impl SplitChunksPlugin {
         if used_chunks_len < cache_group.min_chunks as usize {
           // `min_size` is not satisfied, ignore this invalid `ModuleGroup`
           tracing::trace!("ModuleGroup({module_group_key}) is skipped. Reason: used_chunks_len({used_chunks_len:?}) < cache_group.min_chunks({:?})", cache_group.min_chunks);
+          if self.explain {
+            self.explain_min_chunks_violation(
+              compilation,
+              cache_group,
+              &used_chunks,
+              used_chunks_len,
+            );
+          }
           continue;
           // return;
         }
@@ -129,6 +152,16 @@ impl SplitChunksPlugin {
         );
       }
 
+      if self.explain {
+        self.explain_split(
+          compilation,
+          cache_group,
+          &module_group,
+          new_chunk,
+          is_reuse_existing_chunk,
+        );
+      }
+
       self.move_modules_to_new_chunk_and_remove_from_old_chunks(
         &module_group,
         new_chunk,
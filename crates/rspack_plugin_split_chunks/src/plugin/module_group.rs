@@ -347,6 +347,7 @@ impl SplitChunksPlugin {
                 let ctx = CacheGroupTestFnCtx { compilation, module };
                 f(ctx).await?.unwrap_or_default()
               }
+              CacheGroupTest::Package(test) => test.test(module),
               CacheGroupTest::Enabled => true,
             };
             let is_match_the_type: bool = (cache_group.r#type)(module);
@@ -1,33 +1,47 @@
 #![feature(let_chains)]
 
+mod mangle_properties;
+mod size_report;
+
 use std::{
-  collections::HashMap,
+  collections::{BTreeMap, HashMap},
   hash::Hash,
   path::Path,
-  sync::{mpsc, LazyLock, Mutex},
+  sync::{mpsc, Arc, LazyLock, Mutex},
 };
 
 use cow_utils::CowUtils;
+pub use mangle_properties::ManglePropertiesOptions;
+use mangle_properties::{assign_property_names, used_property_names};
 use once_cell::sync::OnceCell;
 use rayon::prelude::*;
 use regex::Regex;
+use rspack_cacheable::{cacheable, from_bytes, to_bytes};
 use rspack_core::{
+  cache::persistent::ScopeCache,
   diagnostics::MinifyError,
   rspack_sources::{
-    ConcatSource, MapOptions, RawStringSource, Source, SourceExt, SourceMapSource,
-    SourceMapSourceOptions,
+    BoxSource, ConcatSource, MapOptions, RawStringSource, Source, SourceExt, SourceMap,
+    SourceMapSource, SourceMapSourceOptions,
   },
-  AssetInfo, ChunkUkey, Compilation, CompilationAsset, CompilationParams, CompilationProcessAssets,
-  CompilerCompilation, Plugin, PluginContext,
+  AssetInfo, BoxModule, ChunkUkey, Compilation, CompilationAsset, CompilationBuildModule,
+  CompilationId, CompilationParams, CompilationProcessAssets, CompilationRuntimeModule,
+  CompilerCompilation, CompilerId, ModuleIdentifier, Plugin, PluginContext,
 };
 use rspack_error::{miette::IntoDiagnostic, Diagnostic, DiagnosticExt, Result};
-use rspack_hash::RspackHash;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_javascript_compiler::JavaScriptCompiler;
 use rspack_plugin_javascript::{ExtractedCommentsInfo, JavascriptModulesChunkHash, JsPlugin};
-use rspack_util::asset_condition::AssetConditions;
+use rspack_util::{
+  asset_condition::AssetConditions, identifier::make_paths_absolute, source_map::SourceMapKind,
+};
+use rustc_hash::FxHashMap;
+use serde_json::json;
+use size_report::{attribute_sizes, join_sizes, AssetSizes};
 use swc_config::types::BoolOrDataConfig;
 use swc_core::{
+  atoms::Atom,
   base::config::JsMinifyFormatOptions,
   common::comments::{CommentKind, SingleThreadedComments},
 };
@@ -37,6 +51,7 @@ pub use swc_ecma_minifier::option::{
 };
 
 const PLUGIN_NAME: &str = "rspack.SwcJsMinimizerRspackPlugin";
+const CACHE_SCOPE: &str = "plugin_swc_js_minimizer";
 
 static JAVASCRIPT_ASSET_REGEXP: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"\.[cm]?js(\?.*)?$").expect("Invalid RegExp"));
@@ -48,6 +63,8 @@ pub struct PluginOptions {
   pub exclude: Option<AssetConditions>,
   pub extract_comments: Option<ExtractComments>,
   pub minimizer_options: MinimizerOptions,
+  /// Filename of the JSON asset reporting the minified size of each module.
+  pub size_report: Option<String>,
 }
 
 #[derive(Debug, Default)]
@@ -57,6 +74,7 @@ pub struct MinimizerOptions {
   pub mangle: BoolOrDataConfig<MangleOptions>,
   pub format: JsMinifyFormatOptions,
   pub module: Option<bool>,
+  pub mangle_properties: Option<ManglePropertiesOptions>,
 
   /// Internal fields for hashing only.
   /// This guaranteed these field should only be readonly.
@@ -90,6 +108,7 @@ impl std::hash::Hash for MinimizerOptions {
           .map(|v| serde_json::to_string(v).expect("Should be able to serialize"))
       })
       .hash(state);
+    self.mangle_properties.hash(state);
   }
 }
 
@@ -120,15 +139,127 @@ struct NormalizedExtractComments<'a> {
   banner: Option<String>,
 }
 
+/// The minified asset persisted in the storage of the persistent cache.
+#[cacheable]
+struct CachedAsset {
+  code: String,
+  map: Option<String>,
+  extracted_comments: Option<String>,
+  sizes: Option<AssetSizes>,
+  /// The warnings of the minifier, reported again when the asset is restored.
+  warnings: Vec<String>,
+}
+
+impl CachedAsset {
+  fn to_source(&self, filename: &str) -> Option<BoxSource> {
+    let source = if let Some(map) = &self.map {
+      SourceMapSource::new(SourceMapSourceOptions {
+        value: self.code.clone(),
+        name: filename,
+        source_map: SourceMap::from_json(map).ok()?,
+        original_source: None,
+        inner_source_map: None,
+        remove_original_source: false,
+      })
+      .boxed()
+    } else {
+      RawStringSource::from(self.code.clone()).boxed()
+    };
+    Some(source)
+  }
+}
+
+fn minify_warnings(filename: &str, warnings: Vec<String>) -> Vec<Diagnostic> {
+  warnings
+    .into_iter()
+    .map(|warning| {
+      Diagnostic::warn("SwcJsMinimizerRspackPlugin".to_string(), warning)
+        .with_file(Some(filename.into()))
+    })
+    .collect()
+}
+
 #[plugin]
 #[derive(Debug)]
 pub struct SwcJsMinimizerRspackPlugin {
   options: PluginOptions,
+  cache: ScopeCache,
 }
 
 impl SwcJsMinimizerRspackPlugin {
   pub fn new(options: PluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, ScopeCache::new(CACHE_SCOPE))
+  }
+
+  /// Everything the minified asset depends on: the options, the input, how the
+  /// input is parsed and the names of the mangled properties it uses.
+  fn cache_key(
+    &self,
+    filename: &str,
+    is_module: Option<bool>,
+    input: &str,
+    input_source_map: Option<&SourceMap>,
+    property_names: Option<&BTreeMap<&str, &str>>,
+  ) -> Vec<u8> {
+    let mut hasher = RspackHash::new(&HashFunction::MD4);
+    self.options.hash(&mut hasher);
+    filename.hash(&mut hasher);
+    is_module.hash(&mut hasher);
+    input.hash(&mut hasher);
+    input_source_map.hash(&mut hasher);
+    property_names.hash(&mut hasher);
+    hasher
+      .digest(&HashDigest::Hex)
+      .encoded()
+      .as_bytes()
+      .to_vec()
+  }
+
+  /// Assigns the names of mangled properties across all assets to minify, and
+  /// saves them to the name cache.
+  async fn mangle_properties(
+    &self,
+    compilation: &Compilation,
+  ) -> Result<Option<BTreeMap<String, String>>> {
+    let Some(options) = &self.options.minimizer_options.mangle_properties else {
+      return Ok(None);
+    };
+    let name_cache = options
+      .name_cache
+      .as_ref()
+      .map(|name_cache| compilation.options.context.as_path().join(name_cache));
+    let existing = match &name_cache {
+      Some(name_cache) => compilation.input_filesystem.read(name_cache).await.ok(),
+      None => None,
+    };
+    let previous = existing
+      .as_deref()
+      .and_then(|existing| serde_json::from_slice(existing).ok())
+      .unwrap_or_default();
+
+    let sources = compilation
+      .assets()
+      .iter()
+      .filter(|(filename, asset)| is_minimize_target(&self.options, filename, asset))
+      .filter_map(|(_, asset)| asset.get_source())
+      .map(|source| source.source())
+      .collect::<Vec<_>>();
+    let names = assign_property_names(options, sources.iter().map(|source| &**source), previous);
+
+    if let Some(name_cache) = name_cache {
+      let content =
+        serde_json::to_string_pretty(&names).expect("should serialize name cache") + "\n";
+      if existing.as_deref() != Some(content.as_bytes()) {
+        let intermediate_filesystem = compilation.intermediate_filesystem.as_ref();
+        if let Some(dir) = name_cache.parent() {
+          intermediate_filesystem.create_dir_all(dir).await?;
+        }
+        intermediate_filesystem
+          .write(&name_cache, content.as_bytes())
+          .await?;
+      }
+    }
+    Ok(Some(names))
   }
 }
 
@@ -155,6 +286,37 @@ async fn js_chunk_hash(
   Ok(())
 }
 
+// The size report attributes the minified code to modules with the source maps
+// of the assets, so modules get a source map even without `devtool`. Modules
+// with the source map of `devtool` are left as they are.
+#[plugin_hook(CompilationBuildModule for SwcJsMinimizerRspackPlugin)]
+async fn build_module(
+  &self,
+  _compiler_id: CompilerId,
+  _compilation_id: CompilationId,
+  module: &mut BoxModule,
+) -> Result<()> {
+  if module.get_source_map_kind().is_empty() {
+    module.set_source_map_kind(SourceMapKind::SimpleSourceMap);
+  }
+  Ok(())
+}
+
+#[plugin_hook(CompilationRuntimeModule for SwcJsMinimizerRspackPlugin)]
+async fn runtime_module(
+  &self,
+  compilation: &mut Compilation,
+  module: &ModuleIdentifier,
+  _chunk: &ChunkUkey,
+) -> Result<()> {
+  if let Some(module) = compilation.runtime_modules.get_mut(module)
+    && module.get_source_map_kind().is_empty()
+  {
+    module.set_source_map_kind(SourceMapKind::SimpleSourceMap);
+  }
+  Ok(())
+}
+
 #[plugin_hook(CompilationProcessAssets for SwcJsMinimizerRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_SIZE)]
 async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   let options = &self.options;
@@ -163,6 +325,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   let (tx, rx) = mpsc::channel::<Vec<Diagnostic>>();
   // collect all extracted comments info
   let all_extracted_comments = Mutex::new(HashMap::new());
+  let all_sizes = Mutex::new(HashMap::new());
   let extract_comments_condition = options
     .extract_comments
     .as_ref()
@@ -171,24 +334,24 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       Regex::new(condition)
         .unwrap_or_else(|_| panic!("`{condition}` is invalid extractComments condition"))
     });
+  let storage = compilation.cache.storage();
+  if let Some(storage) = &storage {
+    self.cache.load(storage).await?;
+  }
+  let property_names = self.mangle_properties(compilation).await?;
+  let renamed_properties = property_names.as_ref().map(|names| {
+    Arc::new(
+      names
+        .iter()
+        .map(|(property, name)| (Atom::from(property.as_str()), Atom::from(name.as_str())))
+        .collect::<FxHashMap<_, _>>(),
+    )
+  });
   let enter_span = tracing::Span::current();
   compilation
     .assets_mut()
     .par_iter_mut()
-    .filter(|(filename, original)| {
-      // propagate span in rayon to keep parent relation
-      if !JAVASCRIPT_ASSET_REGEXP.is_match(filename) {
-        return false
-      }
-
-      let is_matched = match_object(options, filename);
-
-      if !is_matched || original.get_info().minimized.unwrap_or(false) {
-        return false
-      }
-
-      true
-    })
+    .filter(|(filename, original)| is_minimize_target(options, filename, original))
     .try_for_each_with(tx,|tx, (filename, original)| -> Result<()>  {
       let _guard = enter_span.enter();
       let filename = filename.split('?').next().expect("Should have filename");
@@ -208,6 +371,62 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           None
         };
 
+        let cache_key = storage
+          .as_ref()
+          .map(|_| {
+            self.cache_key(
+              filename,
+              is_module,
+              &input,
+              input_source_map.as_ref(),
+              property_names
+                .as_ref()
+                .map(|names| used_property_names(names, &input))
+                .as_ref(),
+            )
+          });
+        if let Some(cache_key) = &cache_key
+          && let Some(cached) = self.cache.get(cache_key)
+          && let Ok(cached) = from_bytes::<CachedAsset, ()>(&cached, &())
+          && let Some(source) = cached.to_source(filename)
+        {
+          if let Some(comments) = cached.extracted_comments {
+            all_extracted_comments
+              .lock()
+              .expect("all_extract_comments lock failed")
+              .insert(
+                filename.to_string(),
+                ExtractedCommentsInfo {
+                  source: RawStringSource::from(comments).boxed(),
+                  comments_file_name: format!("{filename}.LICENSE.txt"),
+                },
+              );
+          }
+          if let Some(sizes) = cached.sizes {
+            all_sizes
+              .lock()
+              .expect("all_sizes lock failed")
+              .insert(filename.to_string(), sizes);
+          }
+          if !cached.warnings.is_empty() {
+            tx.send(minify_warnings(filename, cached.warnings)).into_diagnostic()?;
+          }
+          original.set_source(Some(source));
+          original.get_info_mut().minimized.replace(true);
+          return Ok(());
+        }
+
+        // The sources of the input are only known from its source map. Modules
+        // have one with `sizeReport` even without `devtool`, but assets added
+        // by other plugins may not, which are reported without modules.
+        let original_sizes = options.size_report.as_ref().map(|_| {
+          let sizes = input_source_map
+            .as_ref()
+            .map(|map| attribute_sizes(&input, map).0)
+            .unwrap_or_default();
+          (input.len() as u64, sizes)
+        });
+
         let js_minify_options = rspack_javascript_compiler::minify::JsMinifyOptions {
           minify: minimizer_options.minify.unwrap_or(true),
           compress: minimizer_options.compress.clone(),
@@ -216,6 +435,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           source_map: BoolOrDataConfig::from_bool(input_source_map.is_some()),
           inline_sources_content: true, /* Using true so original_source can be None in SourceMapSource */
           module: is_module,
+          property_names: renamed_properties.clone(),
           ..Default::default()
           };
         let extract_comments_option = options.extract_comments.as_ref().map(|extract_comments| {
@@ -313,6 +533,9 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
             },
         };
 
+        if !output.diagnostics.is_empty() {
+          tx.send(minify_warnings(filename, output.diagnostics.clone())).into_diagnostic()?;
+        }
         let source = if let Some(source_map) = output.map {
           SourceMapSource::new(SourceMapSourceOptions {
             value: output.code,
@@ -340,6 +563,50 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         } else {
           source
         };
+        let final_source_map = if storage.is_some() || original_sizes.is_some() {
+          source.map(&MapOptions::default())
+        } else {
+          None
+        };
+        let sizes = if let Some((original, original_sizes)) = original_sizes {
+          let code = source.source();
+          let minimized_sizes = final_source_map
+            .as_ref()
+            .map(|map| attribute_sizes(&code, map).0)
+            .unwrap_or_default();
+          let sizes = AssetSizes {
+            original,
+            minimized: code.len() as u64,
+            sources: join_sizes(original_sizes, minimized_sizes),
+          };
+          all_sizes
+            .lock()
+            .expect("all_sizes lock failed")
+            .insert(filename.to_string(), sizes.clone());
+          Some(sizes)
+        } else {
+          None
+        };
+        if let Some(storage) = &storage
+          && let Some(cache_key) = cache_key
+        {
+          let cached = CachedAsset {
+            code: source.source().into_owned(),
+            map: final_source_map.and_then(|map| map.to_json().ok()),
+            extracted_comments: all_extracted_comments
+              .lock()
+              .expect("all_extract_comments lock failed")
+              .get(filename)
+              .map(|comments| comments.source.source().into_owned()),
+            sizes,
+            warnings: output.diagnostics,
+          };
+          self.cache.set(
+            storage,
+            cache_key,
+            to_bytes(&cached, &()).expect("should to bytes success"),
+          );
+        }
         original.set_source(Some(source));
         original.get_info_mut().minimized.replace(true);
       }
@@ -347,6 +614,26 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       Ok(())
   })?;
   compilation.extend_diagnostics(rx.into_iter().flatten().collect::<Vec<_>>());
+  if let Some(storage) = &storage {
+    self.cache.clean(storage);
+  }
+  if let Some(size_report) = &options.size_report {
+    let all_sizes = all_sizes.into_inner().expect("all_sizes lock failed");
+    let (content, module_sizes) = build_size_report(compilation, all_sizes);
+    for (identifier, size) in module_sizes {
+      *compilation
+        .minimized_module_sizes
+        .entry(identifier)
+        .or_default() += size as f64;
+    }
+    compilation.emit_asset(
+      size_report.clone(),
+      CompilationAsset::new(
+        Some(RawStringSource::from(content).boxed()),
+        AssetInfo::default(),
+      ),
+    );
+  }
 
   // write all extracted comments to assets
   all_extracted_comments
@@ -370,6 +657,83 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   Ok(())
 }
 
+/// Attributes the sizes of the sources of minimized assets to modules.
+///
+/// Returns the report, and the minified bytes of each module to show in stats.
+fn build_size_report(
+  compilation: &Compilation,
+  all_sizes: HashMap<String, AssetSizes>,
+) -> (String, Vec<(ModuleIdentifier, u64)>) {
+  let context = &compilation.options.context;
+  let module_graph = compilation.get_module_graph();
+  let mut module_sizes = vec![];
+  let mut report = serde_json::Map::new();
+  for (asset, sizes) in all_sizes.into_iter().collect::<BTreeMap<_, _>>() {
+    let mut modules = sizes
+      .sources
+      .iter()
+      .map(|source| {
+        // Before `SourceMapDevToolPlugin`, sources are named after the module
+        // identifiers.
+        let identifier = ModuleIdentifier::from(
+          match source.source.strip_prefix("webpack://") {
+            Some(stripped) => make_paths_absolute(context.as_str(), stripped),
+            None => source.source.clone(),
+          }
+          .as_str(),
+        );
+        let name = if let Some(module) = module_graph.module_by_identifier(&identifier) {
+          module_sizes.push((module.identifier(), source.minimized));
+          module.readable_identifier(context).into_owned()
+        } else if compilation.runtime_modules.contains_key(&identifier) {
+          module_sizes.push((identifier, source.minimized));
+          identifier.to_string()
+        } else {
+          source.source.clone()
+        };
+        (name, source)
+      })
+      .collect::<Vec<_>>();
+    modules.sort_by(|(a_name, a), (b_name, b)| {
+      b.minimized
+        .cmp(&a.minimized)
+        .then_with(|| a_name.cmp(b_name))
+    });
+    let attributed = sizes
+      .sources
+      .iter()
+      .map(|source| source.minimized)
+      .sum::<u64>();
+    report.insert(
+      asset,
+      json!({
+        "originalSize": sizes.original,
+        "minimizedSize": sizes.minimized,
+        "savedSize": sizes.original.saturating_sub(sizes.minimized),
+        "unattributedSize": sizes.minimized.saturating_sub(attributed),
+        "modules": modules
+          .into_iter()
+          .map(|(name, source)| json!({
+            "name": name,
+            "originalSize": source.original,
+            "minimizedSize": source.minimized,
+            "savedSize": source.original.saturating_sub(source.minimized),
+          }))
+          .collect::<Vec<_>>(),
+      }),
+    );
+  }
+
+  let content = serde_json::to_string_pretty(&report).expect("should serialize size report");
+  (content, module_sizes)
+}
+
+fn is_minimize_target(options: &PluginOptions, filename: &str, asset: &CompilationAsset) -> bool {
+  JAVASCRIPT_ASSET_REGEXP.is_match(filename)
+    && match_object(options, filename)
+    && !asset.get_info().minimized.unwrap_or(false)
+}
+
 pub fn match_object(obj: &PluginOptions, str: &str) -> bool {
   if let Some(condition) = &obj.test {
     if !condition.try_match(str) {
@@ -409,6 +773,18 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
       .compilation_hooks
       .process_assets
       .tap(process_assets::new(self));
+    if self.options.size_report.is_some() {
+      ctx
+        .context
+        .compilation_hooks
+        .build_module
+        .tap(build_module::new(self));
+      ctx
+        .context
+        .compilation_hooks
+        .runtime_module
+        .tap(runtime_module::new(self));
+    }
     Ok(())
   }
 }
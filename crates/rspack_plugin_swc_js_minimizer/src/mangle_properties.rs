@@ -0,0 +1,435 @@
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  sync::LazyLock,
+};
+
+use regex::Regex;
+use rspack_regex::RspackRegex;
+
+static IDENTIFIER_REGEXP: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"[A-Za-z_$][\w$]*").expect("Invalid RegExp"));
+
+/// Reserved words are valid property names since ES5, but are kept out of the
+/// generated names for older parsers.
+const RESERVED_WORDS: &[&str] = &[
+  "do", "if", "in", "for", "let", "new", "try", "var", "case", "else", "enum", "eval", "null",
+  "this", "true", "void", "with",
+];
+
+/// Properties the runtime and the engine rely on, which are never renamed even
+/// if they match the regex.
+const ALWAYS_RESERVED: &[&str] = &["__proto__", "__esModule", "constructor", "prototype"];
+
+/// Properties of builtin objects and common DOM APIs, like terser's `domprops`.
+/// Renaming them would break code that reads them from objects the bundle
+/// doesn't create.
+const BUILTIN_PROPERTIES: &[&str] = &[
+  // Object
+  "assign",
+  "create",
+  "defineProperties",
+  "defineProperty",
+  "entries",
+  "freeze",
+  "fromEntries",
+  "getOwnPropertyDescriptor",
+  "getOwnPropertyDescriptors",
+  "getOwnPropertyNames",
+  "getOwnPropertySymbols",
+  "getPrototypeOf",
+  "hasOwn",
+  "hasOwnProperty",
+  "is",
+  "isExtensible",
+  "isFrozen",
+  "isPrototypeOf",
+  "isSealed",
+  "keys",
+  "preventExtensions",
+  "propertyIsEnumerable",
+  "seal",
+  "setPrototypeOf",
+  "toLocaleString",
+  "toString",
+  "valueOf",
+  "values",
+  "__defineGetter__",
+  "__defineSetter__",
+  "__lookupGetter__",
+  "__lookupSetter__",
+  // property descriptors
+  "configurable",
+  "enumerable",
+  "get",
+  "set",
+  "value",
+  "writable",
+  // Function
+  "apply",
+  "arguments",
+  "bind",
+  "call",
+  "caller",
+  "length",
+  "name",
+  // Array and iterators
+  "at",
+  "concat",
+  "copyWithin",
+  "every",
+  "fill",
+  "filter",
+  "find",
+  "findIndex",
+  "findLast",
+  "findLastIndex",
+  "flat",
+  "flatMap",
+  "forEach",
+  "from",
+  "includes",
+  "indexOf",
+  "isArray",
+  "join",
+  "lastIndexOf",
+  "map",
+  "next",
+  "of",
+  "pop",
+  "push",
+  "reduce",
+  "reduceRight",
+  "return",
+  "reverse",
+  "shift",
+  "slice",
+  "some",
+  "sort",
+  "splice",
+  "throw",
+  "toReversed",
+  "toSorted",
+  "toSpliced",
+  "unshift",
+  "with",
+  "done",
+  // String and RegExp
+  "charAt",
+  "charCodeAt",
+  "codePointAt",
+  "endsWith",
+  "exec",
+  "flags",
+  "fromCharCode",
+  "fromCodePoint",
+  "global",
+  "index",
+  "input",
+  "lastIndex",
+  "localeCompare",
+  "match",
+  "matchAll",
+  "normalize",
+  "padEnd",
+  "padStart",
+  "raw",
+  "repeat",
+  "replace",
+  "replaceAll",
+  "search",
+  "source",
+  "split",
+  "startsWith",
+  "substr",
+  "substring",
+  "test",
+  "toLowerCase",
+  "toUpperCase",
+  "trim",
+  "trimEnd",
+  "trimStart",
+  "groups",
+  // Number and Math
+  "abs",
+  "ceil",
+  "floor",
+  "isFinite",
+  "isInteger",
+  "isNaN",
+  "max",
+  "min",
+  "parseFloat",
+  "parseInt",
+  "pow",
+  "random",
+  "round",
+  "sign",
+  "sqrt",
+  "toFixed",
+  "toPrecision",
+  "trunc",
+  // Map, Set and WeakRef
+  "add",
+  "clear",
+  "delete",
+  "deref",
+  "has",
+  "size",
+  // Promise
+  "all",
+  "allSettled",
+  "any",
+  "catch",
+  "finally",
+  "race",
+  "reject",
+  "resolve",
+  "then",
+  // Error
+  "cause",
+  "message",
+  "stack",
+  // JSON and Reflect
+  "construct",
+  "deleteProperty",
+  "ownKeys",
+  "parse",
+  "stringify",
+  "toJSON",
+  // module and import interop
+  "default",
+  "exports",
+  "meta",
+  "require",
+  "url",
+  // globals and DOM
+  "addEventListener",
+  "appendChild",
+  "body",
+  "childNodes",
+  "children",
+  "classList",
+  "className",
+  "createElement",
+  "createTextNode",
+  "currentScript",
+  "dataset",
+  "dispatchEvent",
+  "document",
+  "documentElement",
+  "getAttribute",
+  "getElementById",
+  "getElementsByTagName",
+  "head",
+  "href",
+  "innerHTML",
+  "insertBefore",
+  "location",
+  "nodeType",
+  "onerror",
+  "onload",
+  "parentNode",
+  "preventDefault",
+  "querySelector",
+  "querySelectorAll",
+  "removeAttribute",
+  "removeChild",
+  "removeEventListener",
+  "setAttribute",
+  "setTimeout",
+  "clearTimeout",
+  "src",
+  "style",
+  "target",
+  "textContent",
+  "type",
+  "window",
+];
+
+const FIRST_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_";
+const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_0123456789";
+
+#[derive(Debug, Hash)]
+pub struct ManglePropertiesOptions {
+  /// Only properties matching this regex are renamed.
+  pub regex: RspackRegex,
+  /// Properties that are never renamed, even if they match `regex`, in
+  /// addition to the builtin properties that are always reserved.
+  pub reserved: Vec<String>,
+  /// Path of the JSON file that names are read from and saved to, so names stay
+  /// stable across builds.
+  pub name_cache: Option<String>,
+}
+
+/// The `n`th generated name: `a`, `b`, ..., `_`, `aa`, `ab`, ...
+fn nth_name(mut n: usize) -> String {
+  let mut name = String::new();
+  name.push(FIRST_CHARS[n % FIRST_CHARS.len()] as char);
+  n /= FIRST_CHARS.len();
+  while n > 0 {
+    n -= 1;
+    name.push(CHARS[n % CHARS.len()] as char);
+    n /= CHARS.len();
+  }
+  name
+}
+
+/// Assigns the new names of properties for the whole compilation.
+///
+/// The sources aren't parsed here: every identifier-like word is scanned, words
+/// matching the regex are candidates to rename, and the other words are
+/// reserved so a generated name never collides with a property that is kept.
+/// Names of `previous` are reused unless they collide, and are kept even if the
+/// property is gone, so they aren't assigned to another property later.
+pub fn assign_property_names<'a>(
+  options: &ManglePropertiesOptions,
+  sources: impl Iterator<Item = &'a str>,
+  previous: BTreeMap<String, String>,
+) -> BTreeMap<String, String> {
+  let words = sources
+    .flat_map(|source| IDENTIFIER_REGEXP.find_iter(source))
+    .map(|word| word.as_str())
+    .collect::<BTreeSet<_>>();
+  let mut candidates = BTreeSet::new();
+  let mut reserved = options
+    .reserved
+    .iter()
+    .map(String::as_str)
+    .chain(ALWAYS_RESERVED.iter().copied())
+    .chain(BUILTIN_PROPERTIES.iter().copied())
+    .map(str::to_string)
+    .collect::<BTreeSet<_>>();
+  for word in words {
+    if options.regex.test(word) {
+      candidates.insert(word);
+    } else {
+      reserved.insert(word.to_string());
+    }
+  }
+
+  let mut names = BTreeMap::new();
+  let mut used = BTreeSet::new();
+  for (property, name) in previous {
+    if reserved.contains(&property) || reserved.contains(&name) || used.contains(&name) {
+      continue;
+    }
+    used.insert(name.clone());
+    names.insert(property, name);
+  }
+
+  let mut next = 0;
+  for candidate in candidates {
+    if reserved.contains(candidate) || names.contains_key(candidate) {
+      continue;
+    }
+    let name = loop {
+      let name = nth_name(next);
+      next += 1;
+      if !reserved.contains(&name) && !used.contains(&name) && !RESERVED_WORDS.contains(&&*name) {
+        break name;
+      }
+    };
+    used.insert(name.clone());
+    names.insert(candidate.to_string(), name);
+  }
+  names
+}
+
+/// The assigned names of the properties `source` may use. Only these affect
+/// how `source` is minified, so the names of other assets don't invalidate it.
+pub fn used_property_names<'a>(
+  names: &'a BTreeMap<String, String>,
+  source: &str,
+) -> BTreeMap<&'a str, &'a str> {
+  IDENTIFIER_REGEXP
+    .find_iter(source)
+    .filter_map(|word| names.get_key_value(word.as_str()))
+    .map(|(property, name)| (property.as_str(), name.as_str()))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn options(regex: &str) -> ManglePropertiesOptions {
+    ManglePropertiesOptions {
+      regex: RspackRegex::new(regex).expect("should be a valid regex"),
+      reserved: vec![],
+      name_cache: None,
+    }
+  }
+
+  #[test]
+  fn should_generate_names() {
+    assert_eq!(nth_name(0), "a");
+    assert_eq!(nth_name(53), "_");
+    assert_eq!(nth_name(54), "aa");
+    assert_eq!(nth_name(55), "ba");
+  }
+
+  #[test]
+  fn should_assign_names_across_sources() {
+    let names = assign_property_names(
+      &options("^_"),
+      ["a._foo = 1; b.a = 2;", "a._foo + a._bar"].into_iter(),
+      Default::default(),
+    );
+    assert_eq!(names.get("_bar").map(String::as_str), Some("c"));
+    assert_eq!(names.get("_foo").map(String::as_str), Some("d"));
+  }
+
+  #[test]
+  fn should_keep_previous_names() {
+    let previous = BTreeMap::from([
+      ("_foo".to_string(), "x".to_string()),
+      ("_gone".to_string(), "y".to_string()),
+      ("_taken".to_string(), "a".to_string()),
+    ]);
+    let names = assign_property_names(
+      &options("^_"),
+      ["o._foo; o._taken; o._new; o.a"].into_iter(),
+      previous,
+    );
+    assert_eq!(names.get("_foo").map(String::as_str), Some("x"));
+    assert_eq!(names.get("_gone").map(String::as_str), Some("y"));
+    assert_eq!(names.get("_new").map(String::as_str), Some("b"));
+    assert_eq!(names.get("_taken").map(String::as_str), Some("c"));
+  }
+
+  #[test]
+  fn should_never_rename_builtin_properties() {
+    let names = assign_property_names(
+      &options("."),
+      ["exports.__esModule = true; C.prototype.constructor; o.__proto__; p.then; _foo"].into_iter(),
+      Default::default(),
+    );
+    for property in [
+      "__esModule",
+      "prototype",
+      "constructor",
+      "__proto__",
+      "then",
+      "exports",
+    ] {
+      assert!(
+        !names.contains_key(property),
+        "{property} should be reserved"
+      );
+    }
+    assert!(names.contains_key("_foo"));
+  }
+
+  #[test]
+  fn should_only_use_names_of_the_source() {
+    let names = BTreeMap::from([
+      ("_foo".to_string(), "a".to_string()),
+      ("_bar".to_string(), "b".to_string()),
+      ("_baz".to_string(), "c".to_string()),
+    ]);
+    assert_eq!(
+      used_property_names(&names, "o._foo; \"_baz\" in o"),
+      BTreeMap::from([("_baz", "c"), ("_foo", "a")])
+    );
+  }
+}
@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+
+use rspack_cacheable::cacheable;
+use rspack_core::rspack_sources::SourceMap;
+
+/// Bytes of an asset and of its sources before and after minification.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct AssetSizes {
+  pub original: u64,
+  pub minimized: u64,
+  pub sources: Vec<SourceSizes>,
+}
+
+/// Bytes of a source in an asset before and after minification.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct SourceSizes {
+  pub source: String,
+  pub original: u64,
+  pub minimized: u64,
+}
+
+/// Converts the UTF-16 columns of source maps to byte offsets of a line.
+struct LineCursor<'a> {
+  line: &'a str,
+  column: u32,
+  offset: usize,
+}
+
+impl<'a> LineCursor<'a> {
+  fn new(line: &'a str) -> Self {
+    Self {
+      line,
+      column: 0,
+      offset: 0,
+    }
+  }
+
+  /// Byte offset of `column`, which must not be before the previous column.
+  fn offset_of(&mut self, column: u32) -> usize {
+    while self.column < column {
+      let Some(char) = self.line[self.offset..].chars().next() else {
+        break;
+      };
+      self.column += char.len_utf16() as u32;
+      self.offset += char.len_utf8();
+    }
+    self.offset
+  }
+}
+
+/// Attributes the bytes of `code` to the sources of `map`.
+///
+/// A mapping owns the bytes up to the next mapping of the same line, or up to
+/// the end of the line. Bytes owned by a mapping without an original position,
+/// and bytes before the first mapping of a line, are unattributed.
+pub fn attribute_sizes(code: &str, map: &SourceMap) -> (BTreeMap<String, u64>, u64) {
+  let lines = code.split('\n').collect::<Vec<_>>();
+  let mut sizes = BTreeMap::<String, u64>::new();
+  let mut attributed = 0u64;
+  let mut attribute = |source_index: Option<u32>, size: usize| {
+    if let Some(source) = source_index.and_then(|index| map.get_source(index as usize)) {
+      *sizes.entry(source.to_string()).or_default() += size as u64;
+      attributed += size as u64;
+    }
+  };
+
+  let mut cursor: Option<(u32, LineCursor)> = None;
+  // The segment being measured on the line of `cursor`, as (start offset,
+  // source index).
+  let mut segment: Option<(usize, Option<u32>)> = None;
+  for mapping in map.decoded_mappings() {
+    let line = mapping.generated_line;
+    if cursor.as_ref().map(|(current_line, _)| *current_line) != Some(line) {
+      if let Some((_, cursor)) = &cursor
+        && let Some((start, source_index)) = segment.take()
+      {
+        attribute(source_index, cursor.line.len() - start);
+      }
+      segment = None;
+      cursor = (line as usize)
+        .checked_sub(1)
+        .and_then(|index| lines.get(index))
+        .map(|text| (line, LineCursor::new(text)));
+    }
+    let Some((_, line_cursor)) = &mut cursor else {
+      continue;
+    };
+    let offset = line_cursor.offset_of(mapping.generated_column);
+    if let Some((start, source_index)) = segment.take() {
+      attribute(source_index, offset - start);
+    }
+    segment = Some((
+      offset,
+      mapping.original.map(|original| original.source_index),
+    ));
+  }
+  if let Some((_, cursor)) = &cursor
+    && let Some((start, source_index)) = segment
+  {
+    attribute(source_index, cursor.line.len() - start);
+  }
+
+  (sizes, (code.len() as u64).saturating_sub(attributed))
+}
+
+/// Joins the sizes of the sources before and after minification.
+pub fn join_sizes(
+  original: BTreeMap<String, u64>,
+  minimized: BTreeMap<String, u64>,
+) -> Vec<SourceSizes> {
+  let mut sizes = BTreeMap::<String, SourceSizes>::new();
+  for (source, size) in original {
+    sizes
+      .entry(source.clone())
+      .or_insert_with(|| SourceSizes {
+        source,
+        original: 0,
+        minimized: 0,
+      })
+      .original += size;
+  }
+  for (source, size) in minimized {
+    sizes
+      .entry(source.clone())
+      .or_insert_with(|| SourceSizes {
+        source,
+        original: 0,
+        minimized: 0,
+      })
+      .minimized += size;
+  }
+  sizes.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn source_map(mappings: &str) -> SourceMap {
+    SourceMap::from_json(&format!(
+      r#"{{"version":3,"sources":["a.js","b.js"],"names":[],"mappings":"{mappings}"}}"#
+    ))
+    .expect("should be a valid source map")
+  }
+
+  #[test]
+  fn should_attribute_segments_to_sources() {
+    // `aaa` and `c` map to a.js, `bb` to b.js, `;` and the line break aren't
+    // mapped.
+    let (sizes, unattributed) = attribute_sizes("aaabb;\nc", &source_map("AAAA,GCAA,E;ADAA"));
+    assert_eq!(sizes.get("a.js"), Some(&4));
+    assert_eq!(sizes.get("b.js"), Some(&2));
+    assert_eq!(unattributed, 2);
+  }
+
+  #[test]
+  fn should_count_bytes_of_utf16_columns() {
+    // `é` is one UTF-16 unit but two bytes.
+    let (sizes, unattributed) = attribute_sizes("éa", &source_map("AAAA,CCAA"));
+    assert_eq!(sizes.get("a.js"), Some(&2));
+    assert_eq!(sizes.get("b.js"), Some(&1));
+    assert_eq!(unattributed, 0);
+  }
+}
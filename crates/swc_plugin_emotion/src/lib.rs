@@ -0,0 +1,372 @@
+use std::{hash::Hash, path::Path};
+
+use cow_utils::CowUtils;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use serde::{de::IgnoredAny, Deserialize};
+use sugar_path::SugarPath;
+use swc_core::{
+  common::DUMMY_SP,
+  ecma::{
+    ast::{
+      CallExpr, Callee, Expr, ExprOrSpread, IdentName, ImportSpecifier, KeyValueProp, Lit,
+      MemberExpr, MemberProp, Module, ModuleDecl, ModuleExportName, ModuleItem, ObjectLit, Pat,
+      Prop, PropName, PropOrSpread, Str, Tpl, VarDeclarator,
+    },
+    atoms::Atom,
+    visit::{visit_mut_pass, VisitMut, VisitMutPass, VisitMutWith},
+  },
+};
+
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum AutoLabel {
+  Never,
+  #[default]
+  DevOnly,
+  Always,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(try_from = "RawEmotionOptions")]
+pub struct EmotionOptions {
+  /// When to add the name of the variable to the class names.
+  pub auto_label: AutoLabel,
+  /// Format of the labels, with the `[local]`, `[filename]` and `[dirname]`
+  /// placeholders.
+  pub label_format: String,
+}
+
+/// Options of `@swc/plugin-emotion`, including the ones that aren't supported,
+/// so they are rejected instead of being ignored.
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct RawEmotionOptions {
+  auto_label: AutoLabel,
+  label_format: Option<String>,
+  source_map: bool,
+  css_prop_optimization: bool,
+  import_map: Option<IgnoredAny>,
+}
+
+impl TryFrom<RawEmotionOptions> for EmotionOptions {
+  type Error = String;
+
+  fn try_from(value: RawEmotionOptions) -> Result<Self, Self::Error> {
+    if value.source_map {
+      return Err("`sourceMap` of emotion is not supported".to_string());
+    }
+    if value.css_prop_optimization {
+      return Err("`cssPropOptimization` of emotion is not supported".to_string());
+    }
+    if value.import_map.is_some() {
+      return Err("`importMap` of emotion is not supported".to_string());
+    }
+    let default = Self::default();
+    Ok(Self {
+      auto_label: value.auto_label,
+      label_format: value.label_format.unwrap_or(default.label_format),
+    })
+  }
+}
+
+impl Default for EmotionOptions {
+  fn default() -> Self {
+    Self {
+      auto_label: Default::default(),
+      label_format: "[local]".to_string(),
+    }
+  }
+}
+
+/// Adds labels to the styles of `css` from `@emotion/react` and `@emotion/css`,
+/// and labels and component selector targets to the components of
+/// `@emotion/styled`. The styles of their tagged templates are minified.
+pub fn emotion<'a>(
+  options: &'a EmotionOptions,
+  file_path: &'a Path,
+  root_dir: &'a Path,
+  development: bool,
+) -> VisitMutPass<Emotion<'a>> {
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  file_path
+    .relative(root_dir)
+    .to_slash_lossy()
+    .hash(&mut hasher);
+  visit_mut_pass(Emotion {
+    options,
+    file_path,
+    file_hash: hasher.digest(&HashDigest::Hex).rendered(6).to_string(),
+    auto_label: match options.auto_label {
+      AutoLabel::Never => false,
+      AutoLabel::DevOnly => development,
+      AutoLabel::Always => true,
+    },
+    css: vec![],
+    styled: vec![],
+    local: None,
+    count: 0,
+  })
+}
+
+pub struct Emotion<'a> {
+  options: &'a EmotionOptions,
+  file_path: &'a Path,
+  file_hash: String,
+  auto_label: bool,
+  /// Local names of `css` imported from emotion.
+  css: Vec<Atom>,
+  /// Local names of `styled` imported from `@emotion/styled`.
+  styled: Vec<Atom>,
+  /// Name of the variable being declared.
+  local: Option<Atom>,
+  count: usize,
+}
+
+/// Removes the comments and the whitespace that doesn't matter from a quasi of
+/// a style template, leaving quoted strings untouched. The whitespace at the
+/// start of the `first` quasi and at the end of the `last` quasi is removed,
+/// the whitespace next to an interpolation is kept as a single space.
+fn minify_css(css: &str, first: bool, last: bool) -> String {
+  fn is_separator(c: char) -> bool {
+    matches!(c, ';' | '{' | '}' | ',')
+  }
+  // Whether the whitespace between `prev` and `next` matters, `None` being the
+  // start or the end of the quasi.
+  let keep_space = |prev: Option<char>, next: Option<char>| {
+    prev.map_or(!first, |prev| !is_separator(prev) && prev != ':')
+      && next.map_or(!last, |next| !is_separator(next))
+  };
+
+  let mut minified = String::with_capacity(css.len());
+  let mut chars = css.chars().peekable();
+  let mut quote = None;
+  let mut space = false;
+  while let Some(c) = chars.next() {
+    if let Some(q) = quote {
+      minified.push(c);
+      if c == q {
+        quote = None;
+      }
+      continue;
+    }
+    match c {
+      '/' if chars.peek() == Some(&'*') => {
+        chars.next();
+        let mut prev = None;
+        for c in chars.by_ref() {
+          if prev == Some('*') && c == '/' {
+            break;
+          }
+          prev = Some(c);
+        }
+        space = true;
+      }
+      c if c.is_whitespace() => space = true,
+      c => {
+        if space && keep_space(minified.chars().last(), Some(c)) {
+          minified.push(' ');
+        }
+        space = false;
+        if c == '"' || c == '\'' {
+          quote = Some(c);
+        }
+        minified.push(c);
+      }
+    }
+  }
+  if space && keep_space(minified.chars().last(), None) {
+    minified.push(' ');
+  }
+  minified
+}
+
+impl Emotion<'_> {
+  fn is_import(imports: &[Atom], expr: &Expr) -> bool {
+    matches!(expr, Expr::Ident(ident) if imports.contains(&ident.sym))
+  }
+
+  /// Whether `tag` is a component created by `styled`.
+  fn is_styled_tag(&self, tag: &Expr) -> bool {
+    matches!(tag, Expr::Call(CallExpr {
+      callee: Callee::Expr(callee),
+      ..
+    }) if Self::is_import(&self.styled, callee))
+  }
+
+  fn minify(tpl: &mut Tpl) {
+    // The cooked value of quasis with escapes differs from the raw one.
+    if tpl.quasis.iter().any(|quasi| quasi.raw.contains('\\')) {
+      return;
+    }
+    let len = tpl.quasis.len();
+    for (index, quasi) in tpl.quasis.iter_mut().enumerate() {
+      let minified: Atom = minify_css(&quasi.raw, index == 0, index + 1 == len).into();
+      quasi.cooked = Some(minified.clone());
+      quasi.raw = minified;
+    }
+  }
+
+  fn label(&self) -> Option<String> {
+    if !self.auto_label {
+      return None;
+    }
+    let local = self.local.as_ref()?;
+    let filename = self
+      .file_path
+      .file_stem()
+      .map(|stem| stem.to_string_lossy())
+      .unwrap_or_default();
+    let dirname = self
+      .file_path
+      .parent()
+      .and_then(|dir| dir.file_name())
+      .map(|dir| dir.to_string_lossy())
+      .unwrap_or_default();
+    let label = self
+      .options
+      .label_format
+      .cow_replace("[local]", local.as_str())
+      .cow_replace("[filename]", &filename)
+      .cow_replace("[dirname]", &dirname)
+      .into_owned();
+    Some(label)
+  }
+
+  /// Options of `styled`: the target used by component selectors, and the
+  /// label.
+  fn styled_options(&mut self) -> ExprOrSpread {
+    let mut props = vec![("target", format!("e{}{}", self.file_hash, self.count))];
+    self.count += 1;
+    if let Some(label) = self.label() {
+      props.push(("label", label));
+    }
+    let props = props
+      .into_iter()
+      .map(|(key, value)| {
+        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+          key: PropName::Ident(IdentName::new(key.into(), DUMMY_SP)),
+          value: Box::new(Expr::Lit(Lit::Str(Str::from(value)))),
+        })))
+      })
+      .collect();
+    ExprOrSpread {
+      spread: None,
+      expr: Box::new(Expr::Object(ObjectLit {
+        span: DUMMY_SP,
+        props,
+      })),
+    }
+  }
+}
+
+impl VisitMut for Emotion<'_> {
+  fn visit_mut_module(&mut self, module: &mut Module) {
+    for item in &module.body {
+      let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+        continue;
+      };
+      if import.type_only {
+        continue;
+      }
+      let source = import.src.value.as_str();
+      for specifier in &import.specifiers {
+        match specifier {
+          ImportSpecifier::Default(default) if source == "@emotion/styled" => {
+            self.styled.push(default.local.sym.clone())
+          }
+          ImportSpecifier::Named(named)
+            if source == "@emotion/react" || source == "@emotion/css" =>
+          {
+            let imported = match &named.imported {
+              Some(ModuleExportName::Ident(ident)) => &ident.sym,
+              Some(ModuleExportName::Str(str)) => &str.value,
+              None => &named.local.sym,
+            };
+            if imported == "css" {
+              self.css.push(named.local.sym.clone());
+            }
+          }
+          _ => {}
+        }
+      }
+    }
+    if !self.css.is_empty() || !self.styled.is_empty() {
+      module.visit_mut_children_with(self);
+    }
+  }
+
+  fn visit_mut_var_declarator(&mut self, declarator: &mut VarDeclarator) {
+    let Pat::Ident(name) = &declarator.name else {
+      declarator.visit_mut_children_with(self);
+      return;
+    };
+    let parent = self.local.replace(name.id.sym.clone());
+    declarator.visit_mut_children_with(self);
+    self.local = parent;
+  }
+
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    expr.visit_mut_children_with(self);
+    match expr {
+      // css`color: red;`
+      Expr::TaggedTpl(tagged_tpl) if Self::is_import(&self.css, &tagged_tpl.tag) => {
+        Self::minify(&mut tagged_tpl.tpl);
+        let Some(label) = self.label() else {
+          return;
+        };
+        if let Some(quasi) = tagged_tpl.tpl.quasis.last_mut() {
+          quasi.raw = format!("{};label:{label};", quasi.raw).into();
+          quasi.cooked = quasi
+            .cooked
+            .as_ref()
+            .map(|cooked| format!("{cooked};label:{label};").into());
+        }
+      }
+      // styled.div`color: red;`, which is `styled("div", { ... })` by now
+      Expr::TaggedTpl(tagged_tpl) if self.is_styled_tag(&tagged_tpl.tag) => {
+        Self::minify(&mut tagged_tpl.tpl);
+      }
+      Expr::Call(call) => {
+        let Callee::Expr(callee) = &mut call.callee else {
+          return;
+        };
+        // css({ color: 'red' })
+        if Self::is_import(&self.css, callee) {
+          if let Some(label) = self.label() {
+            call.args.push(ExprOrSpread {
+              spread: None,
+              expr: Box::new(Expr::Lit(Lit::Str(Str::from(format!("label:{label};"))))),
+            });
+          }
+        // styled(Button)
+        } else if Self::is_import(&self.styled, callee) && call.args.len() == 1 {
+          let options = self.styled_options();
+          call.args.push(options);
+        }
+      }
+      // styled.div
+      Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(tag),
+        ..
+      }) if Self::is_import(&self.styled, obj) => {
+        let tag = Str::from(tag.sym.clone());
+        let styled = obj.clone();
+        let options = self.styled_options();
+        *expr = Expr::Call(CallExpr {
+          span: DUMMY_SP,
+          callee: Callee::Expr(styled),
+          args: vec![
+            ExprOrSpread {
+              spread: None,
+              expr: Box::new(Expr::Lit(Lit::Str(tag))),
+            },
+            options,
+          ],
+          ..Default::default()
+        });
+      }
+      _ => {}
+    }
+  }
+}
@@ -0,0 +1,11 @@
+import { css } from "@emotion/react";
+import styled from "@emotion/styled";
+
+const base = css`
+  padding: 0 8px;
+`;
+
+const Link = styled.a`
+  ${base};
+  text-decoration: none;
+`;
@@ -0,0 +1,6 @@
+import { css } from "@emotion/react";
+import styled from "@emotion/styled";
+const base = css`padding:0 8px;`;
+const Link = styled("a", {
+    target: "e52f0860"
+})`${base};text-decoration:none;`;
@@ -0,0 +1,10 @@
+import { css as emotionCss } from "@emotion/css";
+
+const container = emotionCss({
+  display: "flex",
+});
+
+function render() {
+  const title = emotionCss({ fontSize: 20 }, container);
+  return title;
+}
@@ -0,0 +1,10 @@
+import { css as emotionCss } from "@emotion/css";
+const container = emotionCss({
+    display: "flex"
+}, "label:css-object-input--container;");
+function render() {
+    const title = emotionCss({
+        fontSize: 20
+    }, container, "label:css-object-input--title;");
+    return title;
+}
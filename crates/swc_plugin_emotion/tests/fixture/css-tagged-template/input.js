@@ -0,0 +1,14 @@
+import { css } from "@emotion/react";
+
+const primary = "red";
+
+export const button = css`
+  /* the primary color */
+  color: ${primary};
+  font-family: "Helvetica  Neue", sans-serif;
+  margin: ${0} ${1}px;
+
+  &:hover {
+    color: blue;
+  }
+`;
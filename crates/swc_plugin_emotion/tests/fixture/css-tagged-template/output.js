@@ -0,0 +1,3 @@
+import { css } from "@emotion/react";
+const primary = "red";
+export const button = css`color:${primary};font-family:"Helvetica  Neue",sans-serif;margin:${0} ${1}px;&:hover{color:blue;};label:button;`;
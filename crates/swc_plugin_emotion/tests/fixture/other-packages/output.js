@@ -0,0 +1,8 @@
+import { css } from "styled-components";
+import styled from "@emotion/react";
+const base = css`
+  padding: 0 8px;
+`;
+const Link = styled.a`
+  text-decoration: none;
+`;
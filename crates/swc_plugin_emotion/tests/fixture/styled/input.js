@@ -0,0 +1,23 @@
+import styled from "@emotion/styled";
+
+function Button(props) {
+  return <button {...props} />;
+}
+
+const Container = styled.div`
+  display: flex;
+  ${(props) => props.inline && "display: inline-flex;"}
+`;
+
+const PrimaryButton = styled(Button)`
+  color: white;
+`;
+
+const Label = styled.span({ color: "gray" });
+
+export const App = () => (
+  <Container>
+    <PrimaryButton />
+    <Label />
+  </Container>
+);
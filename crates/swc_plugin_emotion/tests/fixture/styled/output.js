@@ -0,0 +1,22 @@
+import styled from "@emotion/styled";
+function Button(props) {
+    return <button {...props}/>;
+}
+const Container = styled("div", {
+    target: "e3467e70",
+    label: "Container"
+})`display:flex;${(props)=>props.inline && "display: inline-flex;"}`;
+const PrimaryButton = styled(Button, {
+    target: "e3467e71",
+    label: "PrimaryButton"
+})`color:white;`;
+const Label = styled("span", {
+    target: "e3467e72",
+    label: "Label"
+})({
+    color: "gray"
+});
+export const App = ()=>(<Container>
+    <PrimaryButton/>
+    <Label/>
+  </Container>);
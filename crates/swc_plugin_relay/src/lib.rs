@@ -0,0 +1,221 @@
+use std::{
+  collections::BTreeMap,
+  path::{Path, PathBuf},
+  sync::LazyLock,
+};
+
+use regex::Regex;
+use serde::Deserialize;
+use sugar_path::SugarPath;
+use swc_core::{
+  common::{errors::HANDLER, DUMMY_SP},
+  ecma::{
+    ast::{
+      CallExpr, Callee, Expr, ExprOrSpread, Ident, ImportDecl, ImportDefaultSpecifier,
+      ImportSpecifier, Lit, Module, ModuleDecl, ModuleItem, Str, TaggedTpl,
+    },
+    atoms::Atom,
+    visit::{visit_mut_pass, VisitMut, VisitMutPass, VisitMutWith},
+  },
+};
+
+static DEFINITION_NAME_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"\b(?:fragment|query|mutation|subscription)\s+([A-Za-z_][A-Za-z0-9_]*)")
+    .expect("Invalid RegExp")
+});
+
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RelayLanguage {
+  #[default]
+  JavaScript,
+  TypeScript,
+  Flow,
+}
+
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RelayOptions {
+  /// Directory of all artifacts, relative to the root directory. Artifacts
+  /// are in the `__generated__` directory next to each file by default.
+  pub artifact_directory: Option<PathBuf>,
+  pub language: RelayLanguage,
+  /// Import the artifacts with `import` declarations instead of `require`.
+  pub eager_es_modules: bool,
+}
+
+/// Replaces the ``graphql`...` `` tagged templates with the artifacts that
+/// the relay compiler generated for them.
+pub fn relay<'a>(
+  options: &'a RelayOptions,
+  file_path: &'a Path,
+  root_dir: &'a Path,
+) -> VisitMutPass<Relay<'a>> {
+  visit_mut_pass(Relay {
+    options,
+    file_path,
+    root_dir,
+    imports: Default::default(),
+  })
+}
+
+pub struct Relay<'a> {
+  options: &'a RelayOptions,
+  file_path: &'a Path,
+  root_dir: &'a Path,
+  /// Local names of the artifacts imported by `import` declarations, by
+  /// their requests.
+  imports: BTreeMap<String, Atom>,
+}
+
+impl Relay<'_> {
+  fn artifact_request(&self, name: &str) -> String {
+    let extension = match self.options.language {
+      RelayLanguage::TypeScript => "ts",
+      RelayLanguage::JavaScript | RelayLanguage::Flow => "js",
+    };
+    let filename = format!("{name}.graphql.{extension}");
+    let Some(artifact_directory) = &self.options.artifact_directory else {
+      return format!("./__generated__/{filename}");
+    };
+    let artifact = self.root_dir.join(artifact_directory).join(filename);
+    let context = self.file_path.parent().unwrap_or(self.root_dir);
+    let request = artifact.relative(context);
+    let request = request.to_slash_lossy();
+    if request.starts_with("../") {
+      request.into_owned()
+    } else {
+      format!("./{request}")
+    }
+  }
+
+  fn artifact(&mut self, tagged_tpl: &TaggedTpl) -> Option<Expr> {
+    let Expr::Ident(tag) = &*tagged_tpl.tag else {
+      return None;
+    };
+    if tag.sym != "graphql" {
+      return None;
+    }
+
+    let text = tagged_tpl
+      .tpl
+      .quasis
+      .iter()
+      .map(|quasi| quasi.raw.as_str())
+      .collect::<String>();
+    let name = if tagged_tpl.tpl.exprs.is_empty() {
+      DEFINITION_NAME_REGEXP
+        .captures(&text)
+        .and_then(|captures| captures.get(1))
+    } else {
+      None
+    };
+    let Some(name) = name else {
+      HANDLER.with(|handler| {
+        handler
+          .struct_span_err(
+            tagged_tpl.span,
+            "graphql tagged templates must contain a named operation or fragment without \
+             substitutions",
+          )
+          .emit()
+      });
+      return None;
+    };
+    let request = self.artifact_request(name.as_str());
+
+    if self.options.eager_es_modules {
+      let local = self
+        .imports
+        .entry(request)
+        .or_insert_with(|| format!("__{}", name.as_str()).into());
+      return Some(Expr::Ident(Ident::new_no_ctxt(local.clone(), DUMMY_SP)));
+    }
+    Some(Expr::Call(CallExpr {
+      span: tagged_tpl.span,
+      callee: Callee::Expr(Box::new(Expr::Ident(Ident::new_no_ctxt(
+        "require".into(),
+        DUMMY_SP,
+      )))),
+      args: vec![ExprOrSpread {
+        spread: None,
+        expr: Box::new(Expr::Lit(Lit::Str(Str::from(request)))),
+      }],
+      ..Default::default()
+    }))
+  }
+}
+
+impl VisitMut for Relay<'_> {
+  fn visit_mut_module(&mut self, module: &mut Module) {
+    module.visit_mut_children_with(self);
+    let imports = std::mem::take(&mut self.imports);
+    module.body.splice(
+      0..0,
+      imports.into_iter().map(|(request, local)| {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+          span: DUMMY_SP,
+          specifiers: vec![ImportSpecifier::Default(ImportDefaultSpecifier {
+            span: DUMMY_SP,
+            local: Ident::new_no_ctxt(local, DUMMY_SP),
+          })],
+          src: Box::new(Str::from(request)),
+          type_only: false,
+          with: None,
+          phase: Default::default(),
+        }))
+      }),
+    );
+  }
+
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if let Expr::TaggedTpl(tagged_tpl) = expr {
+      if let Some(artifact) = self.artifact(tagged_tpl) {
+        *expr = artifact;
+        return;
+      }
+    }
+    expr.visit_mut_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn request(options: &RelayOptions, file_path: &str) -> String {
+    let relay = Relay {
+      options,
+      file_path: Path::new(file_path),
+      root_dir: Path::new("/project"),
+      imports: Default::default(),
+    };
+    relay.artifact_request("AppQuery")
+  }
+
+  #[test]
+  fn should_request_generated_artifacts_next_to_the_file() {
+    let options = RelayOptions::default();
+    assert_eq!(
+      request(&options, "/project/src/App.js"),
+      "./__generated__/AppQuery.graphql.js"
+    );
+  }
+
+  #[test]
+  fn should_request_artifacts_in_artifact_directory() {
+    let options = RelayOptions {
+      artifact_directory: Some("src/__generated__".into()),
+      language: RelayLanguage::TypeScript,
+      eager_es_modules: false,
+    };
+    assert_eq!(
+      request(&options, "/project/src/pages/App.tsx"),
+      "../__generated__/AppQuery.graphql.ts"
+    );
+    assert_eq!(
+      request(&options, "/project/src/App.tsx"),
+      "./__generated__/AppQuery.graphql.ts"
+    );
+  }
+}
@@ -0,0 +1,167 @@
+use serde::Deserialize;
+use swc_core::{
+  common::DUMMY_SP,
+  ecma::{
+    ast::{
+      BindingIdent, CallExpr, Callee, ClassDecl, ClassExpr, EmptyStmt, Expr, ExprStmt, FnDecl,
+      FnExpr, Ident, ImportDefaultSpecifier, ImportNamedSpecifier, ImportStarAsSpecifier, Lit,
+      MemberExpr, MemberProp, Module, ModuleItem, Script, Stmt,
+    },
+    visit::{visit_mut_pass, Visit, VisitMut, VisitMutPass, VisitMutWith, VisitWith},
+  },
+};
+
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RemoveConsoleOptions {
+  /// Methods of `console` whose calls are kept, such as `error`.
+  pub exclude: Vec<String>,
+}
+
+/// Removes the calls of the global `console`.
+///
+/// A file that declares its own `console` is left untouched, as its calls
+/// may not refer to the global one.
+pub fn remove_console(options: &RemoveConsoleOptions) -> VisitMutPass<RemoveConsole<'_>> {
+  visit_mut_pass(RemoveConsole { options })
+}
+
+pub struct RemoveConsole<'a> {
+  options: &'a RemoveConsoleOptions,
+}
+
+impl RemoveConsole<'_> {
+  fn is_console_call(&self, expr: &Expr) -> bool {
+    let Expr::Call(CallExpr {
+      callee: Callee::Expr(callee),
+      ..
+    }) = expr
+    else {
+      return false;
+    };
+    let Expr::Member(MemberExpr { obj, prop, .. }) = &**callee else {
+      return false;
+    };
+    if !matches!(&**obj, Expr::Ident(Ident { sym, .. }) if sym == "console") {
+      return false;
+    }
+    let method = match prop {
+      MemberProp::Ident(ident) => &ident.sym,
+      MemberProp::Computed(computed) => match &*computed.expr {
+        Expr::Lit(Lit::Str(str)) => &str.value,
+        _ => return false,
+      },
+      MemberProp::PrivateName(_) => return false,
+    };
+    !self
+      .options
+      .exclude
+      .iter()
+      .any(|exclude| method == exclude.as_str())
+  }
+
+  fn is_console_stmt(&self, stmt: &Stmt) -> bool {
+    matches!(stmt, Stmt::Expr(ExprStmt { expr, .. }) if self.is_console_call(expr))
+  }
+}
+
+impl VisitMut for RemoveConsole<'_> {
+  fn visit_mut_module(&mut self, module: &mut Module) {
+    if !declares_console(module) {
+      module.visit_mut_children_with(self);
+    }
+  }
+
+  fn visit_mut_script(&mut self, script: &mut Script) {
+    if !declares_console(script) {
+      script.visit_mut_children_with(self);
+    }
+  }
+
+  fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+    items.retain(|item| !matches!(item, ModuleItem::Stmt(stmt) if self.is_console_stmt(stmt)));
+    items.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+    stmts.retain(|stmt| !self.is_console_stmt(stmt));
+    stmts.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+    // A statement that can't be removed, such as the body of an `if`.
+    if self.is_console_stmt(stmt) {
+      *stmt = Stmt::Empty(EmptyStmt { span: DUMMY_SP });
+      return;
+    }
+    stmt.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if self.is_console_call(expr) {
+      *expr = *Expr::undefined(DUMMY_SP);
+      return;
+    }
+    expr.visit_mut_children_with(self);
+  }
+}
+
+fn declares_console<N: VisitWith<ConsoleDeclFinder>>(node: &N) -> bool {
+  let mut finder = ConsoleDeclFinder { found: false };
+  node.visit_with(&mut finder);
+  finder.found
+}
+
+struct ConsoleDeclFinder {
+  found: bool,
+}
+
+impl ConsoleDeclFinder {
+  fn check(&mut self, ident: &Ident) {
+    if ident.sym == "console" {
+      self.found = true;
+    }
+  }
+}
+
+impl Visit for ConsoleDeclFinder {
+  fn visit_binding_ident(&mut self, ident: &BindingIdent) {
+    self.check(&ident.id);
+  }
+
+  fn visit_fn_decl(&mut self, decl: &FnDecl) {
+    self.check(&decl.ident);
+    decl.visit_children_with(self);
+  }
+
+  fn visit_fn_expr(&mut self, expr: &FnExpr) {
+    if let Some(ident) = &expr.ident {
+      self.check(ident);
+    }
+    expr.visit_children_with(self);
+  }
+
+  fn visit_class_decl(&mut self, decl: &ClassDecl) {
+    self.check(&decl.ident);
+    decl.visit_children_with(self);
+  }
+
+  fn visit_class_expr(&mut self, expr: &ClassExpr) {
+    if let Some(ident) = &expr.ident {
+      self.check(ident);
+    }
+    expr.visit_children_with(self);
+  }
+
+  fn visit_import_default_specifier(&mut self, specifier: &ImportDefaultSpecifier) {
+    self.check(&specifier.local);
+  }
+
+  fn visit_import_named_specifier(&mut self, specifier: &ImportNamedSpecifier) {
+    self.check(&specifier.local);
+  }
+
+  fn visit_import_star_as_specifier(&mut self, specifier: &ImportStarAsSpecifier) {
+    self.check(&specifier.local);
+  }
+}
@@ -0,0 +1,248 @@
+use std::{hash::Hash, path::Path};
+
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use serde::Deserialize;
+use sugar_path::SugarPath;
+use swc_core::{
+  common::{util::take::Take, DUMMY_SP},
+  ecma::{
+    ast::{
+      CallExpr, Callee, Expr, ExprOrSpread, IdentName, ImportSpecifier, KeyValueProp, Lit,
+      MemberExpr, MemberProp, Module, ModuleDecl, ModuleExportName, ModuleItem, ObjectLit, Pat,
+      Prop, PropName, PropOrSpread, Str, VarDeclarator,
+    },
+    atoms::Atom,
+    visit::{visit_mut_pass, VisitMut, VisitMutPass, VisitMutWith},
+  },
+};
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct StyledComponentsOptions {
+  /// Add the `displayName` of components for debugging.
+  pub display_name: bool,
+  /// Add a `componentId` that is stable between the server and the client.
+  pub ssr: bool,
+  /// Prefix the `displayName` with the name of the file.
+  pub file_name: bool,
+  /// File names that are replaced by the name of their directory in the
+  /// `displayName`.
+  pub meaningless_file_names: Vec<String>,
+  /// Prefix of the `componentId`, for multiple apps on the same page.
+  pub namespace: String,
+}
+
+impl Default for StyledComponentsOptions {
+  fn default() -> Self {
+    Self {
+      display_name: true,
+      ssr: true,
+      file_name: true,
+      meaningless_file_names: vec!["index".to_string()],
+      namespace: String::new(),
+    }
+  }
+}
+
+/// Adds `.withConfig({ displayName, componentId })` to the components created
+/// by the `styled` import of `styled-components`.
+pub fn styled_components<'a>(
+  options: &'a StyledComponentsOptions,
+  file_path: &'a Path,
+  root_dir: &'a Path,
+) -> VisitMutPass<StyledComponents<'a>> {
+  let relative_path = file_path.relative(root_dir);
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  relative_path.to_slash_lossy().hash(&mut hasher);
+  visit_mut_pass(StyledComponents {
+    options,
+    file_path,
+    file_hash: hasher.digest(&HashDigest::Hex).rendered(8).to_string(),
+    styled: vec![],
+    count: 0,
+  })
+}
+
+pub struct StyledComponents<'a> {
+  options: &'a StyledComponentsOptions,
+  file_path: &'a Path,
+  file_hash: String,
+  /// Local names of `styled` imported from `styled-components`.
+  styled: Vec<Atom>,
+  count: usize,
+}
+
+impl StyledComponents<'_> {
+  fn is_styled(&self, expr: &Expr) -> bool {
+    matches!(expr, Expr::Ident(ident) if self.styled.contains(&ident.sym))
+  }
+
+  /// Whether `expr` creates a component when called or tagged, such as
+  /// `styled.div`, `styled(Button)` or `styled.div.attrs(...)`.
+  fn is_component_factory(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(_),
+        ..
+      }) => self.is_styled(obj),
+      Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        ..
+      }) => match &**callee {
+        Expr::Member(MemberExpr {
+          obj,
+          prop: MemberProp::Ident(prop),
+          ..
+        }) if prop.sym == "attrs" => self.is_component_factory(obj),
+        callee => self.is_styled(callee),
+      },
+      _ => false,
+    }
+  }
+
+  /// The factory of the component created by `expr`, if any.
+  fn component_factory<'b>(&self, expr: &'b mut Expr) -> Option<&'b mut Box<Expr>> {
+    let factory = match expr {
+      Expr::TaggedTpl(tagged_tpl) => &mut tagged_tpl.tag,
+      Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        ..
+      }) => callee,
+      _ => return None,
+    };
+    self.is_component_factory(factory).then_some(factory)
+  }
+
+  /// The name of the file in the `displayName`.
+  fn block_name(&self) -> Option<String> {
+    let stem = self.file_path.file_stem()?.to_string_lossy();
+    if self
+      .options
+      .meaningless_file_names
+      .iter()
+      .any(|name| name == &stem)
+    {
+      let dir = self.file_path.parent()?.file_name()?;
+      return Some(dir.to_string_lossy().into_owned());
+    }
+    Some(stem.into_owned())
+  }
+
+  fn config(&mut self, name: Option<&Atom>) -> Vec<(&'static str, String)> {
+    let mut config = vec![];
+    if self.options.display_name {
+      let block_name = if self.options.file_name {
+        self.block_name()
+      } else {
+        None
+      };
+      let display_name = match (block_name, name) {
+        (Some(block_name), Some(name)) if block_name != name.as_str() => {
+          Some(format!("{block_name}__{name}"))
+        }
+        (_, Some(name)) => Some(name.to_string()),
+        (block_name, None) => block_name,
+      };
+      if let Some(display_name) = display_name {
+        config.push(("displayName", display_name));
+      }
+    }
+    if self.options.ssr {
+      let prefix = if self.options.namespace.is_empty() {
+        String::new()
+      } else {
+        format!("{}__", self.options.namespace)
+      };
+      config.push((
+        "componentId",
+        format!("{prefix}sc-{}-{}", self.file_hash, self.count),
+      ));
+      self.count += 1;
+    }
+    config
+  }
+
+  fn add_config(&mut self, expr: &mut Expr, name: Option<&Atom>) {
+    if self.component_factory(expr).is_none() {
+      return;
+    }
+    let config = self.config(name);
+    if config.is_empty() {
+      return;
+    }
+    let Some(factory) = self.component_factory(expr) else {
+      return;
+    };
+    let props = config
+      .into_iter()
+      .map(|(key, value)| {
+        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+          key: PropName::Ident(IdentName::new(key.into(), DUMMY_SP)),
+          value: Box::new(Expr::Lit(Lit::Str(Str::from(value)))),
+        })))
+      })
+      .collect();
+    **factory = Expr::Call(CallExpr {
+      span: DUMMY_SP,
+      callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+        span: DUMMY_SP,
+        obj: factory.take(),
+        prop: MemberProp::Ident(IdentName::new("withConfig".into(), DUMMY_SP)),
+      }))),
+      args: vec![ExprOrSpread {
+        spread: None,
+        expr: Box::new(Expr::Object(ObjectLit {
+          span: DUMMY_SP,
+          props,
+        })),
+      }],
+      ..Default::default()
+    });
+  }
+}
+
+impl VisitMut for StyledComponents<'_> {
+  fn visit_mut_module(&mut self, module: &mut Module) {
+    for item in &module.body {
+      let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+        continue;
+      };
+      if import.type_only || import.src.value != "styled-components" {
+        continue;
+      }
+      for specifier in &import.specifiers {
+        match specifier {
+          ImportSpecifier::Default(default) => self.styled.push(default.local.sym.clone()),
+          ImportSpecifier::Named(named) => {
+            let imported = match &named.imported {
+              Some(ModuleExportName::Ident(ident)) => &ident.sym,
+              Some(ModuleExportName::Str(str)) => &str.value,
+              None => &named.local.sym,
+            };
+            if imported == "styled" || imported == "default" {
+              self.styled.push(named.local.sym.clone());
+            }
+          }
+          ImportSpecifier::Namespace(_) => {}
+        }
+      }
+    }
+    if !self.styled.is_empty() {
+      module.visit_mut_children_with(self);
+    }
+  }
+
+  fn visit_mut_var_declarator(&mut self, declarator: &mut VarDeclarator) {
+    if let (Pat::Ident(name), Some(init)) = (&declarator.name, &mut declarator.init) {
+      let name = name.id.sym.clone();
+      self.add_config(init, Some(&name));
+    }
+    declarator.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    self.add_config(expr, None);
+    expr.visit_mut_children_with(self);
+  }
+}
@@ -0,0 +1,56 @@
+use std::{fs, path::Path};
+
+use rspack_swc_plugin_styled_components::{styled_components, StyledComponentsOptions};
+use swc_core::{
+  common::{sync::Lrc, FileName, SourceMap},
+  ecma::{
+    ast::{EsVersion, Program},
+    codegen::to_code,
+    parser::{parse_file_as_program, EsSyntax, Syntax},
+  },
+};
+
+fn parse(path: &Path) -> Program {
+  let cm: Lrc<SourceMap> = Default::default();
+  let source = fs::read_to_string(path).expect("should read the fixture");
+  let fm = cm.new_source_file(Lrc::new(FileName::Real(path.to_path_buf())), source);
+  parse_file_as_program(
+    &fm,
+    Syntax::Es(EsSyntax {
+      jsx: true,
+      ..Default::default()
+    }),
+    EsVersion::latest(),
+    None,
+    &mut vec![],
+  )
+  .unwrap_or_else(|e| panic!("should parse {}: {e:?}", path.display()))
+}
+
+/// Transforms `input.js` of every directory in `tests/fixture` with the options
+/// of `options.json`, and compares the result with `output.js`.
+#[test]
+fn fixture() {
+  let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixture");
+  let mut dirs = fs::read_dir(&root)
+    .expect("should read the fixtures")
+    .map(|entry| entry.expect("should read the fixture").path())
+    .collect::<Vec<_>>();
+  dirs.sort();
+  for dir in dirs {
+    let options: StyledComponentsOptions = match fs::read_to_string(dir.join("options.json")) {
+      Ok(options) => serde_json::from_str(&options).expect("should be valid options"),
+      Err(_) => Default::default(),
+    };
+    let input = dir.join("input.js");
+    let mut program = parse(&input);
+    program.mutate(styled_components(&options, &input, &root));
+    let expected = parse(&dir.join("output.js"));
+    assert_eq!(
+      to_code(&program),
+      to_code(&expected),
+      "{} should match its output",
+      dir.display()
+    );
+  }
+}
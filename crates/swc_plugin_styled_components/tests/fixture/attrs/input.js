@@ -0,0 +1,7 @@
+import styled from "styled-components";
+
+const Input = styled.input.attrs({ type: "text" })`
+  border: 1px solid gray;
+`;
+
+const Password = styled(Input).attrs({ type: "password" })``;
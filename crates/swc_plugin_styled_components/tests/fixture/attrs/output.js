@@ -0,0 +1,15 @@
+import styled from "styled-components";
+const Input = styled.input.attrs({
+    type: "text"
+}).withConfig({
+    displayName: "input__Input",
+    componentId: "sc-2fb44a9f-0"
+})`
+  border: 1px solid gray;
+`;
+const Password = styled(Input).attrs({
+    type: "password"
+}).withConfig({
+    displayName: "input__Password",
+    componentId: "sc-2fb44a9f-1"
+})``;
@@ -0,0 +1,13 @@
+import styled from "styled-components";
+
+const Button = styled.button`
+  color: red;
+`;
+
+const PrimaryButton = styled(Button)`
+  color: blue;
+`;
+
+export default styled.div`
+  display: flex;
+`;
@@ -0,0 +1,19 @@
+import styled from "styled-components";
+const Button = styled.button.withConfig({
+    displayName: "input__Button",
+    componentId: "sc-c70bc865-0"
+})`
+  color: red;
+`;
+const PrimaryButton = styled(Button).withConfig({
+    displayName: "input__PrimaryButton",
+    componentId: "sc-c70bc865-1"
+})`
+  color: blue;
+`;
+export default styled.div.withConfig({
+    displayName: "input",
+    componentId: "sc-c70bc865-2"
+})`
+  display: flex;
+`;
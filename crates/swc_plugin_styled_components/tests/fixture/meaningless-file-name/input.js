@@ -0,0 +1,5 @@
+import styled from "styled-components";
+
+export const Title = styled.h1`
+  font-size: 2em;
+`;
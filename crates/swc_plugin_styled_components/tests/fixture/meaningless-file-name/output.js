@@ -0,0 +1,7 @@
+import styled from "styled-components";
+export const Title = styled.h1.withConfig({
+    displayName: "meaningless-file-name__Title",
+    componentId: "sc-6a09a865-0"
+})`
+  font-size: 2em;
+`;
@@ -0,0 +1,5 @@
+import { styled } from "styled-components";
+
+const Wrapper = styled.section`
+  padding: 4em;
+`;
@@ -0,0 +1,6 @@
+import { styled } from "styled-components";
+const Wrapper = styled.section.withConfig({
+    componentId: "my-app__sc-4a2deac8-0"
+})`
+  padding: 4em;
+`;
@@ -0,0 +1,11 @@
+import sc from "styled-components";
+const Link = sc.a.withConfig({
+    displayName: "Link"
+})`
+  text-decoration: none;
+`;
+export function render() {
+    return sc.p`
+    margin: 0;
+  `;
+}
@@ -0,0 +1,10 @@
+import styled from "@emotion/styled";
+import { css } from "styled-components";
+
+const Link = styled.a`
+  text-decoration: none;
+`;
+
+const base = css`
+  margin: 0;
+`;
@@ -18,6 +18,7 @@ import { hideBin } from "yargs/helpers";
 import { BuildCommand } from "./commands/build";
 import { PreviewCommand } from "./commands/preview";
 import { ServeCommand } from "./commands/serve";
+import { SymbolicateCommand } from "./commands/symbolicate";
 import type {
 	RspackBuildCLIOptions,
 	RspackCLIColors,
@@ -106,7 +107,8 @@ export class RspackCLI {
 		const builtinCommands = [
 			new BuildCommand(),
 			new ServeCommand(),
-			new PreviewCommand()
+			new PreviewCommand(),
+			new SymbolicateCommand()
 		];
 		for (const command of builtinCommands) {
 			command.apply(this);
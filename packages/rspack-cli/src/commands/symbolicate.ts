@@ -0,0 +1,71 @@
+import fs from "node:fs";
+import path from "node:path";
+import { type RspackOptions, experiments } from "@rspack/core";
+import type yargs from "yargs";
+
+import type { RspackCLI } from "../cli";
+import type { RspackCommand, RspackSymbolicateCLIOptions } from "../types";
+import { commonOptions } from "../utils/options";
+
+const symbolicateOptions = (yargs: yargs.Argv) => {
+	yargs.positional("file", {
+		type: "string",
+		describe: "file containing the stack trace, read from stdin if omitted"
+	});
+	return commonOptions(yargs).options({
+		outputDir: {
+			type: "string",
+			describe: "directory of the emitted assets and source maps"
+		},
+		sourceMapDir: {
+			type: "array",
+			string: true,
+			describe: "directories of source maps kept apart from the output"
+		}
+	});
+};
+
+const defaultRoot = "dist";
+export class SymbolicateCommand implements RspackCommand {
+	async apply(cli: RspackCLI): Promise<void> {
+		cli.program.command(
+			["symbolicate [file]"],
+			"map a stack trace of the build output back to the original sources",
+			symbolicateOptions,
+			async options => {
+				const logger = cli.getLogger();
+				try {
+					const outputDir =
+						options.outputDir ?? (await getOutputDir(cli, options));
+					const stack = options.file
+						? fs.readFileSync(options.file, "utf-8")
+						: fs.readFileSync(process.stdin.fd, "utf-8");
+					const symbolicated = experiments.symbolicateStackTrace(
+						stack,
+						path.resolve(outputDir),
+						(options.sourceMapDir ?? []).map(dir => path.resolve(dir))
+					);
+					logger.raw(symbolicated);
+				} catch (error) {
+					logger.error(error);
+					process.exit(2);
+				}
+			}
+		);
+	}
+}
+
+// the output path of the config, as the `preview` command
+async function getOutputDir(
+	cli: RspackCLI,
+	options: RspackSymbolicateCLIOptions
+): Promise<string> {
+	let config = await cli.loadConfig({ ...options, argv: { ...options } });
+	if (Array.isArray(config)) {
+		config = config[0];
+	}
+	const item = config as RspackOptions;
+	return (
+		item.output?.path ?? path.join(item.context ?? process.cwd(), defaultRoot)
+	);
+}
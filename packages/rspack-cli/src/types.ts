@@ -47,6 +47,11 @@ export interface RspackPreviewCLIOptions extends RspackCLIOptions {
 	server?: string;
 	publicPath?: string;
 }
+export interface RspackSymbolicateCLIOptions extends RspackCLIOptions {
+	file?: string;
+	outputDir?: string;
+	sourceMapDir?: string[];
+}
 export interface RspackCommand {
 	apply(cli: RspackCLI): Promise<void>;
 }
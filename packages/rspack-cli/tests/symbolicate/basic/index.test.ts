@@ -0,0 +1,28 @@
+import fs from "node:fs";
+import { resolve } from "path";
+import { run } from "../../utils/test-utils";
+
+describe("symbolicate command", () => {
+	it("should map a stack trace of the build output", async () => {
+		const build = await run(__dirname, ["build"]);
+		expect(build.exitCode).toBe(0);
+
+		// the frame of the `throw` in the emitted bundle
+		const bundle = fs.readFileSync(resolve(__dirname, "dist/main.js"), "utf-8");
+		const lines = bundle.split("\n");
+		const line = lines.findIndex(content => content.includes("throw"));
+		const column = lines[line].indexOf("throw");
+		const stackFile = resolve(__dirname, "dist/stack.txt");
+		fs.writeFileSync(
+			stackFile,
+			`Error: production\n    at https://cdn.example.com/main.js:${line + 1}:${column + 1}`
+		);
+
+		const { exitCode, stdout } = await run(__dirname, [
+			"symbolicate",
+			stackFile
+		]);
+		expect(exitCode).toBe(0);
+		expect(stdout).toContain("at webpack:///./src/index.js:2:2");
+	});
+});
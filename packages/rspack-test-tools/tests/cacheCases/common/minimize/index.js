@@ -0,0 +1,17 @@
+import value from "./file";
+import { add } from "./utils";
+
+it("should restore minimized assets from persistent cache", async () => {
+	expect(add.toString()).not.toContain("firstOperand");
+	if (COMPILER_INDEX == 0) {
+		expect(add(value, 1)).toBe(2);
+		await NEXT_HMR();
+		expect(value).toBe(2);
+		await NEXT_START();
+	}
+	if (COMPILER_INDEX == 1) {
+		expect(add(value, 1)).toBe(4);
+		await NEXT_HMR();
+		expect(value).toBe(4);
+	}
+});
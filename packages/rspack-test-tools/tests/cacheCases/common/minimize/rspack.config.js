@@ -0,0 +1,14 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: __dirname,
+	optimization: {
+		minimize: true
+	},
+	experiments: {
+		cache: {
+			type: "persistent"
+		},
+		incremental: true
+	},
+	ignoreWarnings: [/not friendly for incremental/]
+};
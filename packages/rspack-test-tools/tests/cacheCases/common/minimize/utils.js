@@ -0,0 +1,3 @@
+export function add(firstOperand, secondOperand) {
+	return firstOperand + secondOperand;
+}
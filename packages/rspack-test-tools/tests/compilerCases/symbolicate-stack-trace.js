@@ -0,0 +1,36 @@
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description:
+		"should symbolicate a stack trace with the source maps of the emitted bundle",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./symbolicate",
+			mode: "production",
+			devtool: "source-map",
+			output: {
+				path: context.getDist()
+			}
+		};
+	},
+	async check(_, compiler, stats) {
+		const compilation = stats.compilation;
+		// the frame of the `throw` in the emitted bundle
+		const lines = compilation.assets["main.js"].source().toString().split("\n");
+		const line = lines.findIndex(content => content.includes("throw"));
+		const column = lines[line].indexOf("throw");
+		const stack = `Error: production\n    at https://cdn.example.com/main.js:${line + 1}:${column + 1}\n    at <anonymous>`;
+
+		expect(compilation.symbolicateStackTrace(stack)).toBe(
+			"Error: production\n    at webpack:///./symbolicate.js:2:2\n    at <anonymous>"
+		);
+		expect(
+			compiler.rspack.experiments.symbolicateStackTrace(
+				stack,
+				compiler.options.output.path
+			)
+		).toBe(
+			"Error: production\n    at webpack:///./symbolicate.js:2:2\n    at <anonymous>"
+		);
+	}
+};
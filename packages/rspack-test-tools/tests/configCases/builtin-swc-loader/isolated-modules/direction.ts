@@ -0,0 +1,4 @@
+export declare const enum Direction {
+	Up = 1,
+	Down = 2
+}
@@ -0,0 +1,5 @@
+declare const enum Direction {
+	Up = 1
+}
+
+export const up = Direction.Up;
@@ -0,0 +1,3 @@
+module.exports = [
+	/Cannot access the ambient const enum 'Direction'/
+];
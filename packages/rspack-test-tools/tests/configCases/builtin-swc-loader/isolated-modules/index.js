@@ -0,0 +1,14 @@
+import { value } from "./reexport";
+
+it("should keep the values re-exported with types", () => {
+	expect(value).toBe(1);
+});
+
+// Only built to check the ambient const enums, which throw when evaluated
+export function loadEnum() {
+	return import("./enum");
+}
+
+export function loadMove() {
+	return import("./move");
+}
@@ -0,0 +1,3 @@
+import { Direction } from "./direction";
+
+export const down = Direction.Down;
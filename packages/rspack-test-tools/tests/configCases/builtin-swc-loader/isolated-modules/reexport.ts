@@ -0,0 +1 @@
+export { Options, value } from "./types";
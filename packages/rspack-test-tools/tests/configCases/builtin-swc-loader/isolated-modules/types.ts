@@ -0,0 +1,5 @@
+export interface Options {
+	name: string;
+}
+
+export const value = 1;
@@ -0,0 +1,4 @@
+module.exports = [
+	/'Options' has no value at runtime, as it is only a type or an ambient declaration in '.\/types'/,
+	/'Direction' is an ambient const enum in '.\/direction', which has no value at runtime/
+];
@@ -0,0 +1,15 @@
+import { add, value } from "./lib";
+
+it("should remove the calls of console except the excluded methods", () => {
+	const log = jest.spyOn(console, "log").mockImplementation(() => {});
+	const info = jest.spyOn(console, "info").mockImplementation(() => {});
+	const error = jest.spyOn(console, "error").mockImplementation(() => {});
+	expect(add(1, 2)).toBe(3);
+	expect(value).toBeUndefined();
+	expect(log).not.toHaveBeenCalled();
+	expect(info).not.toHaveBeenCalled();
+	expect(error).toHaveBeenCalledWith("error");
+	log.mockRestore();
+	info.mockRestore();
+	error.mockRestore();
+});
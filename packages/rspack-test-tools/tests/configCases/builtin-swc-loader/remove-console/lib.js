@@ -0,0 +1,7 @@
+export function add(a, b) {
+	console.log("add", a, b);
+	console.error("error");
+	return a + b;
+}
+
+export const value = console.info("value");
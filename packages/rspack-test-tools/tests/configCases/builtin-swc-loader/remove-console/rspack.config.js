@@ -0,0 +1,22 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /lib\.js$/,
+				use: [
+					{
+						loader: "builtin:swc-loader",
+						options: {
+							rspackExperiments: {
+								removeConsole: {
+									exclude: ["error"]
+								}
+							}
+						}
+					}
+				]
+			}
+		]
+	}
+};
@@ -0,0 +1,40 @@
+import "./style.css";
+
+const fs = require("fs");
+const path = require("path");
+
+const html = fs.readFileSync(
+	path.join(__dirname, "./pages/index.html"),
+	"utf-8"
+);
+
+it("should inline the critical css of links relative to the html with publicPath auto", () => {
+	expect(html).toMatch(/<style>\.hero\{color:red\}<\/style>/);
+	expect(html).not.toContain(".footer");
+	expect(html).toContain(
+		`<link href="../main.css" rel="stylesheet" media="print" onload="this.media='all'">`
+	);
+});
+
+it("should keep the critical css of media stylesheets conditional", () => {
+	expect(html).toContain(
+		"<style>@media (min-width: 768px){.hero{font-size:2em}}</style>"
+	);
+	expect(html).toContain(
+		`<link rel="stylesheet" href="../wide.css" media="print" onload="this.media='(min-width: 768px)'">`
+	);
+});
+
+it("should not apply the critical css of screen stylesheets to print", () => {
+	expect(html).toContain("<style>@media screen{.hero{padding:0}}</style>");
+	expect(html).toContain(
+		`<link rel="stylesheet" href="../screen.css" media="print" onload="this.media='screen'">`
+	);
+});
+
+it("should not inline print stylesheets", () => {
+	expect(html).toContain(
+		'<link rel="stylesheet" href="../print.css" media="print">'
+	);
+	expect(html).not.toContain("color:black");
+});
@@ -0,0 +1,43 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	entry: {
+		main: "./index.js",
+		wide: "./wide.css",
+		screen: "./screen.css",
+		print: "./print.css"
+	},
+	output: {
+		publicPath: "auto"
+	},
+	externals: {
+		path: "require('path')",
+		fs: "require('fs')"
+	},
+	node: {
+		__dirname: false
+	},
+	experiments: {
+		css: true
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			filename: "pages/index.html",
+			chunks: ["main"],
+			templateContent: `<!DOCTYPE html>
+<html>
+	<head>
+		<link rel="stylesheet" href="../wide.css" media="(min-width: 768px)">
+		<link rel="stylesheet" href="../screen.css" media="screen">
+		<link rel="stylesheet" href="../print.css" media="print">
+	</head>
+	<body>
+		<div class="hero">hero</div>
+	</body>
+</html>`
+		}),
+		new rspack.CriticalCssPlugin()
+	]
+};
@@ -0,0 +1,12 @@
+import value from "./module";
+
+it("should restore the module ids of the records", () => {
+	expect(value).toBe("module");
+	expect(`${require.resolveWeak("./module")}`).toBe("42");
+});
+
+it("should restore the chunk ids of the records", async () => {
+	const { default: lazy } = await import(/* webpackChunkName: "lazy" */ "./lazy");
+	expect(lazy).toBe("lazy");
+	expect(`${__STATS__.namedChunkGroups.lazy.chunks[0]}`).toBe("7");
+});
@@ -0,0 +1,10 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		moduleIds: "deterministic",
+		chunkIds: "deterministic"
+	},
+	recordsInputPath: path.resolve(__dirname, "records.json")
+};
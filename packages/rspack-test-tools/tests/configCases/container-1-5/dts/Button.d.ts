@@ -0,0 +1,3 @@
+import type { Theme } from "./theme";
+
+export default function Button(theme: Theme): string;
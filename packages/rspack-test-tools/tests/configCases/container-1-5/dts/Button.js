@@ -0,0 +1,3 @@
+export default function Button(theme) {
+	return `button ${theme.color}`;
+}
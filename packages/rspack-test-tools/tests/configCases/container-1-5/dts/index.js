@@ -0,0 +1,13 @@
+import fs from "fs";
+import path from "path";
+
+it("should emit the declarations of the exposes next to the remote entry", () => {
+	const archive = fs.readFileSync(path.join(__dirname, "@mf-types.zip"));
+	// local file header signature
+	expect(archive.readUInt32LE(0)).toBe(0x04034b50);
+	// the entry names are stored uncompressed
+	const content = archive.toString("latin1");
+	expect(content).toContain("Button.d.ts");
+	expect(content).toContain("compiled-types/Button.d.ts");
+	expect(content).toContain("compiled-types/theme.d.ts");
+});
@@ -0,0 +1,23 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js"
+	},
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "container",
+			filename: "container.js",
+			library: {
+				type: "commonjs-module"
+			},
+			exposes: {
+				"./Button": "./Button"
+			},
+			dts: {
+				typesFolder: false
+			}
+		})
+	]
+};
@@ -0,0 +1,3 @@
+import React from "react";
+
+export default () => `Button with ${React}`;
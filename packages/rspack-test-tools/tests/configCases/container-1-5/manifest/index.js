@@ -0,0 +1,53 @@
+import fs from "fs";
+import path from "path";
+
+// never called, only makes the remote part of the build
+export const loadRemote = () => import("remote/Header");
+
+it("should emit the manifest", () => {
+	const manifest = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "mf-manifest.json"), "utf-8")
+	);
+	expect(manifest.name).toBe("container");
+	expect(manifest.metaData.publicPath).toBe("/");
+	expect(manifest.metaData.remoteEntry).toEqual({
+		name: "container.js",
+		path: "",
+		type: "commonjs-module"
+	});
+
+	expect(manifest.exposes).toHaveLength(1);
+	const [button] = manifest.exposes;
+	expect(button.id).toBe("container:Button");
+	expect(button.path).toBe("./Button");
+	expect(button.file).toBeUndefined();
+	expect(button.assets.js.sync.length).toBeGreaterThan(0);
+
+	expect(manifest.shared).toHaveLength(1);
+	const [react] = manifest.shared;
+	expect(react.name).toBe("react");
+	expect(react.version).toBe("0.1.2");
+	expect(react.requiredVersion).toBe("^0.1.0");
+	expect(react.singleton).toBe(true);
+	expect(react.eager).toBe(false);
+	expect(react.assets.js.sync.length).toBeGreaterThan(0);
+
+	expect(manifest.remotes).toEqual([
+		{
+			alias: "remote",
+			federationContainerName: "remote",
+			moduleName: "Header",
+			entry: "http://localhost:3001/remoteEntry.js",
+			shareScope: "default"
+		}
+	]);
+});
+
+it("should list the sources and users in the stats", () => {
+	const stats = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "mf-stats.json"), "utf-8")
+	);
+	expect(stats.exposes[0].file).toEqual(["./Button"]);
+	expect(stats.shared[0].usedIn).toEqual(["./Button.js"]);
+	expect(stats.remotes[0].usedIn).toEqual(["./index.js"]);
+});
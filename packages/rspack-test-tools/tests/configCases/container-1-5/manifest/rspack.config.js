@@ -0,0 +1,32 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js",
+		publicPath: "/"
+	},
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "container",
+			filename: "container.js",
+			library: {
+				type: "commonjs-module"
+			},
+			exposes: {
+				"./Button": "./Button"
+			},
+			remotes: {
+				remote: "remote@http://localhost:3001/remoteEntry.js"
+			},
+			shared: {
+				react: {
+					singleton: true,
+					version: "0.1.2",
+					requiredVersion: "^0.1.0"
+				}
+			},
+			manifest: true
+		})
+	]
+};
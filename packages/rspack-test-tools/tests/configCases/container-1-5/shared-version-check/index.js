@@ -0,0 +1,4 @@
+it("should build while reporting the conflict", async () => {
+	const { default: React } = await import("react");
+	expect(React).toBe("react");
+});
@@ -0,0 +1,20 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "host",
+			shared: {
+				react: {
+					singleton: true,
+					version: "0.1.2",
+					requiredVersion: "^0.1.0"
+				}
+			},
+			sharedVersionCheck: {
+				manifests: ["./remote-stats.json"]
+			}
+		})
+	]
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	/Shared singleton "react" \(share scope "default"\) is loaded in version 1\.0\.0 provided by remote, which doesn't satisfy "\^0\.1\.0"/
+];
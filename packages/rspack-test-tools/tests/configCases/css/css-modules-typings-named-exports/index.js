@@ -0,0 +1,16 @@
+import * as styles from "typed-style";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should only declare the names that are valid identifiers as named exports", () => {
+	const declaration = fs.readFileSync(
+		path.join(__dirname, "src", "style.module.css.d.ts"),
+		"utf-8"
+	);
+	expect(declaration).toBe(
+		"// This file is generated by rspack, do not edit.\nexport const button: string;\n"
+	);
+	expect(styles.button).toBeTruthy();
+	expect(styles["is-active"]).toBeTruthy();
+});
@@ -0,0 +1,41 @@
+const fs = require("fs");
+const path = require("path");
+const { rspack } = require("@rspack/core");
+
+/** @type {function(any, any): import("@rspack/core").Configuration} */
+module.exports = (env, { testPath }) => {
+	// the declarations are written next to the css modules, so copy them
+	// out of the source tree
+	const dir = path.join(testPath, "src");
+	fs.mkdirSync(dir, { recursive: true });
+	fs.copyFileSync(
+		path.join(__dirname, "style.module.css"),
+		path.join(dir, "style.module.css")
+	);
+	return {
+		target: "web",
+		node: {
+			__dirname: false
+		},
+		resolve: {
+			alias: {
+				"typed-style": path.join(dir, "style.module.css")
+			}
+		},
+		module: {
+			rules: [
+				{
+					test: /\.module\.css$/,
+					type: "css/module",
+					generator: {
+						exportsConvention: "as-is"
+					}
+				}
+			]
+		},
+		experiments: {
+			css: true
+		},
+		plugins: [new rspack.CssModulesTypingsPlugin()]
+	};
+};
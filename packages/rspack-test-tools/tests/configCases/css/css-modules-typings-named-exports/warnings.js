@@ -0,0 +1,3 @@
+module.exports = [
+	[/The declarations of .*style\.module\.css leave out "is-active", as they are not valid identifiers for named exports/]
+];
@@ -0,0 +1,31 @@
+import styles from "typed-style";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+const expectedExports = {
+	"as-is": ["btn_primary", "title"],
+	"camel-case": ["btn_primary", "btnPrimary", "title"],
+	"camel-case-only": ["btnPrimary", "title"],
+	dashes: ["btn_primary", "btn-primary", "title"],
+	"dashes-only": ["btn-primary", "title"]
+};
+
+it("should declare the exports named after the exports convention", () => {
+	const declaration = fs.readFileSync(
+		path.join(__dirname, CONVENTION, "style.module.css.d.ts"),
+		"utf-8"
+	);
+	const names = expectedExports[CONVENTION];
+	expect(declaration).toBe(
+		[
+			"// This file is generated by rspack, do not edit.",
+			"declare const styles: {",
+			...names.map(name => `  readonly "${name}": string;`),
+			"};",
+			"export default styles;",
+			""
+		].join("\n")
+	);
+	expect(Object.keys(styles).sort()).toEqual([...names].sort());
+});
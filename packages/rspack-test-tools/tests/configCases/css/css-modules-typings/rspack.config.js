@@ -0,0 +1,60 @@
+const fs = require("fs");
+const path = require("path");
+const { rspack } = require("@rspack/core");
+
+const conventions = [
+	"as-is",
+	"camel-case",
+	"camel-case-only",
+	"dashes",
+	"dashes-only"
+];
+
+/** @type {function(any, any): import("@rspack/core").Configuration[]} */
+module.exports = (env, { testPath }) =>
+	conventions.map(convention => {
+		// the declarations are written next to the css modules, so copy them
+		// out of the source tree
+		const dir = path.join(testPath, convention);
+		fs.mkdirSync(dir, { recursive: true });
+		fs.copyFileSync(
+			path.join(__dirname, "style.module.css"),
+			path.join(dir, "style.module.css")
+		);
+		return {
+			target: "web",
+			node: {
+				__dirname: false
+			},
+			resolve: {
+				alias: {
+					"typed-style": path.join(dir, "style.module.css")
+				}
+			},
+			module: {
+				parser: {
+					"css/module": {
+						namedExports: false
+					}
+				},
+				rules: [
+					{
+						test: /\.module\.css$/,
+						type: "css/module",
+						generator: {
+							exportsConvention: convention
+						}
+					}
+				]
+			},
+			experiments: {
+				css: true
+			},
+			plugins: [
+				new rspack.DefinePlugin({
+					CONVENTION: JSON.stringify(convention)
+				}),
+				new rspack.CssModulesTypingsPlugin()
+			]
+		};
+	});
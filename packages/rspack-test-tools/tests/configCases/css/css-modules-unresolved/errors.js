@@ -0,0 +1,4 @@
+module.exports = [
+	[/Cannot compose "missing-class" from "\.\/base\.module\.css": the module does not export it\./],
+	[/Cannot import @value "missing-value" from "\.\/base\.module\.css": the module does not export it\./]
+];
@@ -0,0 +1,6 @@
+import * as styles from "./index.module.css";
+
+it("should still compile the resolved composes and @value", () => {
+	expect(styles.primary).toBe("red");
+	expect(styles.title).toContain(" ");
+});
@@ -0,0 +1,15 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	module: {
+		rules: [
+			{
+				test: /\.module\.css$/,
+				type: "css/module"
+			}
+		]
+	},
+	experiments: {
+		css: true
+	}
+};
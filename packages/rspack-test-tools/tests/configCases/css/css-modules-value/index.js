@@ -0,0 +1,26 @@
+import * as colors from "./colors.module.css";
+import * as styles from "./index.module.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should export @value with the exports convention", () => {
+	expect(colors.primaryColor).toBe("red");
+	expect(colors.secondaryColor).toBe("blue");
+	expect(colors.text).toBe("text");
+	expect(Object.keys(colors)).not.toContain("primary-color");
+});
+
+it("should re-export imported @value with the exports convention", () => {
+	expect(styles.primaryColor).toBe("red");
+	expect(styles.accentColor).toBe("blue");
+	expect(styles.spacing).toBe("4px");
+	expect(styles.title).toBe("title");
+});
+
+it("should replace @value in the css", () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(css).not.toContain("@value");
+	expect(css).toMatch(/\.text\s*\{\s*color: red;/);
+	expect(css).toMatch(/color: red;\s*border-color: blue;\s*margin: 4px;/);
+});
@@ -0,0 +1,23 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: {
+		__dirname: false,
+		__filename: false
+	},
+	module: {
+		rules: [
+			{
+				test: /\.module\.css$/,
+				type: "css/module",
+				generator: {
+					exportsConvention: "camel-case-only",
+					localIdentName: "[local]"
+				}
+			}
+		]
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,26 @@
+import * as styles from "./style.module.css";
+
+it("should only drop the rules whose selectors require unused classes", async () => {
+	const fs = __non_webpack_require__("fs");
+	const path = __non_webpack_require__("path");
+	expect(styles.used).toBe("used");
+
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "./bundle0.css"),
+		"utf-8"
+	);
+	expect(css).not.toContain("color: blue");
+	// negations and the other pseudo-class arguments don't require the class
+	expect(css).toContain(".used:not(.unused)");
+	expect(css).toContain(":is(.unused, .used)");
+	expect(css).toContain(".used:has(.unused)");
+	// every alternative of :where() requires it
+	expect(css).not.toContain("margin: 1px");
+	// only the unused selectors of a list are dropped
+	expect(css).toContain(".used > span");
+	expect(css).not.toMatch(/\.unused,/);
+	// nested in @media and @supports
+	expect(css).not.toContain(".unused .used");
+	expect(css).toContain("left: 0");
+	expect(css).not.toContain(".used.unused");
+});
@@ -0,0 +1,23 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	target: "web",
+	node: {
+		__dirname: false,
+		__filename: false
+	},
+	module: {
+		generator: {
+			"css/auto": {
+				localIdentName: "[local]",
+				exportsOnly: false
+			}
+		}
+	},
+	optimization: {
+		minimize: false
+	},
+	experiments: {
+		css: true
+	}
+};
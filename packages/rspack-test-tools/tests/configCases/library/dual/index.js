@@ -0,0 +1,73 @@
+const fs = require("fs");
+const path = require("path");
+const { execFileSync } = require("child_process");
+const { pathToFileURL } = require("url");
+
+// Load the outputs with node itself, the CommonJS variant imports the async
+// chunk with `import()` like the ES module does.
+const load = loader =>
+	JSON.parse(
+		execFileSync(
+			process.execPath,
+			[
+				"-e",
+				`Promise.resolve(${loader}).then(async lib => {
+	process.stdout.write(JSON.stringify({
+		add: lib.add(1, 2),
+		upper: lib.strings.upper("dual"),
+		version: lib.VERSION,
+		url: lib.url,
+		default: lib.default,
+		greeting: await lib.loadGreeting()
+	}));
+});`
+			],
+			{ encoding: "utf-8" }
+		)
+	);
+
+const expected = {
+	add: 3,
+	upper: "DUAL",
+	version: "1.0.0",
+	url: expect.stringMatching(/^file:\/\/.*\/lib\.js$/),
+	default: "dual",
+	greeting: "hello dual"
+};
+
+it("should emit an ES module, a CommonJS variant and an exports map", () => {
+	const packageJson = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "package.json"), "utf-8")
+	);
+	expect(packageJson).toEqual({
+		type: "module",
+		exports: {
+			".": {
+				import: "./main.mjs",
+				require: "./main.cjs"
+			}
+		}
+	});
+
+	const esm = fs.readFileSync(path.resolve(__dirname, "main.mjs"), "utf-8");
+	expect(esm).toContain("export { ");
+	expect(esm).not.toContain("module.exports");
+
+	const cjs = fs.readFileSync(path.resolve(__dirname, "main.cjs"), "utf-8");
+	expect(cjs.startsWith('"use strict";\n')).toBe(true);
+	expect(cjs).toContain("module.exports = __webpack_exports__;");
+	expect(cjs).not.toContain("export {");
+	expect(cjs).not.toContain("import.meta");
+});
+
+it("should load the ES module", () => {
+	const file = path.resolve(__dirname, "main.mjs");
+	expect(load(`import(${JSON.stringify(pathToFileURL(file).href)})`)).toEqual(
+		expected
+	);
+});
+
+it("should load the CommonJS variant", () => {
+	const file = path.resolve(__dirname, "main.cjs");
+	expect(load(`require(${JSON.stringify(file)})`)).toEqual(expected);
+});
@@ -0,0 +1,10 @@
+export { add } from "./math";
+export * as strings from "./strings";
+export * from "./constants";
+
+export const url = import.meta.url;
+
+export const loadGreeting = () =>
+	import("./greeting").then(({ greeting }) => greeting("dual"));
+
+export default "dual";
@@ -0,0 +1,29 @@
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		entry: {
+			main: "./lib.js"
+		},
+		output: {
+			filename: "[name].mjs",
+			module: true,
+			library: {
+				type: "dual"
+			}
+		},
+		experiments: {
+			outputModule: true
+		},
+		optimization: {
+			minimize: false
+		}
+	},
+	{
+		entry: {
+			index: "./index.js"
+		},
+		output: {
+			filename: "index.js"
+		}
+	}
+];
@@ -0,0 +1 @@
+export const upper = value => value.toUpperCase();
@@ -0,0 +1,23 @@
+import { createApp, nextTick } from "vue";
+import App from "./App.vue";
+
+it("should mount the component compiled by builtin:vue-loader", async () => {
+	const root = document.createElement("div");
+	document.body.appendChild(root);
+	const app = createApp(App, { title: "Hello" });
+	app.mount(root);
+
+	expect(root.querySelector("h1").textContent).toBe("Hello");
+	const button = root.querySelector("button");
+	const total = root.querySelector(".total");
+	expect(button.textContent).toBe("count: 0");
+	expect(total.hasAttribute(App.__scopeId)).toBe(true);
+
+	button.click();
+	await nextTick();
+	expect(button.textContent).toBe("count: 1");
+	expect(total.textContent).toBe("total: 1");
+
+	app.unmount();
+	root.remove();
+});
@@ -0,0 +1,29 @@
+const { DefinePlugin, VueLoaderRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: false,
+	entry: {
+		main: "./index.js"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.vue$/,
+				loader: "builtin:vue-loader"
+			}
+		]
+	},
+	plugins: [
+		new VueLoaderRspackPlugin(),
+		new DefinePlugin({
+			__VUE_OPTIONS_API__: JSON.stringify(true),
+			__VUE_PROD_DEVTOOLS__: JSON.stringify(false),
+			__VUE_PROD_HYDRATION_MISMATCH_DETAILS__: JSON.stringify(false)
+		})
+	],
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,12 @@
+import a from "./a";
+import b from "./b";
+
+it("should use the hash of the module name as the module id", () => {
+	expect(a).toBe("a");
+	expect(b).toBe("b");
+	const aId = `${require.resolveWeak("./a")}`;
+	const bId = `${require.resolveWeak("./b")}`;
+	expect(aId).toMatch(/^[0-9a-f]{8,}$/);
+	expect(bId).toMatch(/^[0-9a-f]{8,}$/);
+	expect(aId).not.toBe(bId);
+});
@@ -0,0 +1,12 @@
+const { ids } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new ids.HashedModuleIdsPlugin({
+			hashDigest: "hex",
+			hashDigestLength: 8,
+			salt: "app"
+		})
+	]
+};
@@ -0,0 +1 @@
+import("./lazy").then(({ default: lazy }) => lazy());
@@ -0,0 +1,26 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should import the chunks with the specifiers in the import map", () => {
+	const importMap = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "importmap.json"), "utf-8")
+	);
+	const files = fs.readdirSync(__dirname);
+	const fileOf = name => files.find(file => file.startsWith(`${name}.`));
+
+	expect(importMap.imports).toEqual({
+		"my-app-chunk/app": `./${fileOf("app")}`,
+		"my-app-chunk/lazy_js": `./${fileOf("lazy_js")}`,
+		"my-app-chunk/runtime": `./${fileOf("runtime")}`
+	});
+
+	const app = fs.readFileSync(path.resolve(__dirname, fileOf("app")), "utf-8");
+	expect(app).toContain("from 'my-app-chunk/runtime'");
+
+	const runtime = fs.readFileSync(
+		path.resolve(__dirname, fileOf("runtime")),
+		"utf-8"
+	);
+	expect(runtime).toContain('import("my-app-chunk/" + chunkId)');
+	expect(runtime).not.toContain(fileOf("lazy_js"));
+});
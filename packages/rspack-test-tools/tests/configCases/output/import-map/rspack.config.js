@@ -0,0 +1,33 @@
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		entry: {
+			app: "./app.js"
+		},
+		output: {
+			filename: "[name].[contenthash:8].mjs",
+			chunkFilename: "[name].[contenthash:8].mjs",
+			module: true,
+			chunkFormat: "module",
+			chunkLoading: "import",
+			uniqueName: "my-app",
+			importMap: true
+		},
+		experiments: {
+			outputModule: true
+		},
+		optimization: {
+			runtimeChunk: "single",
+			chunkIds: "named",
+			minimize: false
+		}
+	},
+	{
+		entry: {
+			index: "./index.js"
+		},
+		output: {
+			filename: "index.js"
+		}
+	}
+];
@@ -0,0 +1,3 @@
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.default = "cjs default";
+exports._internalValue = 42;
@@ -0,0 +1,7 @@
+const esm = require("./esm");
+
+module.exports = {
+	isEsModule: esm.__esModule,
+	defaultValue: esm.default,
+	flag: esm._internalFlag
+};
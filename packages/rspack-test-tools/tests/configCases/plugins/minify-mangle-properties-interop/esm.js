@@ -0,0 +1,13 @@
+export default "esm default";
+export const _internalFlag = true;
+
+export class Counter {
+	constructor() {
+		this._internalCount = 0;
+	}
+}
+
+Counter.prototype.increment = function () {
+	this._internalCount += 1;
+	return this;
+};
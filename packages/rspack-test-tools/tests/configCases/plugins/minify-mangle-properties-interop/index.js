@@ -0,0 +1,24 @@
+import cjsDefault, { _internalValue } from "./cjs";
+import { Counter } from "./esm";
+import consumer from "./consumer";
+
+it("should keep the interop of commonjs modules working", () => {
+	expect(cjsDefault).toBe("cjs default");
+	expect(_internalValue).toBe(42);
+});
+
+it("should keep the interop of esm modules required from commonjs working", () => {
+	expect(consumer.isEsModule).toBe(true);
+	expect(consumer.defaultValue).toBe("esm default");
+	expect(consumer.flag).toBe(true);
+});
+
+it("should keep constructors and prototypes", async () => {
+	const counter = new Counter().increment();
+	expect(counter.constructor).toBe(Counter);
+	expect(Object.getPrototypeOf(counter)).toBe(Counter.prototype);
+	expect(counter._internalCount).toBe(1);
+	expect(Object.keys(counter)).not.toContain("_internalCount");
+	const { default: lazy } = await import("./cjs");
+	expect(lazy).toBe("cjs default");
+});
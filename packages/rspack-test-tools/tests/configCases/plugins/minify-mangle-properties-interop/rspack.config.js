@@ -0,0 +1,18 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		minimize: true
+	},
+	plugins: [
+		new rspack.SwcJsMinimizerRspackPlugin({
+			minimizerOptions: {
+				mangleProperties: {
+					// also matches the properties the interop runtime relies on
+					regex: /^_|^(constructor|prototype|default|then)$/
+				}
+			}
+		})
+	]
+};
@@ -0,0 +1,25 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should mangle properties consistently across chunks", async () => {
+	const { createStore } = await import(/* webpackChunkName: "store" */ "./store");
+	const store = createStore();
+	store.increment();
+	expect(store._internalCount).toBe(2);
+	expect(Object.keys(store)).not.toContain("_internalCount");
+	expect(Object.keys(store)).toContain("increment");
+});
+
+it("should mangle the quoted property of in checks", async () => {
+	const { createStore } = await import(/* webpackChunkName: "store" */ "./store");
+	const store = createStore();
+	expect("_internalCount" in store).toBe(true);
+	expect("_internalMissing" in store).toBe(false);
+});
+
+it("should save the mangled names to the name cache", () => {
+	const nameCache = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "property-names.json"), "utf-8")
+	);
+	expect(Object.keys(nameCache)).toContain("_internalCount");
+});
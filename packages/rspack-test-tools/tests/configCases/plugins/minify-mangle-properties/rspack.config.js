@@ -0,0 +1,19 @@
+const path = require("path");
+const { rspack } = require("@rspack/core");
+
+/** @type {function(any, any): import("@rspack/core").Configuration} */
+module.exports = (env, { testPath }) => ({
+	optimization: {
+		minimize: true
+	},
+	plugins: [
+		new rspack.SwcJsMinimizerRspackPlugin({
+			minimizerOptions: {
+				mangleProperties: {
+					regex: /^_internal/,
+					nameCache: path.join(testPath, "property-names.json")
+				}
+			}
+		})
+	]
+});
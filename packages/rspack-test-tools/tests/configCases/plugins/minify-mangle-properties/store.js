@@ -0,0 +1,8 @@
+export function createStore() {
+	return {
+		_internalCount: 1,
+		increment() {
+			this._internalCount += 1;
+		}
+	};
+}
@@ -0,0 +1,20 @@
+const fs = require("fs");
+const path = require("path");
+import { multiplyNumbers } from "./math";
+
+it("should attribute the minified bytes to modules without devtool", () => {
+	expect(multiplyNumbers(2, 3)).toBe(6);
+	const report = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "minify-report.json"), "utf-8")
+	);
+	const asset = report["bundle0.js"];
+	const math = asset.modules.find(module => module.name === "./math.js");
+	expect(math.minimizedSize).toBeGreaterThan(0);
+	expect(math.savedSize).toBeGreaterThan(0);
+});
+
+it("should not emit source maps without devtool", () => {
+	expect(fs.existsSync(path.join(__dirname, "bundle0.js.map"))).toBe(false);
+	const lastLine = fs.readFileSync(__filename, "utf-8").trim().split("\n").pop();
+	expect(lastLine.startsWith("//#")).toBe(false);
+});
@@ -0,0 +1,5 @@
+// The long names and the comment are removed by minification.
+export function multiplyNumbers(firstOperand, secondOperand) {
+	const productOfOperands = firstOperand * secondOperand;
+	return productOfOperands;
+}
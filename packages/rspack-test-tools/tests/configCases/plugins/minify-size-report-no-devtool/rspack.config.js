@@ -0,0 +1,13 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		minimize: true
+	},
+	plugins: [
+		new rspack.SwcJsMinimizerRspackPlugin({
+			sizeReport: true
+		})
+	]
+};
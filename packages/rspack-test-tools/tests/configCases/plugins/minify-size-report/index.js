@@ -0,0 +1,15 @@
+const fs = require("fs");
+const path = require("path");
+import { multiplyNumbers } from "./math";
+
+it("should attribute the minified bytes to modules", () => {
+	expect(multiplyNumbers(2, 3)).toBe(6);
+	const report = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "minify-report.json"), "utf-8")
+	);
+	const asset = report["bundle0.js"];
+	expect(asset.minimizedSize).toBeLessThan(asset.originalSize);
+	const math = asset.modules.find(module => module.name === "./math.js");
+	expect(math.minimizedSize).toBeGreaterThan(0);
+	expect(math.savedSize).toBeGreaterThan(0);
+});
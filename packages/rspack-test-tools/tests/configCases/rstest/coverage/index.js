@@ -0,0 +1,59 @@
+import { compute } from "./src/math";
+import format from "./src/format";
+
+const fs = require("fs");
+const path = require("path");
+
+const findFile = (coverage, name) =>
+	Object.values(coverage).find(file => file.path.endsWith(name));
+const findMath = coverage => findFile(coverage, "math.ts");
+
+it("should count the executed code", () => {
+	expect(compute(1)).toBe(1);
+
+	const coverage = findMath(globalThis.__coverage__);
+	expect(coverage.f).toEqual({ 0: 1, 1: 0 });
+	expect(coverage.b[0]).toEqual([0, 1]);
+	expect(coverage.b[1]).toEqual([0, 0]);
+});
+
+it("should count the exported declarations and the functions with directives", () => {
+	expect(format(1)).toBe("value: 1");
+
+	const coverage = findFile(globalThis.__coverage__, "format.js");
+	expect(coverage.s).toEqual({ 0: 1, 1: 1, 2: 1 });
+	expect(coverage.f).toEqual({ 0: 1 });
+	expect(coverage.statementMap[0].start.line).toBe(2);
+	expect(coverage.statementMap[2].start.line).toBe(9);
+
+	const math = findMath(globalThis.__coverage__);
+	const classify = Object.keys(math.statementMap).find(
+		index => math.statementMap[index].start.line === 12
+	);
+	expect(math.s[classify]).toBe(1);
+});
+
+it("should not instrument excluded modules", () => {
+	const files = Object.keys(globalThis.__coverage__);
+	expect(files.some(file => file.endsWith("index.js"))).toBe(false);
+});
+
+it("should emit the coverage map with the locations of the original source", () => {
+	const coverageMap = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "coverage-map.json"), "utf-8")
+	);
+	const coverage = findMath(coverageMap);
+
+	expect(coverage.fnMap[0].name).toBe("compute");
+	expect(coverage.fnMap[0].line).toBe(5);
+	expect(coverage.fnMap[1].name).toBe("classify");
+	expect(coverage.fnMap[1].line).toBe(12);
+	expect(coverage.branchMap[0].type).toBe("if");
+	expect(coverage.branchMap[0].line).toBe(6);
+	expect(coverage.branchMap[1].type).toBe("cond-expr");
+	expect(coverage.branchMap[1].line).toBe(13);
+
+	const lines = Object.values(coverage.statementMap).map(loc => loc.start.line);
+	expect(lines).toContain(7);
+	expect(lines).toContain(9);
+});
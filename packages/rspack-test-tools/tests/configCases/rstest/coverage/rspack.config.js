@@ -0,0 +1,35 @@
+const { RstestPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	target: "node",
+	devtool: "source-map",
+	resolve: {
+		extensions: ["...", ".ts"]
+	},
+	module: {
+		rules: [
+			{
+				test: /\.ts$/,
+				loader: "builtin:swc-loader",
+				options: {
+					jsc: {
+						parser: {
+							syntax: "typescript"
+						}
+					}
+				}
+			}
+		]
+	},
+	plugins: [
+		new RstestPlugin({
+			injectModulePathName: false,
+			coverage: {
+				include: /src[\\/]/,
+				filename: "coverage-map.json"
+			}
+		})
+	]
+};
@@ -0,0 +1,9 @@
+"use strict"
+export const prefix = "value: ";
+
+export function format(value) {
+	"use strict"
+	return prefix + value;
+}
+
+export default format;
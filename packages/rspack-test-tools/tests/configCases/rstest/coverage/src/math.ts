@@ -0,0 +1,13 @@
+interface Options {
+	double: boolean;
+}
+
+export function compute(value: number, options?: Options): number {
+	if (options?.double) {
+		return value * 2;
+	}
+	return value;
+}
+
+export const classify = (value: number): string =>
+	value > 0 ? "positive" : "negative";
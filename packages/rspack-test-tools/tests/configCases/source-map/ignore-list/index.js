@@ -0,0 +1,16 @@
+import vendor from "vendor";
+
+it("should add node_modules sources to the ignore list", () => {
+	expect(vendor()).toBe("vendor");
+	const fs = require("fs");
+	const map = JSON.parse(fs.readFileSync(__filename + ".map", "utf-8"));
+	const vendorIndex = map.sources.indexOf(
+		"webpack:///./node_modules/vendor/index.js"
+	);
+	expect(vendorIndex).not.toBe(-1);
+	expect(map.ignoreList).toContain(vendorIndex);
+	expect(map.x_google_ignoreList).toEqual(map.ignoreList);
+	expect(map.ignoreList).not.toContain(
+		map.sources.indexOf("webpack:///./index.js")
+	);
+});
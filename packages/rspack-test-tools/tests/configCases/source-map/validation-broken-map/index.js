@@ -0,0 +1,3 @@
+it("should report the mappings out of the range of the original source", () => {
+	expect(require("./broken")).toBe("broken");
+});
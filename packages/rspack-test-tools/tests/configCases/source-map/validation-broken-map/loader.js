@@ -0,0 +1,11 @@
+/** @type {import("@rspack/core").LoaderDefinition} */
+module.exports = function (content) {
+	// maps the first line to the line 20 of a source with a single line
+	this.callback(null, content, {
+		version: 3,
+		sources: [this.resourcePath],
+		sourcesContent: ["// a single line"],
+		names: [],
+		mappings: "AAmBA"
+	});
+};
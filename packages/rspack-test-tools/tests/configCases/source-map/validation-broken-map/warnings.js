@@ -0,0 +1,3 @@
+module.exports = [
+	/Source map 'bundle0\.js\.map' of 'bundle0\.js' has \d+ issue\(s\):[\s\S]*maps out of range to webpack:\/\/\/\.\/broken\.js:20:0/
+];
@@ -0,0 +1,9 @@
+it("should report the assets with a low coverage", () => {
+	const fs = require("fs");
+	const path = require("path");
+	const report = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "source-map-report.json"), "utf-8")
+	);
+	expect(report["bundle0.js"].issues).toEqual([]);
+	expect(report["bundle0.js"].coverage).toBeLessThan(0.99);
+});
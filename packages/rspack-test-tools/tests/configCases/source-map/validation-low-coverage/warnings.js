@@ -0,0 +1,3 @@
+module.exports = [
+	/Source map 'bundle0\.js\.map' of 'bundle0\.js' covers \d+\.\d{2}% of the generated code, expected at least 99\.00%\./
+];
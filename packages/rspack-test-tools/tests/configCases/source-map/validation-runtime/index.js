@@ -0,0 +1,25 @@
+it("should report the mappings into the runtime modules loading async chunks", async () => {
+	const fs = require("fs");
+	const path = require("path");
+	const { value } = await import(/* webpackChunkName: "async" */ "./async");
+	expect(value).toBe("async");
+
+	const report = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "source-map-report.json"), "utf-8")
+	);
+	const map = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "bundle0.js.map"), "utf-8")
+	);
+	expect(map.sources.some(source => /webpack\/runtime\//.test(source))).toBe(
+		true
+	);
+	expect(report["bundle0.js"].issues.length).toBeGreaterThan(0);
+	for (const issue of report["bundle0.js"].issues) {
+		expect(issue).toMatch(/^\d+ mapping\(s\) into the runtime code '.*webpack\/runtime\//);
+	}
+	expect(report["async.js"].issues).toEqual([]);
+	for (const file of ["bundle0.js", "async.js"]) {
+		expect(report[file].coverage).toBeGreaterThan(0);
+		expect(report[file].coverage).toBeLessThanOrEqual(1);
+	}
+});
@@ -0,0 +1,16 @@
+const { SourceMapValidationPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: "source-map",
+	output: {
+		chunkFilename: "[name].js"
+	},
+	plugins: [
+		new SourceMapValidationPlugin({
+			// the bootstrap of such a small bundle isn't mapped
+			minCoverage: 0,
+			filename: "source-map-report.json"
+		})
+	]
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	/Source map 'bundle0\.js\.map' of 'bundle0\.js' has \d+ issue\(s\):\n  - \d+ mapping\(s\) into the runtime code '.*webpack\/runtime\//
+];
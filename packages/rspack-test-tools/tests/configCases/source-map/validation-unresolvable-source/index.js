@@ -0,0 +1,3 @@
+it("should report the sources that can't be resolved", () => {
+	expect(require("./file")).toBe("file");
+});
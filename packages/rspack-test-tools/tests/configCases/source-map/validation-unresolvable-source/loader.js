@@ -0,0 +1,12 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").LoaderDefinition} */
+module.exports = function (content) {
+	// maps to a file that doesn't exist, without its content
+	this.callback(null, content, {
+		version: 3,
+		sources: [path.join(__dirname, "missing.js")],
+		names: [],
+		mappings: "AAAA"
+	});
+};
@@ -0,0 +1,20 @@
+const { SourceMapValidationPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: "source-map",
+	module: {
+		rules: [
+			{
+				test: /file\.js$/,
+				loader: "./loader.js"
+			}
+		]
+	},
+	plugins: [
+		new SourceMapValidationPlugin({
+			// the bootstrap of such a small bundle isn't mapped
+			minCoverage: 0
+		})
+	]
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	/Source map 'bundle0\.js\.map' of 'bundle0\.js' has \d+ issue\(s\):[\s\S]*source 'webpack:\/\/\/\.\/missing\.js' can't be resolved, it has no sourcesContent and names no file/
+];
@@ -0,0 +1,12 @@
+it("should report the coverage of emitted source maps", () => {
+	const fs = require("fs");
+	const path = require("path");
+	const report = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "source-map-report.json"), "utf-8")
+	);
+	const bundle = report["bundle0.js"];
+	expect(bundle.sourceMap).toBe("bundle0.js.map");
+	expect(bundle.issues).toEqual([]);
+	expect(bundle.coverage).toBeGreaterThan(0);
+	expect(bundle.coverage).toBeLessThanOrEqual(1);
+});
@@ -0,0 +1,17 @@
+import helper from "@babel/helper";
+import gpl from "gpl-lib";
+
+it("should split the packages by their package.json", () => {
+	expect(helper).toBe("helper");
+	expect(gpl).toBe("gpl");
+
+	const { chunks, modules } = __STATS__;
+	const chunkOf = name => {
+		const module = modules.find(module => module.name.includes(name));
+		const [id] = module.chunks;
+		return chunks.find(chunk => chunk.id === id);
+	};
+	expect(chunkOf("@babel/helper").names).toEqual(["babel"]);
+	expect(chunkOf("gpl-lib").names).toEqual(["gpl"]);
+	expect(chunkOf("./index.js").names).toEqual(["main"]);
+});
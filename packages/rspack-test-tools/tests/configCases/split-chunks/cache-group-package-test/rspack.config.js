@@ -0,0 +1,26 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	target: "node",
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			chunks: "all",
+			minSize: 0,
+			cacheGroups: {
+				default: false,
+				defaultVendors: false,
+				babel: {
+					test: { package: { name: "@babel/*", source: "node_modules" } },
+					name: "babel"
+				},
+				gpl: {
+					test: { package: { license: "GPL*" } },
+					name: "gpl"
+				}
+			}
+		}
+	}
+};
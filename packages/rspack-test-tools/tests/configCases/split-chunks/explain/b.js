@@ -0,0 +1,4 @@
+import shared from "./shared";
+import limited from "./limited";
+
+export default `b ${shared} ${limited}`;
@@ -0,0 +1,26 @@
+it("should explain the split chunks in stats", async () => {
+	const [{ default: a }, { default: b }] = await Promise.all([
+		import(/* webpackChunkName: "a" */ "./a"),
+		import(/* webpackChunkName: "b" */ "./b")
+	]);
+	expect(a).toBe("a shared limited");
+	expect(b).toBe("b shared limited");
+
+	const { chunks } = __STATS__;
+	const shared = chunks.find(chunk => chunk.names.includes("shared"));
+	expect(shared.splitChunks.decisions).toEqual([
+		expect.objectContaining({
+			cacheGroup: "shared",
+			name: "shared",
+			modules: 1,
+			reusedExistingChunk: false
+		})
+	]);
+
+	for (const name of ["a", "b"]) {
+		const chunk = chunks.find(chunk => chunk.names.includes(name));
+		expect(chunk.splitChunks.blocked).toContainEqual(
+			expect.stringContaining('cache group "limited" kept its modules')
+		);
+	}
+});
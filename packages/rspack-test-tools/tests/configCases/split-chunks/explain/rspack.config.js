@@ -0,0 +1,30 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	target: "node",
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			explain: true,
+			minSize: 0,
+			cacheGroups: {
+				default: false,
+				defaultVendors: false,
+				shared: {
+					test: /shared\.js/,
+					chunks: "async",
+					minChunks: 2,
+					name: "shared"
+				},
+				limited: {
+					test: /limited\.js/,
+					chunks: "async",
+					minChunks: 2,
+					maxAsyncRequests: 1
+				}
+			}
+		}
+	}
+};
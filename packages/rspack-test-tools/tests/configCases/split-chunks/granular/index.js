@@ -0,0 +1,19 @@
+import react from "react";
+import utils from "@scope/utils";
+import app from "./app";
+
+it("should split the chunks by how often they change", () => {
+	expect(react).toBe("react");
+	expect(utils).toBe("utils");
+	expect(app).toBe("app");
+
+	const { chunks, modules } = __STATS__;
+	const chunkOf = name => {
+		const module = modules.find(module => module.name.includes(name));
+		const [id] = module.chunks;
+		return chunks.find(chunk => chunk.id === id);
+	};
+	expect(chunkOf("react").names).toEqual(["framework"]);
+	expect(chunkOf("@scope/utils").names).toEqual(["lib-scope-utils"]);
+	expect(chunkOf("./app.js").names).toEqual(["main"]);
+});
@@ -0,0 +1,14 @@
+const { optimize } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	target: "node",
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: false
+	},
+	plugins: [new optimize.GranularChunksPlugin({ minSize: 0 })]
+};
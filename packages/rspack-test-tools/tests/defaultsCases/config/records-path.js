@@ -3,5 +3,14 @@ module.exports = {
 	description: "records",
 	options: () => ({ recordsPath: "some-path" }),
 	diff: e =>
-		e.toMatchInlineSnapshot(`Compared values have no visual difference.`)
+		e.toMatchInlineSnapshot(`
+		- Expected
+		+ Received
+
+		@@ ... @@
+		-   "recordsInputPath": false,
+		-   "recordsOutputPath": false,
+		+   "recordsInputPath": "some-path",
+		+   "recordsOutputPath": "some-path",
+	`)
 };
@@ -0,0 +1,5 @@
+function fail(message) {
+	throw new Error(message);
+}
+
+fail(process.env.NODE_ENV);
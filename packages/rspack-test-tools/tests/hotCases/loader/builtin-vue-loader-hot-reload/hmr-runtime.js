@@ -0,0 +1,23 @@
+const records = new Map();
+export const calls = [];
+
+globalThis.__VUE_HMR_RUNTIME__ = {
+	createRecord(id, component) {
+		calls.push(["createRecord", id]);
+		if (records.has(id)) return false;
+		records.set(id, component);
+		return true;
+	},
+	rerender(id, render) {
+		calls.push(["rerender", id]);
+		records.get(id).render = render;
+	},
+	reload(id, component) {
+		calls.push(["reload", id]);
+		records.set(id, component);
+	}
+};
+
+export function getRecord(id) {
+	return records.get(id);
+}
@@ -0,0 +1,31 @@
+import { calls, getRecord } from "./hmr-runtime";
+import App from "./App.vue";
+
+it("should rerender template updates and reload script updates", done => {
+	const id = App.__hmrId;
+	const render = App.render;
+	expect(calls).toEqual([["createRecord", id]]);
+	NEXT(
+		require("../../update")(done, true, () => {
+			// only the template module is updated, the component is kept
+			expect(calls).toEqual([
+				["createRecord", id],
+				["rerender", id]
+			]);
+			expect(getRecord(id)).toBe(App);
+			expect(App.render).not.toBe(render);
+			NEXT(
+				require("../../update")(done, true, () => {
+					expect(calls.slice(2)).toEqual([
+						["createRecord", id],
+						["reload", id]
+					]);
+					const component = getRecord(id);
+					expect(component).not.toBe(App);
+					expect(component.data().message).toBe("second");
+					done();
+				})
+			);
+		})
+	);
+});
@@ -0,0 +1,19 @@
+const { VueLoaderRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.vue$/,
+				loader: "builtin:vue-loader"
+			}
+		]
+	},
+	// The render functions are never called, and the development build of Vue
+	// would replace the recording `__VUE_HMR_RUNTIME__` of `hmr-runtime.js`.
+	externals: {
+		vue: "{}"
+	},
+	plugins: [new VueLoaderRspackPlugin()]
+};
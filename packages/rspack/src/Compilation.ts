@@ -769,6 +769,17 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 		return this.#inner.getAssetPathWithInfo(filename, pathData);
 	}
 
+	/**
+	 * Maps the frames of a stack trace back to the original sources with the
+	 * source maps emitted by this compilation.
+	 *
+	 * Note: only the source maps of `SourceMapDevToolPlugin` are used, eval
+	 * source maps are not supported.
+	 */
+	symbolicateStackTrace(stack: string): string {
+		return this.#inner.symbolicateStackTrace(stack);
+	}
+
 	getLogger(name: string | (() => string)) {
 		if (!name) {
 			throw new TypeError("Compilation.getLogger(name) called without a name");
@@ -45,6 +45,45 @@ export type SwcLoaderOptions = Config & {
 	 */
 	rspackExperiments?: {
 		import?: PluginImportOptions;
+		/**
+		 * Add labels and component selector targets for emotion.
+		 */
+		emotion?:
+			| boolean
+			| {
+					autoLabel?: "never" | "dev-only" | "always";
+					labelFormat?: string;
+			  };
+		/**
+		 * Add `displayName` and `componentId` for styled-components.
+		 */
+		styledComponents?:
+			| boolean
+			| {
+					displayName?: boolean;
+					ssr?: boolean;
+					fileName?: boolean;
+					meaninglessFileNames?: string[];
+					namespace?: string;
+			  };
+		/**
+		 * Replace relay `graphql` tagged templates with their artifacts.
+		 */
+		relay?:
+			| boolean
+			| {
+					artifactDirectory?: string;
+					language?: "javascript" | "typescript" | "flow";
+					eagerEsModules?: boolean;
+			  };
+		/**
+		 * Remove the calls of `console`, except the methods in `exclude`.
+		 */
+		removeConsole?:
+			| boolean
+			| {
+					exclude?: string[];
+			  };
 	};
 };
 
@@ -473,7 +512,46 @@ export const ZodSwcLoaderOptions = ZodSwcConfig.extend({
 	isModule: z.boolean().or(z.literal("unknown")).optional(),
 	rspackExperiments: z
 		.strictObject({
-			import: ZodSwcPluginImportConfig.optional()
+			import: ZodSwcPluginImportConfig.optional(),
+			emotion: z
+				.boolean()
+				.or(
+					z.strictObject({
+						autoLabel: z.enum(["never", "dev-only", "always"]).optional(),
+						labelFormat: z.string().optional()
+					})
+				)
+				.optional(),
+			styledComponents: z
+				.boolean()
+				.or(
+					z.strictObject({
+						displayName: z.boolean().optional(),
+						ssr: z.boolean().optional(),
+						fileName: z.boolean().optional(),
+						meaninglessFileNames: z.string().array().optional(),
+						namespace: z.string().optional()
+					})
+				)
+				.optional(),
+			relay: z
+				.boolean()
+				.or(
+					z.strictObject({
+						artifactDirectory: z.string().optional(),
+						language: z.enum(["javascript", "typescript", "flow"]).optional(),
+						eagerEsModules: z.boolean().optional()
+					})
+				)
+				.optional(),
+			removeConsole: z
+				.boolean()
+				.or(
+					z.strictObject({
+						exclude: z.string().array().optional()
+					})
+				)
+				.optional()
 		})
 		.optional()
 }) satisfies z.ZodType<SwcLoaderOptions>;
@@ -0,0 +1,37 @@
+import {
+	BuiltinPluginName,
+	type RawCriticalCssPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export interface CriticalCssPluginOptions {
+	/**
+	 * How the full stylesheet is loaded after its critical rules are inlined.
+	 * - `media`: `<link media="print" onload="this.media='all'">`
+	 * - `swap`: `<link rel="preload" as="style" onload="this.rel='stylesheet'">`
+	 * - `none`: keep the render-blocking `<link>`
+	 * @default "media"
+	 */
+	preload?: "media" | "swap" | "none";
+	/**
+	 * Keep the original `<link>` in a `<noscript>` element.
+	 * @default true
+	 */
+	noscriptFallback?: boolean;
+	/**
+	 * Minify the inlined css.
+	 * @default true
+	 */
+	minify?: boolean;
+}
+
+/**
+ * Inlines the css rules used by the static markup of each page emitted by
+ * `HtmlRspackPlugin` into a `<style>` tag and loads the rest asynchronously.
+ */
+export const CriticalCssPlugin = create(
+	BuiltinPluginName.CriticalCssPlugin,
+	(options: CriticalCssPluginOptions = {}): RawCriticalCssPluginOptions =>
+		options
+);
@@ -0,0 +1,26 @@
+import {
+	BuiltinPluginName,
+	type RawCssModulesTypingsPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export interface CssModulesTypingsPluginOptions {
+	/**
+	 * Emit a single json asset with this filename that maps each css module to
+	 * its exports, instead of writing a `.d.ts` next to each css module.
+	 */
+	manifest?: string;
+}
+
+/**
+ * Generates TypeScript declarations for the exports of css modules, named after
+ * the configured `exportsConvention`. Declarations are only rewritten when the
+ * exports change.
+ */
+export const CssModulesTypingsPlugin = create(
+	BuiltinPluginName.CssModulesTypingsPlugin,
+	(
+		options: CssModulesTypingsPluginOptions = {}
+	): RawCssModulesTypingsPluginOptions => options
+);
@@ -0,0 +1,29 @@
+import {
+	BuiltinPluginName,
+	type RawGranularChunksPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export type GranularChunksPluginOptions = {
+	/** The number of chunks an entry or an async chunk is split into at most, defaults to `25`. */
+	maxChunks?: number;
+	/** Globs of the packages put into the `framework` chunk, defaults to `["react", "react-dom", "scheduler"]`. */
+	frameworkPackages?: string[];
+	/** The minimum size of a split chunk in bytes, defaults to `20000`. */
+	minSize?: number;
+	/** The chunks bigger than this are split into parts of similar sizes, defaults to `250000`. */
+	maxSize?: number;
+};
+
+/**
+ * Splits the chunks into many small chunks grouped by how often they change,
+ * for HTTP/2. It replaces `optimization.splitChunks`, which should be `false`.
+ */
+export const GranularChunksPlugin = create(
+	BuiltinPluginName.GranularChunksPlugin,
+	(
+		options: GranularChunksPluginOptions = {}
+	): RawGranularChunksPluginOptions => ({ ...options }),
+	"compilation"
+);
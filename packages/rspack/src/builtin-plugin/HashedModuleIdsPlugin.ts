@@ -0,0 +1,41 @@
+import {
+	BuiltinPluginName,
+	type RawHashedModuleIdsPluginOptions
+} from "@rspack/binding";
+import { z } from "zod";
+
+import { validate } from "../util/validate";
+import { create } from "./base";
+
+export type HashedModuleIdsPluginOptions = {
+	/** The context the module names are hashed relative to, defaults to the compiler context. */
+	context?: string;
+	/** The hash function, defaults to `"md4"`. */
+	hashFunction?: "md4" | "xxhash64";
+	/** The encoding of the hash, defaults to `"base64"`. */
+	hashDigest?: "hex" | "base64";
+	/** The minimum length of the ids, defaults to `4`. */
+	hashDigestLength?: number;
+	/** Mixed into the hash, so compilers sharing a page don't share ids. */
+	salt?: string;
+};
+
+const hashedModuleIdsPluginOptions = z.strictObject({
+	context: z.string().optional(),
+	hashFunction: z.enum(["md4", "xxhash64"]).optional(),
+	hashDigest: z.enum(["hex", "base64"]).optional(),
+	hashDigestLength: z.number().int().min(1).optional(),
+	salt: z.string().optional()
+}) satisfies z.ZodType<HashedModuleIdsPluginOptions>;
+
+export const HashedModuleIdsPlugin = create(
+	BuiltinPluginName.HashedModuleIdsPlugin,
+	(
+		options: HashedModuleIdsPluginOptions = {}
+	): RawHashedModuleIdsPluginOptions => {
+		validate(options, hashedModuleIdsPluginOptions);
+
+		return { ...options };
+	},
+	"compilation"
+);
@@ -117,6 +117,32 @@ const defaultHttpClient = async (
 	};
 };
 
+export function getRawHttpUriPluginOptions(
+	compiler: Compiler,
+	options: HttpUriPluginOptions
+): RawHttpUriPluginOptions {
+	const lockfileLocation =
+		options.lockfileLocation ??
+		path.join(
+			compiler.context,
+			compiler.name ? `${compiler.name}.rspack.lock` : "rspack.lock"
+		);
+	const cacheLocation =
+		options.cacheLocation === false
+			? undefined
+			: (options.cacheLocation ?? `${lockfileLocation}.data`);
+
+	return {
+		allowedUris: options.allowedUris,
+		lockfileLocation,
+		cacheLocation,
+		upgrade: options.upgrade ?? false,
+		// frozen: options.frozen,
+		// proxy: options.proxy,
+		httpClient: options.httpClient ?? defaultHttpClient
+	};
+}
+
 /**
  * Plugin that allows loading modules from HTTP URLs
  */
@@ -129,27 +155,9 @@ export class HttpUriPlugin extends RspackBuiltinPlugin {
 	}
 
 	raw(compiler: Compiler): BuiltinPlugin | undefined {
-		const { options } = this;
-		const lockfileLocation =
-			options.lockfileLocation ??
-			path.join(
-				compiler.context,
-				compiler.name ? `${compiler.name}.rspack.lock` : "rspack.lock"
-			);
-		const cacheLocation =
-			options.cacheLocation === false
-				? undefined
-				: (options.cacheLocation ?? `${lockfileLocation}.data`);
-
-		const raw: RawHttpUriPluginOptions = {
-			allowedUris: options.allowedUris,
-			lockfileLocation,
-			cacheLocation,
-			upgrade: options.upgrade ?? false,
-			// frozen: options.frozen,
-			// proxy: options.proxy,
-			httpClient: options.httpClient ?? defaultHttpClient
-		};
-		return createBuiltinPlugin(this.name, raw);
+		return createBuiltinPlugin(
+			this.name,
+			getRawHttpUriPluginOptions(compiler, this.options)
+		);
 	}
 }
@@ -0,0 +1,11 @@
+import {
+	BuiltinPluginName,
+	type RawRecordIdsPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export const RecordIdsPlugin = create(
+	BuiltinPluginName.RecordIdsPlugin,
+	(options: RawRecordIdsPluginOptions) => ({ ...options })
+);
@@ -0,0 +1,26 @@
+import {
+	BuiltinPluginName,
+	type RawSourceMapValidationPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export type SourceMapValidationPluginOptions = {
+	/**
+	 * Warn about assets whose ratio of mapped generated code is lower, from 0 to 1.
+	 * @default 0.5
+	 */
+	minCoverage?: number;
+	/**
+	 * Emit the per-asset coverage and issues as a JSON asset with this filename.
+	 */
+	filename?: string;
+};
+
+export const SourceMapValidationPlugin = create(
+	BuiltinPluginName.SourceMapValidationPlugin,
+	(
+		options: SourceMapValidationPluginOptions = {}
+	): RawSourceMapValidationPluginOptions => options,
+	"compilation"
+);
@@ -13,6 +13,7 @@ import type { Compiler } from "../Compiler";
 import type { Module } from "../Module";
 import type {
 	OptimizationSplitChunksCacheGroup,
+	OptimizationSplitChunksCacheGroupPackageTest,
 	OptimizationSplitChunksOptions
 } from "../config";
 import { JsSplitChunkSizes } from "../util/SplitChunkSize";
@@ -59,7 +60,12 @@ function toRawSplitChunksOptions(
 		return name;
 	}
 
-	function getTest(test: OptimizationSplitChunksCacheGroup["test"]) {
+	function getTest(
+		test: Exclude<
+			OptimizationSplitChunksCacheGroup["test"],
+			OptimizationSplitChunksCacheGroupPackageTest
+		>
+	) {
 		if (typeof test === "function") {
 			return (ctx: JsCacheGroupTestCtx) => {
 				// chunk graph and module graph should all exist in the optimizeChunks stage
@@ -114,9 +120,12 @@ function toRawSplitChunksOptions(
 					maxInitialSize,
 					...passThrough
 				} = group;
+				const isPackageTest =
+					typeof test === "object" && !(test instanceof RegExp);
 				const rawGroup: RawCacheGroupOptions = {
 					key,
-					test: getTest(test),
+					test: isPackageTest ? undefined : getTest(test),
+					packageTest: isPackageTest ? test.package : undefined,
 					name: getName(name),
 					chunks: getChunks(chunks),
 					minSize: JsSplitChunkSizes.__to_binding(minSize),
@@ -28,7 +28,25 @@ export type SwcJsMinimizerRspackPluginOptions = {
 		mangle?: TerserMangleOptions | boolean;
 		format?: JsFormatOptions & ToSnakeCaseProperties<JsFormatOptions>;
 		module?: boolean;
+		/**
+		 * Rename the properties matching `regex` consistently across all chunks.
+		 */
+		mangleProperties?: {
+			regex: RegExp;
+			reserved?: string[];
+			/**
+			 * Path of the JSON file that keeps the mangled names stable across
+			 * builds, relative to `context`.
+			 */
+			nameCache?: string;
+		};
 	};
+	/**
+	 * Emit a JSON asset attributing the minified bytes of each asset to its
+	 * modules. `true` emits `minify-report.json`. Modules get source maps for
+	 * the report even without `devtool`.
+	 */
+	sizeReport?: boolean | string;
 };
 
 /**
@@ -300,8 +318,13 @@ export const SwcJsMinimizerRspackPlugin = create(
 				mangle,
 				format,
 				minify: options?.minimizerOptions?.minify,
-				module: options?.minimizerOptions?.module
-			}
+				module: options?.minimizerOptions?.module,
+				mangleProperties: options?.minimizerOptions?.mangleProperties
+			},
+			sizeReport:
+				options?.sizeReport === true
+					? "minify-report.json"
+					: options?.sizeReport || undefined
 		};
 	},
 	"compilation"
@@ -29,6 +29,8 @@ export * from "./ExternalsPlugin";
 export * from "./FileUriPlugin";
 export * from "./FlagDependencyExportsPlugin";
 export * from "./FlagDependencyUsagePlugin";
+export * from "./GranularChunksPlugin";
+export * from "./HashedModuleIdsPlugin";
 export * from "./HotModuleReplacementPlugin";
 export * from "./html-plugin/index";
 export * from "./HttpExternalsRspackPlugin";
@@ -54,12 +56,14 @@ export * from "./ProgressPlugin";
 export * from "./RstestPlugin";
 export * from "./ProvidePlugin";
 export * from "./RealContentHashPlugin";
+export * from "./RecordIdsPlugin";
 export * from "./RemoveEmptyChunksPlugin";
 export * from "./RuntimeChunkPlugin";
 export * from "./RuntimePlugin";
 export * from "./SideEffectsFlagPlugin";
 export * from "./SizeLimitsPlugin";
 export * from "./SourceMapDevToolPlugin";
+export * from "./SourceMapValidationPlugin";
 export * from "./SplitChunksPlugin";
 export * from "./LightningCssMinimizerRspackPlugin";
 export * from "./RemoveDuplicateModulesPlugin";
@@ -78,3 +82,6 @@ export * from "./RsdoctorPlugin";
 export * from "./SubresourceIntegrityPlugin";
 export * from "./ModuleInfoHeaderPlugin";
 export * from "./CssChunkingPlugin";
+export * from "./CriticalCssPlugin";
+export * from "./CssModulesTypingsPlugin";
+export * from "./VueLoaderRspackPlugin";
@@ -54,7 +54,9 @@ import type {
 	AssetParserOptions,
 	AssetResourceGeneratorOptions,
 	CssAutoGeneratorOptions,
+	CssAutoParserOptions,
 	CssGeneratorOptions,
+	CssModuleParserOptions,
 	CssParserOptions,
 	GeneratorOptionsByModuleType,
 	JavascriptParserOptions,
@@ -610,11 +612,13 @@ function getRawAssetParserDataUrl(
 }
 
 function getRawCssParserOptions(
-	parser: CssParserOptions
+	parser: CssParserOptions | CssAutoParserOptions | CssModuleParserOptions
 ): RawCssParserOptions | RawCssAutoParserOptions | RawCssModuleParserOptions {
 	return {
 		namedExports: parser.namedExports,
-		url: parser.url
+		url: parser.url,
+		warnUnusedClasses:
+			"warnUnusedClasses" in parser ? parser.warnUnusedClasses : undefined
 	};
 }
 
@@ -447,6 +447,19 @@ const getSwcLoaderOptions: GetLoaderOptions = (options, _) => {
 					rspackExperiments.import || rspackExperiments.pluginImport
 				);
 			}
+			// `true` enables a transform with its default options
+			for (const transform of [
+				"emotion",
+				"styledComponents",
+				"relay",
+				"removeConsole"
+			]) {
+				if (rspackExperiments[transform] === true) {
+					rspackExperiments[transform] = {};
+				} else if (rspackExperiments[transform] === false) {
+					delete rspackExperiments[transform];
+				}
+			}
 		}
 	}
 	return options;
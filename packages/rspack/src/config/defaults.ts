@@ -84,6 +84,8 @@ export const applyRspackOptionsDefaults = (
 	D(options, "profile", false);
 	// IGNORE(bail): bail is default to false in webpack, but it's set in `Compilation`
 	D(options, "bail", false);
+	D(options, "recordsInputPath", false);
+	D(options, "recordsOutputPath", false);
 
 	// IGNORE(cache): cache is default to { type: "memory" } in webpack when the mode is development,
 	// but Rspack currently does not support this option
@@ -809,6 +811,12 @@ const applyOutputDefaults = (
 		D(trustedTypes, "onPolicyCreationFailure", "stop");
 	}
 
+	const { importMap } = output;
+	if (importMap) {
+		D(importMap, "filename", "importmap.json");
+		F(importMap, "prefix", () => `${output.uniqueName || "rspack"}-chunk/`);
+	}
+
 	const forEachEntry = (fn: (desc: EntryDescriptionNormalized) => void) => {
 		if (typeof entry === "function") {
 			return;
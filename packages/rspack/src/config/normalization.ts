@@ -51,6 +51,7 @@ import type {
 	HotUpdateMainFilename,
 	Iife,
 	ImportFunctionName,
+	ImportMap,
 	ImportMetaName,
 	Incremental,
 	IncrementalPresets,
@@ -71,6 +72,7 @@ import type {
 	Plugins,
 	Profile,
 	PublicPath,
+	RecordsPath,
 	Resolve,
 	RspackFutureOptions,
 	RspackOptions,
@@ -222,7 +224,10 @@ export const getNormalizedRspackOptions = (
 				chunkLoadTimeout: output.chunkLoadTimeout,
 				charset: output.charset,
 				environment: cloneObject(output.environment),
-				compareBeforeEmit: output.compareBeforeEmit
+				compareBeforeEmit: output.compareBeforeEmit,
+				importMap: optionalNestedConfig(output.importMap, importMap =>
+					importMap === true ? {} : { ...importMap }
+				)
 			};
 		}),
 		resolve: nestedConfig(config.resolve, resolve => ({
@@ -362,7 +367,15 @@ export const getNormalizedRspackOptions = (
 		devServer: config.devServer,
 		profile: config.profile,
 		amd: config.amd,
-		bail: config.bail
+		bail: config.bail,
+		recordsInputPath:
+			config.recordsInputPath !== undefined
+				? config.recordsInputPath
+				: config.recordsPath,
+		recordsOutputPath:
+			config.recordsOutputPath !== undefined
+				? config.recordsOutputPath
+				: config.recordsPath
 	};
 };
 
@@ -585,6 +598,7 @@ export interface OutputNormalized {
 	charset?: boolean;
 	chunkLoadTimeout?: number;
 	compareBeforeEmit?: boolean;
+	importMap?: ImportMap;
 }
 
 export interface ModuleOptionsNormalized {
@@ -675,4 +689,6 @@ export interface RspackOptionsNormalized {
 	profile?: Profile;
 	amd?: Amd;
 	bail?: Bail;
+	recordsInputPath?: RecordsPath;
+	recordsOutputPath?: RecordsPath;
 }
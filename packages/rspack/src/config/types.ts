@@ -109,6 +109,7 @@ export type LibraryType =
 	| string
 	| "var"
 	| "module"
+	| "dual"
 	| "assign"
 	| "assign-properties"
 	| "this"
@@ -334,6 +335,20 @@ export type TrustedTypes = {
 	onPolicyCreationFailure?: "continue" | "stop";
 };
 
+/** Imports the ESM chunks with bare specifiers resolved by an import map, so a chunk keeps its hash when the chunks it imports change. */
+export type ImportMap = {
+	/**
+	 * The filename of the emitted import map, it is also injected into the HTML by `HtmlRspackPlugin`.
+	 * @default 'importmap.json'
+	 */
+	filename?: string;
+	/**
+	 * The prefix of the specifiers, followed by the chunk id.
+	 * @default `${output.uniqueName}-chunk/`
+	 */
+	prefix?: string;
+};
+
 /** The encoding to use when generating the hash. */
 export type HashDigest = string;
 
@@ -553,6 +568,12 @@ export type Output = {
 	 */
 	importMetaName?: ImportMetaName;
 
+	/**
+	 * Emit an import map for the ESM chunks, which import each other by their specifiers in it.
+	 * Only works with `output.chunkFormat: 'module'`.
+	 */
+	importMap?: true | ImportMap;
+
 	/**
 	 * Tells Rspack to add IIFE wrapper around emitted code.
 	 * @default true
@@ -963,6 +984,7 @@ export type AssetParserOptions = {
 
 export type CssParserNamedExports = boolean;
 export type CssParserUrl = boolean;
+export type CssParserWarnUnusedClasses = boolean;
 
 /** Options object for `css` modules. */
 export type CssParserOptions = {
@@ -992,6 +1014,12 @@ export type CssAutoParserOptions = {
 	 * @default true
 	 * */
 	url?: CssParserUrl;
+
+	/**
+	 * Warn about the local classes that are never imported, requires `optimization.usedExports`.
+	 * @default false
+	 * */
+	warnUnusedClasses?: CssParserWarnUnusedClasses;
 };
 
 /** Options object for `css/module` modules. */
@@ -1007,6 +1035,12 @@ export type CssModuleParserOptions = {
 	 * @default true
 	 * */
 	url?: CssParserUrl;
+
+	/**
+	 * Warn about the local classes that are never imported, requires `optimization.usedExports`.
+	 * @default false
+	 * */
+	warnUnusedClasses?: CssParserWarnUnusedClasses;
 };
 
 type ExportsPresence = "error" | "warn" | "auto" | false;
@@ -2244,10 +2278,26 @@ export type OptimizationSplitChunksCacheGroupTestFn = (
 	}
 ) => boolean;
 
+/** Selects the modules by the `package.json` they are resolved with. */
+export type OptimizationSplitChunksCacheGroupPackageTest = {
+	package: {
+		/** Glob of the package name, e.g. `@babel/*`. */
+		name?: string;
+		/** Glob of the package license, e.g. `MIT` or `*GPL*`. */
+		license?: string;
+		/** Whether the package is installed in `node_modules` or is part of the workspace. */
+		source?: "node_modules" | "workspace";
+	};
+};
+
 /** How to splitting chunks. */
 export type OptimizationSplitChunksCacheGroup = {
 	/** Controls which modules are selected by this cache group. */
-	test?: string | RegExp | OptimizationSplitChunksCacheGroupTestFn;
+	test?:
+		| string
+		| RegExp
+		| OptimizationSplitChunksCacheGroupTestFn
+		| OptimizationSplitChunksCacheGroupPackageTest;
 
 	/**
 	 * A module can belong to multiple cache groups.
@@ -2304,6 +2354,12 @@ export type OptimizationSplitChunksOptions = {
 	 * The value is `false` in development mode.
 	 * */
 	hidePathInfo?: boolean;
+
+	/**
+	 * Records why each chunk was split, exposed as `splitChunks` of the chunks in stats.
+	 * @default false
+	 * */
+	explain?: boolean;
 } & SharedOptimizationSplitChunksCacheGroup;
 
 export type Optimization = {
@@ -2793,6 +2849,14 @@ export type Amd = false | Record<string, any>;
 export type Bail = boolean;
 //#endregion
 
+//#region Records
+/**
+ * The absolute path of a JSON file storing the ids of the modules and chunks,
+ * which are reused by the next build to keep them stable. `false` to disable.
+ */
+export type RecordsPath = string | false;
+//#endregion
+
 //#region Performance
 /** Options to control how Rspack notifies you of assets and entry points that exceed a specific file limit.   */
 export type Performance =
@@ -2950,6 +3014,18 @@ export type RspackOptions = {
 	 * Performance optimization options.
 	 */
 	performance?: Performance;
+	/**
+	 * Where the ids of the modules and chunks are read from and written to.
+	 */
+	recordsPath?: RecordsPath;
+	/**
+	 * Where the ids of the previous build are read from, defaults to `recordsPath`.
+	 */
+	recordsInputPath?: RecordsPath;
+	/**
+	 * Where the ids of this build are written to, defaults to `recordsPath`.
+	 */
+	recordsOutputPath?: RecordsPath;
 };
 
 /** Configuration for Rspack */
@@ -108,6 +108,7 @@ const libraryType = z
 	.enum([
 		"var",
 		"module",
+		"dual",
 		"assign",
 		"assign-properties",
 		"this",
@@ -270,6 +271,11 @@ const trustedTypes = z.strictObject({
 	onPolicyCreationFailure: z.enum(["continue", "stop"]).optional()
 }) satisfies z.ZodType<t.TrustedTypes>;
 
+const importMap = z.strictObject({
+	filename: z.string().optional(),
+	prefix: z.string().optional()
+}) satisfies z.ZodType<t.ImportMap>;
+
 const hashDigest = z.string() satisfies z.ZodType<t.HashDigest>;
 
 const hashDigestLength = z.number() satisfies z.ZodType<t.HashDigestLength>;
@@ -364,7 +370,8 @@ const output = z.strictObject({
 	chunkLoadTimeout: z.number().optional(),
 	charset: z.boolean().optional(),
 	environment: environment.optional(),
-	compareBeforeEmit: z.boolean().optional()
+	compareBeforeEmit: z.boolean().optional(),
+	importMap: z.literal(true).or(importMap).optional()
 }) satisfies z.ZodType<t.Output>;
 //#endregion
 
@@ -605,6 +612,9 @@ const cssParserNamedExports =
 
 const cssParserUrl = z.boolean() satisfies z.ZodType<t.CssParserUrl>;
 
+const cssParserWarnUnusedClasses =
+	z.boolean() satisfies z.ZodType<t.CssParserWarnUnusedClasses>;
+
 const cssParserOptions = z.strictObject({
 	namedExports: cssParserNamedExports.optional(),
 	url: cssParserUrl.optional()
@@ -612,12 +622,14 @@ const cssParserOptions = z.strictObject({
 
 const cssAutoParserOptions = z.strictObject({
 	namedExports: cssParserNamedExports.optional(),
-	url: cssParserUrl.optional()
+	url: cssParserUrl.optional(),
+	warnUnusedClasses: cssParserWarnUnusedClasses.optional()
 }) satisfies z.ZodType<t.CssAutoParserOptions>;
 
 const cssModuleParserOptions = z.strictObject({
 	namedExports: cssParserNamedExports.optional(),
-	url: cssParserUrl.optional()
+	url: cssParserUrl.optional(),
+	warnUnusedClasses: cssParserWarnUnusedClasses.optional()
 }) satisfies z.ZodType<t.CssModuleParserOptions>;
 
 const dynamicImportMode = z.enum(["eager", "lazy", "weak", "lazy-once"]);
@@ -1208,7 +1220,20 @@ const sharedOptimizationSplitChunksCacheGroup = {
 	automaticNameDelimiter: z.string().optional()
 };
 const optimizationSplitChunksCacheGroup = z.strictObject({
-	test: z.string().or(z.instanceof(RegExp)).or(anyFunction).optional(),
+	test: z
+		.string()
+		.or(z.instanceof(RegExp))
+		.or(anyFunction)
+		.or(
+			z.strictObject({
+				package: z.strictObject({
+					name: z.string().optional(),
+					license: z.string().optional(),
+					source: z.enum(["node_modules", "workspace"]).optional()
+				})
+			})
+		)
+		.optional(),
 	priority: z.number().optional(),
 	enforce: z.boolean().optional(),
 	reuseExistingChunk: z.boolean().optional(),
@@ -1233,6 +1258,7 @@ const optimizationSplitChunksOptions = z.strictObject({
 		})
 		.optional(),
 	hidePathInfo: z.boolean().optional(),
+	explain: z.boolean().optional(),
 	...sharedOptimizationSplitChunksCacheGroup
 }) satisfies z.ZodType<t.OptimizationSplitChunksOptions>;
 
@@ -1419,6 +1445,12 @@ const amd = z
 const bail = z.boolean() satisfies z.ZodType<t.Bail>;
 //#endregion
 
+//#region Records
+const recordsPath = z
+	.literal(false)
+	.or(z.string()) satisfies z.ZodType<t.RecordsPath>;
+//#endregion
+
 //#region Performance
 const performance = z
 	.strictObject({
@@ -1462,5 +1494,8 @@ export const rspackOptions = z.strictObject({
 	profile: profile.optional(),
 	amd: amd.optional(),
 	bail: bail.optional(),
-	performance: performance.optional()
+	performance: performance.optional(),
+	recordsPath: recordsPath.optional(),
+	recordsInputPath: recordsPath.optional(),
+	recordsOutputPath: recordsPath.optional()
 }) satisfies z.ZodType<t.RspackOptions>;
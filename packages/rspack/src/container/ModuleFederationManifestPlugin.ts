@@ -0,0 +1,32 @@
+import {
+	BuiltinPluginName,
+	type RawModuleFederationManifestPluginOptions
+} from "@rspack/binding";
+
+import { create } from "../builtin-plugin/base";
+
+export type ModuleFederationManifestPluginOptions = {
+	/**
+	 * The name of the federated application.
+	 * Defaults to the container name, then `output.uniqueName`.
+	 */
+	name?: string;
+	/**
+	 * The filename of the manifest.
+	 * @default "mf-manifest.json"
+	 */
+	filename?: string;
+	/**
+	 * The filename of the stats, which additionally lists the source files of
+	 * the exposes and the modules using each shared package and remote.
+	 * @default "mf-stats.json"
+	 */
+	statsFilename?: string;
+};
+
+export const ModuleFederationManifestPlugin = create(
+	BuiltinPluginName.ModuleFederationManifestPlugin,
+	(
+		options: ModuleFederationManifestPluginOptions = {}
+	): RawModuleFederationManifestPluginOptions => options
+);
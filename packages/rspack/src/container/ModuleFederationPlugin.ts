@@ -1,9 +1,21 @@
 import type { Compiler } from "../Compiler";
 import type { ExternalsType } from "../config";
 import { externalsType } from "../config/zod";
+import {
+	SharedVersionCheckPlugin,
+	type SharedVersionCheckPluginOptions
+} from "../sharing/SharedVersionCheckPlugin";
 import { isValidate } from "../util/validate";
+import {
+	ModuleFederationManifestPlugin,
+	type ModuleFederationManifestPluginOptions
+} from "./ModuleFederationManifestPlugin";
 import type { ModuleFederationPluginV1Options } from "./ModuleFederationPluginV1";
 import { ModuleFederationRuntimePlugin } from "./ModuleFederationRuntimePlugin";
+import {
+	ModuleFederationTypesPlugin,
+	type ModuleFederationTypesPluginOptions
+} from "./ModuleFederationTypesPlugin";
 import { parseOptions } from "./options";
 
 export interface ModuleFederationPluginOptions
@@ -11,6 +23,21 @@ export interface ModuleFederationPluginOptions
 	runtimePlugins?: RuntimePlugins;
 	implementation?: string;
 	shareStrategy?: "version-first" | "loaded-first";
+	/**
+	 * Emits `mf-manifest.json` and `mf-stats.json` describing the exposes,
+	 * remotes and shared packages of the build.
+	 */
+	manifest?: boolean | Omit<ModuleFederationManifestPluginOptions, "name">;
+	/**
+	 * Reports shared version conflicts at build time, optionally together with
+	 * the manifests of the other federated builds.
+	 */
+	sharedVersionCheck?: boolean | SharedVersionCheckPluginOptions;
+	/**
+	 * Emits the declarations of the exposes as a types archive next to the
+	 * remote entry, and extracts the types archives of the remotes.
+	 */
+	dts?: boolean | ModuleFederationTypesPluginOptions;
 }
 export type RuntimePlugins = string[];
 
@@ -37,6 +64,24 @@ export class ModuleFederationPlugin {
 			...this._options,
 			enhanced: true
 		}).apply(compiler);
+		if (this._options.manifest) {
+			new ModuleFederationManifestPlugin({
+				...(this._options.manifest === true ? {} : this._options.manifest),
+				name: this._options.name
+			}).apply(compiler);
+		}
+		if (this._options.sharedVersionCheck) {
+			new SharedVersionCheckPlugin(
+				this._options.sharedVersionCheck === true
+					? {}
+					: this._options.sharedVersionCheck
+			).apply(compiler);
+		}
+		if (this._options.dts) {
+			new ModuleFederationTypesPlugin(
+				this._options.dts === true ? {} : this._options.dts
+			).apply(compiler);
+		}
 	}
 }
 
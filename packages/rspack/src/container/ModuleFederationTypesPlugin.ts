@@ -0,0 +1,47 @@
+import {
+	BuiltinPluginName,
+	type RawModuleFederationTypesPluginOptions
+} from "@rspack/binding";
+
+import type { Compiler } from "../Compiler";
+import { getRawHttpUriPluginOptions } from "../builtin-plugin/HttpUriPlugin";
+import { create } from "../builtin-plugin/base";
+
+/**
+ * Packages the declarations of the exposes into a types archive next to the
+ * remote entry, and extracts the archives of the remotes into `typesFolder`.
+ *
+ * The declarations of an expose are read from the `.d.ts` next to its source.
+ * Typescript sources without one get their declarations emitted as with
+ * `isolatedDeclarations`, so their exports need explicit type annotations.
+ * Javascript sources without a declaration file are skipped with a warning.
+ */
+export type ModuleFederationTypesPluginOptions = {
+	/**
+	 * The filename of the types archive, emitted next to the remote entry and
+	 * fetched next to the remote entries of the remotes.
+	 * @default "@mf-types.zip"
+	 */
+	archiveFilename?: string;
+	/**
+	 * Where the types of the remotes are extracted, relative to `context`, one
+	 * folder per remote. `false` to not fetch the types of the remotes.
+	 * @default "@mf-types"
+	 */
+	typesFolder?: string | false;
+};
+
+export const ModuleFederationTypesPlugin = create(
+	BuiltinPluginName.ModuleFederationTypesPlugin,
+	function (
+		this: Compiler,
+		options: ModuleFederationTypesPluginOptions = {}
+	): RawModuleFederationTypesPluginOptions {
+		// the archives of `http(s)` remotes are fetched like `http(s)` modules
+		const { buildHttp } = this.options.experiments;
+		return {
+			...options,
+			http: buildHttp ? getRawHttpUriPluginOptions(this, buildHttp) : undefined
+		};
+	}
+);
@@ -204,31 +204,53 @@ interface Webworker {
 
 export const webworker: Webworker = { WebWorkerTemplatePlugin };
 
+import { GranularChunksPlugin } from "./builtin-plugin";
 import { LimitChunkCountPlugin } from "./builtin-plugin";
 import { RuntimeChunkPlugin } from "./builtin-plugin";
 import { SplitChunksPlugin } from "./builtin-plugin";
 import { RemoveDuplicateModulesPlugin } from "./builtin-plugin";
 import { RsdoctorPlugin } from "./builtin-plugin";
 import { CssChunkingPlugin } from "./builtin-plugin";
+import { CriticalCssPlugin } from "./builtin-plugin";
+import { CssModulesTypingsPlugin } from "./builtin-plugin";
 
 interface Optimize {
+	GranularChunksPlugin: typeof GranularChunksPlugin;
 	LimitChunkCountPlugin: typeof LimitChunkCountPlugin;
 	RuntimeChunkPlugin: typeof RuntimeChunkPlugin;
 	SplitChunksPlugin: typeof SplitChunksPlugin;
 }
 
 export const optimize: Optimize = {
+	GranularChunksPlugin,
 	LimitChunkCountPlugin,
 	RuntimeChunkPlugin,
 	SplitChunksPlugin
 };
 
+export type { GranularChunksPluginOptions } from "./builtin-plugin";
+
+import { HashedModuleIdsPlugin } from "./builtin-plugin";
+
+export type { HashedModuleIdsPluginOptions } from "./builtin-plugin";
+
+interface Ids {
+	HashedModuleIdsPlugin: typeof HashedModuleIdsPlugin;
+}
+
+export const ids: Ids = { HashedModuleIdsPlugin };
+
+import { ModuleFederationManifestPlugin } from "./container/ModuleFederationManifestPlugin";
 import { ModuleFederationPlugin } from "./container/ModuleFederationPlugin";
 
+export type { ModuleFederationManifestPluginOptions } from "./container/ModuleFederationManifestPlugin";
 export type { ModuleFederationPluginOptions } from "./container/ModuleFederationPlugin";
 import { ModuleFederationPluginV1 } from "./container/ModuleFederationPluginV1";
 
 export type { ModuleFederationPluginV1Options } from "./container/ModuleFederationPluginV1";
+import { ModuleFederationTypesPlugin } from "./container/ModuleFederationTypesPlugin";
+
+export type { ModuleFederationTypesPluginOptions } from "./container/ModuleFederationTypesPlugin";
 import { ContainerPlugin } from "./container/ContainerPlugin";
 import { ContainerReferencePlugin } from "./container/ContainerReferencePlugin";
 
@@ -251,13 +273,16 @@ export type {
 export const container = {
 	ContainerPlugin,
 	ContainerReferencePlugin,
+	ModuleFederationManifestPlugin,
 	ModuleFederationPlugin,
-	ModuleFederationPluginV1
+	ModuleFederationPluginV1,
+	ModuleFederationTypesPlugin
 };
 
 import { ConsumeSharedPlugin } from "./sharing/ConsumeSharedPlugin";
 import { ProvideSharedPlugin } from "./sharing/ProvideSharedPlugin";
 import { SharePlugin } from "./sharing/SharePlugin";
+import { SharedVersionCheckPlugin } from "./sharing/SharedVersionCheckPlugin";
 
 export type {
 	Consumes,
@@ -280,10 +305,12 @@ export type {
 	SharedObject,
 	SharePluginOptions
 } from "./sharing/SharePlugin";
+export type { SharedVersionCheckPluginOptions } from "./sharing/SharedVersionCheckPlugin";
 export const sharing = {
 	ProvideSharedPlugin,
 	ConsumeSharedPlugin,
-	SharePlugin
+	SharePlugin,
+	SharedVersionCheckPlugin
 };
 
 ///// Rspack Postfixed Internal Plugins /////
@@ -294,6 +321,7 @@ export type { LightningCssMinimizerRspackPluginOptions } from "./builtin-plugin"
 export type { CircularDependencyRspackPluginOptions } from "./builtin-plugin";
 export type { CopyRspackPluginOptions } from "./builtin-plugin";
 export type { SourceMapDevToolPluginOptions } from "./builtin-plugin";
+export type { SourceMapValidationPluginOptions } from "./builtin-plugin";
 export type { EvalDevToolModulePluginOptions } from "./builtin-plugin";
 export type {
 	CssExtractRspackLoaderOptions,
@@ -307,7 +335,9 @@ export { CopyRspackPlugin } from "./builtin-plugin";
 export { SourceMapDevToolPlugin } from "./builtin-plugin";
 export { EvalSourceMapDevToolPlugin } from "./builtin-plugin";
 export { EvalDevToolModulePlugin } from "./builtin-plugin";
+export { SourceMapValidationPlugin } from "./builtin-plugin";
 export { CssExtractRspackPlugin } from "./builtin-plugin";
+export { VueLoaderRspackPlugin } from "./builtin-plugin";
 export { ContextReplacementPlugin } from "./builtin-plugin";
 
 ///// Rspack Postfixed Internal Loaders /////
@@ -336,6 +366,9 @@ import { JavaScriptTracer } from "./trace";
 ///// Experiments SWC /////
 import { minify, transform } from "./swc";
 
+///// Experiments Symbolicate /////
+import { symbolicateStackTrace } from "./symbolicate";
+
 interface Experiments {
 	globalTrace: {
 		register: (
@@ -353,7 +386,10 @@ interface Experiments {
 		transform: typeof transform;
 		minify: typeof minify;
 	};
+	symbolicateStackTrace: typeof symbolicateStackTrace;
 	CssChunkingPlugin: typeof CssChunkingPlugin;
+	CriticalCssPlugin: typeof CriticalCssPlugin;
+	CssModulesTypingsPlugin: typeof CssModulesTypingsPlugin;
 }
 
 export const experiments: Experiments = {
@@ -384,5 +420,8 @@ export const experiments: Experiments = {
 		minify,
 		transform
 	},
-	CssChunkingPlugin
+	symbolicateStackTrace,
+	CssChunkingPlugin,
+	CriticalCssPlugin,
+	CssModulesTypingsPlugin
 };
@@ -0,0 +1,27 @@
+import {
+	BuiltinPluginName,
+	type RawSharedVersionCheckPluginOptions
+} from "@rspack/binding";
+
+import { create } from "../builtin-plugin/base";
+
+export type SharedVersionCheckPluginOptions = {
+	/**
+	 * Paths to the `mf-stats.json` or `mf-manifest.json` of the other federated
+	 * builds, relative to `context`. Their provided and required versions are
+	 * checked together with the ones of this build.
+	 */
+	manifests?: string[];
+};
+
+/**
+ * Reports shared modules whose required version can't be satisfied by any
+ * provided version, and singletons loaded in a version that doesn't satisfy
+ * all of their consumers.
+ */
+export const SharedVersionCheckPlugin = create(
+	BuiltinPluginName.SharedVersionCheckPlugin,
+	(
+		options: SharedVersionCheckPluginOptions = {}
+	): RawSharedVersionCheckPluginOptions => options
+);
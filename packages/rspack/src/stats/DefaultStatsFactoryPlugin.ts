@@ -14,6 +14,7 @@ import type {
 	JsStatsAssetInfo,
 	JsStatsError,
 	JsStatsModule,
+	JsStatsSize,
 	JsStatsWarning
 } from "@rspack/binding";
 import type { Chunk } from "../Chunk";
@@ -1226,6 +1227,9 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			]);
 			sizes.sort((a, b) => -compareIds(a, b));
 			object.sizes = Object.fromEntries(sizes);
+			if (commonAttributes.minimizedSize !== undefined) {
+				object.minimizedSize = commonAttributes.minimizedSize;
+			}
 			object.built = commonAttributes.built;
 			object.codeGenerated = commonAttributes.codeGenerated;
 			object.buildTimeExecuted = commonAttributes.buildTimeExecuted;
@@ -1406,6 +1410,22 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			object.initial = chunk.initial;
 			object.entry = chunk.entry;
 			object.reason = chunk.reason;
+			if (chunk.splitChunks) {
+				const toSizes = (sizes: JsStatsSize[]) =>
+					Object.fromEntries(
+						sizes.map(({ sourceType, size }) => [sourceType, size])
+					);
+				object.splitChunks = {
+					decisions: chunk.splitChunks.decisions.map(decision => ({
+						...decision,
+						minSize: toSizes(decision.minSize),
+						maxAsyncSize: toSizes(decision.maxAsyncSize),
+						maxInitialSize: toSizes(decision.maxInitialSize)
+					})),
+					maxSizePart: chunk.splitChunks.maxSizePart,
+					blocked: chunk.splitChunks.blocked
+				};
+			}
 			object.size = chunk.size;
 			object.sizes = Object.fromEntries(
 				chunk.sizes.map(({ sourceType, size }) => [sourceType, size])
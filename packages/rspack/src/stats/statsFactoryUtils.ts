@@ -26,6 +26,24 @@ export type KnownStatsChunkGroup = {
 	isOverSizeLimit?: boolean;
 };
 
+export type StatsChunkSplitChunks = {
+	/** The cache groups whose modules were moved into the chunk. */
+	decisions: {
+		cacheGroup: string;
+		name?: string;
+		priority: number;
+		modules: number;
+		minSize: Record<string, number>;
+		maxAsyncSize: Record<string, number>;
+		maxInitialSize: Record<string, number>;
+		reusedExistingChunk: boolean;
+	}[];
+	/** The key of the part when the chunk was split by `maxSize`. */
+	maxSizePart?: string;
+	/** The splits which kept their modules in this chunk. */
+	blocked: string[];
+};
+
 export type KnownStatsChunk = {
 	type: string;
 	rendered: boolean;
@@ -33,6 +51,7 @@ export type KnownStatsChunk = {
 	entry: boolean;
 	// recorded: boolean;
 	reason?: string;
+	splitChunks?: StatsChunkSplitChunks;
 	size: number;
 	sizes?: Record<string, number>;
 	names?: string[];
@@ -107,6 +126,7 @@ export type KnownStatsModule = {
 	postOrderIndex?: number;
 	size: number;
 	sizes: Record<string, number>;
+	minimizedSize?: number;
 	cacheable?: boolean;
 	built: boolean;
 	codeGenerated: boolean;
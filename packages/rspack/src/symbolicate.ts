@@ -0,0 +1,15 @@
+import binding from "@rspack/binding";
+
+/**
+ * Maps the frames of a stack trace back to the original sources with the
+ * source maps of an output directory. Source maps kept apart from the output,
+ * such as maps not uploaded to the CDN, are looked up in `sourceMapDirs` and
+ * matched by the debug id of the assets.
+ */
+export function symbolicateStackTrace(
+	stack: string,
+	outputDir: string,
+	sourceMapDirs: string[] = []
+): string {
+	return binding.symbolicateStackTrace(stack, outputDir, sourceMapDirs);
+}
@@ -366,6 +366,7 @@ describe("snapshots", () => {
 		    "ignoreBrowserWarnings": undefined,
 		    "iife": true,
 		    "importFunctionName": "import",
+		    "importMap": undefined,
 		    "importMetaName": "import.meta",
 		    "library": undefined,
 		    "module": false,